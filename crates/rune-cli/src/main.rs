@@ -47,7 +47,7 @@ use std::fmt::Write as _;
 use std::path::PathBuf;
 use std::sync::Arc;
 
-use runestick::{Item, UnitFnKind, Value, VmExecution};
+use runestick::{Capabilities, Capability, Item, UnitFnKind, Value, VmExecution};
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -63,6 +63,7 @@ async fn main() -> Result<()> {
     let mut dump_functions = false;
     let mut dump_types = false;
     let mut help = false;
+    let mut capabilities = Capabilities::none();
 
     let mut options = rune::Options::default();
 
@@ -90,6 +91,33 @@ async fn main() -> Result<()> {
             "--dump-types" => {
                 dump_types = true;
             }
+            "--allow" => {
+                let name = match args.next() {
+                    Some(name) => name,
+                    None => {
+                        println!("expected capability to `--allow`");
+                        return Ok(());
+                    }
+                };
+
+                let capability = Capability::ALL
+                    .iter()
+                    .copied()
+                    .find(|capability| capability.to_string() == name);
+
+                match capability {
+                    Some(capability) => {
+                        capabilities = capabilities.with(capability);
+                    }
+                    None => {
+                        println!("Unrecognized capability: {}", name);
+                        help = true;
+                    }
+                }
+            }
+            "--allow-all" => {
+                capabilities = Capabilities::all();
+            }
             "-O" => {
                 let opt = match args.next() {
                     Some(opt) => opt,
@@ -127,6 +155,8 @@ async fn main() -> Result<()> {
         println!("  --dump-functions  - Dump available functions.");
         println!("  --dump-types      - Dump available types.");
         println!("  --no-linking      - Disable link time checks.");
+        println!("  --allow <cap>     - Grant scripts the given capability, one of `net`, `fs`, `env`, `process` or `stdin`.");
+        println!("  --allow-all       - Grant scripts all capabilities.");
         println!();
         println!("Compiler options:");
         println!("  -O <option>       - Update the given compiler option.");
@@ -134,6 +164,7 @@ async fn main() -> Result<()> {
         println!("Available <option> arguments:");
         println!("  memoize-instance-fn[=<true/false>] - Inline the lookup of an instance function where appropriate.");
         println!("  link-checks[=<true/false>] - Perform linker checks which makes sure that called functions exist.");
+        println!("  catch-panics[=<true/false>] - Make `try` blocks catch panics and convert them into errors.");
         return Ok(());
     }
 
@@ -144,7 +175,7 @@ async fn main() -> Result<()> {
         }
     };
 
-    let context = Arc::new(rune::default_context()?);
+    let context = Arc::new(rune::default_context_with(capabilities)?);
     let mut warnings = rune::Warnings::new();
 
     let unit = match rune::load_path(&*context, &options, &path, &mut warnings) {
@@ -180,47 +211,8 @@ async fn main() -> Result<()> {
     }
 
     if dump_unit {
-        use std::io::Write as _;
-
         println!("# instructions:");
-
-        let mut first_function = true;
-
-        for (n, inst) in vm.unit().iter_instructions().enumerate() {
-            let out = std::io::stdout();
-            let mut out = out.lock();
-
-            let debug_inst = vm
-                .unit()
-                .debug_info()
-                .and_then(|debug| debug.instruction_at(n));
-
-            if let Some((hash, function)) = vm.unit().function_at(n) {
-                if first_function {
-                    first_function = false;
-                } else {
-                    println!();
-                }
-
-                println!("fn {} ({}):", function.signature, hash);
-            }
-
-            if let Some(inst) = debug_inst {
-                if let Some(label) = inst.label {
-                    println!("{}:", label);
-                }
-            }
-
-            write!(out, "  {:04} = {}", n, inst)?;
-
-            if let Some(inst) = debug_inst {
-                if let Some(comment) = &inst.comment {
-                    write!(out, " // {}", comment)?;
-                }
-            }
-
-            println!();
-        }
+        print!("{}", vm.unit().disassemble());
 
         println!("# imports:");
 
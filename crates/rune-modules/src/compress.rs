@@ -0,0 +1,132 @@
+//! The native `compress` module for the [Rune Language].
+//!
+//! [Rune Language]: https://github.com/rune-rs/rune
+//!
+//! ## Usage
+//!
+//! Add the following to your `Cargo.toml`:
+//!
+//! ```toml
+//! rune-modules = {version = "0.6.16", features = ["compress"]}
+//! ```
+//!
+//! Install it into your context:
+//!
+//! ```rust
+//! # fn main() -> runestick::Result<()> {
+//! let mut context = runestick::Context::with_default_modules()?;
+//! context.install(&rune_modules::compress::module()?)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Use it in Rune:
+//!
+//! ```rust,ignore
+//! use compress;
+//!
+//! fn main() {
+//!     let data = compress::gzip_encode(b"hello world")?;
+//!     let data = compress::gzip_decode(data)?;
+//!     dbg(data);
+//! }
+//! ```
+
+use flate2::read::{DeflateDecoder, GzDecoder};
+use flate2::write::{DeflateEncoder, GzEncoder};
+use flate2::Compression;
+use runestick::{Bytes, ContextError, Module};
+use std::fmt;
+use std::fmt::Write as _;
+use std::io;
+use std::io::{Read as _, Write as _};
+
+/// Construct the `compress` module.
+pub fn module() -> Result<Module, ContextError> {
+    let mut module = Module::new(&["compress"]);
+    module.ty(&["Error"]).build::<Error>()?;
+    module.function(&["gzip_encode"], gzip_encode)?;
+    module.function(&["gzip_decode"], gzip_decode)?;
+    module.function(&["deflate_encode"], deflate_encode)?;
+    module.function(&["deflate_decode"], deflate_decode)?;
+    module.inst_fn(runestick::STRING_DISPLAY, Error::display)?;
+    Ok(module)
+}
+
+/// Error raised when compressing or decompressing data fails.
+#[derive(Debug)]
+pub struct Error {
+    inner: io::Error,
+}
+
+impl Error {
+    fn display(&self, buf: &mut String) -> fmt::Result {
+        write!(buf, "{}", self.inner)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(inner: io::Error) -> Self {
+        Self { inner }
+    }
+}
+
+/// Compress the given bytes using gzip.
+fn gzip_encode(bytes: &[u8]) -> Result<Bytes, Error> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(bytes)?;
+    Ok(Bytes::from_vec(encoder.finish()?))
+}
+
+/// Decompress the given gzip-compressed bytes.
+fn gzip_decode(bytes: &[u8]) -> Result<Bytes, Error> {
+    let mut output = Vec::new();
+    GzDecoder::new(bytes).read_to_end(&mut output)?;
+    Ok(Bytes::from_vec(output))
+}
+
+/// Compress the given bytes using raw deflate.
+fn deflate_encode(bytes: &[u8]) -> Result<Bytes, Error> {
+    let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(bytes)?;
+    Ok(Bytes::from_vec(encoder.finish()?))
+}
+
+/// Decompress the given raw deflate-compressed bytes.
+fn deflate_decode(bytes: &[u8]) -> Result<Bytes, Error> {
+    let mut output = Vec::new();
+    DeflateDecoder::new(bytes).read_to_end(&mut output)?;
+    Ok(Bytes::from_vec(output))
+}
+
+runestick::impl_external!(Error);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &[u8] = b"hello hello hello hello world";
+
+    #[test]
+    fn test_gzip() {
+        let encoded = gzip_encode(INPUT).unwrap();
+        assert_eq!(&encoded[..2], &[0x1f, 0x8b]);
+        assert_eq!(&gzip_decode(&encoded).unwrap()[..], INPUT);
+        assert_eq!(&gzip_decode(&gzip_encode(b"").unwrap()).unwrap()[..], b"");
+        assert!(gzip_decode(b"not gzip at all").is_err());
+        assert!(gzip_decode(&encoded[..encoded.len() / 2]).is_err());
+    }
+
+    #[test]
+    fn test_deflate() {
+        let encoded = deflate_encode(INPUT).unwrap();
+        assert!(encoded.len() < INPUT.len());
+        assert_eq!(&deflate_decode(&encoded).unwrap()[..], INPUT);
+        assert!(deflate_decode(&[0xff, 0xff, 0xff, 0xff]).is_err());
+
+        let mut buf = String::new();
+        let error = deflate_decode(&[0xff, 0xff, 0xff, 0xff]).unwrap_err();
+        error.display(&mut buf).unwrap();
+        assert!(!buf.is_empty());
+    }
+}
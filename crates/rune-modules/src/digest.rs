@@ -0,0 +1,137 @@
+//! The native `digest` module for the [Rune Language].
+//!
+//! [Rune Language]: https://github.com/rune-rs/rune
+//!
+//! ## Usage
+//!
+//! Add the following to your `Cargo.toml`:
+//!
+//! ```toml
+//! rune-modules = {version = "0.6.16", features = ["digest"]}
+//! ```
+//!
+//! Install it into your context:
+//!
+//! ```rust
+//! # fn main() -> runestick::Result<()> {
+//! let mut context = runestick::Context::with_default_modules()?;
+//! context.install(&rune_modules::digest::module()?)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Use it in Rune:
+//!
+//! ```rust,ignore
+//! use digest;
+//!
+//! fn main() {
+//!     let digest = digest::sha256("hello world");
+//!     dbg(digest);
+//! }
+//! ```
+//!
+//! All functions accept either a string or bytes, and return the raw digest
+//! as bytes.
+
+use runestick::{Bytes, ContextError, Module, Value, VmError};
+use sha2::Digest as _;
+
+/// Construct the `digest` module.
+pub fn module() -> Result<Module, ContextError> {
+    let mut module = Module::new(&["digest"]);
+    module.function(&["sha256"], sha256)?;
+    module.function(&["md5"], md5)?;
+    module.function(&["blake3"], blake3)?;
+    Ok(module)
+}
+
+/// Calculate the SHA-256 digest of the given string or bytes.
+fn sha256(value: Value) -> Result<Bytes, VmError> {
+    digest(&value, |bytes| sha2::Sha256::digest(bytes).to_vec())
+}
+
+/// Calculate the MD5 digest of the given string or bytes.
+fn md5(value: Value) -> Result<Bytes, VmError> {
+    digest(&value, |bytes| md5::Md5::digest(bytes).to_vec())
+}
+
+/// Calculate the BLAKE3 digest of the given string or bytes.
+fn blake3(value: Value) -> Result<Bytes, VmError> {
+    digest(&value, |bytes| blake3::hash(bytes).as_bytes().to_vec())
+}
+
+/// Run the digest function over the bytes of a string or bytes value.
+fn digest<F>(value: &Value, f: F) -> Result<Bytes, VmError>
+where
+    F: FnOnce(&[u8]) -> Vec<u8>,
+{
+    let output = match value {
+        Value::Bytes(bytes) => f(&bytes.borrow_ref()?),
+        Value::String(string) => f(string.borrow_ref()?.as_bytes()),
+        Value::StaticString(string) => f(string.as_bytes()),
+        actual => return Err(VmError::bad_argument::<Bytes>(0, actual)?),
+    };
+
+    Ok(Bytes::from_vec(output))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use runestick::Shared;
+
+    fn bytes(bytes: &[u8]) -> Value {
+        Value::from(Shared::new(Bytes::from_vec(bytes.to_vec())))
+    }
+
+    fn string(string: &str) -> Value {
+        Value::from(Shared::new(String::from(string)))
+    }
+
+    fn hex(bytes: Result<Bytes, VmError>) -> String {
+        bytes
+            .unwrap()
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect()
+    }
+
+    #[test]
+    fn test_sha256() {
+        assert_eq!(
+            hex(sha256(bytes(b""))),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            hex(sha256(bytes(b"abc"))),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(hex(sha256(string("abc"))), hex(sha256(bytes(b"abc"))));
+    }
+
+    #[test]
+    fn test_md5() {
+        assert_eq!(hex(md5(bytes(b""))), "d41d8cd98f00b204e9800998ecf8427e");
+        assert_eq!(hex(md5(bytes(b"abc"))), "900150983cd24fb0d6963f7d28e17f72");
+        assert_eq!(hex(md5(string("abc"))), hex(md5(bytes(b"abc"))));
+    }
+
+    #[test]
+    fn test_blake3() {
+        assert_eq!(
+            hex(blake3(bytes(b""))),
+            "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262"
+        );
+        assert_eq!(
+            hex(blake3(bytes(b"abc"))),
+            "6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85"
+        );
+        assert_eq!(hex(blake3(string("abc"))), hex(blake3(bytes(b"abc"))));
+    }
+
+    #[test]
+    fn test_bad_argument() {
+        assert!(sha256(Value::Integer(1)).is_err());
+    }
+}
@@ -0,0 +1,50 @@
+//! The native `env` module for the [Rune Language].
+//!
+//! [Rune Language]: https://github.com/rune-rs/rune
+//!
+//! ## Usage
+//!
+//! Add the following to your `Cargo.toml`:
+//!
+//! ```toml
+//! rune-modules = {version = "0.6.16", features = ["env"]}
+//! ```
+//!
+//! Install it into your context:
+//!
+//! ```rust
+//! # fn main() -> runestick::Result<()> {
+//! let mut context = runestick::Context::with_default_modules()?;
+//! context.grant(runestick::Capability::Env);
+//! context.install(&rune_modules::env::module()?)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Use it in Rune:
+//!
+//! ```rust,ignore
+//! fn main() {
+//!     if let Some(home) = env::var("HOME") {
+//!         println(`home is {home}`);
+//!     }
+//! }
+//! ```
+
+use std::env;
+
+/// Construct the `env` module.
+///
+/// Requires the [env][runestick::Capability::Env] capability.
+pub fn module() -> Result<runestick::Module, runestick::ContextError> {
+    let mut module = runestick::Module::new(&["env"]);
+    module.require(runestick::Capability::Env);
+    module.function(&["var"], var)?;
+    Ok(module)
+}
+
+/// Get the value of the given environment variable, or `None` if it isn't set
+/// or isn't valid unicode.
+fn var(name: &str) -> Option<String> {
+    env::var(name).ok()
+}
@@ -15,6 +15,7 @@
 //! ```rust
 //! # fn main() -> runestick::Result<()> {
 //! let mut context = runestick::Context::with_default_modules()?;
+//! context.grant(runestick::Capability::Fs);
 //! context.install(&rune_modules::fs::module()?)?;
 //! # Ok(())
 //! # }
@@ -33,8 +34,11 @@ use std::io;
 use tokio::fs;
 
 /// Construct the `fs` module.
+///
+/// Requires the [fs][runestick::Capability::Fs] capability.
 pub fn module() -> Result<runestick::Module, runestick::ContextError> {
     let mut module = runestick::Module::new(&["fs"]);
+    module.require(runestick::Capability::Fs);
     module.async_function(&["read_to_string"], read_to_string)?;
     Ok(module)
 }
@@ -15,6 +15,7 @@
 //! ```rust
 //! # fn main() -> runestick::Result<()> {
 //! let mut context = runestick::Context::with_default_modules()?;
+//! context.grant(runestick::Capability::Net);
 //! context.install(&rune_modules::http::module()?)?;
 //! context.install(&rune_modules::json::module()?)?;
 //! # Ok(())
@@ -30,6 +31,11 @@
 //! fn main() {
 //!     let client = http::Client::new();
 //!     let response = client.get("http://worldtimeapi.org/api/ip");
+//!
+//!     if response.status().as_int() != 200 {
+//!         dbg(response.header("content-type"));
+//!     }
+//!
 //!     let text = response.text();
 //!     let json = json::from_string(text);
 //!
@@ -39,62 +45,219 @@
 //!         dbg(timezone);
 //!     }
 //!
-//!     let body = json::to_bytes(#{"hello": "world"});
-//!
 //!     let response = client.post("https://postman-echo.com/post")
-//!         .body_bytes(body)
+//!         .json(#{"hello": "world"})?
 //!         .send();
 //!
 //!     let response = json::from_string(response.text());
 //!     dbg(response);
+//!
+//!     let response = client.post("https://postman-echo.com/post")
+//!         .form(#{"name": "rune", "kind": "language"})?
+//!         .send();
+//!
+//!     let response = json::from_string(response.text());
+//!     dbg(response["form"]);
 //! }
 //! ```
 
-use runestick::Bytes;
+use runestick::{Bytes, FromValue as _, Object, Value};
 use std::fmt;
 use std::fmt::Write as _;
+use std::time::Duration;
 
 /// Construct the `http` module.
+///
+/// Requires the [net][runestick::Capability::Net] capability.
 pub fn module() -> Result<runestick::Module, runestick::ContextError> {
     let mut module = runestick::Module::new(&["http"]);
+    module.require(runestick::Capability::Net);
 
     module.ty(&["Client"]).build::<Client>()?;
+    module.ty(&["ClientBuilder"]).build::<ClientBuilder>()?;
     module.ty(&["Response"]).build::<Response>()?;
     module.ty(&["RequestBuilder"]).build::<RequestBuilder>()?;
     module.ty(&["StatusCode"]).build::<StatusCode>()?;
     module.ty(&["Error"]).build::<Error>()?;
+    module.ty(&["Form"]).build::<Form>()?;
 
     module.function(&["Client", "new"], Client::new)?;
+    module.function(&["Client", "builder"], Client::builder)?;
+    module.function(&["Form", "new"], Form::new)?;
     module.async_function(&["get"], get)?;
 
     module.async_inst_fn("get", Client::get)?;
     module.async_inst_fn("post", Client::post)?;
 
+    module.inst_fn("timeout", ClientBuilder::timeout)?;
+    module.inst_fn("base_url", ClientBuilder::base_url)?;
+    module.inst_fn("default_header", ClientBuilder::default_header)?;
+    module.inst_fn("build", ClientBuilder::build)?;
+
     module.async_inst_fn("text", Response::text)?;
     module.inst_fn("status", Response::status)?;
+    module.inst_fn("header", Response::header)?;
+    module.inst_fn("headers", Response::headers)?;
+
+    module.inst_fn("as_int", StatusCode::as_int)?;
 
     module.async_inst_fn("send", RequestBuilder::send)?;
     module.inst_fn("header", RequestBuilder::header)?;
     module.async_inst_fn("body_bytes", RequestBuilder::body_bytes)?;
+    module.inst_fn("form", RequestBuilder::form)?;
+    module.inst_fn("json", RequestBuilder::json)?;
+    module.inst_fn("multipart", RequestBuilder::multipart)?;
+
+    module.inst_fn("text", Form::text)?;
+    module.inst_fn("bytes", Form::bytes)?;
 
     module.inst_fn(runestick::STRING_DISPLAY, StatusCode::display)?;
+    module.inst_fn(runestick::STRING_DISPLAY, Error::display)?;
     Ok(module)
 }
 
 #[derive(Debug)]
 pub struct Error {
-    inner: reqwest::Error,
+    kind: ErrorKind,
+}
+
+#[derive(Debug)]
+enum ErrorKind {
+    Reqwest(reqwest::Error),
+    /// A form field was not a string.
+    FormValue {
+        key: String,
+    },
+    /// A value could not be serialized as json.
+    Json(serde_json::Error),
+    /// A url could not be parsed.
+    Url(url::ParseError),
+    /// A header name was not valid.
+    HeaderName(reqwest::header::InvalidHeaderName),
+    /// A header value was not valid.
+    HeaderValue(reqwest::header::InvalidHeaderValue),
+}
+
+impl Error {
+    fn form_value(key: String) -> Self {
+        Self {
+            kind: ErrorKind::FormValue { key },
+        }
+    }
+
+    fn display(&self, buf: &mut String) -> fmt::Result {
+        match &self.kind {
+            ErrorKind::Reqwest(error) => write!(buf, "{}", error),
+            ErrorKind::FormValue { key } => {
+                write!(buf, "expected form field `{}` to be a string", key)
+            }
+            ErrorKind::Json(error) => write!(buf, "{}", error),
+            ErrorKind::Url(error) => write!(buf, "{}", error),
+            ErrorKind::HeaderName(error) => write!(buf, "{}", error),
+            ErrorKind::HeaderValue(error) => write!(buf, "{}", error),
+        }
+    }
 }
 
 impl From<reqwest::Error> for Error {
-    fn from(inner: reqwest::Error) -> Self {
-        Self { inner }
+    fn from(error: reqwest::Error) -> Self {
+        Self {
+            kind: ErrorKind::Reqwest(error),
+        }
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Self {
+        Self {
+            kind: ErrorKind::Json(error),
+        }
+    }
+}
+
+impl From<url::ParseError> for Error {
+    fn from(error: url::ParseError) -> Self {
+        Self {
+            kind: ErrorKind::Url(error),
+        }
+    }
+}
+
+impl From<reqwest::header::InvalidHeaderName> for Error {
+    fn from(error: reqwest::header::InvalidHeaderName) -> Self {
+        Self {
+            kind: ErrorKind::HeaderName(error),
+        }
+    }
+}
+
+impl From<reqwest::header::InvalidHeaderValue> for Error {
+    fn from(error: reqwest::header::InvalidHeaderValue) -> Self {
+        Self {
+            kind: ErrorKind::HeaderValue(error),
+        }
     }
 }
 
 #[derive(Debug)]
 struct Client {
     client: reqwest::Client,
+    /// The url that relative request urls are joined with.
+    base_url: Option<url::Url>,
+}
+
+#[derive(Debug)]
+struct ClientBuilder {
+    builder: reqwest::ClientBuilder,
+    base_url: Option<url::Url>,
+    headers: reqwest::header::HeaderMap,
+}
+
+impl ClientBuilder {
+    /// Set a timeout in milliseconds for requests made by the client.
+    fn timeout(self, millis: u64) -> Self {
+        Self {
+            builder: self.builder.timeout(Duration::from_millis(millis)),
+            ..self
+        }
+    }
+
+    /// Set the base url that the client joins request urls with.
+    ///
+    /// A trailing slash is added to the path if it's missing, so that
+    /// relative paths extend the base url rather than replace its last
+    /// segment.
+    fn base_url(self, base_url: &str) -> Result<Self, Error> {
+        let mut base_url = url::Url::parse(base_url)?;
+
+        if !base_url.path().ends_with('/') {
+            let path = format!("{}/", base_url.path());
+            base_url.set_path(&path);
+        }
+
+        Ok(Self {
+            base_url: Some(base_url),
+            ..self
+        })
+    }
+
+    /// Add a header that is sent with every request made by the client.
+    fn default_header(mut self, name: &str, value: &str) -> Result<Self, Error> {
+        let name = reqwest::header::HeaderName::from_bytes(name.as_bytes())?;
+        let value = reqwest::header::HeaderValue::from_str(value)?;
+        self.headers.append(name, value);
+        Ok(self)
+    }
+
+    /// Build the client.
+    fn build(self) -> Result<Client, Error> {
+        let client = self.builder.default_headers(self.headers).build()?;
+
+        Ok(Client {
+            client,
+            base_url: self.base_url,
+        })
+    }
 }
 
 #[derive(Debug)]
@@ -108,6 +271,11 @@ pub struct StatusCode {
 }
 
 impl StatusCode {
+    /// Get the status code as an integer.
+    fn as_int(&self) -> i64 {
+        i64::from(self.inner.as_u16())
+    }
+
     fn display(&self, buf: &mut String) -> fmt::Result {
         write!(buf, "{}", self.inner)
     }
@@ -125,6 +293,42 @@ impl Response {
 
         StatusCode { inner }
     }
+
+    /// Get the value of the header with the given name, if present.
+    ///
+    /// Multiple headers with the same name are joined with `, `.
+    fn header(&self, name: &str) -> Option<String> {
+        header_value(self.response.headers(), name)
+    }
+
+    /// Get all headers as an object keyed by their lowercase names.
+    ///
+    /// Multiple headers with the same name are joined with `, `.
+    fn headers(&self) -> Object<String> {
+        let headers = self.response.headers();
+        let mut object = runestick::object_with_capacity(headers.keys_len());
+
+        for name in headers.keys() {
+            if let Some(value) = header_value(headers, name.as_str()) {
+                object.insert(name.as_str().to_owned(), value);
+            }
+        }
+
+        object
+    }
+}
+
+/// Get the comma-joined value of all headers with the given name.
+fn header_value(headers: &reqwest::header::HeaderMap, name: &str) -> Option<String> {
+    let mut values = headers.get_all(name).iter();
+    let mut output = String::from_utf8_lossy(values.next()?.as_bytes()).into_owned();
+
+    for value in values {
+        output.push_str(", ");
+        output.push_str(&String::from_utf8_lossy(value.as_bytes()));
+    }
+
+    Some(output)
 }
 
 #[derive(Debug)]
@@ -154,24 +358,128 @@ impl RequestBuilder {
             request: self.request.body(bytes),
         })
     }
+
+    /// Set an `application/x-www-form-urlencoded` body from an object of
+    /// string fields.
+    fn form(self, form: Object<Value>) -> Result<Self, Error> {
+        let form = form_fields(form)?;
+
+        Ok(Self {
+            request: self.request.form(&form),
+        })
+    }
+
+    /// Set a `multipart/form-data` body.
+    fn multipart(self, form: Form) -> Self {
+        Self {
+            request: self.request.multipart(form.form),
+        }
+    }
+
+    /// Set a json body serialized from the given value, along with a
+    /// `Content-Type: application/json` header.
+    fn json(self, value: Value) -> Result<Self, Error> {
+        let body = serde_json::to_vec(&value)?;
+
+        Ok(Self {
+            request: self
+                .request
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(body),
+        })
+    }
+}
+
+/// A `multipart/form-data` body.
+#[derive(Debug)]
+pub struct Form {
+    form: reqwest::multipart::Form,
+}
+
+impl Form {
+    fn new() -> Self {
+        Self {
+            form: reqwest::multipart::Form::new(),
+        }
+    }
+
+    /// Add a text field to the form.
+    fn text(self, name: &str, value: &str) -> Self {
+        Self {
+            form: self.form.text(name.to_owned(), value.to_owned()),
+        }
+    }
+
+    /// Add a file part to the form with the given file name and content
+    /// type.
+    fn bytes(
+        self,
+        name: &str,
+        bytes: Bytes,
+        file_name: &str,
+        content_type: &str,
+    ) -> Result<Self, Error> {
+        let part = reqwest::multipart::Part::bytes(bytes.into_vec())
+            .file_name(file_name.to_owned())
+            .mime_str(content_type)?;
+
+        Ok(Self {
+            form: self.form.part(name.to_owned(), part),
+        })
+    }
+}
+
+/// Convert an object into form fields, erroring on values which aren't
+/// strings.
+fn form_fields(form: Object<Value>) -> Result<Vec<(String, String)>, Error> {
+    let mut fields = Vec::with_capacity(form.len());
+
+    for (key, value) in form {
+        let value = match String::from_value(value) {
+            Ok(value) => value,
+            Err(..) => return Err(Error::form_value(key)),
+        };
+
+        fields.push((key, value));
+    }
+
+    Ok(fields)
 }
 
 impl Client {
     fn new() -> Self {
         Self {
             client: reqwest::Client::new(),
+            base_url: None,
+        }
+    }
+
+    /// Construct a builder for a configured client.
+    fn builder() -> ClientBuilder {
+        ClientBuilder {
+            builder: reqwest::Client::builder(),
+            base_url: None,
+            headers: reqwest::header::HeaderMap::new(),
         }
     }
 
     /// Construct a builder to GET the given URL.
     async fn get(&self, url: &str) -> Result<RequestBuilder, Error> {
-        let request = self.client.get(url);
+        let request = match &self.base_url {
+            Some(base_url) => self.client.get(base_url.join(url)?),
+            None => self.client.get(url),
+        };
+
         Ok(RequestBuilder { request })
     }
 
     /// Construct a builder to POST to the given URL.
     async fn post(&self, url: &str) -> Result<RequestBuilder, Error> {
-        let request = self.client.post(url);
+        let request = match &self.base_url {
+            Some(base_url) => self.client.post(base_url.join(url)?),
+            None => self.client.post(url),
+        };
+
         Ok(RequestBuilder { request })
     }
 }
@@ -185,6 +493,133 @@ async fn get(url: &str) -> Result<Response, Error> {
 
 runestick::impl_external!(Error);
 runestick::impl_external!(Client);
+runestick::impl_external!(ClientBuilder);
 runestick::impl_external!(Response);
 runestick::impl_external!(RequestBuilder);
 runestick::impl_external!(StatusCode);
+runestick::impl_external!(Form);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use runestick::Shared;
+
+    #[test]
+    fn test_form_fields() {
+        let mut form = Object::new();
+        form.insert(String::from("name"), Value::from(String::from("rune")));
+        let fields = form_fields(form).unwrap();
+        assert_eq!(fields, vec![(String::from("name"), String::from("rune"))]);
+
+        let mut form = Object::new();
+        form.insert(String::from("count"), Value::from(42i64));
+        let error = form_fields(form).unwrap_err();
+        let mut buf = String::new();
+        error.display(&mut buf).unwrap();
+        assert_eq!(buf, "expected form field `count` to be a string");
+    }
+
+    #[test]
+    fn test_header_value() {
+        use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE, SET_COOKIE};
+
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("text/plain"));
+        headers.append(SET_COOKIE, HeaderValue::from_static("a=1"));
+        headers.append(SET_COOKIE, HeaderValue::from_static("b=2"));
+
+        assert_eq!(
+            header_value(&headers, "Content-Type").as_deref(),
+            Some("text/plain")
+        );
+        assert_eq!(
+            header_value(&headers, "set-cookie").as_deref(),
+            Some("a=1, b=2")
+        );
+        assert_eq!(header_value(&headers, "x-missing"), None);
+    }
+
+    #[tokio::test]
+    async fn test_client_builder() {
+        // NB: the listener accepts connections but never responds to them.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let client = Client::builder()
+            .timeout(50)
+            .base_url(&format!("http://{}/api", addr))
+            .unwrap()
+            .default_header("x-test", "rune")
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let request = client.get("slow").await.unwrap().request.build().unwrap();
+        assert_eq!(request.url().as_str(), format!("http://{}/api/slow", addr));
+
+        let error = client.get("slow").await.unwrap().send().await.unwrap_err();
+
+        match error.kind {
+            ErrorKind::Reqwest(error) => assert!(error.is_timeout()),
+            kind => panic!("unexpected error: {:?}", kind),
+        }
+
+        assert!(Client::builder().base_url("not a url").is_err());
+        assert!(Client::builder().default_header("bad name", "x").is_err());
+    }
+
+    #[test]
+    fn test_multipart() {
+        let form = Form::new()
+            .text("name", "rune")
+            .bytes(
+                "file",
+                Bytes::from_vec(b"hello".to_vec()),
+                "hello.txt",
+                "text/plain",
+            )
+            .unwrap();
+
+        let builder = RequestBuilder {
+            request: reqwest::Client::new().post("http://localhost/post"),
+        };
+
+        let request = builder.multipart(form).request.build().unwrap();
+        let content_type = request.headers()[reqwest::header::CONTENT_TYPE]
+            .to_str()
+            .unwrap();
+        assert!(content_type.starts_with("multipart/form-data; boundary="));
+
+        let form = Form::new().bytes("file", Bytes::new(), "empty", "not a mime");
+        assert!(form.is_err());
+    }
+
+    #[test]
+    fn test_json_body() {
+        let builder = RequestBuilder {
+            request: reqwest::Client::new().post("http://localhost/post"),
+        };
+
+        let mut object = Object::new();
+        object.insert(String::from("k"), Value::from(String::from("v")));
+
+        let request = builder.json(Value::from(Shared::new(object))).unwrap();
+        let request = request.request.build().unwrap();
+
+        assert_eq!(
+            request.headers()[reqwest::header::CONTENT_TYPE],
+            "application/json"
+        );
+        assert_eq!(
+            request.body().and_then(|body| body.as_bytes()),
+            Some(&b"{\"k\":\"v\"}"[..])
+        );
+
+        let builder = RequestBuilder {
+            request: reqwest::Client::new().post("http://localhost/post"),
+        };
+
+        let value = Value::from(Shared::new(Ok::<_, Value>(Value::Unit)));
+        assert!(builder.json(value).is_err());
+    }
+}
@@ -40,8 +40,15 @@
 //! * [toml]
 //! * [time]
 //! * [fs]
+//! * [env]
 //! * [process]
 //! * [signal]
+//! * [sqlite]
+//! * [websocket]
+//! * [compress]
+//! * [digest]
+//! * [uuid]
+//! * [log]
 //!
 //! ## Features
 //!
@@ -51,16 +58,30 @@
 //! * `toml` for the [toml module][toml]
 //! * `time` for the [time module][time]
 //! * `fs` for the [fs module]][fs]
+//! * `env` for the [env module][env]
 //! * `process` for the [process module]][process]
 //! * `signal` for the [process module]][signal]
+//! * `sqlite` for the [sqlite module][sqlite]
+//! * `websocket` for the [websocket module][websocket]
+//! * `compress` for the [compress module][compress]
+//! * `digest` for the [digest module][digest]
+//! * `uuid` for the [uuid module][uuid]
+//! * `log` for the [log module][log]
 //!
 //! [http]: https://docs.rs/rune-modules/0/rune_modules/http/
 //! [json]: https://docs.rs/rune-modules/0/rune_modules/json/
 //! [toml]: https://docs.rs/rune-modules/0/rune_modules/toml/
 //! [time]: https://docs.rs/rune-modules/0/rune_modules/time/
 //! [fs]: https://docs.rs/rune-modules/0/rune_modules/fs/
+//! [env]: https://docs.rs/rune-modules/0/rune_modules/env/
 //! [process]: https://docs.rs/rune-modules/0/rune_modules/process/
 //! [signal]: https://docs.rs/rune-modules/0/rune_modules/signal/
+//! [sqlite]: https://docs.rs/rune-modules/0/rune_modules/sqlite/
+//! [websocket]: https://docs.rs/rune-modules/0/rune_modules/websocket/
+//! [compress]: https://docs.rs/rune-modules/0/rune_modules/compress/
+//! [digest]: https://docs.rs/rune-modules/0/rune_modules/digest/
+//! [uuid]: https://docs.rs/rune-modules/0/rune_modules/uuid/
+//! [log]: https://docs.rs/rune-modules/0/rune_modules/log/
 
 #[cfg(feature = "http")]
 pub mod http;
@@ -77,8 +98,29 @@ pub mod time;
 #[cfg(feature = "fs")]
 pub mod fs;
 
+#[cfg(feature = "env")]
+pub mod env;
+
 #[cfg(feature = "process")]
 pub mod process;
 
 #[cfg(feature = "signal")]
 pub mod signal;
+
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+
+#[cfg(feature = "websocket")]
+pub mod websocket;
+
+#[cfg(feature = "compress")]
+pub mod compress;
+
+#[cfg(feature = "digest")]
+pub mod digest;
+
+#[cfg(feature = "uuid")]
+pub mod uuid;
+
+#[cfg(feature = "log")]
+pub mod log;
@@ -0,0 +1,121 @@
+//! The native `log` module for the [Rune Language].
+//!
+//! [Rune Language]: https://github.com/rune-rs/rune
+//!
+//! Forwards diagnostics from scripts to the [log] crate, so that they end up
+//! in the same stream as the diagnostics of the host.
+//!
+//! ## Usage
+//!
+//! Add the following to your `Cargo.toml`:
+//!
+//! ```toml
+//! rune-modules = {version = "0.6.16", features = ["log"]}
+//! ```
+//!
+//! Install it into your context:
+//!
+//! ```rust
+//! # fn main() -> runestick::Result<()> {
+//! let mut context = runestick::Context::with_default_modules()?;
+//! context.install(&rune_modules::log::module()?)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Use it in Rune:
+//!
+//! ```rust,ignore
+//! fn main() {
+//!     log::info("starting up");
+//! }
+//! ```
+//!
+//! The `print`, `println`, `dbg` and `dbg_pretty` functions can also be routed
+//! to the logger by installing [print_module] instead of the default
+//! [Io][runestick::DefaultModule::Io] module:
+//!
+//! ```rust
+//! use runestick::{Context, ContextConfig};
+//!
+//! # fn main() -> runestick::Result<()> {
+//! let mut context = Context::with_config(&ContextConfig::default())?;
+//! context.install(&rune_modules::log::print_module()?)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use runestick::{ContextError, Module, Stack, VmError};
+
+/// The target used for records unless another one is specified.
+pub const DEFAULT_TARGET: &str = "rune";
+
+/// Construct the `log` module, logging to the [DEFAULT_TARGET].
+pub fn module() -> Result<Module, ContextError> {
+    module_with_target(DEFAULT_TARGET)
+}
+
+/// Construct the `log` module, logging to the given target.
+pub fn module_with_target(target: &'static str) -> Result<Module, ContextError> {
+    let mut module = Module::new(&["log"]);
+    module.function(
+        &["error"],
+        move |m: &str| ::log::error!(target: target, "{}", m),
+    )?;
+    module.function(
+        &["warn"],
+        move |m: &str| ::log::warn!(target: target, "{}", m),
+    )?;
+    module.function(
+        &["info"],
+        move |m: &str| ::log::info!(target: target, "{}", m),
+    )?;
+    module.function(
+        &["debug"],
+        move |m: &str| ::log::debug!(target: target, "{}", m),
+    )?;
+    Ok(module)
+}
+
+/// Construct the `print`, `println`, `dbg` and `dbg_pretty` functions in the
+/// `std` module, logging to the [DEFAULT_TARGET].
+///
+/// Printed messages are logged at the info level, and debugged values at the
+/// debug level.
+pub fn print_module() -> Result<Module, ContextError> {
+    print_module_with_target(DEFAULT_TARGET)
+}
+
+/// Construct the `print`, `println`, `dbg` and `dbg_pretty` functions in the
+/// `std` module, logging to the given target.
+pub fn print_module_with_target(target: &'static str) -> Result<Module, ContextError> {
+    let mut module = Module::new(&["std"]);
+    module.function(
+        &["print"],
+        move |m: &str| ::log::info!(target: target, "{}", m),
+    )?;
+    module.function(
+        &["println"],
+        move |m: &str| ::log::info!(target: target, "{}", m),
+    )?;
+    module.raw_fn(&["dbg"], move |stack, args| dbg(target, stack, args, false))?;
+    module.raw_fn(&["dbg_pretty"], move |stack, args| {
+        dbg(target, stack, args, true)
+    })?;
+    Ok(module)
+}
+
+fn dbg(target: &str, stack: &mut Stack, args: usize, pretty: bool) -> Result<(), VmError> {
+    for _ in 0..args {
+        let value = stack.pop()?;
+
+        if pretty {
+            ::log::debug!(target: target, "{:#?}", value);
+        } else {
+            ::log::debug!(target: target, "{:?}", value);
+        }
+    }
+
+    stack.push(runestick::Value::Unit);
+    Ok(())
+}
@@ -15,6 +15,7 @@
 //! ```rust
 //! # fn main() -> runestick::Result<()> {
 //! let mut context = runestick::Context::with_default_modules()?;
+//! context.grant(runestick::Capability::Process);
 //! context.install(&rune_modules::process::module()?)?;
 //! # Ok(())
 //! # }
@@ -28,22 +29,29 @@
 //! fn main() {
 //!     let command = Command::new("ls");
 //!     command.run().await;
+//!
+//!     let output = process::run("echo", ["hello"]).await?;
+//!     println(output.stdout);
 //! }
 //! ```
 
-use runestick::{Bytes, Shared, Value, VmError};
+use runestick::{Bytes, Object, Shared, Value, VmError};
 use std::fmt;
 use std::io;
 use tokio::process;
 
 /// Construct the `process` module.
+///
+/// Requires the [process][runestick::Capability::Process] capability.
 pub fn module() -> Result<runestick::Module, runestick::ContextError> {
     let mut module = runestick::Module::new(&["process"]);
+    module.require(runestick::Capability::Process);
     module.ty(&["Command"]).build::<Command>()?;
     module.ty(&["Child"]).build::<Child>()?;
     module.ty(&["ExitStatus"]).build::<ExitStatus>()?;
     module.ty(&["Output"]).build::<Output>()?;
 
+    module.async_function(&["run"], run)?;
     module.function(&["Command", "new"], Command::new)?;
     module.inst_fn("spawn", Command::spawn)?;
     module.inst_fn("arg", Command::arg)?;
@@ -59,6 +67,29 @@ pub fn module() -> Result<runestick::Module, runestick::ContextError> {
     Ok(module)
 }
 
+/// Run the given command with the given arguments to completion, capturing
+/// its output.
+///
+/// The output is an object with the `stdout` and `stderr` of the process as
+/// strings, and its exit `code`, which is `None` if the process was terminated
+/// by a signal.
+async fn run(command: String, args: Vec<String>) -> Result<io::Result<Object<Value>>, VmError> {
+    let output = match process::Command::new(command).args(args).output().await {
+        Ok(output) => output,
+        Err(error) => return Ok(Err(error)),
+    };
+
+    let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+    let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+    let code = output.status.code().map(i64::from);
+
+    let mut object = Object::new();
+    object.insert(String::from("stdout"), Value::from(Shared::new(stdout)));
+    object.insert(String::from("stderr"), Value::from(Shared::new(stderr)));
+    object.insert(String::from("code"), runestick::ToValue::to_value(code)?);
+    Ok(Ok(object))
+}
+
 struct Command {
     inner: process::Command,
 }
@@ -15,6 +15,7 @@
 //! ```rust
 //! # fn main() -> runestick::Result<()> {
 //! let mut context = runestick::Context::with_default_modules()?;
+//! context.grant(runestick::Capability::Process);
 //! context.install(&rune_modules::signal::module()?)?;
 //! # Ok(())
 //! # }
@@ -32,8 +33,11 @@
 use tokio::signal;
 
 /// Construct the `signal` module.
+///
+/// Requires the [process][runestick::Capability::Process] capability.
 pub fn module() -> Result<runestick::Module, runestick::ContextError> {
     let mut module = runestick::Module::new(&["signal"]);
+    module.require(runestick::Capability::Process);
     module.async_function(&["ctrl_c"], signal::ctrl_c)?;
     Ok(module)
 }
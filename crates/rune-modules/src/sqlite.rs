@@ -0,0 +1,305 @@
+//! The native `sqlite` module for the [Rune Language].
+//!
+//! [Rune Language]: https://github.com/rune-rs/rune
+//!
+//! ## Usage
+//!
+//! Add the following to your `Cargo.toml`:
+//!
+//! ```toml
+//! rune-modules = {version = "0.6.16", features = ["sqlite"]}
+//! ```
+//!
+//! Install it into your context:
+//!
+//! ```rust
+//! # fn main() -> runestick::Result<()> {
+//! let mut context = runestick::Context::with_default_modules()?;
+//! context.grant(runestick::Capability::Fs);
+//! context.install(&rune_modules::sqlite::module()?)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Use it in Rune:
+//!
+//! ```rust,ignore
+//! use sqlite;
+//!
+//! fn main() {
+//!     let db = sqlite::open("app.db")?;
+//!     db.execute("CREATE TABLE IF NOT EXISTS users (name TEXT, age INTEGER)", [])?;
+//!     db.execute("INSERT INTO users (name, age) VALUES (?, ?)", ["bob", 42])?;
+//!
+//!     for row in db.query("SELECT name, age FROM users WHERE age > ?", [18])? {
+//!         println(`{row.name} is {row.age}`);
+//!     }
+//! }
+//! ```
+//!
+//! Parameters are bound from a vector of values: units and `None` bind as
+//! `NULL`, and booleans bind as integers. Rows are returned as objects keyed
+//! by column name, where `NULL` columns are represented as units.
+
+use runestick::{Bytes, ContextError, Module, Object, Shared, TypeInfo, Value, VmError};
+use rusqlite::types::Value as SqlValue;
+use std::fmt;
+use std::fmt::Write as _;
+
+/// Construct the `sqlite` module.
+///
+/// Requires the [fs][runestick::Capability::Fs] capability.
+pub fn module() -> Result<Module, ContextError> {
+    let mut module = Module::new(&["sqlite"]);
+    module.require(runestick::Capability::Fs);
+    module.ty(&["Connection"]).build::<Connection>()?;
+    module.ty(&["Error"]).build::<Error>()?;
+    module.function(&["open"], open)?;
+    module.inst_fn("execute", Connection::execute)?;
+    module.inst_fn("query", Connection::query)?;
+    module.inst_fn(runestick::STRING_DISPLAY, Error::display)?;
+    Ok(module)
+}
+
+/// Error raised by the `sqlite` module.
+#[derive(Debug)]
+pub struct Error {
+    kind: ErrorKind,
+}
+
+#[derive(Debug)]
+enum ErrorKind {
+    Sqlite(rusqlite::Error),
+    /// A parameter had a type which can't be bound.
+    UnsupportedParameter {
+        index: usize,
+        type_info: TypeInfo,
+    },
+}
+
+impl Error {
+    fn display(&self, buf: &mut String) -> fmt::Result {
+        match &self.kind {
+            ErrorKind::Sqlite(error) => write!(buf, "{}", error),
+            ErrorKind::UnsupportedParameter { index, type_info } => write!(
+                buf,
+                "parameter #{} has unsupported type `{}`",
+                index, type_info
+            ),
+        }
+    }
+}
+
+impl From<rusqlite::Error> for Error {
+    fn from(error: rusqlite::Error) -> Self {
+        Self {
+            kind: ErrorKind::Sqlite(error),
+        }
+    }
+}
+
+/// A connection to an sqlite database.
+#[derive(Debug)]
+pub struct Connection {
+    inner: rusqlite::Connection,
+}
+
+/// Open a connection to the database at the given path, creating it if it
+/// doesn't exist.
+///
+/// The special path `:memory:` opens a new in-memory database.
+fn open(path: &str) -> Result<Connection, Error> {
+    let inner = rusqlite::Connection::open(path)?;
+    Ok(Connection { inner })
+}
+
+impl Connection {
+    /// Execute a statement, returning the number of rows that were changed.
+    fn execute(&self, sql: &str, params: &[Value]) -> Result<Result<i64, Error>, VmError> {
+        let params = match bind(params)? {
+            Ok(params) => params,
+            Err(error) => return Ok(Err(error)),
+        };
+
+        Ok(match self.inner.execute(sql, params) {
+            Ok(changed) => Ok(changed as i64),
+            Err(error) => Err(error.into()),
+        })
+    }
+
+    /// Run a query, returning every row as an object keyed by column name.
+    fn query(
+        &self,
+        sql: &str,
+        params: &[Value],
+    ) -> Result<Result<Vec<Object<Value>>, Error>, VmError> {
+        let params = match bind(params)? {
+            Ok(params) => params,
+            Err(error) => return Ok(Err(error)),
+        };
+
+        Ok(self.query_rows(sql, params))
+    }
+
+    fn query_rows(&self, sql: &str, params: Vec<SqlValue>) -> Result<Vec<Object<Value>>, Error> {
+        let mut statement = self.inner.prepare(sql)?;
+
+        let columns = statement
+            .column_names()
+            .into_iter()
+            .map(String::from)
+            .collect::<Vec<_>>();
+
+        let mut rows = statement.query(params)?;
+        let mut output = Vec::new();
+
+        while let Some(row) = rows.next()? {
+            let mut object = runestick::object_with_capacity(columns.len());
+
+            for (n, column) in columns.iter().enumerate() {
+                object.insert(column.clone(), from_sql(row.get(n)?));
+            }
+
+            output.push(object);
+        }
+
+        Ok(output)
+    }
+}
+
+/// Convert parameters into values that can be bound to a statement.
+fn bind(params: &[Value]) -> Result<Result<Vec<SqlValue>, Error>, VmError> {
+    let mut output = Vec::with_capacity(params.len());
+
+    for (index, param) in params.iter().enumerate() {
+        let value = match param {
+            Value::Unit => SqlValue::Null,
+            Value::Bool(b) => SqlValue::Integer(i64::from(*b)),
+            Value::Byte(b) => SqlValue::Integer(i64::from(*b)),
+            Value::Integer(n) => SqlValue::Integer(*n),
+            Value::Float(n) => SqlValue::Real(*n),
+            Value::StaticString(s) => SqlValue::Text((***s).to_owned()),
+            Value::String(s) => SqlValue::Text(s.borrow_ref()?.clone()),
+            Value::Bytes(b) => SqlValue::Blob(b.borrow_ref()?.to_vec()),
+            Value::Option(option) if option.borrow_ref()?.is_none() => SqlValue::Null,
+            actual => {
+                return Ok(Err(Error {
+                    kind: ErrorKind::UnsupportedParameter {
+                        index,
+                        type_info: actual.type_info()?,
+                    },
+                }));
+            }
+        };
+
+        output.push(value);
+    }
+
+    Ok(Ok(output))
+}
+
+/// Convert a column value into a Rune value.
+fn from_sql(value: SqlValue) -> Value {
+    match value {
+        SqlValue::Null => Value::Unit,
+        SqlValue::Integer(n) => Value::Integer(n),
+        SqlValue::Real(n) => Value::Float(n),
+        SqlValue::Text(s) => Value::from(Shared::new(s)),
+        SqlValue::Blob(b) => Value::from(Shared::new(Bytes::from_vec(b))),
+    }
+}
+
+runestick::impl_external!(Connection);
+runestick::impl_external!(Error);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn string(value: &Value) -> String {
+        value
+            .clone()
+            .into_string()
+            .unwrap()
+            .borrow_ref()
+            .unwrap()
+            .clone()
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let db = open(":memory:").unwrap();
+
+        let changed = db
+            .execute(
+                "CREATE TABLE users (name TEXT, age INTEGER, score REAL, avatar BLOB)",
+                &[],
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(changed, 0);
+
+        let params = vec![
+            Value::from(Shared::new(String::from("bob"))),
+            Value::Integer(42),
+            Value::Float(1.5),
+            Value::from(Shared::new(Bytes::from_vec(vec![1, 2, 3]))),
+        ];
+
+        let changed = db
+            .execute("INSERT INTO users VALUES (?, ?, ?, ?)", &params)
+            .unwrap()
+            .unwrap();
+        assert_eq!(changed, 1);
+
+        let params = vec![
+            Value::from(Shared::new(String::from("alice"))),
+            Value::Integer(7),
+            Value::Unit,
+            Value::Unit,
+        ];
+
+        db.execute("INSERT INTO users VALUES (?, ?, ?, ?)", &params)
+            .unwrap()
+            .unwrap();
+
+        let rows = db
+            .query(
+                "SELECT name, age, score, avatar FROM users WHERE age > ? ORDER BY age",
+                &[Value::Integer(0)],
+            )
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(string(&rows[0]["name"]), "alice");
+        assert!(matches!(rows[0]["age"], Value::Integer(7)));
+        assert!(matches!(rows[0]["score"], Value::Unit));
+        assert!(matches!(rows[0]["avatar"], Value::Unit));
+
+        assert_eq!(string(&rows[1]["name"]), "bob");
+        assert!(matches!(rows[1]["age"], Value::Integer(42)));
+        assert!(matches!(rows[1]["score"], Value::Float(n) if n == 1.5));
+
+        let avatar = rows[1]["avatar"].clone().into_bytes().unwrap();
+        assert_eq!(&avatar.borrow_ref().unwrap()[..], &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let db = open(":memory:").unwrap();
+
+        let error = db.execute("NOT SQL", &[]).unwrap().unwrap_err();
+        assert!(matches!(error.kind, ErrorKind::Sqlite(..)));
+
+        let error = db.query("SELECT * FROM missing", &[]).unwrap().unwrap_err();
+        assert!(matches!(error.kind, ErrorKind::Sqlite(..)));
+
+        let params = vec![Value::Integer(1), Value::from(Shared::new(Vec::new()))];
+        let error = db.query("SELECT ?, ?", &params).unwrap().unwrap_err();
+
+        let mut buf = String::new();
+        error.display(&mut buf).unwrap();
+        assert_eq!(buf, "parameter #1 has unsupported type `Vec`");
+    }
+}
@@ -30,8 +30,12 @@
 //!     dbg(data);
 //! }
 //! ```
+//!
+//! Tables are converted into objects and arrays into vectors. Dates and times
+//! don't have a native representation in Rune, so they are converted into
+//! strings in their TOML format.
 
-use runestick::{Bytes, ContextError, Module, Value};
+use runestick::{Bytes, ContextError, Module, Shared, Value};
 
 /// Construct the `toml` module.
 pub fn module() -> Result<Module, ContextError> {
@@ -44,21 +48,80 @@ pub fn module() -> Result<Module, ContextError> {
 }
 
 fn from_bytes(bytes: &[u8]) -> runestick::Result<Value> {
-    Ok(toml::from_slice(&bytes)?)
+    Ok(from_toml(toml::from_slice(&bytes)?))
 }
 
 /// Get value from toml string.
 fn from_string(string: &str) -> runestick::Result<Value> {
-    Ok(toml::from_str(string)?)
+    Ok(from_toml(toml::from_str(string)?))
 }
 
 /// Convert any value to a toml string.
 fn to_string(value: Value) -> runestick::Result<String> {
-    Ok(toml::to_string(&value)?)
+    Ok(toml::to_string(&toml::Value::try_from(&value)?)?)
 }
 
 /// Convert any value to toml bytes.
 fn to_bytes(value: Value) -> runestick::Result<Bytes> {
-    let bytes = toml::to_vec(&value)?;
+    let bytes = toml::to_vec(&toml::Value::try_from(&value)?)?;
     Ok(Bytes::from_vec(bytes))
 }
+
+/// Convert a parsed toml value into a Rune value.
+fn from_toml(value: toml::Value) -> Value {
+    match value {
+        toml::Value::String(string) => Value::from(string),
+        toml::Value::Integer(integer) => Value::from(integer),
+        toml::Value::Float(float) => Value::from(float),
+        toml::Value::Boolean(boolean) => Value::from(boolean),
+        toml::Value::Datetime(datetime) => Value::from(datetime.to_string()),
+        toml::Value::Array(array) => Value::vec(array.into_iter().map(from_toml).collect()),
+        toml::Value::Table(table) => {
+            let mut object = runestick::object_with_capacity(table.len());
+
+            for (key, value) in table {
+                object.insert(key, from_toml(value));
+            }
+
+            Value::from(Shared::new(object))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOCUMENT: &str = r#"
+title = "example"
+released = 1979-05-27T07:32:00Z
+
+[owner]
+name = "Tom"
+tags = ["a", "b"]
+
+[owner.limits]
+max = 42
+ratio = 0.5
+"#;
+
+    #[test]
+    fn test_round_trip() -> runestick::Result<()> {
+        let value = from_string(DOCUMENT)?;
+        let output = to_string(value)?;
+
+        let expected: toml::Value = toml::from_str(DOCUMENT)?;
+        let actual: toml::Value = toml::from_str(&output)?;
+
+        // NB: dates are converted into strings, so compare them separately.
+        assert_eq!(actual["released"].as_str(), Some("1979-05-27T07:32:00Z"));
+        assert_eq!(actual["title"], expected["title"]);
+        assert_eq!(actual["owner"], expected["owner"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(from_string("[owner\nname = 1").is_err());
+    }
+}
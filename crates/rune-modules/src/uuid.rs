@@ -0,0 +1,176 @@
+//! The native `uuid` module for the [Rune Language].
+//!
+//! [Rune Language]: https://github.com/rune-rs/rune
+//!
+//! ## Usage
+//!
+//! Add the following to your `Cargo.toml`:
+//!
+//! ```toml
+//! rune-modules = {version = "0.6.16", features = ["uuid"]}
+//! ```
+//!
+//! Install it into your context:
+//!
+//! ```rust
+//! # fn main() -> runestick::Result<()> {
+//! let mut context = runestick::Context::with_default_modules()?;
+//! context.install(&rune_modules::uuid::module()?)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Use it in Rune:
+//!
+//! ```rust,ignore
+//! use uuid;
+//!
+//! fn main() {
+//!     let id = uuid::v4();
+//!     dbg(id);
+//!
+//!     let id = uuid::parse("{67E55044-10B1-426F-9247-BB680E5FE0C8}")?;
+//!     dbg(id);
+//! }
+//! ```
+//!
+//! UUIDs are represented as strings in their lowercase, hyphenated form.
+
+use runestick::{ContextError, Module};
+use std::fmt;
+use std::fmt::Write as _;
+
+/// Construct the `uuid` module.
+pub fn module() -> Result<Module, ContextError> {
+    let mut module = Module::new(&["uuid"]);
+    module.ty(&["Error"]).build::<Error>()?;
+    module.function(&["v4"], v4)?;
+    module.function(&["parse"], parse)?;
+    module.inst_fn(runestick::STRING_DISPLAY, Error::display)?;
+    Ok(module)
+}
+
+/// Error raised when parsing an invalid uuid.
+#[derive(Debug)]
+pub struct Error {
+    input: String,
+}
+
+impl Error {
+    fn display(&self, buf: &mut String) -> fmt::Result {
+        write!(buf, "invalid uuid `{}`", self.input)
+    }
+}
+
+/// Generate a random (version 4) uuid.
+fn v4() -> String {
+    let mut bytes = rand::random::<[u8; 16]>();
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    format(&bytes)
+}
+
+/// Parse a uuid and normalize it into its lowercase, hyphenated form.
+///
+/// Accepts the hyphenated form, the simple form without hyphens, and either
+/// of them surrounded by braces.
+fn parse(input: &str) -> Result<String, Error> {
+    let error = || Error {
+        input: input.to_owned(),
+    };
+
+    let s = match input.strip_prefix('{') {
+        Some(s) => s.strip_suffix('}').ok_or_else(error)?,
+        None => input,
+    };
+
+    let digits = match s.len() {
+        32 => s.to_owned(),
+        36 => {
+            let hyphens = [8, 13, 18, 23];
+
+            for (n, c) in s.char_indices() {
+                if (c == '-') != hyphens.contains(&n) {
+                    return Err(error());
+                }
+            }
+
+            s.replace('-', "")
+        }
+        _ => return Err(error()),
+    };
+
+    if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(error());
+    }
+
+    let mut bytes = [0u8; 16];
+
+    for (n, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&digits[n * 2..n * 2 + 2], 16).map_err(|_| error())?;
+    }
+
+    Ok(format(&bytes))
+}
+
+/// Format uuid bytes in their lowercase, hyphenated form.
+fn format(bytes: &[u8; 16]) -> String {
+    let mut output = String::with_capacity(36);
+
+    for (n, byte) in bytes.iter().enumerate() {
+        if let 4 | 6 | 8 | 10 = n {
+            output.push('-');
+        }
+
+        write!(output, "{:02x}", byte).expect("writing to a string can't fail");
+    }
+
+    output
+}
+
+runestick::impl_external!(Error);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_v4() {
+        let id = v4();
+        assert_eq!(id.len(), 36);
+        assert_eq!(id.as_bytes()[14], b'4');
+        assert!(matches!(id.as_bytes()[19], b'8' | b'9' | b'a' | b'b'));
+        assert_eq!(parse(&id).unwrap(), id);
+        assert_ne!(v4(), id);
+    }
+
+    #[test]
+    fn test_parse() {
+        let expected = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+        assert_eq!(
+            parse("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap(),
+            expected
+        );
+        assert_eq!(
+            parse("67E55044-10B1-426F-9247-BB680E5FE0C8").unwrap(),
+            expected
+        );
+        assert_eq!(parse("67e5504410b1426f9247bb680e5fe0c8").unwrap(), expected);
+        assert_eq!(
+            parse("{67e55044-10b1-426f-9247-bb680e5fe0c8}").unwrap(),
+            expected
+        );
+
+        assert!(parse("").is_err());
+        assert!(parse("garbage").is_err());
+        assert!(parse("67e55044-10b1-426f-9247-bb680e5fe0cg").is_err());
+        assert!(parse("67e5504410b1-426f-9247-bb680e5fe0c8-").is_err());
+        assert!(parse("{67e55044-10b1-426f-9247-bb680e5fe0c8").is_err());
+        assert!(parse("+7e5504410b1426f9247bb680e5fe0c8").is_err());
+        assert!(parse("åäö55044-10b1-426f-9247-bb680e5f").is_err());
+
+        let mut buf = String::new();
+        parse("garbage").unwrap_err().display(&mut buf).unwrap();
+        assert_eq!(buf, "invalid uuid `garbage`");
+    }
+}
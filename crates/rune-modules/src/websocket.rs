@@ -0,0 +1,204 @@
+//! The native `websocket` module for the [Rune Language].
+//!
+//! [Rune Language]: https://github.com/rune-rs/rune
+//!
+//! ## Usage
+//!
+//! Add the following to your `Cargo.toml`:
+//!
+//! ```toml
+//! rune-modules = {version = "0.6.16", features = ["websocket"]}
+//! ```
+//!
+//! Install it into your context:
+//!
+//! ```rust
+//! # fn main() -> runestick::Result<()> {
+//! let mut context = runestick::Context::with_default_modules()?;
+//! context.grant(runestick::Capability::Net);
+//! context.install(&rune_modules::websocket::module()?)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Use it in Rune:
+//!
+//! ```rust,ignore
+//! use websocket;
+//!
+//! async fn main() {
+//!     let socket = websocket::connect("ws://localhost:9001").await?;
+//!     socket.send("hello").await?;
+//!
+//!     while let Some(message) = socket.recv().await? {
+//!         dbg(message);
+//!     }
+//!
+//!     socket.close().await?;
+//! }
+//! ```
+//!
+//! Text messages are received as strings and binary messages as bytes.
+//! Receiving `None` means that the connection was closed. Only plain `ws://`
+//! urls are supported.
+
+use futures_util::{SinkExt, StreamExt as _};
+use runestick::{Bytes, ContextError, Module, Shared, Value};
+use std::fmt;
+use std::fmt::Write as _;
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::{self, Message};
+use tokio_tungstenite::WebSocketStream;
+
+/// Construct the `websocket` module.
+///
+/// Requires the [net][runestick::Capability::Net] capability.
+pub fn module() -> Result<Module, ContextError> {
+    let mut module = Module::new(&["websocket"]);
+    module.require(runestick::Capability::Net);
+    module.ty(&["WebSocket"]).build::<WebSocket>()?;
+    module.ty(&["Error"]).build::<Error>()?;
+    module.async_function(&["connect"], connect)?;
+    module.async_inst_fn("send", WebSocket::send)?;
+    module.async_inst_fn("send_bytes", WebSocket::send_bytes)?;
+    module.async_inst_fn("recv", WebSocket::recv)?;
+    module.async_inst_fn("close", WebSocket::close)?;
+    module.inst_fn(runestick::STRING_DISPLAY, Error::display)?;
+    Ok(module)
+}
+
+/// Error raised by the `websocket` module.
+#[derive(Debug)]
+pub struct Error {
+    inner: tungstenite::Error,
+}
+
+impl Error {
+    fn display(&self, buf: &mut String) -> fmt::Result {
+        write!(buf, "{}", self.inner)
+    }
+}
+
+impl From<tungstenite::Error> for Error {
+    fn from(inner: tungstenite::Error) -> Self {
+        Self { inner }
+    }
+}
+
+/// A connected websocket.
+#[derive(Debug)]
+pub struct WebSocket {
+    inner: WebSocketStream<TcpStream>,
+}
+
+/// Connect to the websocket server at the given url.
+async fn connect(url: &str) -> Result<WebSocket, Error> {
+    let (inner, _) = tokio_tungstenite::connect_async(url).await?;
+    Ok(WebSocket { inner })
+}
+
+impl WebSocket {
+    /// Send a text message.
+    async fn send(&mut self, text: &str) -> Result<(), Error> {
+        self.inner.send(Message::Text(text.to_owned())).await?;
+        Ok(())
+    }
+
+    /// Send a binary message.
+    async fn send_bytes(&mut self, bytes: &[u8]) -> Result<(), Error> {
+        self.inner.send(Message::Binary(bytes.to_vec())).await?;
+        Ok(())
+    }
+
+    /// Receive the next text or binary message, or `None` if the connection
+    /// was closed.
+    ///
+    /// Control frames are handled transparently and are never returned.
+    async fn recv(&mut self) -> Result<Option<Value>, Error> {
+        while let Some(message) = self.inner.next().await {
+            match message? {
+                Message::Text(text) => return Ok(Some(Value::from(text))),
+                Message::Binary(bytes) => {
+                    let bytes = Shared::new(Bytes::from_vec(bytes));
+                    return Ok(Some(Value::from(bytes)));
+                }
+                Message::Close(..) => break,
+                Message::Ping(..) | Message::Pong(..) => (),
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Close the connection.
+    ///
+    /// Closing an already closed connection does nothing.
+    async fn close(&mut self) -> Result<(), Error> {
+        match SinkExt::close(&mut self.inner).await {
+            Ok(()) | Err(tungstenite::Error::AlreadyClosed) => Ok(()),
+            Err(error) => Err(error.into()),
+        }
+    }
+}
+
+runestick::impl_external!(WebSocket);
+runestick::impl_external!(Error);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::TcpListener;
+
+    /// Spawn a server which echoes every text and binary message back, and
+    /// return the url to connect to it.
+    async fn echo_server() -> String {
+        let mut listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+
+            while let Some(Ok(message)) = socket.next().await {
+                if message.is_text() || message.is_binary() {
+                    socket.send(message).await.unwrap();
+                }
+            }
+        });
+
+        format!("ws://{}", addr)
+    }
+
+    #[tokio::test]
+    async fn test_echo() {
+        let mut socket = connect(&echo_server().await).await.unwrap();
+
+        socket.send("hello").await.unwrap();
+        let message = socket.recv().await.unwrap().unwrap();
+        let text = message.into_string().unwrap();
+        assert_eq!(&*text.borrow_ref().unwrap(), "hello");
+
+        socket.send_bytes(&[1, 2, 3]).await.unwrap();
+        let message = socket.recv().await.unwrap().unwrap();
+        let bytes = message.into_bytes().unwrap();
+        assert_eq!(&bytes.borrow_ref().unwrap()[..], &[1, 2, 3]);
+
+        socket.close().await.unwrap();
+        assert!(socket.recv().await.unwrap().is_none());
+        socket.close().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_connect_error() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        drop(listener);
+
+        let error = connect(&format!("ws://{}", addr)).await.unwrap_err();
+        let mut buf = String::new();
+        error.display(&mut buf).unwrap();
+        assert!(!buf.is_empty());
+
+        assert!(connect("not a url").await.is_err());
+    }
+}
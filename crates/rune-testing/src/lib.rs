@@ -127,9 +127,9 @@ macro_rules! rune {
 ///
 /// # fn main() {
 /// assert_parse_error! {
-///     r#"fn main() { 0 < 10 >= 10 }"#,
+///     r#"fn main() { 2 * 3 % 4 }"#,
 ///     PrecedenceGroupRequired { span } => {
-///         assert_eq!(span, Span::new(12, 18));
+///         assert_eq!(span, Span::new(12, 17));
 ///     }
 /// };
 /// # }
@@ -0,0 +1,32 @@
+use rune_testing::*;
+
+#[test]
+fn test_disassemble() -> Result<()> {
+    let context = runestick::Context::with_default_modules()?;
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        fn add(a, b) {
+            a + b
+        }
+
+        fn main() {
+            if add(1, 2) > 2 { "yes" } else { "no" }
+        }
+        "#,
+    )?;
+
+    let listing = unit.disassemble();
+    let lines = listing.lines().collect::<Vec<_>>();
+
+    assert!(lines.iter().any(|l| l.starts_with("fn add(arg, arg) (")));
+    assert!(lines.iter().any(|l| l.starts_with("fn main() (")));
+    assert!(lines.contains(&"  0000 = copy 0 // var `a` ; `a`"));
+    assert!(lines.contains(&"  0002 = add ; `a + b`"));
+    assert!(lines.iter().any(|l| l.contains("= gt ; `add(1, 2) > 2`")));
+    assert!(lines.iter().any(|l| l.contains("= jump-if")));
+    assert!(lines.iter().any(|l| l.starts_with("if_then_")));
+    assert!(lines.iter().any(|l| l.contains("= return")));
+    Ok(())
+}
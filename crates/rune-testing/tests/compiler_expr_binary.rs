@@ -3,9 +3,9 @@ use rune_testing::*;
 #[test]
 fn test_binary_exprs() {
     assert_parse_error! {
-        r#"fn main() { 0 < 10 >= 10 }"#,
+        r#"fn main() { 2 * 3 % 4 }"#,
         PrecedenceGroupRequired { span } => {
-            assert_eq!(span, Span::new(12, 18));
+            assert_eq!(span, Span::new(12, 17));
         }
     };
 
@@ -15,3 +15,38 @@ fn test_binary_exprs() {
     assert_parse!(r#"fn main() { 0 < 10 && 10 > 0 }"#);
     assert_parse!(r#"fn main() { 0 < 10 && 10 > 0 || true }"#);
 }
+
+#[test]
+fn test_chained_comparison() {
+    assert_parse_error! {
+        r#"fn main() { 0 < 10 >= 10 }"#,
+        ChainedComparison { span, first, shared, last, .. } => {
+            assert_eq!(span, Span::new(12, 24));
+            assert_eq!(first, Span::new(12, 18));
+            assert_eq!(shared, Span::new(16, 18));
+            assert_eq!(last, Span::new(22, 24));
+        }
+    };
+
+    let suggestion = |source: &str| {
+        let error = rune::parse_all::<rune::ast::DeclFile>(source).unwrap_err();
+        error.suggestion(source)
+    };
+
+    assert_eq!(
+        suggestion(r#"fn main() { 1 < 2 < 3 }"#).as_deref(),
+        Some("1 < 2 && 2 < 3")
+    );
+
+    assert_eq!(
+        suggestion(r#"fn main() { 0 < 10 >= 10 }"#).as_deref(),
+        Some("0 < 10 && 10 >= 10")
+    );
+
+    assert_eq!(
+        suggestion(r#"fn main() { let a = 1; a + 1 == b * 2 != c.d }"#).as_deref(),
+        Some("a + 1 == b * 2 && b * 2 != c.d")
+    );
+
+    assert_eq!(suggestion(r#"fn main() { 2 * 3 % 4 }"#), None);
+}
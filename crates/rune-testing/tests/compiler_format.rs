@@ -0,0 +1,128 @@
+use rune_testing::*;
+
+#[test]
+fn test_format() {
+    let source = r#"
+use std::iter :: * ;
+struct Point {x,y}
+enum Shape{Circle(r),Square{w},}
+
+impl Point{fn sum(self){self.x+self.y}}
+fn main(a,b) {
+    let p=Point{x:1,y:2};let v=[1 ,2,3];
+    let o = #{"a":a,b};
+    let t = (1,);
+    let f = |a,b| a+b;
+    if a < b { a } else if let Some(v) = v.get(0) { v } else { b };
+    match v { [a, ..] if a > 1 => a, (a,) => { a }, _ => 0, }
+    'outer: for i in 0..10 { break 'outer; }
+    for (k,v) in o { k }
+    foo(
+        1,
+        2
+    )
+}
+"#;
+
+    let expected = r#"use std::iter::*;
+
+struct Point {
+    x,
+    y,
+}
+
+enum Shape {
+    Circle(r),
+    Square { w },
+}
+
+impl Point {
+    fn sum(self) {
+        self.x + self.y
+    }
+}
+
+fn main(a, b) {
+    let p = Point { x: 1, y: 2 };
+    let v = [1, 2, 3];
+    let o = #{"a": a, b};
+    let t = (1,);
+    let f = |a, b| a + b;
+    if a < b {
+        a
+    } else if let Some(v) = v.get(0) {
+        v
+    } else {
+        b
+    };
+    match v {
+        [a, ..] if a > 1 => a,
+        (a,) => {
+            a
+        },
+        _ => 0,
+    }
+    'outer: for i in 0..10 {
+        break 'outer;
+    }
+    for (k, v) in o {
+        k
+    }
+    foo(
+        1,
+        2,
+    )
+}
+"#;
+
+    let formatted = rune::format(source).unwrap();
+    assert_eq!(formatted, expected);
+    assert_eq!(rune::format(&formatted).unwrap(), formatted);
+}
+
+#[test]
+fn test_format_comments() {
+    let source = r#"
+// The entry point.
+fn main() { // returns a sum
+    let a = 1; // first
+
+
+    // Blank lines above collapse into one.
+    let b = [
+        1, // one
+        2,
+    ];
+    // Before the end.
+}
+
+// At the end.
+"#;
+
+    let expected = r#"// The entry point.
+fn main() { // returns a sum
+    let a = 1; // first
+
+    // Blank lines above collapse into one.
+    let b = [
+        1, // one
+        2,
+    ];
+    // Before the end.
+}
+
+// At the end.
+"#;
+
+    let formatted = rune::format(source).unwrap();
+    assert_eq!(formatted, expected);
+    assert_eq!(rune::format(&formatted).unwrap(), formatted);
+}
+
+#[test]
+fn test_format_error() {
+    match rune::format("fn main() { 1 < 2 < 3 }") {
+        Err(ChainedComparison { .. }) => (),
+        other => panic!("expected a chained comparison error, but got {:?}", other),
+    }
+}
@@ -20,6 +20,23 @@ fn break_outside_of_loop() {
     };
 }
 
+#[test]
+fn test_if_missing_else() {
+    assert_compile_error! {
+        r#"fn main() { let n = if true { 1 }; }"#,
+        IfMissingElse { span } => {
+            assert_eq!(span, Span::new(20, 33));
+        }
+    };
+
+    assert_compile_error! {
+        r#"fn foo(n) { n } fn main() { foo(if true { 1 } else if false { 2 }) }"#,
+        IfMissingElse { span } => {
+            assert_eq!(span, Span::new(32, 65));
+        }
+    };
+}
+
 #[test]
 fn test_pointers() {
     assert_compile_error! {
@@ -63,6 +80,45 @@ fn test_wrong_arguments() {
     };
 }
 
+#[test]
+fn test_argument_count_mismatch() {
+    assert_compile_error! {
+        r#"fn add(a, b) { a + b } fn main() { add(1, 2, 3) }"#,
+        ArgumentCountMismatch { span, expected, actual } => {
+            assert_eq!(span, Span::new(35, 47));
+            assert_eq!(expected, 2);
+            assert_eq!(actual, 3);
+        }
+    };
+}
+
+#[test]
+fn test_named_arguments() {
+    assert_compile_error! {
+        r#"fn add(a, b) { a + b } fn main() { add(a: 1, c: 2) }"#,
+        UnknownNamedArgument { span, name } => {
+            assert_eq!(span, Span::new(45, 46));
+            assert_eq!(name, "c");
+        }
+    };
+
+    assert_compile_error! {
+        r#"fn add(a, b) { a + b } fn main() { add(1, a: 2) }"#,
+        DuplicateArgument { span, name } => {
+            assert_eq!(span, Span::new(42, 43));
+            assert_eq!(name, "a");
+        }
+    };
+
+    assert_compile_error! {
+        r#"fn add(a, b) { a + b } fn main() { add(b: 2) }"#,
+        MissingArgument { span, name } => {
+            assert_eq!(span, Span::new(35, 44));
+            assert_eq!(name, "a");
+        }
+    };
+}
+
 #[test]
 fn test_bad_struct_declaration() {
     assert_compile_error! {
@@ -90,3 +146,114 @@ fn test_bad_struct_declaration() {
         }
     };
 }
+
+#[test]
+fn test_link_missing_function_spans() {
+    use runestick::{
+        Call, Context, Hash, Inst, Item, LinkerError, LinkerErrors, Unit, UnitFnSignature,
+    };
+
+    let context = Context::with_default_modules().unwrap();
+    let missing = Hash::type_hash(&["missing"]);
+    let mut unit = Unit::new();
+
+    let calls = vec![
+        ("foo", vec![Span::new(0, 10), Span::new(10, 20)]),
+        ("bar", vec![Span::new(30, 40)]),
+    ];
+
+    for (name, spans) in calls {
+        let mut asm = unit.new_assembly();
+
+        for span in spans {
+            asm.push(
+                Inst::Call {
+                    hash: missing,
+                    args: 0,
+                },
+                span,
+            );
+        }
+
+        asm.push(Inst::ReturnUnit, Span::empty());
+
+        let signature = UnitFnSignature {
+            path: Item::of(&[name]),
+            args: 0,
+            defaults: 0,
+            variadic: false,
+        };
+
+        unit.new_function(0, signature, asm, Call::Immediate)
+            .unwrap();
+    }
+
+    let mut errors = LinkerErrors::new();
+    assert!(!unit.link(&context, &mut errors));
+
+    let mut errors = errors.errors();
+
+    match errors.next() {
+        Some(LinkerError::MissingFunction { hash, spans }) => {
+            assert_eq!(hash, missing);
+            assert_eq!(
+                spans,
+                vec![Span::new(0, 10), Span::new(10, 20), Span::new(30, 40)]
+            );
+        }
+        error => panic!("unexpected error: {:?}", error),
+    }
+
+    assert!(errors.next().is_none());
+}
+
+#[test]
+fn test_unit_functions() {
+    use runestick::{Context, Hash, Item};
+
+    let context = Context::with_default_modules().unwrap();
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        struct Point(x, y);
+
+        fn main(a, b = 2) {
+            a + b
+        }
+
+        async fn fetch() {
+            1
+        }
+        "#,
+    )
+    .unwrap();
+
+    let functions = unit
+        .functions()
+        .into_iter()
+        .map(|f| {
+            (
+                f.hash,
+                f.signature.path.clone(),
+                f.signature.args,
+                f.signature.defaults,
+                f.is_async(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        functions,
+        vec![
+            (Hash::type_hash(&["main"]), Item::of(&["main"]), 2, 1, false),
+            (
+                Hash::type_hash(&["fetch"]),
+                Item::of(&["fetch"]),
+                0,
+                0,
+                true
+            ),
+        ]
+    );
+}
@@ -0,0 +1,74 @@
+use rune::ast;
+use rune_testing::*;
+use runestick::Source;
+
+#[test]
+fn test_parse_source() {
+    let source = Source::new(
+        "main",
+        r#"
+        use std::string;
+
+        struct Point { x, y }
+
+        enum Shape { Circle(r), Square { w } }
+
+        impl Point {
+            fn sum(self) { self.x + self.y }
+        }
+
+        fn main() {
+            Point { x: 1, y: 2 }.sum()
+        }
+        "#,
+    );
+
+    let file = rune::parse_source(&source).unwrap();
+    let mut names = Vec::new();
+
+    for (decl, _) in &file.decls {
+        let name = match decl {
+            ast::Decl::DeclUse(..) => String::from("use"),
+            ast::Decl::DeclStruct(decl) => {
+                format!("struct {}", source.source(decl.ident.span()).unwrap())
+            }
+            ast::Decl::DeclEnum(decl) => {
+                let variants = decl.variants.len();
+                let name = source.source(decl.name.span()).unwrap();
+                format!("enum {} ({} variants)", name, variants)
+            }
+            ast::Decl::DeclImpl(decl) => format!("impl ({} functions)", decl.functions.len()),
+            ast::Decl::DeclFn(decl) => format!("fn {}", source.source(decl.name.span()).unwrap()),
+        };
+
+        names.push(name);
+    }
+
+    assert_eq!(
+        names,
+        vec![
+            "use",
+            "struct Point",
+            "enum Shape (2 variants)",
+            "impl (1 functions)",
+            "fn main",
+        ]
+    );
+
+    let dump = format!("{:#?}", file);
+    assert!(dump.contains("DeclFn"));
+    assert!(dump.contains("ExprBinary"));
+}
+
+#[test]
+fn test_parse_source_error() {
+    let source = Source::new("main", "fn main() { 1 < 2 < 3 }");
+
+    match rune::parse_source(&source) {
+        Err(error @ ChainedComparison { .. }) => {
+            let suggestion = error.suggestion(source.as_str());
+            assert_eq!(suggestion.as_deref(), Some("1 < 2 && 2 < 3"));
+        }
+        other => panic!("expected a chained comparison error, but got {:?}", other),
+    }
+}
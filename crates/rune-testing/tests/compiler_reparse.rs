@@ -0,0 +1,59 @@
+use runestick::{Source, Span};
+
+fn edit(source: &Source, span: Span, replacement: &str) -> (Source, rune::Reparse) {
+    let file = rune::parse_source(source).unwrap();
+    let edited = rune::rewrite(source, vec![(span, replacement)]).unwrap();
+    let edited = Source::new(source.name(), edited);
+    let reparse = rune::reparse_source(&edited, file, span, replacement.len()).unwrap();
+    (edited, reparse)
+}
+
+#[test]
+fn test_reparse_single_function() {
+    let source = Source::new(
+        "main",
+        r#"
+fn foo(a) { a + 1 }
+
+fn bar(b) {
+    let c = b * 2;
+    c
+}
+
+fn baz() { bar(foo(3)) }
+"#,
+    );
+
+    let start = source.as_str().find("2;").unwrap();
+    let (edited, reparse) = edit(&source, Span::new(start, start + 1), "20 + b");
+
+    assert_eq!(reparse.reparsed, 1..2);
+    assert_eq!(reparse.file.decls.len(), 3);
+
+    let expected = rune::parse_source(&edited).unwrap();
+    assert_eq!(format!("{:?}", reparse.file), format!("{:?}", expected));
+}
+
+#[test]
+fn test_reparse_new_declaration() {
+    let source = Source::new("main", "fn foo() { 1 }\n\nfn bar() { 2 }\n");
+
+    let (edited, reparse) = edit(&source, Span::point(15), "struct Point { x, y }\n");
+
+    assert_eq!(reparse.reparsed, 1..2);
+    assert_eq!(reparse.file.decls.len(), 3);
+
+    let expected = rune::parse_source(&edited).unwrap();
+    assert_eq!(format!("{:?}", reparse.file), format!("{:?}", expected));
+}
+
+#[test]
+fn test_reparse_fallback() {
+    let source = Source::new("main", "fn foo() { 1 }   fn bar() { 2 }\n");
+
+    let (edited, reparse) = edit(&source, Span::point(16), "//");
+
+    assert_eq!(edited.as_str(), "fn foo() { 1 }  // fn bar() { 2 }\n");
+    assert_eq!(reparse.reparsed, 0..1);
+    assert_eq!(reparse.file.decls.len(), 1);
+}
@@ -0,0 +1,67 @@
+use rune::ast;
+use runestick::{Source, Span};
+
+#[test]
+fn test_rewrite_identifiers() {
+    let source = Source::new(
+        "main",
+        r#"
+fn foo(a) { a + 1 }
+
+fn main() {
+    foo(2)
+}
+"#,
+    );
+
+    let file = rune::parse_source(&source).unwrap();
+    let mut edits = Vec::new();
+
+    for (decl, _) in &file.decls {
+        if let ast::Decl::DeclFn(decl_fn) = decl {
+            let name = source.source(decl_fn.name.span()).unwrap();
+            edits.push((decl_fn.name.span(), format!("{}_renamed", name)));
+        }
+    }
+
+    let out = rune::rewrite(&source, edits).unwrap();
+
+    assert_eq!(
+        out,
+        r#"
+fn foo_renamed(a) { a + 1 }
+
+fn main_renamed() {
+    foo(2)
+}
+"#
+    );
+}
+
+#[test]
+fn test_rewrite_overlapping() {
+    let source = Source::new("main", "fn main() { foo(bar) }");
+
+    let error = rune::rewrite(
+        &source,
+        vec![(Span::new(12, 20), "baz()"), (Span::new(16, 19), "qux")],
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        error,
+        rune::RewriteError::Overlapping {
+            span: Span::new(16, 19),
+            other: Span::new(12, 20),
+        }
+    );
+
+    let error = rune::rewrite(&source, vec![(Span::new(20, 40), "")]).unwrap_err();
+
+    assert_eq!(
+        error,
+        rune::RewriteError::BadSpan {
+            span: Span::new(20, 40),
+        }
+    );
+}
@@ -0,0 +1,43 @@
+use rune::ast;
+use rune::ast::Syntax;
+use rune::parse_all;
+
+#[test]
+fn test_syntax_eq_indentation() {
+    let a = r#"match value { Some(n) if n > 0 => foo(n, #{a: 1}), _ => [1, 2] }"#;
+
+    let b = r#"
+        match value {
+            Some(n) if n > 0 => foo(n, #{
+                a: 1
+            }),
+            // a comment
+            _ => [
+                1,
+                2
+            ]
+        }
+    "#;
+
+    let a_expr = parse_all::<ast::Expr>(a).unwrap();
+    let b_expr = parse_all::<ast::Expr>(b.trim()).unwrap();
+
+    assert_eq!(Syntax::new(&a_expr, a), Syntax::new(&b_expr, b.trim()));
+}
+
+#[test]
+fn test_syntax_eq_differences() {
+    let a = "foo(1, bar)";
+    let a_expr = parse_all::<ast::Expr>(a).unwrap();
+
+    for b in &[
+        "foo(1, baz)",
+        "foo(2, bar)",
+        "foo(1, bar,)",
+        "foo(1)",
+        "foo.bar(1, bar)",
+    ] {
+        let b_expr = parse_all::<ast::Expr>(b).unwrap();
+        assert_ne!(Syntax::new(&a_expr, a), Syntax::new(&b_expr, b));
+    }
+}
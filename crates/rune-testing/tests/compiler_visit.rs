@@ -0,0 +1,98 @@
+use rune::ast;
+use rune::ast::fold::{self, Fold};
+use rune::ast::visit::{self, Visit};
+
+/// Counts all function calls.
+#[derive(Default)]
+struct CountCalls {
+    calls: usize,
+}
+
+impl Visit for CountCalls {
+    fn visit_expr_call(&mut self, expr: &ast::ExprCall) {
+        self.calls += 1;
+        visit::walk_expr_call(self, expr);
+    }
+}
+
+#[test]
+fn test_visit_count_calls() {
+    let file = rune::parse_all::<ast::DeclFile>(
+        r#"
+        struct Point { x, y }
+
+        impl Point {
+            fn len(self) { sqrt(self.x * self.x + self.y * self.y) }
+        }
+
+        fn main() {
+            let points = [Point { x: 1, y: 2 }, Point { x: 3, y: 4 }];
+            let sum = |a, b| a + add(b, 0);
+
+            for p in points.iter() {
+                if let Some(n) = p.len() {
+                    dbg(sum(n, 1));
+                }
+            }
+
+            match foo() {
+                Some(v) => bar(v)?,
+                _ => baz().await,
+            }
+        }
+        "#,
+    )
+    .unwrap();
+
+    let mut count = CountCalls::default();
+    count.visit_decl_file(&file);
+    assert_eq!(count.calls, 9);
+}
+
+/// Strips all grouped expressions, like `(a)`.
+struct Ungroup;
+
+impl Fold for Ungroup {
+    fn fold_expr(&mut self, expr: ast::Expr) -> ast::Expr {
+        match expr {
+            ast::Expr::ExprGroup(group) => self.fold_expr(*group.expr),
+            expr => fold::walk_expr(self, expr),
+        }
+    }
+}
+
+/// Counts all grouped expressions.
+#[derive(Default)]
+struct CountGroups {
+    groups: usize,
+}
+
+impl Visit for CountGroups {
+    fn visit_expr_group(&mut self, expr: &ast::ExprGroup) {
+        self.groups += 1;
+        visit::walk_expr_group(self, expr);
+    }
+}
+
+#[test]
+fn test_fold_ungroup() {
+    let file = rune::parse_all::<ast::DeclFile>(
+        r#"
+        fn main() {
+            let a = ((1) + [(2), #{"b": (3)}].len());
+            if (a) { (a) } else { ((0)) }
+        }
+        "#,
+    )
+    .unwrap();
+
+    let mut count = CountGroups::default();
+    count.visit_decl_file(&file);
+    assert_eq!(count.groups, 8);
+
+    let file = Ungroup.fold_decl_file(file);
+
+    let mut count = CountGroups::default();
+    count.visit_decl_file(&file);
+    assert_eq!(count.groups, 0);
+}
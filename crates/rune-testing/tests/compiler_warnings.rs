@@ -10,6 +10,29 @@ fn test_let_pattern_might_panic() {
     };
 }
 
+#[test]
+fn test_for_pattern_no_warnings() {
+    let context = runestick::Context::with_default_modules().unwrap();
+
+    let (_, warnings) = compile_source(
+        &context,
+        r#"fn main() { let n = 0; for (k, v) in #{a: 1} { n = k.len() + v; } n }"#,
+    )
+    .expect("source should compile");
+
+    assert!(warnings.is_empty(), "unexpected warnings: {:?}", warnings);
+}
+
+#[test]
+fn test_for_pattern_might_panic() {
+    assert_warnings! {
+        r#"fn main() { let n = 0; for Some(x) in [Some(1)] { n = x; } n }"#,
+        LetPatternMightPanic { span, .. } => {
+            assert_eq!(span, Span::new(27, 34));
+        }
+    };
+}
+
 #[test]
 fn test_template_without_variables() {
     assert_warnings! {
@@ -29,3 +52,60 @@ fn test_remove_variant_parens() {
         }
     };
 }
+
+#[test]
+fn test_unreachable_code() {
+    assert_warnings! {
+        r#"fn main() { if true { return 1; } return 2; 3 }"#,
+        UnreachableCode { span, cause } => {
+            assert_eq!(span, Span::new(44, 45));
+            assert_eq!(cause, Span::new(34, 42));
+        }
+    };
+}
+
+#[test]
+fn test_warning_levels() {
+    use rune::{LoadErrorKind, Options, WarningLevel, WarningLint, Warnings};
+    use runestick::{Context, Source};
+
+    let context = Context::with_default_modules().unwrap();
+    let options = Options::default();
+    let source = Source::new("main", r#"fn main() { 1; 2 };"#);
+
+    let mut warnings = Warnings::new();
+    warnings.set_level(WarningLint::NotUsed, WarningLevel::Allow);
+    warnings.set_level(WarningLint::UnecessarySemiColon, WarningLevel::Deny);
+
+    let error = rune::load_source(&context, &options, source.clone(), &mut warnings).unwrap_err();
+
+    match error.kind() {
+        LoadErrorKind::DeniedWarning { warning, .. } => match warning.kind {
+            UnecessarySemiColon { span } => assert_eq!(span, Span::new(18, 19)),
+            kind => panic!("unexpected warning: {:?}", kind),
+        },
+        kind => panic!("unexpected error: {:?}", kind),
+    }
+
+    assert_eq!(warnings.iter().count(), 1);
+
+    let mut warnings = Warnings::new();
+    warnings.set_level(WarningLint::NotUsed, WarningLevel::Deny);
+    warnings.set_level(WarningLint::UnecessarySemiColon, WarningLevel::Allow);
+
+    let error = rune::load_source(&context, &options, source.clone(), &mut warnings).unwrap_err();
+
+    match error.kind() {
+        LoadErrorKind::DeniedWarning { warning, .. } => {
+            assert!(matches!(warning.kind, NotUsed { .. }));
+        }
+        kind => panic!("unexpected error: {:?}", kind),
+    }
+
+    let mut warnings = Warnings::new();
+    warnings.set_level(WarningLint::NotUsed, WarningLevel::Allow);
+    warnings.set_level(WarningLint::UnecessarySemiColon, WarningLevel::Allow);
+
+    assert!(rune::load_source(&context, &options, source, &mut warnings).is_ok());
+    assert!(warnings.is_empty());
+}
@@ -0,0 +1,112 @@
+use rune_testing::*;
+use runestick::{
+    Capabilities, Capability, Context, ContextConfig, ContextError, FromValue as _, Item, Vm,
+};
+use std::sync::Arc;
+
+const SOURCE: &str = r#"
+async fn main() {
+    fs::read_to_string("file.txt").await
+}
+"#;
+
+#[test]
+fn test_fs_requires_capability() -> Result<()> {
+    let mut context = Context::with_default_modules()?;
+    assert_eq!(context.capabilities(), Capabilities::none());
+
+    match context.install(&rune_modules::fs::module()?) {
+        Err(ContextError::MissingCapability { module, capability }) => {
+            assert_eq!(module, Item::of(&["fs"]));
+            assert_eq!(capability, Capability::Fs);
+        }
+        result => panic!("unexpected result: {:?}", result),
+    }
+
+    assert!(matches!(
+        compile_source(&context, SOURCE),
+        Err(MissingFunction { .. })
+    ));
+
+    context.grant(Capability::Fs);
+    context.install(&rune_modules::fs::module()?)?;
+    compile_source(&context, SOURCE)?;
+    Ok(())
+}
+
+#[test]
+fn test_config_grants_capabilities() -> Result<()> {
+    let config = ContextConfig::default().grant(Capability::Fs);
+    assert_eq!(
+        config.capabilities(),
+        Capabilities::none().with(Capability::Fs)
+    );
+
+    let mut context = Context::with_config(&config)?;
+    context.install(&rune_modules::fs::module()?)?;
+    compile_source(&context, SOURCE)?;
+    Ok(())
+}
+
+#[test]
+fn test_default_context_grants_nothing() -> Result<()> {
+    let context = rune::default_context()?;
+    assert_eq!(context.capabilities(), Capabilities::none());
+
+    assert!(matches!(
+        compile_source(&context, SOURCE),
+        Err(MissingFunction { .. })
+    ));
+
+    let context = rune::default_context_with(Capabilities::none().with(Capability::Fs))?;
+    assert_eq!(
+        context.capabilities(),
+        Capabilities::none().with(Capability::Fs)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_env_requires_capability() -> Result<()> {
+    let mut context = Context::with_default_modules()?;
+
+    assert!(matches!(
+        context.install(&rune_modules::env::module()?),
+        Err(ContextError::MissingCapability {
+            capability: Capability::Env,
+            ..
+        })
+    ));
+
+    context.grant(Capability::Env);
+    context.install(&rune_modules::env::module()?)?;
+
+    std::env::set_var("RUNE_TEST_ENV_REQUIRES_CAPABILITY", "granted");
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"fn main() { env::var("RUNE_TEST_ENV_REQUIRES_CAPABILITY") }"#,
+    )?;
+
+    let vm = Vm::new(Arc::new(context), Arc::new(unit));
+    let output = Option::<String>::from_value(vm.call(&["main"], ())?.complete()?)?;
+    assert_eq!(output.as_deref(), Some("granted"));
+    Ok(())
+}
+
+#[test]
+fn test_signal_requires_process_capability() -> Result<()> {
+    let mut context = Context::with_default_modules()?;
+
+    assert!(matches!(
+        context.install(&rune_modules::signal::module()?),
+        Err(ContextError::MissingCapability {
+            capability: Capability::Process,
+            ..
+        })
+    ));
+
+    context.grant(Capability::Process);
+    context.install(&rune_modules::signal::module()?)?;
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use rune_testing::*;
+use runestick::{Context, ContextConfig, DefaultModule, FromValue as _, Item, Vm};
+use std::sync::Arc;
+
+#[test]
+fn test_default_config_excludes_io() -> Result<()> {
+    let config = ContextConfig::default();
+    assert!(config.contains(DefaultModule::Core));
+    assert!(!config.contains(DefaultModule::Io));
+
+    let context = Context::with_config(&config)?;
+
+    let error = compile_source(&context, r#"fn main() { println("hello") }"#).unwrap_err();
+
+    match error {
+        MissingFunction { item, .. } => {
+            assert_eq!(item, Item::of(&["std", "println"]));
+        }
+        error => panic!("unexpected error: {:?}", error),
+    }
+
+    let context = Arc::new(context);
+    let (unit, _) = compile_source(&context, r#"fn main() { [1, 2, 3].len() }"#)?;
+    let vm = Vm::new(context, Arc::new(unit));
+    let output = vm.call(&["main"], ())?.complete()?;
+    assert_eq!(i64::from_value(output)?, 3);
+    Ok(())
+}
+
+#[test]
+fn test_config_with_and_without() -> Result<()> {
+    let config = ContextConfig::default()
+        .with(DefaultModule::Io)
+        .without(DefaultModule::Vec);
+
+    let context = Context::with_config(&config)?;
+    compile_source(&context, r#"fn main() { println("hello") }"#)?;
+
+    let error = compile_source(&context, r#"fn main() { std::vec::Vec::new() }"#).unwrap_err();
+    assert!(matches!(error, MissingFunction { .. }));
+
+    let context = Context::with_config(&ContextConfig::empty())?;
+    assert!(context.iter_functions().next().is_none());
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use rune::termcolor::Buffer;
+use rune::EmitDiagnostics as _;
+use rune::{Options, Warnings};
+use rune_testing::*;
+use runestick::{Context, Source};
+
+#[test]
+fn test_codes() {
+    let context = Context::with_default_modules().unwrap();
+
+    let error = compile_source(&context, "fn main() { x }").unwrap_err();
+    assert_eq!(error.code(), "E0006");
+
+    let error = compile_source(&context, "fn main() {").unwrap_err();
+    assert_eq!(error.code(), "P0001");
+
+    let error = run::<_, _, ()>(&["main"], (), "fn main() { 1 / 0 }").unwrap_err();
+    let error = error.downcast_ref::<runestick::VmError>().unwrap();
+    assert_eq!(error.kind().code(), "R0009");
+
+    let (_, warnings) = compile_source(&context, "fn main() { 1; 2 };").unwrap();
+    let codes = warnings
+        .iter()
+        .map(|w| w.kind.lint().code())
+        .collect::<Vec<_>>();
+    assert_eq!(codes, vec!["W0005", "W0001"]);
+}
+
+#[test]
+fn test_emit_code() {
+    let context = Context::with_default_modules().unwrap();
+    let source = Source::new("main", "fn main() { x }");
+    let mut warnings = Warnings::new();
+
+    let error =
+        rune::load_source(&context, &Options::default(), source, &mut warnings).unwrap_err();
+
+    let mut out = Buffer::no_color();
+    error.emit_diagnostics(&mut out).unwrap();
+    let out = String::from_utf8(out.into_inner()).unwrap();
+    assert!(out.starts_with("error[E0006]: compile error"), "{}", out);
+}
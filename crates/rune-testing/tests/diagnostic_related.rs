@@ -0,0 +1,41 @@
+use rune::termcolor::Buffer;
+use rune::EmitDiagnostics as _;
+use rune::{Options, Warnings};
+use rune_testing::*;
+use runestick::{Context, Source};
+
+const SOURCE: &str = r#"fn main() { #{a: 1, b: 2, a: 3} }"#;
+
+#[test]
+fn test_related_spans() {
+    let context = Context::with_default_modules().unwrap();
+    let error = compile_source(&context, SOURCE).unwrap_err();
+
+    assert_eq!(error.span(), Span::new(26, 30));
+    assert_eq!(
+        error.related(),
+        vec![
+            (Span::new(14, 18), "previously defined here"),
+            (Span::new(12, 31), "object being defined here"),
+        ]
+    );
+}
+
+#[test]
+fn test_emit_related_spans() {
+    let context = Context::with_default_modules().unwrap();
+    let source = Source::new("main", SOURCE);
+    let mut warnings = Warnings::new();
+
+    let error =
+        rune::load_source(&context, &Options::default(), source, &mut warnings).unwrap_err();
+
+    let mut out = Buffer::no_color();
+    error.emit_diagnostics(&mut out).unwrap();
+    let out = String::from_utf8(out.into_inner()).unwrap();
+
+    assert!(out.starts_with("error[E0043]: compile error"), "{}", out);
+    assert!(out.contains("duplicate key in literal object"), "{}", out);
+    assert!(out.contains("previously defined here"), "{}", out);
+    assert!(out.contains("object being defined here"), "{}", out);
+}
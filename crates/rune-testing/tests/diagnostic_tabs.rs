@@ -0,0 +1,36 @@
+use rune::termcolor::Buffer;
+use rune::EmitDiagnostics as _;
+use rune::{Options, Warnings};
+use runestick::{Context, Source};
+
+#[test]
+fn test_tab_aligned_caret() {
+    let context = Context::with_default_modules().unwrap();
+    let source = Source::new("main", "fn main() {\n\tlet a = 1;\n\t\tx\n}\n");
+    let mut warnings = Warnings::new();
+
+    let error =
+        rune::load_source(&context, &Options::default(), source, &mut warnings).unwrap_err();
+
+    let mut out = Buffer::no_color();
+    error.emit_diagnostics(&mut out).unwrap();
+    let out = String::from_utf8(out.into_inner()).unwrap();
+
+    assert!(!out.contains('\t'), "{}", out);
+
+    let lines = out.lines().collect::<Vec<_>>();
+    let code = lines.iter().position(|l| l.ends_with(" x")).unwrap();
+    let column = |line: &str, c: char| line.chars().position(|o| o == c).unwrap();
+
+    assert_eq!(
+        column(lines[code], 'x'),
+        column(lines[code + 1], '^'),
+        "{}",
+        out
+    );
+    assert!(
+        lines[code + 1].ends_with("^ missing variable `x`"),
+        "{}",
+        out
+    );
+}
@@ -0,0 +1,58 @@
+use anyhow::Context as _;
+use rune_testing::*;
+use runestick::{Context, FromValue as _, Module, Vm};
+use std::sync::Arc;
+
+fn parse(input: &str) -> runestick::Result<i64> {
+    let number = input
+        .parse::<i64>()
+        .with_context(|| format!("failed to parse `{}`", input))?;
+
+    Ok(number)
+}
+
+#[test]
+fn test_anyhow_error_is_catchable() -> Result<()> {
+    let mut module = Module::new(&["native"]);
+    module.function(&["parse"], parse)?;
+
+    let mut context = Context::with_default_modules()?;
+    context.install(&module)?;
+    let context = Arc::new(context);
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        fn sum(a, b) {
+            Ok(native::parse(a)? + native::parse(b)?)
+        }
+
+        fn main() {
+            let ok = sum("1", "2");
+
+            let err = match sum("1", "x") {
+                Ok(..) => ("", "", []),
+                Err(error) => (`{error}`, error.message(), error.chain()),
+            };
+
+            (ok, err)
+        }
+        "#,
+    )?;
+
+    let vm = Vm::new(context, Arc::new(unit));
+    let output = vm.call(&["main"], ())?.complete()?;
+    let (ok, err) = <(Result<i64, Value>, (String, String, Vec<String>))>::from_value(output)?;
+
+    assert_eq!(ok.unwrap(), 3);
+    assert_eq!(err.0, "failed to parse `x`");
+    assert_eq!(err.1, "failed to parse `x`");
+    assert_eq!(
+        err.2,
+        vec![
+            String::from("failed to parse `x`"),
+            String::from("invalid digit found in string"),
+        ]
+    );
+    Ok(())
+}
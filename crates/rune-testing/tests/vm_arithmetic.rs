@@ -207,3 +207,171 @@ fn test_div() {
         DivideByZero => {}
     );
 }
+
+#[test]
+fn test_float_equality() {
+    assert_eq! {
+        rune! {
+            (bool, bool, bool, bool) => r#"
+            fn main() {
+                let nan = 0.0 / 0.0;
+                (nan == nan, nan != nan, float::total_eq(nan, nan), float::total_eq(0.0, -0.0))
+            }
+            "#
+        },
+        (false, true, true, false),
+    };
+
+    assert_eq! {
+        rune! {
+            (bool, bool, bool, bool, bool) => r#"
+            fn main() {
+                (1 == 1.0, -2.0 == -2, 1 == 1.5, 2 == -2.0, [1, 2.0] == [1.0, 2])
+            }
+            "#
+        },
+        (true, true, false, false, true),
+    };
+}
+
+#[test]
+fn test_rem() {
+    assert_eq! {
+        rune! {
+            i64 => r#"
+            fn main() {
+                let x = 10;
+                x %= 3;
+                x
+            }
+            "#
+        },
+        1,
+    };
+
+    assert_vm_error!(
+        r#"
+        fn main() {
+            let x = 10;
+            x %= 0;
+        }
+        "#,
+        DivideByZero => {}
+    );
+}
+
+#[test]
+fn test_bitwise() {
+    assert_eq! {
+        rune! {
+            (i64, i64, i64) => r#"
+            fn main() {
+                let mask = 0xFF;
+                mask &= 0xF;
+                let a = 0b1010;
+                a |= 0b0101;
+                let b = 0b1100;
+                b ^= 0b1010;
+                (mask, a, b)
+            }
+            "#
+        },
+        (0xF, 0b1111, 0b0110),
+    };
+
+    assert_eq! {
+        rune! {
+            (i64, i64, i64, i64) => r#"
+            fn main() {
+                (0b1100 & 0b1010, 0b1100 | 0b1010, 0b1100 ^ 0b1010, 1 | 2 & 3 ^ 4)
+            }
+            "#
+        },
+        (0b1000, 0b1110, 0b0110, 1 | 2 & 3 ^ 4),
+    };
+
+    assert_eq! {
+        rune! {
+            (bool, bool, bool, bool) => r#"
+            fn main() {
+                let a = true;
+                a &= false;
+                (true & false, true | false, true ^ true, a)
+            }
+            "#
+        },
+        (false, true, false, false),
+    };
+
+    assert_eq! {
+        rune! {
+            i64 => r#"
+            fn main() {
+                let f = |a, b| a | b;
+                f(1, 2)
+            }
+            "#
+        },
+        3,
+    };
+
+    assert_vm_error!(
+        r#"
+        fn main() {
+            let mask = "hello";
+            mask &= 0xF;
+        }
+        "#,
+        UnsupportedBinaryOperation { op, .. } => {
+            assert_eq!(op, &"&=");
+        }
+    );
+
+    assert_vm_error!(
+        r#"
+        fn main() {
+            1.0 | 2.0
+        }
+        "#,
+        UnsupportedBinaryOperation { op, .. } => {
+            assert_eq!(op, &"|");
+        }
+    );
+}
+
+#[test]
+fn test_shift() {
+    assert_eq! {
+        rune! {
+            (i64, i64, i64, i64) => r#"
+            fn main() {
+                let a = 1;
+                a <<= 4;
+                let b = 256;
+                b >>= 4;
+                (a, b, 1 << 2 + 1, 64 >> 3)
+            }
+            "#
+        },
+        (16, 16, 8, 8),
+    };
+
+    assert_vm_error!(
+        r#"
+        fn main() {
+            let a = 1;
+            a <<= 64;
+        }
+        "#,
+        Overflow => {}
+    );
+
+    assert_vm_error!(
+        r#"
+        fn main() {
+            1 >> (0 - 1)
+        }
+        "#,
+        Overflow => {}
+    );
+}
@@ -0,0 +1,137 @@
+use rune_testing::*;
+
+#[test]
+fn test_passing_asserts() {
+    assert_eq! {
+        rune!(i64 => r#"
+        fn main() {
+            assert(true);
+            assert(1 < 2, "one is less than two");
+            assert_eq([1, 2], [1, 2]);
+            assert_eq("a", "a", "strings should be equal");
+            42
+        }
+        "#),
+        42,
+    };
+}
+
+#[test]
+fn test_failing_asserts() {
+    assert_vm_error!(
+        r#"fn main() { assert(1 > 2); }"#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "assertion failed");
+        }
+    );
+
+    assert_vm_error!(
+        r#"fn main() { assert(false, "should be true"); }"#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "assertion failed `should be true`");
+        }
+    );
+
+    assert_vm_error!(
+        r#"fn main() { assert_eq(1, 2); }"#,
+        Panic { reason } => {
+            assert_eq!(
+                reason.to_string(),
+                "assertion failed `left == right` (left: 1, right: 2)"
+            );
+        }
+    );
+
+    assert_vm_error!(
+        r#"fn main() { assert_eq(1, 2, "numbers differ"); }"#,
+        Panic { reason } => {
+            assert_eq!(
+                reason.to_string(),
+                "assertion failed `left == right`: numbers differ (left: 1, right: 2)"
+            );
+        }
+    );
+
+    assert_vm_error!(
+        r#"fn main() { assert_eq(["a"], ["b"]); }"#,
+        Panic { reason } => {
+            assert_eq!(
+                reason.to_string(),
+                "assertion failed `left == right` (left: [\"a\"], right: [\"b\"])"
+            );
+        }
+    );
+
+    // External types are formatted through `STRING_DEBUG`.
+    assert_vm_error!(
+        r#"fn main() { assert_eq(std::collections::SortedMap::new(), ()); }"#,
+        Panic { reason } => {
+            assert_eq!(
+                reason.to_string(),
+                "assertion failed `left == right` (left: {}, right: ())"
+            );
+        }
+    );
+}
+
+#[test]
+fn test_panics() {
+    assert_vm_error!(
+        r#"fn main() { panic("something went wrong"); }"#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "something went wrong");
+        }
+    );
+
+    assert_vm_error!(
+        r#"fn main() { todo(); }"#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "not yet implemented");
+        }
+    );
+
+    assert_vm_error!(
+        r#"fn main() { unimplemented(); }"#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "functionality has not been implemented yet");
+        }
+    );
+}
+
+#[test]
+fn test_try_catch() {
+    assert_eq! {
+        rune!((Result<i64, String>, String) => r#"
+        fn main() {
+            let n = 41;
+            let ok = try_catch(|| n + 1);
+
+            let err = match try_catch(|| panic("boom")) {
+                Err(message) => message,
+                Ok(_) => "no panic",
+            };
+
+            (ok, err)
+        }
+        "#),
+        (Ok(42), String::from("boom")),
+    };
+
+    assert_eq! {
+        rune!(String => r#"
+        fn main() {
+            match try_catch(|| assert_eq(1, 2)) {
+                Err(message) => message,
+                Ok(_) => "no panic",
+            }
+        }
+        "#),
+        String::from("assertion failed `left == right` (left: 1, right: 2)"),
+    };
+
+    // Errors which are not panics are propagated.
+    assert_vm_error!(
+        r#"fn main() { try_catch(|| 1 + "a") }"#,
+        UnsupportedBinaryOperation { .. } => {}
+    );
+}
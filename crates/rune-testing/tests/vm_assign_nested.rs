@@ -0,0 +1,67 @@
+use rune_testing::*;
+
+#[test]
+fn test_nested_vec_assign() {
+    assert_eq! {
+        rune!(Vec<Vec<i64>> => r#"
+        fn main() {
+            let v = [[1, 2], [3]];
+            v[0][1] = 5;
+            v[1][0] = 6;
+            v
+        }
+        "#),
+        vec![vec![1, 5], vec![6]],
+    };
+
+    assert_eq! {
+        rune!((i64, i64) => r#"
+        fn main() {
+            let t = ((1, 2), 3);
+            t[0][1] = 5;
+            (t.0).1 = 5 + (t.0).1;
+            ((t.0).1, t.1)
+        }
+        "#),
+        (10, 3),
+    };
+}
+
+#[test]
+fn test_nested_object_assign() {
+    assert_eq! {
+        rune!((i64, i64, i64) => r#"
+        struct Point { x, y }
+
+        fn main() {
+            let o = #{a: #{b: 1}, v: [#{c: 1}], p: Point { x: 1, y: 2 }};
+            o.a.b = 2;
+            o.v[0].c = 3;
+            o["p"].y = 4;
+            (o.a.b, o.v[0].c, o.p.y)
+        }
+        "#),
+        (2, 3, 4),
+    };
+}
+
+#[test]
+fn test_nested_assign_missing() {
+    assert_vm_error!(
+        r#"fn main() { let v = [[1]]; v[0][1] = 5; }"#,
+        MissingIndex { index, .. } => assert_eq!(index.to_string(), "1i64")
+    );
+
+    assert_vm_error!(
+        r#"fn main() { let o = #{a: #{}}; o.b.c = 5; }"#,
+        ObjectIndexMissing { .. } => ()
+    );
+}
+
+#[test]
+fn test_nested_assign_compound() {
+    assert_compile_error! {
+        r#"fn main() { let o = #{a: #{b: 1}}; o.a.b += 1; }"#,
+        UnsupportedAssignBinOp { .. } => ()
+    };
+}
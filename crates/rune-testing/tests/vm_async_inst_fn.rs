@@ -0,0 +1,73 @@
+use rune_testing::*;
+use runestick::{Context, FromValue as _, Module, Vm};
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+struct Client;
+
+#[derive(Debug, Clone)]
+struct FetchError {
+    message: String,
+}
+
+impl FetchError {
+    fn message(&self) -> String {
+        self.message.clone()
+    }
+}
+
+runestick::impl_external!(Client);
+runestick::impl_external!(FetchError);
+
+impl Client {
+    async fn fetch(&self, id: i64) -> Result<i64, FetchError> {
+        if id < 0 {
+            return Err(FetchError {
+                message: format!("no item with id {}", id),
+            });
+        }
+
+        Ok(id * 10)
+    }
+}
+
+#[test]
+fn test_fallible_async_inst_fn() -> Result<()> {
+    let mut module = Module::new(&["client"]);
+    module.ty(&["Client"]).build::<Client>()?;
+    module.ty(&["FetchError"]).build::<FetchError>()?;
+    module.async_inst_fn("fetch", Client::fetch)?;
+    module.inst_fn("message", FetchError::message)?;
+
+    let mut context = Context::with_default_modules()?;
+    context.install(&module)?;
+    let context = Arc::new(context);
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        async fn sum(client, a, b) {
+            Ok(client.fetch(a).await? + client.fetch(b).await?)
+        }
+
+        async fn main(client) {
+            let ok = sum(client, 1, 2).await;
+
+            let err = match sum(client, 1, -2).await {
+                Ok(..) => "unexpected",
+                Err(error) => error.message(),
+            };
+
+            (ok, err)
+        }
+        "#,
+    )?;
+
+    let vm = Vm::new(context, Arc::new(unit));
+    let output = block_on(vm.call(&["main"], (Client,))?.async_complete())?;
+    let (ok, err) = <(Result<i64, Value>, String)>::from_value(output)?;
+
+    assert_eq!(ok.unwrap(), 30);
+    assert_eq!(err, "no item with id -2");
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use rune_testing::*;
+use runestick::{Context, FromValue as _, Module, Value, Vm};
+use std::sync::Arc;
+
+fn str_len(value: &str) -> usize {
+    value.len()
+}
+
+fn bytes_len(value: &[u8]) -> usize {
+    value.len()
+}
+
+fn values_len(value: &[Value]) -> usize {
+    value.len()
+}
+
+#[test]
+fn test_borrowed_args() -> Result<()> {
+    let mut module = Module::new(&["native"]);
+    module.function(&["str_len"], str_len)?;
+    module.function(&["bytes_len"], bytes_len)?;
+    module.function(&["values_len"], values_len)?;
+    module.function(&["trim"], str::trim)?;
+
+    let mut context = Context::with_default_modules()?;
+    context.install(&module)?;
+    let context = Arc::new(context);
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        fn main() {
+            let s = String::from_str("hello");
+            (
+                native::str_len(s),
+                native::str_len("static"),
+                native::bytes_len(b"bytes"),
+                native::values_len([1, 2, 3]),
+                // NB: the argument is only referenced by the call.
+                native::trim(String::from_str("  trimmed  ")),
+                s,
+            )
+        }
+        "#,
+    )?;
+
+    let vm = Vm::new(context, Arc::new(unit));
+    let output = vm.call(&["main"], ())?.complete()?;
+    let output = <(usize, usize, usize, usize, String, String)>::from_value(output)?;
+    assert_eq!(
+        output,
+        (5, 6, 5, 3, String::from("trimmed"), String::from("hello"))
+    );
+    Ok(())
+}
@@ -165,3 +165,67 @@ fn test_nested_async_closure() {
         }
     };
 }
+
+#[test]
+fn test_capture_by_reference() {
+    assert_eq! {
+        (3, 3),
+        rune! {
+            (i64, i64) => r#"
+            fn main() {
+                let counter = 0;
+                let increment = || { counter += 1; counter };
+
+                increment();
+                increment();
+                let last = increment();
+
+                (counter, last)
+            }
+            "#
+        }
+    };
+
+    // Closures see assignments made after they were created, and share the
+    // captured variable with each other.
+    assert_eq! {
+        (12, 12),
+        rune! {
+            (i64, i64) => r#"
+            fn main() {
+                let n = 1;
+                let get = || n;
+                let set = |v| { n = v; };
+
+                n = 10;
+                set(get() + 2);
+                (n, get())
+            }
+            "#
+        }
+    };
+
+    // Every loop iteration binds a new variable.
+    assert_eq! {
+        vec![0, 1, 2],
+        rune! {
+            Vec<i64> => r#"
+            fn main() {
+                let closures = [];
+
+                for n in [0, 1, 2] {
+                    closures.push(|| n);
+                }
+
+                let out = [];
+
+                for c in closures {
+                    out.push(c());
+                }
+
+                out
+            }
+            "#
+        }
+    };
+}
@@ -0,0 +1,76 @@
+use rune_testing::*;
+
+#[test]
+fn test_min_max() {
+    assert_eq! {
+        rune!((i64, i64, f64, f64, String, String) => r#"
+        use std::cmp;
+
+        fn main() {
+            (
+                cmp::min(1, 2),
+                cmp::max(1, 2),
+                cmp::min(1.5, -2.5),
+                cmp::max(1.5, -2.5),
+                cmp::min("apple", "banana"),
+                cmp::max("apple", "banana"),
+            )
+        }
+        "#),
+        (1, 2, -2.5, 1.5, String::from("apple"), String::from("banana")),
+    };
+
+    assert_vm_error!(
+        r#"use std::cmp; fn main() { cmp::min(1, "a") }"#,
+        BadReturn { error, .. } => {
+            match error.kind() {
+                UnsupportedBinaryOperation { op, .. } => assert_eq!(*op, "min"),
+                kind => panic!("unexpected error: {:?}", kind),
+            }
+        }
+    );
+}
+
+#[test]
+fn test_clamp() {
+    assert_eq! {
+        rune!((i64, i64, i64, f64, String) => r#"
+        use std::cmp;
+
+        fn main() {
+            (
+                cmp::clamp(-5, 0, 10),
+                cmp::clamp(5, 0, 10),
+                cmp::clamp(15, 0, 10),
+                cmp::clamp(2.5, 0.0, 1.0),
+                cmp::clamp("m", "a", "f"),
+            )
+        }
+        "#),
+        (0, 5, 10, 1.0, String::from("f")),
+    };
+
+    assert_eq! {
+        rune!(String => r#"
+        use std::cmp;
+
+        fn main() {
+            match try_catch(|| cmp::clamp(5, 10, 0)) {
+                Err(message) => message,
+                Ok(_) => "no panic",
+            }
+        }
+        "#),
+        String::from("clamp requires that `min <= max`"),
+    };
+
+    assert_vm_error!(
+        r#"use std::cmp; fn main() { cmp::clamp(1, 0, "a") }"#,
+        BadReturn { error, .. } => {
+            match error.kind() {
+                UnsupportedBinaryOperation { op, .. } => assert_eq!(*op, "clamp"),
+                kind => panic!("unexpected error: {:?}", kind),
+            }
+        }
+    );
+}
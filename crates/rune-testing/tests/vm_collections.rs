@@ -0,0 +1,69 @@
+use rune_testing::*;
+
+#[test]
+fn test_sorted_map() {
+    assert_eq! {
+        rune!((Vec<String>, Vec<(String, i64)>, String) => r#"
+        use std::collections::SortedMap;
+
+        fn main() {
+            let map = SortedMap::new();
+            map["c"] = 3;
+            map.insert("a", 1);
+            map["b"] = 2;
+
+            let entries = [];
+
+            for entry in map {
+                entries.push(entry);
+            }
+
+            (map.keys(), entries, `{map:?}`)
+        }
+        "#),
+        (
+            vec![String::from("a"), String::from("b"), String::from("c")],
+            vec![
+                (String::from("a"), 1),
+                (String::from("b"), 2),
+                (String::from("c"), 3),
+            ],
+            String::from("{\"a\": 1, \"b\": 2, \"c\": 3}"),
+        ),
+    };
+
+    assert_eq! {
+        rune!((i64, Option<i64>, Option<i64>, bool, i64, Vec<i64>) => r#"
+        use std::collections::SortedMap;
+
+        fn main() {
+            let map = SortedMap::new();
+            map["x"] = 1;
+            map["y"] = 2;
+            map["x"] = 10;
+
+            let removed = map.remove("y");
+            let missing = map.get("y");
+
+            (map["x"], removed, missing, map.contains_key("x"), map.len(), map.values())
+        }
+        "#),
+        (10, Some(2), None, true, 1, vec![10]),
+    };
+
+    assert_eq! {
+        rune!(String => r#"
+        use std::collections::SortedMap;
+
+        fn main() {
+            let map = SortedMap::new();
+
+            match try_catch(|| map["missing"]) {
+                Err(message) => message,
+                Ok(_) => "no panic",
+            }
+        }
+        "#),
+        String::from("missing key `missing`"),
+    };
+}
@@ -0,0 +1,43 @@
+use rune_testing::*;
+use runestick::{Bytes, Context, FromValue as _, Vm};
+use std::sync::Arc;
+
+#[test]
+fn test_compress() -> Result<()> {
+    let mut context = Context::with_default_modules()?;
+    context.install(&rune_modules::compress::module()?)?;
+    let context = Arc::new(context);
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        fn main() {
+            let input = b"hello hello hello world";
+
+            let gzip = compress::gzip_encode(input)?;
+            let deflate = compress::deflate_encode(input)?;
+
+            let invalid = match compress::gzip_decode(b"not gzip at all") {
+                Ok(..) => false,
+                Err(error) => error is compress::Error,
+            };
+
+            Ok((
+                compress::gzip_decode(gzip)?,
+                compress::deflate_decode(deflate)?,
+                invalid,
+            ))
+        }
+        "#,
+    )?;
+
+    let vm = Vm::new(context, Arc::new(unit));
+    let output = vm.call(&["main"], ())?.complete()?;
+    let (gzip, deflate, invalid) =
+        <Result<(Bytes, Bytes, bool), Value>>::from_value(output)?.unwrap();
+
+    assert_eq!(&gzip[..], b"hello hello hello world");
+    assert_eq!(&deflate[..], b"hello hello hello world");
+    assert!(invalid);
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use rune_testing::*;
+use runestick::{Context, ContextError, FromValue as _, Module, Vm};
+use std::sync::Arc;
+
+#[test]
+fn test_module_constants() -> Result<()> {
+    let mut module = Module::new(&["config"]);
+    module.constant(&["ANSWER"], 42i64)?;
+    module.constant(&["RATIO"], 0.5f64)?;
+    module.constant(&["VERSION"], "1.0.0")?;
+
+    let mut context = Context::with_default_modules()?;
+    context.install(&module)?;
+    let context = Arc::new(context);
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        use config::VERSION;
+
+        fn main() {
+            (config::ANSWER + 1, config::RATIO, VERSION)
+        }
+        "#,
+    )?;
+
+    // NB: constants are inlined, so the unit doesn't reference them.
+    assert!(unit
+        .iter_instructions()
+        .any(|inst| matches!(inst, runestick::Inst::Integer { number: 42 })));
+
+    let vm = Vm::new(context, Arc::new(unit));
+    let output = vm.call(&["main"], ())?.complete()?;
+    let output = <(i64, f64, String)>::from_value(output)?;
+    assert_eq!(output, (43, 0.5, String::from("1.0.0")));
+    Ok(())
+}
+
+#[test]
+fn test_module_constant_errors() {
+    let mut module = Module::new(&["config"]);
+    module.constant(&["ANSWER"], 42i64).unwrap();
+
+    assert!(matches!(
+        module.constant(&["ANSWER"], 43i64),
+        Err(ContextError::ConflictingConstantName { .. })
+    ));
+
+    assert!(matches!(
+        module.constant(&["VALUES"], vec![1i64, 2i64]),
+        Err(ContextError::UnsupportedConstant { .. })
+    ));
+}
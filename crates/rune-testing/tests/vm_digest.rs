@@ -0,0 +1,57 @@
+use rune_testing::*;
+use runestick::{Bytes, Context, FromValue as _, Vm};
+use std::sync::Arc;
+
+fn hex(bytes: &Bytes) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[test]
+fn test_digest() -> Result<()> {
+    let mut context = Context::with_default_modules()?;
+    context.install(&rune_modules::digest::module()?)?;
+    let context = Arc::new(context);
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        use digest;
+
+        fn main() {
+            (
+                digest::sha256("abc"),
+                digest::sha256(b"abc"),
+                digest::md5(`a{"b"}c`),
+                digest::blake3(b"abc"),
+            )
+        }
+        "#,
+    )?;
+
+    let vm = Vm::new(context, Arc::new(unit));
+    let output = vm.call(&["main"], ())?.complete()?;
+    let (a, b, c, d) = <(Bytes, Bytes, Bytes, Bytes)>::from_value(output)?;
+
+    let sha256 = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+    assert_eq!(hex(&a), sha256);
+    assert_eq!(hex(&b), sha256);
+    assert_eq!(hex(&c), "900150983cd24fb0d6963f7d28e17f72");
+    assert_eq!(
+        hex(&d),
+        "6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_digest_bad_argument() -> Result<()> {
+    let mut context = Context::with_default_modules()?;
+    context.install(&rune_modules::digest::module()?)?;
+    let context = Arc::new(context);
+
+    let (unit, _) = compile_source(&context, r#"fn main() { digest::sha256(42) }"#)?;
+
+    let vm = Vm::new(context, Arc::new(unit));
+    assert!(vm.call(&["main"], ())?.complete().is_err());
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use rune_testing::*;
+
+#[test]
+fn test_float_constants() {
+    assert!(rune!(bool => r#"fn main() { float::NAN.is_nan() }"#));
+
+    assert_eq! {
+        rune!((f64, f64) => r#"fn main() { (float::INFINITY, float::NEG_INFINITY) }"#),
+        (f64::INFINITY, f64::NEG_INFINITY),
+    };
+}
+
+#[test]
+fn test_float_predicates() {
+    assert_eq! {
+        rune!((bool, bool, bool) => r#"
+        fn main() {
+            (float::NAN.is_nan(), float::INFINITY.is_nan(), 1.0.is_nan())
+        }
+        "#),
+        (true, false, false),
+    };
+
+    assert_eq! {
+        rune!((bool, bool, bool) => r#"
+        fn main() {
+            (float::INFINITY.is_infinite(), float::NEG_INFINITY.is_infinite(), float::NAN.is_infinite())
+        }
+        "#),
+        (true, true, false),
+    };
+
+    assert_eq! {
+        rune!((bool, bool, bool) => r#"
+        fn main() {
+            (1.0.is_finite(), float::INFINITY.is_finite(), float::NAN.is_finite())
+        }
+        "#),
+        (true, false, false),
+    };
+}
+
+#[test]
+fn test_float_min_max() {
+    assert_eq! {
+        rune!((f64, f64, f64, f64) => r#"
+        fn main() {
+            (1.0.min(2.0), 1.0.max(2.0), float::NAN.min(1.0), 2.0.max(float::NAN))
+        }
+        "#),
+        (1.0, 2.0, 1.0, 2.0),
+    };
+
+    assert!(rune!(bool => r#"fn main() { float::NAN.max(float::NAN).is_nan() }"#));
+}
@@ -0,0 +1,101 @@
+use rune_testing::*;
+
+#[test]
+fn test_for_object_entries() {
+    let (sum, mut keys) = rune! {
+        (i64, Vec<String>) => r#"
+        fn main() {
+            let object = #{"a": 1, "b": 2, "c": 3};
+            let sum = 0;
+            let keys = [];
+
+            for (key, value) in object {
+                sum += value;
+                keys.push(key);
+            }
+
+            (sum, keys)
+        }
+        "#
+    };
+
+    // NB: objects iterate in the order of their hash map.
+    keys.sort();
+    assert_eq!(sum, 6);
+    assert_eq!(keys, vec!["a", "b", "c"]);
+
+    assert_eq! {
+        rune! {
+            Vec<String> => r#"
+            fn main() {
+                let object = std::collections::SortedMap::new();
+                object.insert("b", 2);
+                object.insert("a", 1);
+
+                let out = [];
+
+                for (key, value) in object {
+                    out.push(`{key}={value}`);
+                }
+
+                out
+            }
+            "#
+        },
+        vec![String::from("a=1"), String::from("b=2")],
+    };
+}
+
+#[test]
+fn test_for_patterns() {
+    assert_eq! {
+        rune! {
+            i64 => r#"
+            fn main() {
+                let sum = 0;
+
+                for [a, (b, c)] in [[1, (2, 3)], [4, (5, 6)]] {
+                    sum += a * b * c;
+                }
+
+                for _ in std::iter::range(0, 2) {
+                    sum += 1;
+                }
+
+                sum
+            }
+            "#
+        },
+        128,
+    };
+
+    assert_eq! {
+        rune! {
+            Vec<i64> => r#"
+            fn main() {
+                let fns = [];
+
+                for (n, m) in [(1, 2), (3, 4)] {
+                    fns.push(|| n + m);
+                }
+
+                let out = [];
+
+                for f in fns {
+                    out.push(f());
+                }
+
+                out
+            }
+            "#
+        },
+        vec![3, 7],
+    };
+
+    assert_vm_error!(
+        r#"fn main() { for (a, b) in [(1, 2), 3] { } }"#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "pattern did not match");
+        }
+    );
+}
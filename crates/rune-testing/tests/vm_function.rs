@@ -50,3 +50,194 @@ fn test_function() {
     let value: Value = function.call((1i64,)).unwrap();
     assert!(matches!(value, Value::TypedTuple(..)));
 }
+
+#[test]
+fn test_variadic_function() {
+    assert_eq! {
+        rune!(i64 => r#"
+        fn sum(..xs) {
+            let out = 0;
+
+            for x in xs {
+                out = out + x;
+            }
+
+            out
+        }
+
+        fn main() {
+            sum() + sum(1) + sum(2, 3, 4)
+        }
+        "#),
+        10,
+    };
+
+    assert_eq! {
+        rune!((i64, Vec<i64>) => r#"
+        fn split(first, ..rest) {
+            (first, rest)
+        }
+
+        fn main() {
+            split(1, 2, 3)
+        }
+        "#),
+        (1, vec![2, 3]),
+    };
+
+    // Through function pointers and closures.
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn count(a, ..rest) {
+            rest.len() + a
+        }
+
+        fn main() {
+            let f = count;
+            let g = |..xs| xs.len();
+            [f(10), f(10, 1, 2), g(), g(1, 2, 3)]
+        }
+        "#),
+        vec![10, 12, 0, 3],
+    };
+
+    let function = rune! {
+        Function => r#"
+        fn sum(a, ..xs) {
+            let out = a;
+
+            for x in xs {
+                out = out + x;
+            }
+
+            out
+        }
+
+        fn main() {
+            sum
+        }
+        "#
+    };
+
+    assert_eq!(function.call::<_, i64>((1i64, 2i64, 3i64)).unwrap(), 6i64);
+    assert!(function.call::<_, i64>(()).is_err());
+
+    assert_compile_error! {
+        r#"fn f(a, ..rest) { a } fn main() { f() }"#,
+        ArgumentCountMismatch { span, expected, actual } => {
+            assert_eq!(span, Span::new(34, 37));
+            assert_eq!(expected, 1);
+            assert_eq!(actual, 0);
+        }
+    };
+
+    assert_compile_error! {
+        r#"fn f(..rest, a) { a } fn main() { f() }"#,
+        UnsupportedRestArgument { span } => {
+            assert_eq!(span, Span::new(5, 11));
+        }
+    };
+}
+
+#[test]
+fn test_default_arguments() {
+    assert_eq! {
+        rune!((String, String) => r#"
+        fn greet(name, greeting = "Hello") {
+            `{greeting}, {name}!`
+        }
+
+        fn main() {
+            (greet("World"), greet("World", "Goodbye"))
+        }
+        "#),
+        (String::from("Hello, World!"), String::from("Goodbye, World!")),
+    };
+
+    // Defaults can refer to earlier arguments, and combine with rest
+    // arguments.
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn f(a, b = a * 2, ..rest) {
+            a + b + rest.len()
+        }
+
+        fn main() {
+            let g = |a, b = 10| a + b;
+            [f(1), f(1, 1), f(1, 1, 0, 0), g(1), g(1, 2)]
+        }
+        "#),
+        vec![3, 2, 4, 11, 3],
+    };
+
+    let function = rune! {
+        Function => r#"
+        fn add(a, b = 1) {
+            a + b
+        }
+
+        fn main() {
+            add
+        }
+        "#
+    };
+
+    assert_eq!(function.call::<_, i64>((1i64,)).unwrap(), 2i64);
+    assert_eq!(function.call::<_, i64>((1i64, 2i64)).unwrap(), 3i64);
+    assert!(function.call::<_, i64>(()).is_err());
+    assert!(function.call::<_, i64>((1i64, 2i64, 3i64)).is_err());
+
+    assert_compile_error! {
+        r#"fn f(a, b = 1) { a } fn main() { f(1, 2, 3) }"#,
+        ArgumentCountMismatch { expected, actual, .. } => {
+            assert_eq!(expected, 2);
+            assert_eq!(actual, 3);
+        }
+    };
+
+    assert_compile_error! {
+        r#"fn f(a = 1, b) { a } fn main() { f(1, 2) }"#,
+        RequiredArgumentAfterDefault { span } => {
+            assert_eq!(span, Span::new(12, 13));
+        }
+    };
+}
+
+#[test]
+fn test_named_arguments() {
+    assert_eq! {
+        rune!(Vec<(i64, i64, i64)> => r#"
+        fn point(x, y, z = 0) {
+            (x, y, z)
+        }
+
+        fn main() {
+            [point(y: 2, x: 1), point(1, z: 3, y: 2), point(z: 3, y: 2, x: 1)]
+        }
+        "#),
+        vec![(1, 2, 0), (1, 2, 3), (1, 2, 3)],
+    };
+}
+
+#[test]
+fn test_named_arguments_evaluation_order() {
+    assert_eq! {
+        rune!((Vec<String>, (i64, i64, i64)) => r#"
+        fn point(x, y, z = 0) {
+            (x, y, z)
+        }
+
+        fn log(out, name, value) {
+            out.push(name);
+            value
+        }
+
+        fn main() {
+            let out = [];
+            let point = point(z: log(out, "z", 3), x: log(out, "x", 1), y: log(out, "y", 2));
+            (out, point)
+        }
+        "#),
+        (vec![String::from("z"), String::from("x"), String::from("y")], (1, 2, 3)),
+    };
+}
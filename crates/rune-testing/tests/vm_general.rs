@@ -100,6 +100,47 @@ fn test_if() {
         },
         10,
     };
+
+    assert_eq! {
+        rune! {
+            i64 => r#"
+            fn main() {
+                let n = 6;
+                let x = if n > 5 { 10 } else { 0 };
+                x + 1
+            }
+            "#
+        },
+        11,
+    };
+
+    assert_eq! {
+        rune! {
+            i64 => r#"
+            fn add_one(n) {
+                n + 1
+            }
+
+            fn main() {
+                let n = 3;
+                add_one(if n > 5 { 10 } else if n > 2 { 20 } else { 0 })
+            }
+            "#
+        },
+        21,
+    };
+
+    assert_eq! {
+        rune! {
+            () => r#"
+            fn main() {
+                let n = 6;
+                if n > 5 { n + 1 }
+            }
+            "#
+        },
+        (),
+    };
 }
 
 #[test]
@@ -147,6 +188,32 @@ fn test_vectors() {
     };
 }
 
+#[test]
+fn test_ordering() {
+    assert_eq! {
+        rune!((bool, bool, bool, bool, bool, bool) => r#"
+        fn main() {
+            (
+                [1, 2] < [1, 3],
+                (1, "a") < (1, "b"),
+                [1, 2] < [1, 2, 0],
+                [[1], [2]] >= [[1], [1, 5]],
+                1 < 1.5,
+                (0.0 / 0.0) < 1.0,
+            )
+        }
+        "#),
+        (true, true, true, true, true, false),
+    };
+
+    assert_vm_error!(
+        r#"fn main() { [1, 2] < [1, "a"] }"#,
+        UnsupportedBinaryOperation { op, .. } => {
+            assert_eq!(*op, "<");
+        }
+    );
+}
+
 #[test]
 fn test_while() {
     assert_eq! {
@@ -304,6 +371,56 @@ fn test_for() {
     };
 }
 
+#[test]
+fn test_loop_values() {
+    assert_eq! {
+        rune!(() => r#"fn main() { let c = false; let _ = while c { }; }"#),
+        (),
+    };
+
+    assert_eq! {
+        rune!(() => r#"fn main() { let c = false; let x = while c { }; x }"#),
+        (),
+    };
+
+    assert_eq! {
+        rune!(() => r#"fn main() { let x = for n in std::iter::range(0, 3) { }; x }"#),
+        (),
+    };
+
+    assert_eq! {
+        rune!(i64 => r#"fn main() { let v = 7; let x = loop { break v; }; x }"#),
+        7,
+    };
+
+    assert_eq! {
+        rune!(i64 => r#"fn main() { let v = 7; loop { break v } }"#),
+        7,
+    };
+
+    assert_eq! {
+        rune!(i64 => r#"
+        fn main() {
+            let a = 1;
+            let x = [while false { }, for n in std::iter::range(0, 2) { }, loop { break 3; }];
+            a + x[2]
+        }
+        "#),
+        4,
+    };
+
+    assert_eq! {
+        rune!(i64 => r#"
+        fn main() {
+            let a = 1;
+            let x = (a, for n in std::iter::range(0, 2) { let b = n; }, loop { let c = 2; break c; });
+            a + x.2
+        }
+        "#),
+        3,
+    };
+}
+
 #[test]
 fn test_return() {
     assert_eq! {
@@ -710,6 +827,102 @@ fn test_template_string() {
     };
 }
 
+#[test]
+fn test_template_string_debug() {
+    assert_eq! {
+        rune! {
+            String => r#"
+            fn main() {
+                let s = "hello";
+                let v = [1, s, (2,), Some('a'), Err(2.5)];
+                `{s} {s:?} {v:?}`
+            }
+            "#
+        },
+        r#"hello "hello" [1, "hello", (2,), Some('a'), Err(2.5)]"#,
+    };
+}
+
+#[test]
+fn test_cyclic_debug() {
+    let source = r#"
+    fn main() {
+        let v = [1, 2];
+        v.push(v);
+        (`{v:?}`, v)
+    }
+    "#;
+
+    let (template, value) = rune!((String, Value) => source);
+    assert_eq!(template, "[1, 2, ...]");
+    assert_eq!(format!("{:?}", value), "[1, 2, ...]");
+    assert_eq!(format!("{:#?}", value), "[\n    1,\n    2,\n    ...,\n]");
+}
+
+#[test]
+fn test_template_string_pretty_debug() {
+    let source = r#"
+    fn main() {
+        let v = [1, #{"a": [2, (3, "b")]}, []];
+        (`{v:?}`, `{v:#?}`)
+    }
+    "#;
+
+    let (flat, pretty) = rune!((String, String) => source);
+    assert_eq!(flat, r#"[1, {"a": [2, (3, "b")]}, []]"#);
+    assert_eq!(
+        pretty,
+        r#"[
+    1,
+    {
+        "a": [
+            2,
+            (
+                3,
+                "b",
+            ),
+        ],
+    },
+    [],
+]"#
+    );
+}
+
+#[test]
+fn test_deep_clone() {
+    assert_eq! {
+        rune! {
+            (Vec<Vec<i64>>, Vec<Vec<i64>>, String, String) => r#"
+            fn main() {
+                let a = [[1], [2]];
+                let b = clone(a);
+                b[0].push(3);
+                b.push([4]);
+
+                let s = String::from_str("hello");
+                let t = clone(s);
+                t.push_str(" world");
+
+                (a, b, s, t)
+            }
+            "#
+        },
+        (
+            vec![vec![1], vec![2]],
+            vec![vec![1, 3], vec![2], vec![4]],
+            String::from("hello"),
+            String::from("hello world"),
+        ),
+    };
+
+    assert_vm_error!(
+        r#"fn main() { let v = []; v.push(v); clone(v) }"#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "cannot clone a value which contains itself");
+        }
+    );
+}
+
 #[test]
 fn test_variants_as_functions() {
     assert_eq! {
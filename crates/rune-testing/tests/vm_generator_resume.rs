@@ -0,0 +1,82 @@
+use rune_testing::*;
+use runestick::{Context, FromValue as _, Generator, GeneratorState, Stream, Value, Vm};
+use std::sync::Arc;
+
+const SOURCE: &str = r#"
+fn dialogue() {
+    let name = yield "name?";
+    let age = yield `hello {name}, age?`;
+    `{name} is {age}`
+}
+
+async fn async_dialogue() {
+    let a = yield 1;
+    let b = yield a + 1;
+    a + b
+}
+
+fn generator() {
+    dialogue()
+}
+
+fn stream() {
+    async_dialogue()
+}
+"#;
+
+fn vm() -> Result<Vm> {
+    let context = Arc::new(Context::with_default_modules()?);
+    let (unit, _) = compile_source(&context, SOURCE)?;
+    Ok(Vm::new(context, Arc::new(unit)))
+}
+
+#[test]
+fn test_generator_resume_from_host() -> Result<()> {
+    let output = vm()?.call(&["generator"], ())?.complete()?;
+    let mut generator = Generator::from_value(output)?;
+
+    let mut answers = vec![Value::from(42i64), Value::from(String::from("Alice"))];
+    let mut state = generator.resume(Value::Unit)?;
+    let mut questions = Vec::new();
+
+    let output = loop {
+        match state {
+            GeneratorState::Yielded(question) => {
+                questions.push(String::from_value(question)?);
+                state = generator.resume(answers.pop().unwrap())?;
+            }
+            GeneratorState::Complete(output) => break String::from_value(output)?,
+        }
+    };
+
+    assert_eq!(questions, vec!["name?", "hello Alice, age?"]);
+    assert_eq!(output, "Alice is 42");
+    assert!(generator.resume(Value::Unit).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_stream_resume_from_host() -> Result<()> {
+    let output = vm()?.call(&["stream"], ())?.complete()?;
+    let mut stream = Stream::from_value(output)?;
+
+    block_on(async move {
+        let mut state = stream.resume(Value::Unit).await?;
+        let mut yielded = Vec::new();
+
+        let output = loop {
+            match state {
+                GeneratorState::Yielded(value) => {
+                    let value = i64::from_value(value)?;
+                    yielded.push(value);
+                    state = stream.resume(Value::from(value * 10)).await?;
+                }
+                GeneratorState::Complete(output) => break i64::from_value(output)?,
+            }
+        };
+
+        assert_eq!(yielded, vec![1, 11]);
+        assert_eq!(output, 120);
+        Ok(())
+    })
+}
@@ -61,3 +61,35 @@ fn test_resume() {
         6,
     };
 }
+
+#[test]
+fn test_yield_evaluates_to_resume_value() {
+    assert_eq! {
+        rune! {
+            (i64, i64, i64, Vec<i64>) => r#"
+            use std::generator::GeneratorState;
+
+            fn foo() {
+                let a = yield;
+                let b = 10 + yield a;
+                let c = if b > 0 { yield b } else { 0 };
+                let d = [yield c, yield];
+                (a, b, c, d)
+            }
+
+            fn main() {
+                let gen = foo();
+                let n = 1;
+
+                loop {
+                    match gen.resume(n) {
+                        GeneratorState::Yielded(..) => n += 1,
+                        GeneratorState::Complete(value) => break value,
+                    }
+                }
+            }
+            "#
+        },
+        (2, 13, 4, vec![5, 6]),
+    };
+}
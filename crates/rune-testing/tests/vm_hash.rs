@@ -0,0 +1,83 @@
+use rune_testing::*;
+
+#[test]
+fn test_hash_equal_structures() {
+    assert_eq! {
+        rune!((bool, bool, bool, bool) => r#"
+        fn main() {
+            let s = "hello";
+
+            (
+                hash([1, "a", (true, 'c')]) == hash([1, "a", (true, 'c')]),
+                hash(s) == hash(`{s}`),
+                hash(#{a: 1, b: 2}) == hash(#{b: 2, a: 1}),
+                hash(Some(Ok(42))) == hash(Some(Ok(42))),
+            )
+        }
+        "#),
+        (true, true, true, true),
+    };
+}
+
+#[test]
+fn test_hash_different_structures() {
+    assert_eq! {
+        rune!((bool, bool, bool, bool, bool) => r#"
+        fn main() {
+            (
+                hash(1) != hash(2),
+                hash([1, 2]) != hash([2, 1]),
+                hash([1, 2]) != hash((1, 2)),
+                hash([[1], 2]) != hash([1, [2]]),
+                hash(Ok(1)) != hash(Err(1)),
+            )
+        }
+        "#),
+        (true, true, true, true, true),
+    };
+}
+
+#[test]
+fn test_hash_typed() {
+    assert_eq! {
+        rune!((bool, bool) => r#"
+        struct Point { x, y }
+        enum Shape { Circle(r), Square(w) }
+
+        fn main() {
+            (
+                hash(Point { x: 1, y: 2 }) == hash(Point { y: 2, x: 1 }),
+                hash(Shape::Circle(1)) != hash(Shape::Square(1)),
+            )
+        }
+        "#),
+        (true, true),
+    };
+}
+
+#[test]
+fn test_hash_is_stable() {
+    // NB: the hash only depends on the structure of the value, so it doesn't
+    // change between runs.
+    assert_eq!(rune!(i64 => r#"fn main() { hash([1, "a"]) }"#), STABLE_HASH);
+}
+
+const STABLE_HASH: i64 = -598126027709552357;
+
+#[test]
+fn test_hash_unsupported() {
+    assert_vm_error!(
+        r#"fn main() { hash(1.0) }"#,
+        UnsupportedHash { actual } => assert_eq!(actual.to_string(), "float")
+    );
+
+    assert_vm_error!(
+        r#"fn main() { hash([1, main]) }"#,
+        UnsupportedHash { .. } => ()
+    );
+
+    assert_vm_error!(
+        r#"fn main() { let a = []; a.push(a); hash(a) }"#,
+        RecursionLimit { .. } => ()
+    );
+}
@@ -0,0 +1,89 @@
+use rune_testing::*;
+use runestick::{Capability, Context, ContextConfig, ContextError, DefaultModule, Input, Item, Vm};
+use std::io;
+use std::sync::Arc;
+
+fn input_context() -> Result<Context> {
+    let config = ContextConfig::default()
+        .with(DefaultModule::Io)
+        .with(DefaultModule::Input)
+        .grant(Capability::Stdin);
+    Ok(Context::with_config(&config)?)
+}
+
+fn run<T>(context: Context, source: &str) -> Result<T>
+where
+    T: runestick::FromValue,
+{
+    let context = Arc::new(context);
+    let (unit, _) = compile_source(&context, source)?;
+    let vm = Vm::new(context, Arc::new(unit));
+    let output = vm.call(&["main"], ())?.complete()?;
+    Ok(T::from_value(output)?)
+}
+
+#[test]
+fn test_read_line() -> Result<()> {
+    let context = input_context()?;
+    context.set_input(io::Cursor::new("hello\n\nworld"));
+
+    let lines: Vec<String> = run(
+        context,
+        r#"
+        fn main() {
+            [
+                std::io::read_line()?,
+                std::io::read_line()?,
+                std::io::read_line()?,
+                std::io::read_line()?,
+            ]
+        }
+        "#,
+    )?;
+
+    // NB: an empty line is distinguishable from the end of input.
+    assert_eq!(lines, vec!["hello\n", "\n", "world", ""]);
+    Ok(())
+}
+
+#[test]
+fn test_read_line_error() -> Result<()> {
+    let context = input_context()?;
+    context.set_input(io::Cursor::new(vec![0xff, b'\n']));
+
+    let is_error: bool = run(
+        context,
+        r#"
+        fn main() {
+            match std::io::read_line() {
+                Err(e) => e is std::io::Error,
+                _ => false,
+            }
+        }
+        "#,
+    )?;
+
+    assert!(is_error);
+    Ok(())
+}
+
+#[test]
+fn test_read_line_requires_capability() -> Result<()> {
+    let read_line = Item::of(&["std", "io", "read_line"]);
+
+    let config = ContextConfig::default().with(DefaultModule::Input);
+    let mut context = Context::with_config(&config)?;
+    assert!(!context.contains_name(&read_line));
+
+    let module = runestick::modules::io::input_module_with_input(Input::stdin())?;
+
+    match context.install(&module) {
+        Err(ContextError::MissingCapability { capability, .. }) => {
+            assert_eq!(capability, Capability::Stdin);
+        }
+        other => panic!("expected missing capability, got {:?}", other),
+    }
+
+    assert!(input_context()?.contains_name(&read_line));
+    Ok(())
+}
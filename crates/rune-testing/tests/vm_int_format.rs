@@ -0,0 +1,86 @@
+use rune_testing::*;
+
+#[test]
+fn test_int_radix() {
+    assert_eq! {
+        rune!((String, String, String, String) => r#"
+        fn main() {
+            (255.to_hex(), 10.to_binary(), 8.to_octal(), 0.to_hex())
+        }
+        "#),
+        (
+            String::from("ff"),
+            String::from("1010"),
+            String::from("10"),
+            String::from("0"),
+        ),
+    };
+
+    assert_eq! {
+        rune!((String, String, String) => r#"
+        fn main() {
+            (35.to_radix(36)?, 255.to_radix(2)?, (0 - 255).to_hex())
+        }
+        "#),
+        (
+            String::from("z"),
+            String::from("11111111"),
+            String::from("-ff"),
+        ),
+    };
+}
+
+#[test]
+fn test_int_radix_padded() {
+    assert_eq! {
+        rune!((String, String, String, String, String) => r#"
+        fn main() {
+            (
+                255.to_hex_padded(4),
+                5.to_binary_padded(8),
+                8.to_octal_padded(1),
+                (0 - 5).to_binary_padded(5),
+                35.to_radix_padded(36, 3)?,
+            )
+        }
+        "#),
+        (
+            String::from("00ff"),
+            String::from("00000101"),
+            String::from("10"),
+            String::from("-0101"),
+            String::from("00z"),
+        ),
+    };
+}
+
+#[test]
+fn test_int_invalid_radix() {
+    assert_eq! {
+        rune!((bool, bool, String) => r#"
+        fn main() {
+            let too_small = match 10.to_radix(1) {
+                Err(e) => e is std::int::RadixError,
+                _ => false,
+            };
+
+            let too_large = match 10.to_radix_padded(37, 4) {
+                Err(e) => e is std::int::RadixError,
+                _ => false,
+            };
+
+            let message = match 10.to_radix(37) {
+                Err(e) => `{e}`,
+                _ => "",
+            };
+
+            (too_small, too_large, message)
+        }
+        "#),
+        (
+            true,
+            true,
+            String::from("radix `37` is not in the range 2 to 36"),
+        ),
+    };
+}
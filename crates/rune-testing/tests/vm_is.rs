@@ -24,3 +24,24 @@ fn test_binop_override() {
         (true, false, false, true),
     };
 }
+
+#[test]
+fn test_is_precedence() {
+    assert_eq! {
+        rune! {
+            (bool, bool, bool) => r#"
+            fn main() {
+                let a = "hello";
+                let b = 42;
+
+                (
+                    a is String && b is int,
+                    a is not String || b + 1 is int,
+                    a is String == b is int,
+                )
+            }
+            "#
+        },
+        (true, true, true),
+    };
+}
@@ -0,0 +1,597 @@
+use rune_testing::*;
+
+#[test]
+fn test_rev() {
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn main() {
+            let out = [];
+
+            for n in [1, 2, 3].iter().rev() {
+                out.push(n);
+            }
+
+            for n in std::iter::range(0, 3).rev() {
+                out.push(n);
+            }
+
+            out
+        }
+        "#),
+        vec![3, 2, 1, 2, 1, 0],
+    };
+
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn main() {
+            let out = [];
+
+            for n in [1, 2, 3, 4, 5].iter().step_by(2) {
+                out.push(n);
+            }
+
+            out
+        }
+        "#),
+        vec![1, 3, 5],
+    };
+
+    assert_vm_error!(
+        r#"fn main() { std::iter::range(0, 3).step_by(2).rev() }"#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "`std::iter::Range` is not a double-ended iterator");
+        }
+    );
+}
+
+#[test]
+fn test_range_step_by() {
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn main() {
+            let out = [];
+
+            for n in std::iter::range(0, 10).step_by(2) {
+                out.push(n);
+            }
+
+            out
+        }
+        "#),
+        vec![0, 2, 4, 6, 8],
+    };
+
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn main() {
+            let out = [];
+
+            for n in std::iter::range(0, 10).step_by(3) {
+                out.push(n);
+            }
+
+            out
+        }
+        "#),
+        vec![0, 3, 6, 9],
+    };
+
+    assert_vm_error!(
+        r#"fn main() { std::iter::range(0, 10).step_by(0) }"#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "step must be non-zero");
+        }
+    );
+}
+
+#[test]
+fn test_peekable() {
+    assert_eq! {
+        rune!((Option<i64>, Option<i64>, Option<i64>, Option<i64>) => r#"
+        fn main() {
+            let it = [1, 2].iter().peekable();
+            let a = it.peek();
+            let b = it.peek();
+            let c = it.next();
+            let d = it.peek();
+            (a, b, c, d)
+        }
+        "#),
+        (Some(1), Some(1), Some(1), Some(2)),
+    };
+
+    assert_eq! {
+        rune!((Option<i64>, Option<i64>, Option<i64>) => r#"
+        fn main() {
+            let it = std::iter::range(0, 1).peekable();
+            let a = it.next();
+            let b = it.peek();
+            let c = it.next();
+            (a, b, c)
+        }
+        "#),
+        (Some(0), None, None),
+    };
+
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn main() {
+            let it = std::iter::range(0, 3).peekable();
+            let out = [];
+
+            while let Some(n) = it.peek() {
+                out.push(n);
+                it.next();
+            }
+
+            out
+        }
+        "#),
+        vec![0, 1, 2],
+    };
+
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn main() {
+            let it = [1, 2, 3].iter().peekable();
+            it.peek();
+
+            let out = [];
+
+            for n in it {
+                out.push(n);
+            }
+
+            out
+        }
+        "#),
+        vec![1, 2, 3],
+    };
+
+    assert_vm_error!(
+        r#"fn main() { std::iter::range(0, 3).peek() }"#,
+        MissingInstanceFunction { .. } => {}
+    );
+}
+
+#[test]
+fn test_take_while() {
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn main() {
+            let out = [];
+
+            for n in std::iter::range(0, 10).take_while(|n| n < 4) {
+                out.push(n);
+            }
+
+            out
+        }
+        "#),
+        vec![0, 1, 2, 3],
+    };
+
+    // Stops at the first false, even if later values match.
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn main() {
+            let out = [];
+
+            for n in [1, 2, 5, 1, 2].iter().take_while(|n| n < 3) {
+                out.push(n);
+            }
+
+            out
+        }
+        "#),
+        vec![1, 2],
+    };
+
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn main() {
+            let out = [];
+
+            for n in std::iter::range(0, 10).take_while(|n| n > 4) {
+                out.push(n);
+            }
+
+            out
+        }
+        "#),
+        Vec::<i64>::new(),
+    };
+}
+
+#[test]
+fn test_skip_while() {
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn main() {
+            let out = [];
+
+            for n in std::iter::range(0, 6).skip_while(|n| n < 4) {
+                out.push(n);
+            }
+
+            out
+        }
+        "#),
+        vec![4, 5],
+    };
+
+    // Starts yielding at the first false, including later values that match.
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn main() {
+            let out = [];
+
+            for n in [1, 2, 5, 1, 2].iter().skip_while(|n| n < 3) {
+                out.push(n);
+            }
+
+            out
+        }
+        "#),
+        vec![5, 1, 2],
+    };
+
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn main() {
+            let out = [];
+
+            for n in std::iter::range(0, 10).skip_while(|n| n < 20) {
+                out.push(n);
+            }
+
+            out
+        }
+        "#),
+        Vec::<i64>::new(),
+    };
+}
+
+#[test]
+fn test_chain() {
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn main() {
+            let out = [];
+
+            for n in std::iter::range(0, 3).chain(std::iter::range(7, 10)) {
+                out.push(n);
+            }
+
+            out
+        }
+        "#),
+        vec![0, 1, 2, 7, 8, 9],
+    };
+
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn main() {
+            let out = [];
+
+            for n in [1, 2].iter().chain(std::iter::range(3, 5)).rev() {
+                out.push(n);
+            }
+
+            out
+        }
+        "#),
+        vec![4, 3, 2, 1],
+    };
+
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn main() {
+            let out = [];
+            let it = std::iter::range(0, 10).take_while(|n| n < 2);
+
+            for n in it.chain([5, 6].iter()) {
+                out.push(n);
+            }
+
+            out
+        }
+        "#),
+        vec![0, 1, 5, 6],
+    };
+}
+
+#[test]
+fn test_count_last_nth() {
+    assert_eq! {
+        rune!(i64 => r#"fn main() { std::iter::range(0, 10).count() }"#),
+        10,
+    };
+
+    assert_eq! {
+        rune!(i64 => r#"fn main() { std::iter::range(0, 0).count() }"#),
+        0,
+    };
+
+    assert_eq! {
+        rune!(Option<i64> => r#"fn main() { std::iter::range(0, 10).last() }"#),
+        Some(9),
+    };
+
+    assert_eq! {
+        rune!(Option<i64> => r#"fn main() { std::iter::range(0, 0).last() }"#),
+        None,
+    };
+
+    assert_eq! {
+        rune!(Option<i64> => r#"fn main() { std::iter::range(0, 10).nth(3) }"#),
+        Some(3),
+    };
+
+    assert_eq! {
+        rune!(Option<i64> => r#"fn main() { std::iter::range(0, 10).nth(10) }"#),
+        None,
+    };
+
+    assert_eq! {
+        rune!((i64, Option<char>, Option<char>) => r#"
+        fn main() {
+            ((0..10).iter().count(), "hello".chars().last(), "hello".chars().nth(5))
+        }
+        "#),
+        (10, Some('o'), None),
+    };
+
+    // Values before the nth are consumed.
+    assert_eq! {
+        rune!(Option<i64> => r#"
+        fn main() {
+            let it = std::iter::range(0, 10);
+            it.nth(2);
+            it.next()
+        }
+        "#),
+        Some(3),
+    };
+}
+
+#[test]
+fn test_collect_object() {
+    let object = rune! {
+        runestick::Object<i64> => r#"
+        fn main() {
+            std::iter::range(0, 3).map(|n| (`key{n}`, n * 2)).collect_object()
+        }
+        "#
+    };
+
+    assert_eq!(object.len(), 3);
+    assert_eq!(object.get("key0"), Some(&0));
+    assert_eq!(object.get("key1"), Some(&2));
+    assert_eq!(object.get("key2"), Some(&4));
+
+    assert_vm_error!(
+        r#"fn main() { [("a", 1, 2)].iter().collect_object() }"#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "expected a `(key, value)` pair, but got a tuple of length 3");
+        }
+    );
+
+    assert_vm_error!(
+        r#"fn main() { [(1, 2)].iter().collect_object() }"#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "object keys must be strings, but got `integer`");
+        }
+    );
+}
+
+#[test]
+fn test_flat_map() {
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn main() {
+            let out = [];
+
+            for n in std::iter::range(0, 4).flat_map(|n| std::iter::range(0, n)) {
+                out.push(n);
+            }
+
+            out
+        }
+        "#),
+        vec![0, 0, 1, 0, 1, 2],
+    };
+
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn main() {
+            let out = [];
+
+            for n in [1, 2, 3].iter().flat_map(|n| [n, n * 10]) {
+                out.push(n);
+            }
+
+            out
+        }
+        "#),
+        vec![1, 10, 2, 20, 3, 30],
+    };
+
+    assert_vm_error!(
+        r#"fn main() { std::iter::range(0, 3).flat_map(|n| n).next() }"#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "`integer` is not iterable");
+        }
+    );
+}
+
+#[test]
+fn test_scan() {
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn main() {
+            let out = [];
+
+            for n in std::iter::range(1, 6).scan(0, |sum, n| Some((sum + n, sum + n))) {
+                out.push(n);
+            }
+
+            out
+        }
+        "#),
+        vec![1, 3, 6, 10, 15],
+    };
+
+    // Returning `None` ends the iteration.
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn main() {
+            let out = [];
+            let it = [1, 2, 3, 4, 5].iter().scan(1, |product, n| {
+                if product > 5 {
+                    return None;
+                }
+
+                Some((product * n, product * n))
+            });
+
+            for n in it {
+                out.push(n);
+            }
+
+            out
+        }
+        "#),
+        vec![1, 2, 6],
+    };
+}
+
+#[test]
+fn test_enumerate() {
+    assert_eq! {
+        rune!(Vec<(i64, i64)> => r#"
+        fn main() {
+            let out = [];
+
+            for (i, n) in (10..13).enumerate() {
+                out.push((i, n));
+            }
+
+            out
+        }
+        "#),
+        vec![(0, 10), (1, 11), (2, 12)],
+    };
+
+    assert_eq! {
+        rune!(Vec<(i64, char)> => r#"
+        fn main() {
+            let out = [];
+
+            for (i, c) in "hej".chars().enumerate() {
+                out.push((i, c));
+            }
+
+            out
+        }
+        "#),
+        vec![(0, 'h'), (1, 'e'), (2, 'j')],
+    };
+
+    assert_eq! {
+        rune!(Vec<(i64, (i64, String))> => r#"
+        fn main() {
+            let out = [];
+
+            for n in ["a", "b"].enumerate().skip_while(|p| p.0 < 1).enumerate() {
+                out.push(n);
+            }
+
+            out
+        }
+        "#),
+        vec![(0, (1, String::from("b")))],
+    };
+}
+
+#[test]
+fn test_range_iter() {
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn main() {
+            let out = [];
+
+            for n in 0..3 {
+                out.push(n);
+            }
+
+            for n in (5..).iter().take_while(|n| n < 7) {
+                out.push(n);
+            }
+
+            out
+        }
+        "#),
+        vec![0, 1, 2, 5, 6],
+    };
+
+    assert_vm_error!(
+        r#"fn main() { for n in ..3 { } }"#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "cannot iterate over a range without a start");
+        }
+    );
+}
+
+#[test]
+fn test_find_any_all() {
+    assert_eq! {
+        rune!((bool, Vec<i64>) => r#"
+        fn main() {
+            let seen = [];
+            let found = std::iter::range(0, 10).any(|n| { seen.push(n); n == 3 });
+            (found, seen)
+        }
+        "#),
+        (true, vec![0, 1, 2, 3]),
+    };
+
+    assert_eq! {
+        rune!((bool, Vec<i64>) => r#"
+        fn main() {
+            let seen = [];
+            let all = [2, 4, 5, 6].iter().all(|n| { seen.push(n); n % 2 == 0 });
+            (all, seen)
+        }
+        "#),
+        (false, vec![2, 4, 5]),
+    };
+
+    assert_eq! {
+        rune!((Option<i64>, Option<i64>, Option<i64>) => r#"
+        fn main() {
+            let it = std::iter::range(0, 10);
+            let found = it.find(|n| n > 4);
+            let next = it.next();
+            (found, next, it.find(|n| n > 10))
+        }
+        "#),
+        (Some(5), Some(6), None),
+    };
+
+    assert_eq! {
+        rune!((bool, bool) => r#"
+        fn main() {
+            ([].iter().any(|n| true), [].iter().all(|n| false))
+        }
+        "#),
+        (false, true),
+    };
+
+    assert_vm_error!(
+        r#"fn main() { std::iter::range(0, 10).any(|n| if n == 2 { panic("boom") } else { false }) }"#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "boom");
+        }
+    );
+}
@@ -0,0 +1,101 @@
+use rune_testing::*;
+use runestick::{Context, ContextConfig, Vm};
+use std::sync::{Arc, Mutex};
+
+/// A logger which records everything logged to it.
+struct TestLogger {
+    records: Mutex<Vec<(log::Level, String, String)>>,
+}
+
+impl log::Log for TestLogger {
+    fn enabled(&self, _: &log::Metadata<'_>) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record<'_>) {
+        self.records.lock().unwrap().push((
+            record.level(),
+            record.target().to_owned(),
+            record.args().to_string(),
+        ));
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: TestLogger = TestLogger {
+    records: Mutex::new(Vec::new()),
+};
+
+/// Take all records logged to the given target.
+fn take_records(target: &str) -> Vec<(log::Level, String)> {
+    let mut records = LOGGER.records.lock().unwrap();
+    let (taken, rest) = records.drain(..).partition::<Vec<_>, _>(|r| r.1 == target);
+    *records = rest;
+    taken.into_iter().map(|(level, _, m)| (level, m)).collect()
+}
+
+fn run(context: Context, source: &str) -> Result<()> {
+    let _ = log::set_logger(&LOGGER);
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let context = Arc::new(context);
+    let (unit, _) = compile_source(&context, source)?;
+    let vm = Vm::new(context, Arc::new(unit));
+    vm.call(&["main"], ())?.complete()?;
+    Ok(())
+}
+
+#[test]
+fn test_log_module() -> Result<()> {
+    let mut context = Context::with_default_modules()?;
+    context.install(&rune_modules::log::module_with_target("plugin")?)?;
+
+    run(
+        context,
+        r#"
+        fn main() {
+            log::info("x");
+            log::warn("careful");
+            log::error(`failed with {1 + 2}`);
+            log::debug("details");
+        }
+        "#,
+    )?;
+
+    assert_eq!(
+        take_records("plugin"),
+        vec![
+            (log::Level::Info, String::from("x")),
+            (log::Level::Warn, String::from("careful")),
+            (log::Level::Error, String::from("failed with 3")),
+            (log::Level::Debug, String::from("details")),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_log_print_module() -> Result<()> {
+    let mut context = Context::with_config(&ContextConfig::default())?;
+    context.install(&rune_modules::log::print_module_with_target("printed")?)?;
+
+    run(
+        context,
+        r#"
+        fn main() {
+            println("hello");
+            dbg(42);
+        }
+        "#,
+    )?;
+
+    assert_eq!(
+        take_records("printed"),
+        vec![
+            (log::Level::Info, String::from("hello")),
+            (log::Level::Debug, String::from("42")),
+        ]
+    );
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use rune_testing::*;
+
+#[test]
+fn test_mem_swap() {
+    assert_eq! {
+        rune!((Vec<i64>, Vec<i64>) => r#"
+        fn main() {
+            let a = [1, 2, 3];
+            let b = [4];
+            let c = a;
+            mem_swap(a, b);
+            (c, b)
+        }
+        "#),
+        (vec![4], vec![1, 2, 3]),
+    };
+
+    assert_eq! {
+        rune!((String, String) => r#"
+        fn main() {
+            let a = String::from_str("a");
+            let b = String::from_str("b");
+            mem_swap(a, b);
+            (a, b)
+        }
+        "#),
+        (String::from("b"), String::from("a")),
+    };
+}
+
+#[test]
+fn test_mem_replace() {
+    assert_eq! {
+        rune!((Vec<i64>, Vec<i64>, Vec<i64>) => r#"
+        fn main() {
+            let a = [1, 2];
+            let b = [3];
+            let old = mem_replace(a, b);
+            b.push(4);
+            (a, b, old)
+        }
+        "#),
+        (vec![3], vec![3, 4], vec![1, 2]),
+    };
+}
+
+#[test]
+fn test_mem_take() {
+    assert_eq! {
+        rune!((Vec<i64>, Vec<i64>, Option<i64>, Option<i64>) => r#"
+        fn main() {
+            let a = [1, 2];
+            let old = mem_take(a);
+            let o = Some(1);
+            let taken = mem_take(o);
+            (a, old, o, taken)
+        }
+        "#),
+        (vec![], vec![1, 2], None, Some(1)),
+    };
+}
+
+#[test]
+fn test_mem_aliasing() {
+    // NB: swapping a value with itself would alias, so it panics instead,
+    // which can be caught.
+    assert_eq! {
+        rune!(bool => r#"
+        fn main() {
+            let a = [1];
+            match try_catch(|| mem_swap(a, a)) {
+                Err(_) => a == [1],
+                _ => false,
+            }
+        }
+        "#),
+        true,
+    };
+}
+
+#[test]
+fn test_mem_unsupported() {
+    assert_vm_error!(
+        r#"fn main() { mem_swap([], "") }"#,
+        BadReturn { error, .. } => match error.kind() {
+            BadArgumentType { arg, .. } => assert_eq!(*arg, 1),
+            kind => panic!("unexpected error {:?}", kind),
+        }
+    );
+
+    assert_vm_error!(
+        r#"fn main() { mem_take(Ok(1)) }"#,
+        BadReturn { error, .. } => match error.kind() {
+            UnsupportedMemOperation { op, .. } => assert_eq!(*op, "mem_take"),
+            kind => panic!("unexpected error {:?}", kind),
+        }
+    );
+}
@@ -0,0 +1,58 @@
+use rune_testing::*;
+use runestick::{Context, FromValue as _, Vm};
+use std::sync::Arc;
+
+fn run_with_limit(source: &str, limit: usize) -> Result<Value, runestick::VmError> {
+    let context = Arc::new(Context::with_default_modules().unwrap());
+    let (unit, _) = compile_source(&context, source).unwrap();
+    let vm = Vm::new(context, Arc::new(unit)).with_memory_limit(limit);
+    vm.call(&["main"], ())?.complete()
+}
+
+#[test]
+fn test_memory_limit() {
+    let source = r#"
+    fn main() {
+        let values = [];
+        let n = 0;
+
+        while n < 1000000 {
+            values.push(n);
+            n += 1;
+        }
+
+        values.len()
+    }
+    "#;
+
+    let error = run_with_limit(source, 1 << 20).unwrap_err();
+
+    match error.kind().into_unwound_ref().0 {
+        MemoryLimitExceeded { limit } => assert_eq!(*limit, 1 << 20),
+        kind => panic!("unexpected error: {:?}", kind),
+    }
+
+    let source = r#"
+    fn main() {
+        let s = "";
+        let n = 0;
+
+        while n < 1000 {
+            s = `{s}{n}`;
+            n += 1;
+        }
+
+        s.len()
+    }
+    "#;
+
+    let error = run_with_limit(source, 1024).unwrap_err();
+
+    match error.kind().into_unwound_ref().0 {
+        MemoryLimitExceeded { .. } => (),
+        kind => panic!("unexpected error: {:?}", kind),
+    }
+
+    let value = run_with_limit(source, 1 << 20).unwrap();
+    assert_eq!(i64::from_value(value).unwrap(), 2890);
+}
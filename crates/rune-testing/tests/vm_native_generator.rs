@@ -0,0 +1,69 @@
+use rune_testing::*;
+use runestick::{Context, FromValue as _, Generator, GeneratorState, Module, Value, Vm};
+use std::sync::Arc;
+
+/// A generator which yields the running total of the values it's resumed
+/// with, and completes with the total after three values.
+fn accumulate() -> Generator {
+    let mut total = 0;
+    let mut count = 0;
+
+    Generator::from_fn(move |value| {
+        if let Value::Integer(n) = value {
+            total += n;
+        }
+
+        count += 1;
+
+        Ok(if count > 3 {
+            GeneratorState::Complete(Value::from(total))
+        } else {
+            GeneratorState::Yielded(Value::from(total))
+        })
+    })
+}
+
+#[test]
+fn test_native_generator() -> Result<()> {
+    let mut module = Module::new(&["native"]);
+    module.function(&["accumulate"], accumulate)?;
+
+    let mut context = Context::with_default_modules()?;
+    context.install(&module)?;
+    let context = Arc::new(context);
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        use std::generator::GeneratorState;
+
+        fn main() {
+            let gen = native::accumulate();
+            let yielded = [];
+
+            while let Some(value) = gen.next() {
+                yielded.push(value);
+            }
+
+            let gen = native::accumulate();
+            let states = [];
+            let completed = 0;
+
+            for n in [1, 2, 3, 4] {
+                match gen.resume(n) {
+                    GeneratorState::Yielded(value) => states.push(value),
+                    GeneratorState::Complete(value) => completed = value,
+                }
+            }
+
+            (yielded, states, completed)
+        }
+        "#,
+    )?;
+
+    let vm = Vm::new(context, Arc::new(unit));
+    let output = vm.call(&["main"], ())?.complete()?;
+    let output = <(Vec<i64>, Vec<i64>, i64)>::from_value(output)?;
+    assert_eq!(output, (vec![0, 0, 0], vec![1, 3, 6], 10));
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use rune_testing::*;
+use runestick::{Context, FromValue as _, Iterator, Module, Vm, VmErrorKind};
+use std::sync::Arc;
+
+fn context() -> Result<Arc<Context>> {
+    let mut module = Module::new(&["native"]);
+    module.function(&["range"], |n: i64| Iterator::from("native::Range", 0..n))?;
+    module.function(&["overflow"], || {
+        Iterator::from("native::Overflow", vec![1u64, u64::MAX].into_iter())
+    })?;
+
+    let mut context = Context::with_default_modules()?;
+    context.install(&module)?;
+    Ok(Arc::new(context))
+}
+
+#[test]
+fn test_native_iterator() -> Result<()> {
+    let context = context()?;
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        fn main() {
+            let sum = 0;
+
+            for n in native::range(5) {
+                sum += n;
+            }
+
+            (sum, native::range(10).count())
+        }
+        "#,
+    )?;
+
+    let vm = Vm::new(context, Arc::new(unit));
+    let output = vm.call(&["main"], ())?.complete()?;
+    assert_eq!(<(i64, i64)>::from_value(output)?, (10, 10));
+    Ok(())
+}
+
+#[test]
+fn test_native_iterator_conversion_error() -> Result<()> {
+    let context = context()?;
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        fn main() {
+            for n in native::overflow() {
+            }
+        }
+        "#,
+    )?;
+
+    let vm = Vm::new(context, Arc::new(unit));
+    let error = vm.call(&["main"], ())?.complete().unwrap_err();
+    let (error, _) = error.into_unwound();
+
+    match error.kind() {
+        VmErrorKind::BadReturn { error, .. } => {
+            assert!(matches!(
+                error.kind(),
+                VmErrorKind::IntegerToValueCoercionError { .. }
+            ));
+        }
+        kind => panic!("unexpected error: {:?}", kind),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use futures::stream::StreamExt as _;
+use rune_testing::*;
+use runestick::{Context, FromValue as _, Module, Stream, Vm};
+use std::sync::Arc;
+
+#[derive(Debug)]
+struct PageError;
+
+runestick::impl_external!(PageError);
+
+/// Produce pages of results, where the page after `pages` fails.
+fn pages(pages: i64) -> Stream {
+    let stream = futures::stream::iter(0..=pages).then(move |page| async move {
+        if page == pages {
+            Err(PageError)
+        } else {
+            Ok(vec![page * 2, page * 2 + 1])
+        }
+    });
+
+    Stream::from_stream(stream)
+}
+
+#[test]
+fn test_native_stream() -> Result<()> {
+    let mut module = Module::new(&["native"]);
+    module.ty(&["PageError"]).build::<PageError>()?;
+    module.function(&["pages"], pages)?;
+
+    let mut context = Context::with_default_modules()?;
+    context.install(&module)?;
+    let context = Arc::new(context);
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        async fn sum(stream) {
+            let sum = 0;
+
+            while let Some(page) = stream.next().await {
+                for n in page? {
+                    sum += n;
+                }
+            }
+
+            Ok(sum)
+        }
+
+        async fn main() {
+            let result = match sum(native::pages(3)).await {
+                Ok(..) => false,
+                Err(error) => error is native::PageError,
+            };
+
+            let stream = native::pages(2);
+            let first = match stream.next().await {
+                Some(Ok(page)) => page,
+                _ => [],
+            };
+
+            (result, first)
+        }
+        "#,
+    )?;
+
+    let vm = Vm::new(context, Arc::new(unit));
+    let output = block_on(vm.call(&["main"], ())?.async_complete())?;
+    let (result, first) = <(bool, Vec<i64>)>::from_value(output)?;
+    assert!(result);
+    assert_eq!(first, vec![0, 1]);
+    Ok(())
+}
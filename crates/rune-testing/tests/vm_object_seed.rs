@@ -0,0 +1,37 @@
+//! Kept in its own test binary since the object seed is global to the
+//! process.
+
+use rune_testing::*;
+
+fn object_keys() -> Vec<String> {
+    rune!(Vec<String> => r#"
+    fn main() {
+        let object = #{};
+        let n = 0;
+
+        while n < 32 {
+            object[`key{n}`] = n;
+            n += 1;
+        }
+
+        let keys = [];
+
+        for entry in object {
+            keys.push(entry.0);
+        }
+
+        keys
+    }
+    "#)
+}
+
+#[test]
+fn test_deterministic_object_order() {
+    runestick::set_object_seed(Some(42));
+    let first = object_keys();
+    let second = object_keys();
+    runestick::set_object_seed(None);
+
+    assert_eq!(first.len(), 32);
+    assert_eq!(first, second);
+}
@@ -0,0 +1,70 @@
+use rune_testing::*;
+use runestick::{Context, ContextConfig, DefaultModule, Vm};
+use std::io;
+use std::sync::{Arc, Mutex};
+
+/// A writer which stores everything written to it in a shared buffer.
+#[derive(Clone, Default)]
+struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+impl Buffer {
+    fn contents(&self) -> String {
+        String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+    }
+}
+
+impl io::Write for Buffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn run(context: Arc<Context>, source: &str) -> Result<()> {
+    let (unit, _) = compile_source(&context, source)?;
+    let vm = Vm::new(context, Arc::new(unit));
+    vm.call(&["main"], ())?.complete()?;
+    Ok(())
+}
+
+#[test]
+fn test_output_sink() -> Result<()> {
+    let context = Context::with_config(&ContextConfig::default().with(DefaultModule::Io))?;
+    let buffer = Buffer::default();
+    context.set_output(buffer.clone());
+
+    run(Arc::new(context), r#"fn main() { println("hi"); }"#)?;
+    assert_eq!(buffer.contents(), "hi\n");
+    Ok(())
+}
+
+#[test]
+fn test_output_sink_all_print_functions() -> Result<()> {
+    let context = Arc::new(Context::with_config(
+        &ContextConfig::default().with(DefaultModule::Io),
+    )?);
+
+    // NB: the sink can be replaced while the context is shared.
+    let buffer = Buffer::default();
+    context.set_output(buffer.clone());
+
+    run(
+        context,
+        r#"
+        fn main() {
+            print("a");
+            print("b");
+            println("");
+            dbg(1, "two");
+            dbg_pretty(());
+        }
+        "#,
+    )?;
+
+    assert_eq!(buffer.contents(), "ab\n\"two\"\n1\n()\n");
+    Ok(())
+}
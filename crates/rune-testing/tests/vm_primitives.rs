@@ -0,0 +1,107 @@
+use rune_testing::*;
+use runestick::{Context, FromValue as _, Module, Vm, VmErrorKind};
+use std::sync::Arc;
+
+fn context() -> Result<Arc<Context>> {
+    let mut module = Module::new(&["native"]);
+    module.function(&["unit"], |value: ()| value)?;
+    module.function(&["bool"], |value: bool| !value)?;
+    module.function(&["byte"], |value: u8| value.wrapping_add(1))?;
+    module.function(&["char"], |value: char| value.to_ascii_uppercase())?;
+    module.function(&["u16"], |value: u16| value + 1)?;
+    module.function(&["i16"], |value: i16| value - 1)?;
+
+    let mut context = Context::with_default_modules()?;
+    context.install(&module)?;
+    Ok(Arc::new(context))
+}
+
+#[test]
+fn test_primitive_round_trip() -> Result<()> {
+    let context = context()?;
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        fn main() {
+            (
+                native::unit(()),
+                native::bool(true),
+                native::byte(b'a'),
+                native::char('a'),
+                native::u16(41),
+                native::i16(-41),
+            )
+        }
+        "#,
+    )?;
+
+    let vm = Vm::new(context, Arc::new(unit));
+    let output = vm.call(&["main"], ())?.complete()?;
+    let output = <((), bool, u8, char, u16, i16)>::from_value(output)?;
+    assert_eq!(output, ((), false, b'b', 'A', 42, -42));
+    Ok(())
+}
+
+#[test]
+fn test_primitive_mismatch() -> Result<()> {
+    let context = context()?;
+
+    let cases = [
+        ("native::unit(1)", "()", "unit"),
+        ("native::bool(1)", "bool", "bool"),
+        ("native::byte('a')", "u8", "byte"),
+        ("native::char(b'a')", "char", "char"),
+    ];
+
+    for (call, to, expected) in cases.iter().copied() {
+        let source = format!("fn main() {{ {} }}", call);
+        let (unit, _) = compile_source(&context, &source)?;
+        let vm = Vm::new(context.clone(), Arc::new(unit));
+        let error = vm.call(&["main"], ())?.complete().unwrap_err();
+
+        let (error, _) = error.into_unwound();
+
+        match error.kind() {
+            VmErrorKind::BadArgument {
+                error,
+                arg: 0,
+                to: actual,
+            } => {
+                assert_eq!(*actual, to);
+
+                match error.kind() {
+                    VmErrorKind::Expected { expected: info, .. } => {
+                        assert_eq!(info.to_string(), expected);
+                    }
+                    kind => panic!("unexpected error: {:?}", kind),
+                }
+            }
+            kind => panic!("unexpected error: {:?}", kind),
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_integer_narrowing() -> Result<()> {
+    let context = context()?;
+    let (unit, _) = compile_source(&context, "fn main() { native::u16(70000) }")?;
+    let vm = Vm::new(context, Arc::new(unit));
+    let error = vm.call(&["main"], ())?.complete().unwrap_err();
+
+    let (error, _) = error.into_unwound();
+
+    match error.kind() {
+        VmErrorKind::BadArgument { error, arg: 0, .. } => {
+            assert!(matches!(
+                error.kind(),
+                VmErrorKind::ValueToIntegerCoercionError { to: "u16", .. }
+            ));
+        }
+        kind => panic!("unexpected error: {:?}", kind),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use rune_testing::*;
+use runestick::{Capability, Context, FromValue as _, Vm};
+use std::sync::Arc;
+
+#[tokio::test]
+async fn test_process_run() -> Result<()> {
+    let mut context = Context::with_default_modules()?;
+
+    // NB: running processes must be explicitly granted.
+    assert!(context.install(&rune_modules::process::module()?).is_err());
+
+    context.grant(Capability::Process);
+    context.install(&rune_modules::process::module()?)?;
+    let context = Arc::new(context);
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        async fn main() {
+            let output = process::run("echo", ["hello", "world"]).await?;
+
+            let spawn_failed = match process::run("this-command-does-not-exist", []).await {
+                Ok(..) => false,
+                Err(error) => error is std::io::Error,
+            };
+
+            Ok((output.stdout, output.stderr, output.code, spawn_failed))
+        }
+        "#,
+    )?;
+
+    let vm = Vm::new(context, Arc::new(unit));
+    let output = vm.call(&["main"], ())?.async_complete().await?;
+    let output = <Result<(String, String, Option<i64>, bool), Value>>::from_value(output)?;
+
+    assert_eq!(
+        output.unwrap(),
+        (String::from("hello world\n"), String::new(), Some(0), true)
+    );
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use rune_testing::*;
+
+#[test]
+fn test_range_forms() {
+    assert_eq! {
+        rune!((Option<i64>, Option<i64>) => r#"fn main() { let r = ..; (r.start, r.end) }"#),
+        (None, None),
+    };
+
+    assert_eq! {
+        rune!((Option<i64>, Option<i64>) => r#"fn main() { let r = 1..; (r.start, r.end) }"#),
+        (Some(1), None),
+    };
+
+    assert_eq! {
+        rune!((Option<i64>, Option<i64>) => r#"fn main() { let r = ..2; (r.start, r.end) }"#),
+        (None, Some(2)),
+    };
+
+    assert_eq! {
+        rune!((Option<i64>, Option<i64>) => r#"fn main() { let r = 1..2; (r.start, r.end) }"#),
+        (Some(1), Some(2)),
+    };
+
+    assert_eq! {
+        rune!((Option<i64>, Option<i64>) => r#"
+        fn main() {
+            let a = 1;
+            let r = a + 1..a * 4;
+            (r.start, r.end)
+        }
+        "#),
+        (Some(2), Some(4)),
+    };
+
+    assert_eq! {
+        rune!((Option<i64>, Option<i64>) => r#"
+        fn main() {
+            let r = 0;
+            r = 3..;
+            (r.start, r.end)
+        }
+        "#),
+        (Some(3), None),
+    };
+}
+
+#[test]
+fn test_range_as_value() {
+    assert_eq! {
+        rune!(i64 => r#"
+        fn len(r) {
+            r.end.unwrap_or(10) - r.start.unwrap_or(0)
+        }
+
+        fn main() {
+            len(..) + len(2..) + len(..4) + len(2..3)
+        }
+        "#),
+        10 + 8 + 4 + 1,
+    };
+}
+
+#[test]
+fn test_range_slicing() {
+    assert_eq! {
+        rune!(Vec<i64> => r#"fn main() { let v = [1, 2, 3, 4]; v[1..3] }"#),
+        vec![2, 3],
+    };
+
+    assert_eq! {
+        rune!(Vec<i64> => r#"fn main() { let v = [1, 2, 3, 4]; v[..] }"#),
+        vec![1, 2, 3, 4],
+    };
+
+    assert_eq! {
+        rune!(Vec<i64> => r#"fn main() { let v = [1, 2, 3, 4]; v[2..] }"#),
+        vec![3, 4],
+    };
+
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn main() {
+            let v = [1, 2, 3, 4];
+            let r = ..2;
+            v[r]
+        }
+        "#),
+        vec![1, 2],
+    };
+
+    assert_vm_error!(
+        r#"fn main() { let v = [1, 2, 3]; v[2..4] }"#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "range out of bounds");
+        }
+    );
+}
@@ -0,0 +1,44 @@
+use rune_testing::*;
+
+#[test]
+fn test_recursion_limit() {
+    let source = r#"
+    fn nested(n) {
+        let value = [];
+
+        while n > 0 {
+            value = [value];
+            n -= 1;
+        }
+
+        value
+    }
+
+    fn main() {
+        let a = nested(4000);
+        let b = nested(4000);
+        let c = nested(3999);
+        (a == b, a != c, a > c)
+    }
+    "#;
+
+    assert_eq!(rune!((bool, bool, bool) => source), (true, true, true));
+
+    runestick::set_recursion_limit(100);
+
+    assert_vm_error!(
+        r#"fn main() { let a = []; a.push(a); a == a }"#,
+        RecursionLimit { limit } => {
+            assert_eq!(*limit, 100);
+        }
+    );
+
+    assert_vm_error!(
+        r#"fn main() { let a = []; a.push(a); a < a }"#,
+        RecursionLimit { limit } => {
+            assert_eq!(*limit, 100);
+        }
+    );
+
+    runestick::set_recursion_limit(runestick::DEFAULT_RECURSION_LIMIT);
+}
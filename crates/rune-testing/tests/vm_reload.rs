@@ -0,0 +1,65 @@
+use rune_testing::*;
+use runestick::{Context, FromValue as _, Hash, Item, Vm, VmErrorKind};
+use std::sync::Arc;
+
+#[test]
+fn test_reload() {
+    let context = Arc::new(Context::with_default_modules().unwrap());
+
+    let v1 = r#"
+    fn main() {
+        helper() + 1
+    }
+
+    fn helper() {
+        1
+    }
+    "#;
+
+    let v2 = r#"
+    fn main() {
+        helper() * 10
+    }
+
+    fn helper() {
+        4
+    }
+    "#;
+
+    let v3 = r#"
+    fn helper() {
+        1
+    }
+    "#;
+
+    let (unit, _) = compile_source(&context, v1).unwrap();
+    let mut vm = Vm::new(context.clone(), Arc::new(unit));
+
+    let mut execution = vm.call(&["main"], ()).unwrap();
+    assert_eq!(i64::from_value(execution.complete().unwrap()).unwrap(), 2);
+    vm = execution.into_vm().unwrap();
+
+    let (unit, _) = compile_source(&context, v2).unwrap();
+    vm.reload(Arc::new(unit), vec![&["main"], &["helper"]])
+        .unwrap();
+
+    let mut execution = vm.call(&["main"], ()).unwrap();
+    assert_eq!(i64::from_value(execution.complete().unwrap()).unwrap(), 40);
+    vm = execution.into_vm().unwrap();
+
+    let (unit, _) = compile_source(&context, v3).unwrap();
+    let error = vm
+        .reload(Arc::new(unit), vec![&["main"], &["helper"]])
+        .unwrap_err();
+
+    match error.kind() {
+        VmErrorKind::MissingFunction { hash } => {
+            assert_eq!(*hash, Hash::type_hash(Item::of(&["main"])));
+        }
+        kind => panic!("unexpected error: {:?}", kind),
+    }
+
+    // NB: the failed reload leaves the previous unit in place.
+    let mut execution = vm.call(&["main"], ()).unwrap();
+    assert_eq!(i64::from_value(execution.complete().unwrap()).unwrap(), 40);
+}
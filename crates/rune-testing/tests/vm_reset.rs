@@ -0,0 +1,55 @@
+use rune_testing::*;
+use runestick::{Context, FromValue as _, Shared, Vm};
+use std::sync::Arc;
+
+#[test]
+fn test_reset() {
+    let context = Arc::new(Context::with_default_modules().unwrap());
+
+    let source = r#"
+    fn add(a, b) {
+        a + b
+    }
+
+    fn fail() {
+        let values = [1, 2, 3];
+        values[10]
+    }
+    "#;
+
+    let (unit, _) = compile_source(&context, source).unwrap();
+    let mut vm = Vm::new(context, Arc::new(unit));
+
+    let mut execution = vm.call(&["add"], (1i64, 2i64)).unwrap();
+    let output = i64::from_value(execution.complete().unwrap()).unwrap();
+    assert_eq!(output, 3);
+
+    vm = execution.into_vm().unwrap();
+    vm.reset();
+
+    let mut execution = vm.call(&["fail"], ()).unwrap();
+    assert!(execution.complete().is_err());
+
+    vm = execution.into_vm().unwrap();
+    assert!(!vm.stack().is_empty());
+    vm.reset();
+    assert!(vm.stack().is_empty());
+
+    let mut execution = vm.call(&["add"], (40i64, 2i64)).unwrap();
+    let output = i64::from_value(execution.complete().unwrap()).unwrap();
+    assert_eq!(output, 42);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "virtual machine reset while values on its stack are borrowed")]
+fn test_reset_with_borrow() {
+    let context = Arc::new(Context::new());
+    let mut vm = Vm::new(context, Arc::new(runestick::Unit::new()));
+
+    let vec = Shared::new(vec![Value::from(1i64)]);
+    vm.stack_mut().push(Value::Vec(vec.clone()));
+
+    let _guard = vec.borrow_ref().unwrap();
+    vm.reset();
+}
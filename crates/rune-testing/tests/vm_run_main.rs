@@ -0,0 +1,80 @@
+use rune_testing::*;
+use runestick::{Context, Module, Vm};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context as TaskContext, Poll};
+
+/// A future which is pending once before it completes, like a very short
+/// sleep.
+struct Sleep {
+    slept: bool,
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<()> {
+        if self.slept {
+            return Poll::Ready(());
+        }
+
+        self.slept = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+async fn sleep() {
+    Sleep { slept: false }.await
+}
+
+fn vm(source: &str) -> Result<Vm> {
+    let mut module = Module::new(&["time"]);
+    module.async_function(&["sleep"], sleep)?;
+
+    let mut context = Context::with_default_modules()?;
+    context.install(&module)?;
+    let context = Arc::new(context);
+
+    let (unit, _) = compile_source(&context, source)?;
+    Ok(Vm::new(context, Arc::new(unit)))
+}
+
+#[test]
+fn test_run_async_main() -> Result<()> {
+    let vm = vm(r#"
+        async fn main() {
+            time::sleep().await;
+            42
+        }
+        "#)?;
+
+    let output = vm.run_main::<i64, _>(block_on)?;
+    assert_eq!(output, 42);
+    Ok(())
+}
+
+#[test]
+fn test_run_sync_main() -> Result<()> {
+    let vm = vm(r#"
+        fn main() {
+            String::from_str("sync")
+        }
+        "#)?;
+
+    let output = vm.run_main::<String, _>(|_| panic!("executor should not be used"))?;
+    assert_eq!(output, "sync");
+    Ok(())
+}
+
+#[test]
+fn test_run_missing_main() -> Result<()> {
+    let vm = vm("fn not_main() {}")?;
+    let error = vm.run_main::<(), _>(block_on).unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        runestick::VmErrorKind::MissingFunction { .. }
+    ));
+    Ok(())
+}
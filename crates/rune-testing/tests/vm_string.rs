@@ -0,0 +1,153 @@
+use rune_testing::*;
+use runestick::Bytes;
+
+#[test]
+fn test_string_lines() {
+    assert_eq! {
+        rune!((Vec<String>, Vec<String>, Vec<String>, Vec<String>) => r#"
+        fn main() {
+            (
+                "foo\nbar".lines(),
+                "foo\r\nbar\r\n".lines(),
+                "foo\n\nbar\n".lines(),
+                "".lines(),
+            )
+        }
+        "#),
+        (
+            vec![String::from("foo"), String::from("bar")],
+            vec![String::from("foo"), String::from("bar")],
+            vec![String::from("foo"), String::new(), String::from("bar")],
+            vec![],
+        ),
+    };
+}
+
+#[test]
+fn test_string_bytes() {
+    assert_eq! {
+        rune!(Bytes => r#"
+        fn main() {
+            "abc".bytes()
+        }
+        "#),
+        Bytes::from_vec(b"abc".to_vec()),
+    };
+}
+
+#[test]
+fn test_string_repeat() {
+    assert_eq! {
+        rune!((String, String, String) => r#"
+        fn main() {
+            ("ab".repeat(3), "ab".repeat(0), "".repeat(10))
+        }
+        "#),
+        (String::from("ababab"), String::new(), String::new()),
+    };
+
+    assert_eq! {
+        rune!(String => r#"
+        fn main() {
+            match try_catch(|| "ab".repeat(1099511627776)) {
+                Err(message) => message,
+                Ok(_) => "no panic",
+            }
+        }
+        "#),
+        String::from("repeated string would be too large"),
+    };
+}
+
+#[test]
+fn test_string_pad() {
+    assert_eq! {
+        rune!((String, String, String, String, String) => r#"
+        fn main() {
+            (
+                "42".pad_start(5, '0'),
+                "42".pad_end(5, '.'),
+                "hello".pad_start(3, ' '),
+                "hello".pad_end(5, ' '),
+                "åäö".pad_start(4, '-'),
+            )
+        }
+        "#),
+        (
+            String::from("00042"),
+            String::from("42..."),
+            String::from("hello"),
+            String::from("hello"),
+            String::from("-åäö"),
+        ),
+    };
+}
+
+#[test]
+fn test_string_find() {
+    assert_eq! {
+        rune!((Option<i64>, Option<i64>, Option<i64>, Option<i64>) => r#"
+        fn main() {
+            let s = "abcabc";
+            (s.find("bc"), s.rfind("bc"), s.find("x"), s.rfind("x"))
+        }
+        "#),
+        (Some(1), Some(4), None, None),
+    };
+
+    // Indexes are byte offsets, so they skip over the width of multi-byte
+    // characters.
+    assert_eq! {
+        rune!((Option<i64>, Option<i64>, Option<i64>) => r#"
+        fn main() {
+            let s = "åäö-åäö";
+            (s.find("ä"), s.rfind("ä"), s.find("-"))
+        }
+        "#),
+        (Some(2), Some(9), Some(6)),
+    };
+}
+
+#[test]
+fn test_string_slice() {
+    assert_eq! {
+        rune!((String, String, String) => r#"
+        fn main() {
+            let s = "åäö-abc";
+            let dash = match s.find("-") { Some(n) => n, None => 0 };
+            (s.slice(0, dash), s.slice(dash + 1, s.len()), s.slice(2, 4))
+        }
+        "#),
+        (String::from("åäö"), String::from("abc"), String::from("ä")),
+    };
+
+    assert_eq! {
+        rune!((String, String, String) => r#"
+        fn main() {
+            let s = "åäö";
+
+            let out_of_range = match try_catch(|| s.slice(2, 10)) {
+                Err(message) => message,
+                Ok(_) => "no panic",
+            };
+
+            let reversed = match try_catch(|| s.slice(4, 2)) {
+                Err(message) => message,
+                Ok(_) => "no panic",
+            };
+
+            let mid_char = match try_catch(|| s.slice(1, 4)) {
+                Err(message) => message,
+                Ok(_) => "no panic",
+            };
+
+            (out_of_range, reversed, mid_char)
+        }
+        "#),
+        (
+            String::from("range `2..10` out of bounds for string of length 6"),
+            String::from("range `4..2` out of bounds for string of length 6"),
+            String::from("range `1..4` is not on a char boundary"),
+        ),
+    };
+}
@@ -41,3 +41,36 @@ fn test_instance_kinds() {
         (4, 5, 6, 7),
     };
 }
+
+#[test]
+fn test_bound_methods() {
+    assert_eq! {
+        rune! {
+            (Vec<i64>, i64) => r#"
+            struct Counter {
+                n,
+            }
+
+            impl Counter {
+                fn add(self, n) {
+                    self.n + n
+                }
+            }
+
+            fn apply(f, values) {
+                for v in values {
+                    f(v);
+                }
+            }
+
+            fn main() {
+                let v = [];
+                apply(v.push, [1, 2, 3]);
+                let add = Counter { n: 10 }.add;
+                (v, add(5))
+            }
+            "#
+        },
+        (vec![1, 2, 3], 15),
+    };
+}
@@ -1,4 +1,6 @@
 use rune_testing::*;
+use runestick::{Context, FromValue as _, Module, Vm};
+use std::sync::Arc;
 
 #[test]
 fn test_unwrap() {
@@ -38,3 +40,104 @@ fn test_unwrap() {
         Err(3),
     };
 }
+
+#[test]
+fn test_try_in_closures() {
+    assert_eq! {
+        rune! {
+            (Result<i64, i64>, Result<i64, i64>, Result<i64, i64>) => r#"
+            fn main() {
+                let n = 10;
+                let add = |v| Ok(v? + n);
+
+                let out = if n > 0 {
+                    let checked = |v| {
+                        let v = v?;
+                        if v < 0 { Err(v) } else { Ok(v) }
+                    };
+
+                    checked(add(Ok(-20)))
+                } else {
+                    Ok(0)
+                };
+
+                (add(Err(1)), add(Ok(2)), out)
+            }
+            "#
+        },
+        (Err(1), Ok(12), Err(-10)),
+    };
+
+    assert_eq! {
+        rune! {
+            (i64, Result<Result<i64, i64>, i64>) => r#"
+            fn main() {
+                fn inner(v) {
+                    Ok(v? * 2)
+                }
+
+                let count = 0;
+
+                for v in [Ok(1), Err(2), Ok(3)] {
+                    if inner(v).is_ok() {
+                        count += 1;
+                    }
+                }
+
+                (count, try { (|| Err(4)?)() })
+            }
+            "#
+        },
+        (2, Ok(Err(4))),
+    };
+}
+
+#[derive(Debug)]
+struct Outcome(Result<i64, i64>);
+
+runestick::impl_external!(Outcome);
+
+impl Outcome {
+    fn ok(value: i64) -> Self {
+        Self(Ok(value))
+    }
+
+    fn err(value: i64) -> Self {
+        Self(Err(value))
+    }
+
+    fn branch(&self) -> Result<i64, i64> {
+        self.0
+    }
+}
+
+#[test]
+fn test_try_protocol() -> Result<()> {
+    let mut module = Module::new(&["outcome"]);
+    module.ty(&["Outcome"]).build::<Outcome>()?;
+    module.function(&["ok"], Outcome::ok)?;
+    module.function(&["err"], Outcome::err)?;
+    module.inst_fn(runestick::TRY, Outcome::branch)?;
+
+    let mut context = Context::with_default_modules()?;
+    context.install(&module)?;
+    let context = Arc::new(context);
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        fn add(a, b) {
+            a? + b?
+        }
+
+        fn main() {
+            (add(outcome::ok(1), outcome::ok(2)), add(outcome::ok(1), outcome::err(-1)))
+        }
+        "#,
+    )?;
+
+    let vm = Vm::new(context, Arc::new(unit));
+    let output = vm.call(&["main"], ())?.complete()?;
+    assert_eq!(<(i64, i64)>::from_value(output)?, (3, -1));
+    Ok(())
+}
@@ -0,0 +1,191 @@
+use rune::{Options, Warnings};
+use rune_testing::*;
+use runestick::{Context, FromValue as _, Source, Vm};
+use std::sync::Arc;
+
+fn run_catch_panics(source: &str) -> Result<Value, runestick::VmError> {
+    let context = Arc::new(Context::with_default_modules().unwrap());
+    let mut options = Options::default();
+    options.parse_option("catch-panics").unwrap();
+
+    let source = Source::new("main", source);
+    let unit = rune::load_source(&context, &options, source, &mut Warnings::new()).unwrap();
+    let vm = Vm::new(context, Arc::new(unit));
+    vm.call(&["main"], ())?.complete()
+}
+
+#[test]
+fn test_try_block() {
+    assert_eq! {
+        rune! {
+            Result<i64, String> => r#"
+            fn check(n) {
+                if n < 0 { Err(`{n} is negative`) } else { Ok(n) }
+            }
+
+            fn main() {
+                let a = 1;
+
+                let result = try {
+                    let b = check(2)?;
+                    a + b + check(-3)? + check(4)?
+                };
+
+                result
+            }
+            "#
+        },
+        Err(String::from("-3 is negative")),
+    };
+
+    assert_eq! {
+        rune! {
+            Result<i64, String> => r#"
+            fn check(n) {
+                if n < 0 { Err(`{n} is negative`) } else { Ok(n) }
+            }
+
+            fn main() {
+                let a = 1;
+                try { a + check(2)? }
+            }
+            "#
+        },
+        Ok(3),
+    };
+
+    assert_eq! {
+        rune! {
+            Result<(), i64> => r#"fn main() { try { Err(1)?; } }"#
+        },
+        Err(1),
+    };
+}
+
+#[test]
+fn test_try_block_temporaries() {
+    assert_eq! {
+        rune! {
+            (i64, Result<i64, i64>, Result<i64, i64>) => r#"
+            fn add(a, b) {
+                a + b
+            }
+
+            fn main() {
+                let out = [];
+
+                for n in [1, -1, 2] {
+                    let result = try {
+                        add(1, if n < 0 { Err(n) } else { Ok(n) }?)
+                    };
+
+                    out.push(result);
+                }
+
+                (out.len(), out[1], out[2])
+            }
+            "#
+        },
+        (3, Err(-1), Ok(3)),
+    };
+
+    assert_eq! {
+        rune! {
+            i64 => r#"
+            fn main() {
+                let n = 0;
+
+                loop {
+                    let result = try {
+                        n += 1;
+
+                        if n == 3 {
+                            break;
+                        }
+
+                        Err(n)?
+                    };
+                }
+
+                n
+            }
+            "#
+        },
+        3,
+    };
+}
+
+#[test]
+fn test_try_block_catch_panics() {
+    let source = r#"
+    fn boom(n) {
+        if n > 1 {
+            panic(`boom {n}`);
+        }
+
+        n
+    }
+
+    fn main() {
+        let values = [];
+
+        for n in [1, 2] {
+            values.push(try { boom(n) });
+        }
+
+        let inner = try {
+            let first = try { boom(1) }?;
+            let second = try { boom(first + 1) }?;
+            second
+        };
+
+        values.push(inner);
+        values
+    }
+    "#;
+
+    let output = Vec::<Result<i64, String>>::from_value(run_catch_panics(source).unwrap()).unwrap();
+
+    assert_eq!(
+        output,
+        vec![
+            Ok(1),
+            Err(String::from("boom 2")),
+            Err(String::from("boom 2")),
+        ]
+    );
+
+    // Breaking out of a block leaves its catch boundary.
+    let source = r#"
+    fn main() {
+        loop {
+            let result = try { break; };
+        }
+
+        panic("outside")
+    }
+    "#;
+
+    let error = run_catch_panics(source).unwrap_err();
+
+    match error.kind().into_unwound_ref().0 {
+        Panic { reason } => assert_eq!(reason.to_string(), "outside"),
+        kind => panic!("unexpected error: {:?}", kind),
+    }
+
+    // Errors which are not panics are propagated.
+    let error = run_catch_panics(r#"fn main() { try { 1 + "a" } }"#).unwrap_err();
+
+    match error.kind().into_unwound_ref().0 {
+        UnsupportedBinaryOperation { .. } => (),
+        kind => panic!("unexpected error: {:?}", kind),
+    }
+
+    // Without the option, panics still propagate out of the block.
+    assert_vm_error!(
+        r#"fn main() { try { panic("boom") } }"#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "boom");
+        }
+    );
+}
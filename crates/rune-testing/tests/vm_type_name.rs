@@ -0,0 +1,110 @@
+use rune_testing::*;
+use runestick::{Context, FromValue as _, Module, Vm};
+use std::sync::Arc;
+
+#[test]
+fn test_type_name() {
+    // NB: types available through the prelude are named as they are in it.
+    assert_eq! {
+        rune!(Vec<String> => r#"
+        fn main() {
+            [
+                type_name(1),
+                type_name(1.5),
+                type_name("hello"),
+                type_name([1, 2]),
+                type_name(#{}),
+                type_name(Some(1)),
+                type_name(()),
+            ]
+        }
+        "#),
+        vec!["int", "float", "String", "Vec", "Object", "Option", "unit"],
+    };
+}
+
+#[test]
+fn test_type_name_function() {
+    // NB: `type_name` is a regular function, so it can be used as a value.
+    assert_eq! {
+        rune!(String => r#"
+        fn main() {
+            let f = type_name;
+            f(1.5)
+        }
+        "#),
+        String::from("float"),
+    };
+}
+
+#[test]
+fn test_type_name_typed() {
+    assert_eq! {
+        rune!((String, String, String) => r#"
+        struct Point { x, y }
+        enum Shape { Circle(r), Square { w } }
+
+        fn main() {
+            (
+                type_name(Point { x: 1, y: 2 }),
+                type_name(Shape::Circle(1)),
+                type_name(Shape::Square { w: 1 }),
+            )
+        }
+        "#),
+        (String::from("Point"), String::from("Shape"), String::from("Shape")),
+    };
+}
+
+#[test]
+fn test_type_name_shadowed() {
+    assert_eq! {
+        rune!(i64 => r#"
+        fn type_name(value) {
+            value + 1
+        }
+
+        fn main() {
+            type_name(41)
+        }
+        "#),
+        42,
+    };
+
+    assert_compile_error! {
+        r#"fn main() { type_name(1, 2) }"#,
+        ArgumentCountMismatch { expected, actual, .. } => {
+            assert_eq!((expected, actual), (1, 2));
+        }
+    };
+}
+
+#[derive(Debug)]
+struct Widget;
+
+runestick::impl_external!(Widget);
+
+#[test]
+fn test_type_name_external() -> Result<()> {
+    let mut module = Module::new(&["widget"]);
+    module.ty(&["Widget"]).build::<Widget>()?;
+    module.function(&["new"], || Widget)?;
+
+    let mut context = Context::with_default_modules()?;
+    context.install(&module)?;
+    let context = Arc::new(context);
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        fn main() {
+            type_name(widget::new())
+        }
+        "#,
+    )?;
+
+    let vm = Vm::new(context, Arc::new(unit));
+    let output = vm.call(&["main"], ())?.complete()?;
+    assert_eq!(String::from_value(output)?, "widget::Widget");
+    Ok(())
+}
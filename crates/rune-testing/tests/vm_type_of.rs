@@ -0,0 +1,77 @@
+use rune_testing::*;
+use runestick::{Context, FromValue as _, Hash, Module, Vm};
+use std::sync::Arc;
+
+#[test]
+fn test_type_of() {
+    assert_eq! {
+        rune!((bool, bool, bool, bool) => r#"
+        fn main() {
+            (
+                type_of(1) == type_of(2),
+                type_of(1) == type_of("a"),
+                type_of(1) != type_of(1.0),
+                type_of("a") == type_of(String::from_str("b")),
+            )
+        }
+        "#),
+        (true, false, true, true),
+    };
+}
+
+#[test]
+fn test_type_of_typed() {
+    assert_eq! {
+        rune!((bool, bool, bool, bool) => r#"
+        struct Point { x, y }
+        struct Unit;
+        enum Shape { Circle(r), Square { w } }
+
+        fn main() {
+            let types = [type_of(Point { x: 1, y: 2 }), type_of(Unit)];
+
+            (
+                types[0] == type_of(Point { x: 3, y: 4 }),
+                types[0] != types[1],
+                type_of(Shape::Circle(1)) == type_of(Shape::Square { w: 1 }),
+                type_of(Some(1)) == type_of(None),
+            )
+        }
+        "#),
+        (true, true, true, true),
+    };
+}
+
+#[derive(Debug)]
+struct Widget;
+
+runestick::impl_external!(Widget);
+
+#[test]
+fn test_type_of_external() -> Result<()> {
+    let mut module = Module::new(&["widget"]);
+    module.ty(&["Widget"]).build::<Widget>()?;
+    module.function(&["new"], || Widget)?;
+
+    let mut context = Context::with_default_modules()?;
+    context.install(&module)?;
+    let context = Arc::new(context);
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        fn main() {
+            let ty = type_of(widget::new());
+            (ty, ty == type_of(widget::new()), ty == type_of(1))
+        }
+        "#,
+    )?;
+
+    let vm = Vm::new(context, Arc::new(unit));
+    let output = vm.call(&["main"], ())?.complete()?;
+    let (ty, same, other) = <(Value, bool, bool)>::from_value(output)?;
+
+    assert!(matches!(ty, Value::Type(hash) if hash == Hash::from_any::<Widget>()));
+    assert_eq!((same, other), (true, false));
+    Ok(())
+}
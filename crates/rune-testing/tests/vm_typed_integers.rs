@@ -0,0 +1,114 @@
+use rune_testing::*;
+
+#[test]
+fn test_byte_wrapping() {
+    assert_eq! {
+        rune! {
+            (u8, u8, u8, u8) => r#"
+            fn main() {
+                let a = 255u8;
+                a += 1u8;
+                (255u8 + 1u8, 0u8 - 1u8, 16u8 * 16u8, a)
+            }
+            "#
+        },
+        (0, 255, 0, 0),
+    };
+
+    assert_eq! {
+        rune! {
+            (u8, u8, u8, u8, u8) => r#"
+            fn main() {
+                (200u8 / 3u8, 200u8 % 3u8, 0xf0u8 | 0x0fu8, 0b10000001u8 << 1u8, 0x80u8 >> 7u8)
+            }
+            "#
+        },
+        (66, 2, 255, 2, 1),
+    };
+
+    assert_eq! {
+        rune! {
+            (bool, bool, i64) => r#"
+            fn main() {
+                let n = match 200u8 + 100u8 { 44u8 => 1, _ => 2 };
+                (255u8 + 1u8 == 0u8, 10u8 < 250u8, n)
+            }
+            "#
+        },
+        (true, true, 1),
+    };
+
+    assert_vm_error!(
+        r#"fn main() { 1u8 / 0u8 }"#,
+        DivideByZero => {}
+    );
+
+    assert_vm_error!(
+        r#"fn main() { 1u8 << 8u8 }"#,
+        Overflow => {}
+    );
+}
+
+#[test]
+fn test_integer_suffix() {
+    assert_eq! {
+        rune! {
+            (i64, i64) => r#"
+            fn main() {
+                (255i64 + 1i64, 0xffi64)
+            }
+            "#
+        },
+        (256, 255),
+    };
+
+    assert_vm_error!(
+        r#"fn main() { 9223372036854775807i64 + 1 }"#,
+        Overflow => {}
+    );
+}
+
+#[test]
+fn test_mixed_widths() {
+    assert_vm_error!(
+        r#"fn main() { 1u8 + 1 }"#,
+        UnsupportedBinaryOperation { op, .. } => {
+            assert_eq!(op, &"+");
+        }
+    );
+
+    assert_eq! {
+        rune! {
+            (i64, u8, String) => r#"
+            fn main() {
+                (250u8.to_int() + 10, 260.to_byte(), `{7u8}`)
+            }
+            "#
+        },
+        (260, 4, String::from("7")),
+    };
+}
+
+#[test]
+fn test_bad_byte_literals() {
+    assert_parse_error! {
+        r#"fn main() { 256u8 }"#,
+        BadByteOutOfBounds { span } => {
+            assert_eq!(span, Span::new(12, 17));
+        }
+    };
+
+    assert_parse_error! {
+        r#"fn main() { -1u8 }"#,
+        BadByteOutOfBounds { span } => {
+            assert_eq!(span, Span::new(12, 16));
+        }
+    };
+
+    assert_parse_error! {
+        r#"fn main() { 1.5u8 }"#,
+        BadNumberLiteral { span } => {
+            assert_eq!(span, Span::new(12, 17));
+        }
+    };
+}
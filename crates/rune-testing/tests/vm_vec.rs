@@ -0,0 +1,152 @@
+use rune_testing::*;
+
+#[test]
+fn test_vec_sum_product() {
+    assert_eq! {
+        rune!((i64, i64, i64, i64) => r#"
+        fn main() {
+            ([1, 2, 3, 4].sum(), [].sum(), [1, 2, 3, 4].product(), [].product())
+        }
+        "#),
+        (10, 0, 24, 1),
+    };
+
+    assert_eq! {
+        rune!((f64, f64) => r#"
+        fn main() {
+            ([1, 2.5, 3].sum(), [2, 0.5, 3].product())
+        }
+        "#),
+        (6.5, 3.0),
+    };
+
+    assert_vm_error!(
+        r#"fn main() { [1, "two", 3].sum() }"#,
+        BadReturn { error, .. } => {
+            match error.kind() {
+                UnsupportedBinaryOperation { op, .. } => assert_eq!(*op, "+"),
+                kind => panic!("unexpected error: {:?}", kind),
+            }
+        }
+    );
+}
+
+#[test]
+fn test_vec_min_max() {
+    assert_eq! {
+        rune!((Option<i64>, Option<i64>, Option<i64>, Option<i64>) => r#"
+        fn main() {
+            ([3, 1, 2].min(), [3, 1, 2].max(), [].min(), [].max())
+        }
+        "#),
+        (Some(1), Some(3), None, None),
+    };
+
+    assert_eq! {
+        rune!((Option<f64>, Option<f64>) => r#"
+        fn main() {
+            ([3, 1.5, 2].min(), [3, 1.5, 2].max())
+        }
+        "#),
+        (Some(1.5), Some(3.0)),
+    };
+
+    assert_eq! {
+        rune!(Option<String> => r#"
+        fn main() {
+            ["b", "c", "a"].min()
+        }
+        "#),
+        Some(String::from("a")),
+    };
+}
+
+#[test]
+fn test_vec_take_skip() {
+    assert_eq! {
+        rune!((Vec<i64>, Vec<i64>, Vec<i64>, Vec<i64>, Vec<i64>, Vec<i64>) => r#"
+        fn main() {
+            let v = [1, 2, 3];
+            (v.take(2), v.take(0), v.take(10), v.skip(1), v.skip(3), v.skip(10))
+        }
+        "#),
+        (vec![1, 2], vec![], vec![1, 2, 3], vec![2, 3], vec![], vec![]),
+    };
+}
+
+#[test]
+fn test_vec_chunks_windows() {
+    assert_eq! {
+        rune!((Vec<Vec<i64>>, Vec<Vec<i64>>, Vec<Vec<i64>>) => r#"
+        fn main() {
+            let v = [1, 2, 3, 4, 5];
+            (v.chunks(2), v.chunks(5), v.chunks(10))
+        }
+        "#),
+        (
+            vec![vec![1, 2], vec![3, 4], vec![5]],
+            vec![vec![1, 2, 3, 4, 5]],
+            vec![vec![1, 2, 3, 4, 5]],
+        ),
+    };
+
+    assert_eq! {
+        rune!((Vec<Vec<i64>>, Vec<Vec<i64>>, Vec<Vec<i64>>) => r#"
+        fn main() {
+            let v = [1, 2, 3];
+            (v.windows(2), v.windows(10), [].windows(2))
+        }
+        "#),
+        (vec![vec![1, 2], vec![2, 3]], vec![vec![1, 2, 3]], vec![]),
+    };
+
+    assert_eq! {
+        rune!((String, String) => r#"
+        fn main() {
+            let chunks = match try_catch(|| [1, 2].chunks(0)) {
+                Err(message) => message,
+                Ok(_) => "no panic",
+            };
+
+            let windows = match try_catch(|| [1, 2].windows(0)) {
+                Err(message) => message,
+                Ok(_) => "no panic",
+            };
+
+            (chunks, windows)
+        }
+        "#),
+        (
+            String::from("chunk size must be non-zero"),
+            String::from("window size must be non-zero"),
+        ),
+    };
+}
+
+#[test]
+fn test_vec_flatten() {
+    assert_eq! {
+        rune!((Vec<i64>, Vec<i64>) => r#"
+        fn main() {
+            ([[1, 2], [3]].flatten(), [[], []].flatten())
+        }
+        "#),
+        (vec![1, 2, 3], vec![]),
+    };
+
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn main() {
+            [1, 2, 3].flat_map(|n| [n, n * 2])
+        }
+        "#),
+        vec![1, 2, 2, 4, 3, 6],
+    };
+
+    assert_vm_error!(
+        r#"fn main() { [[1, 2], 3].flatten() }"#,
+        BadReturn { error, .. } => {
+            assert_eq!(error.to_string(), "expected `Vec`, but found `integer`");
+        }
+    );
+}
@@ -0,0 +1,126 @@
+use rune_testing::*;
+use runestick::{
+    Context, FromValue as _, Generator, GeneratorState, Value, Vm, VmErrorKind, VmHaltInfo,
+};
+use std::sync::Arc;
+
+#[test]
+fn test_yield_host() -> Result<()> {
+    let context = Arc::new(Context::with_default_modules()?);
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        fn work(n) {
+            let total = 0;
+
+            for i in std::iter::range(0, n) {
+                total += yield_host(i);
+            }
+
+            total
+        }
+
+        fn main() {
+            let before = 100;
+            yield_host();
+            before + work(3)
+        }
+        "#,
+    )?;
+
+    let vm = Vm::new(context, Arc::new(unit));
+    let mut execution = vm.call(&["main"], ())?;
+
+    assert!(matches!(
+        execution.resume()?,
+        GeneratorState::Yielded(Value::Unit)
+    ));
+
+    let mut yielded = Vec::new();
+    let mut state = execution.resume_with(Value::Unit)?;
+
+    let output = loop {
+        match state {
+            GeneratorState::Yielded(value) => {
+                let value = i64::from_value(value)?;
+                yielded.push(value);
+                state = execution.resume_with(Value::from(value * 10))?;
+            }
+            GeneratorState::Complete(value) => break i64::from_value(value)?,
+        }
+    };
+
+    assert_eq!(yielded, vec![0, 1, 2]);
+    assert_eq!(output, 130);
+    Ok(())
+}
+
+#[test]
+fn test_yield_host_errors() {
+    assert_compile_error! {
+        r#"fn main() { yield_host(1, 2) }"#,
+        ArgumentCountMismatch { expected: 1, actual: 2, .. } => {}
+    };
+}
+
+#[test]
+fn test_yield_host_in_generator() -> Result<()> {
+    let context = Arc::new(Context::with_default_modules()?);
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        fn values() {
+            yield_host(1);
+            yield 2;
+        }
+
+        fn main() {
+            values()
+        }
+        "#,
+    )?;
+
+    let vm = Vm::new(context, Arc::new(unit));
+    let mut generator = Generator::from_value(vm.call(&["main"], ())?.complete()?)?;
+
+    let error = generator.next().unwrap_err();
+
+    assert!(matches!(
+        error.kind().into_unwound_ref().0,
+        VmErrorKind::Halted {
+            halt: VmHaltInfo::YieldedHost
+        }
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_resume_with_not_suspended() -> Result<()> {
+    let context = Arc::new(Context::with_default_modules()?);
+    let (unit, _) = compile_source(&context, r#"fn main() { yield_host(1) + 1 }"#)?;
+
+    let vm = Vm::new(context, Arc::new(unit));
+    let mut execution = vm.call(&["main"], ())?;
+
+    let error = execution.resume_with(Value::from(1i64)).unwrap_err();
+    assert!(matches!(error.kind(), VmErrorKind::NotSuspended));
+
+    assert!(matches!(
+        execution.resume()?,
+        GeneratorState::Yielded(Value::Integer(1))
+    ));
+
+    let output = match execution.resume_with(Value::from(41i64))? {
+        GeneratorState::Complete(value) => i64::from_value(value)?,
+        GeneratorState::Yielded(..) => panic!("expected execution to complete"),
+    };
+
+    assert_eq!(output, 42);
+
+    let error = execution.resume_with(Value::from(1i64)).unwrap_err();
+    assert!(matches!(error.kind(), VmErrorKind::NotSuspended));
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use crate::ast;
+use crate::ast::SyntaxEq;
+use crate::error::ParseError;
+use crate::parser::Parser;
+use crate::traits::{Parse, Shift};
+use runestick::Span;
+
+/// A single argument in a function call, like `42` or `x: 42`.
+#[derive(Debug, Clone)]
+pub struct CallArg {
+    /// The name of the argument, if it's passed by name.
+    pub name: Option<(ast::Ident, ast::Colon)>,
+    /// The value of the argument.
+    pub expr: ast::Expr,
+}
+
+impl CallArg {
+    /// Get the span of the argument.
+    pub fn span(&self) -> Span {
+        match &self.name {
+            Some((ident, _)) => ident.span().join(self.expr.span()),
+            None => self.expr.span(),
+        }
+    }
+
+    /// Test if the argument is passed by name.
+    pub fn is_named(&self) -> bool {
+        self.name.is_some()
+    }
+}
+
+/// Parse a call argument.
+///
+/// # Examples
+///
+/// ```rust
+/// use rune::{parse_all, ast};
+///
+/// let arg = parse_all::<ast::CallArg>("42").unwrap();
+/// assert!(!arg.is_named());
+///
+/// let arg = parse_all::<ast::CallArg>("x: 42").unwrap();
+/// assert!(arg.is_named());
+/// ```
+impl Parse for CallArg {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, ParseError> {
+        let name = if parser.peek::<ast::Ident>()? && parser.peek2::<ast::Colon>()? {
+            Some((parser.parse()?, parser.parse()?))
+        } else {
+            None
+        };
+
+        Ok(Self {
+            name,
+            expr: parser.parse()?,
+        })
+    }
+}
+
+impl Shift for CallArg {
+    fn shift(&mut self, delta: isize) {
+        self.name.shift(delta);
+        self.expr.shift(delta);
+    }
+}
+
+impl SyntaxEq for CallArg {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.name.syntax_eq(&other.name, source, other_source)
+            && self.expr.syntax_eq(&other.expr, source, other_source)
+    }
+}
@@ -1,7 +1,8 @@
 use crate::ast;
+use crate::ast::SyntaxEq;
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::Parse;
+use crate::traits::{Parse, Shift};
 use runestick::Span;
 
 /// An if condition.
@@ -45,3 +46,22 @@ impl Parse for Condition {
         })
     }
 }
+
+impl Shift for Condition {
+    fn shift(&mut self, delta: isize) {
+        match self {
+            Self::Expr(expr) => expr.shift(delta),
+            Self::ExprLet(expr_let) => expr_let.shift(delta),
+        }
+    }
+}
+
+impl SyntaxEq for Condition {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        match (self, other) {
+            (Self::Expr(a), Self::Expr(b)) => a.syntax_eq(b, source, other_source),
+            (Self::ExprLet(a), Self::ExprLet(b)) => a.syntax_eq(b, source, other_source),
+            _ => false,
+        }
+    }
+}
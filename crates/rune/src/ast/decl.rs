@@ -1,6 +1,7 @@
 use crate::ast;
+use crate::ast::SyntaxEq;
 use crate::parser::Parser;
-use crate::traits::{Parse, Peek};
+use crate::traits::{Parse, Peek, Shift};
 use crate::ParseError;
 use runestick::Span;
 
@@ -71,3 +72,28 @@ impl Parse for Decl {
         })
     }
 }
+
+impl Shift for Decl {
+    fn shift(&mut self, delta: isize) {
+        match self {
+            Self::DeclUse(decl_use) => decl_use.shift(delta),
+            Self::DeclFn(decl_fn) => decl_fn.shift(delta),
+            Self::DeclEnum(decl_enum) => decl_enum.shift(delta),
+            Self::DeclStruct(decl_struct) => decl_struct.shift(delta),
+            Self::DeclImpl(decl_impl) => decl_impl.shift(delta),
+        }
+    }
+}
+
+impl SyntaxEq for Decl {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        match (self, other) {
+            (Self::DeclUse(a), Self::DeclUse(b)) => a.syntax_eq(b, source, other_source),
+            (Self::DeclFn(a), Self::DeclFn(b)) => a.syntax_eq(b, source, other_source),
+            (Self::DeclEnum(a), Self::DeclEnum(b)) => a.syntax_eq(b, source, other_source),
+            (Self::DeclStruct(a), Self::DeclStruct(b)) => a.syntax_eq(b, source, other_source),
+            (Self::DeclImpl(a), Self::DeclImpl(b)) => a.syntax_eq(b, source, other_source),
+            _ => false,
+        }
+    }
+}
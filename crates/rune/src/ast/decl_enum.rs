@@ -1,7 +1,8 @@
 use crate::ast;
+use crate::ast::SyntaxEq;
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::Parse;
+use crate::traits::{Parse, Shift};
 use runestick::Span;
 
 /// An enum declaration.
@@ -73,3 +74,25 @@ impl Parse for DeclEnum {
         })
     }
 }
+
+impl Shift for DeclEnum {
+    fn shift(&mut self, delta: isize) {
+        self.enum_.shift(delta);
+        self.name.shift(delta);
+        self.open.shift(delta);
+        self.variants.shift(delta);
+        self.close.shift(delta);
+    }
+}
+
+impl SyntaxEq for DeclEnum {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.enum_.syntax_eq(&other.enum_, source, other_source)
+            && self.name.syntax_eq(&other.name, source, other_source)
+            && self.open.syntax_eq(&other.open, source, other_source)
+            && self
+                .variants
+                .syntax_eq(&other.variants, source, other_source)
+            && self.close.syntax_eq(&other.close, source, other_source)
+    }
+}
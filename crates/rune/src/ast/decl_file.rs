@@ -1,9 +1,11 @@
 use crate::ast;
+use crate::ast::SyntaxEq;
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::Parse;
+use crate::traits::{Parse, Shift};
 
 /// A parsed file.
+#[derive(Debug, Clone)]
 pub struct DeclFile {
     /// All the declarations in a file.
     pub decls: Vec<(ast::Decl, Option<ast::SemiColon>)>,
@@ -67,3 +69,15 @@ impl Parse for DeclFile {
         Ok(Self { decls })
     }
 }
+
+impl Shift for DeclFile {
+    fn shift(&mut self, delta: isize) {
+        self.decls.shift(delta);
+    }
+}
+
+impl SyntaxEq for DeclFile {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.decls.syntax_eq(&other.decls, source, other_source)
+    }
+}
@@ -1,8 +1,8 @@
 use crate::ast;
-use crate::ast::{Kind, Token};
+use crate::ast::{Kind, SyntaxEq, Token};
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::{Parse, Peek};
+use crate::traits::{Parse, Peek, Shift};
 use runestick::Span;
 
 /// A function.
@@ -43,6 +43,26 @@ impl DeclFn {
     pub fn is_instance(&self) -> bool {
         matches!(self.args.items.first(), Some((ast::FnArg::Self_(..), _)))
     }
+
+    /// Test if the function is variadic, i.e. it ends with a rest argument.
+    pub fn is_variadic(&self) -> bool {
+        matches!(self.args.items.last(), Some((ast::FnArg::Rest(..), _)))
+    }
+
+    /// The number of fixed arguments the function takes, excluding any rest
+    /// argument.
+    pub fn fixed_args(&self) -> usize {
+        self.args.items.len() - self.is_variadic() as usize
+    }
+
+    /// The number of arguments which have default values.
+    pub fn default_args(&self) -> usize {
+        self.args
+            .items
+            .iter()
+            .filter(|(a, _)| a.is_default())
+            .count()
+    }
 }
 
 impl Peek for DeclFn {
@@ -66,6 +86,13 @@ impl Peek for DeclFn {
 ///
 /// let item = parse_all::<ast::DeclFn>("fn hello(foo, bar) {}").unwrap();
 /// assert_eq!(item.args.items.len(), 2);
+///
+/// let item = parse_all::<ast::DeclFn>("fn hello(foo, ..rest) {}").unwrap();
+/// assert!(item.is_variadic());
+/// assert_eq!(item.fixed_args(), 1);
+///
+/// let item = parse_all::<ast::DeclFn>("fn hello(foo, bar = 42) {}").unwrap();
+/// assert_eq!(item.default_args(), 1);
 /// ```
 impl Parse for DeclFn {
     fn parse(parser: &mut Parser<'_>) -> Result<Self, ParseError> {
@@ -78,3 +105,23 @@ impl Parse for DeclFn {
         })
     }
 }
+
+impl Shift for DeclFn {
+    fn shift(&mut self, delta: isize) {
+        self.async_.shift(delta);
+        self.fn_.shift(delta);
+        self.name.shift(delta);
+        self.args.shift(delta);
+        self.body.shift(delta);
+    }
+}
+
+impl SyntaxEq for DeclFn {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.async_.syntax_eq(&other.async_, source, other_source)
+            && self.fn_.syntax_eq(&other.fn_, source, other_source)
+            && self.name.syntax_eq(&other.name, source, other_source)
+            && self.args.syntax_eq(&other.args, source, other_source)
+            && self.body.syntax_eq(&other.body, source, other_source)
+    }
+}
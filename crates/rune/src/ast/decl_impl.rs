@@ -1,7 +1,8 @@
 use crate::ast;
+use crate::ast::SyntaxEq;
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::Parse;
+use crate::traits::{Parse, Shift};
 use runestick::Span;
 
 /// An impl declaration.
@@ -47,3 +48,25 @@ impl Parse for DeclImpl {
         })
     }
 }
+
+impl Shift for DeclImpl {
+    fn shift(&mut self, delta: isize) {
+        self.impl_.shift(delta);
+        self.path.shift(delta);
+        self.open.shift(delta);
+        self.functions.shift(delta);
+        self.close.shift(delta);
+    }
+}
+
+impl SyntaxEq for DeclImpl {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.impl_.syntax_eq(&other.impl_, source, other_source)
+            && self.path.syntax_eq(&other.path, source, other_source)
+            && self.open.syntax_eq(&other.open, source, other_source)
+            && self
+                .functions
+                .syntax_eq(&other.functions, source, other_source)
+            && self.close.syntax_eq(&other.close, source, other_source)
+    }
+}
@@ -1,8 +1,8 @@
 use crate::ast;
-use crate::ast::{Delimiter, Kind};
+use crate::ast::{Delimiter, Kind, SyntaxEq};
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::Parse;
+use crate::traits::{Parse, Shift};
 use runestick::Span;
 
 /// A struct declaration.
@@ -229,3 +229,82 @@ impl Parse for StructBody {
         })
     }
 }
+
+impl Shift for DeclStruct {
+    fn shift(&mut self, delta: isize) {
+        self.struct_.shift(delta);
+        self.ident.shift(delta);
+        self.body.shift(delta);
+    }
+}
+
+impl Shift for DeclStructBody {
+    fn shift(&mut self, delta: isize) {
+        match self {
+            Self::EmptyBody(empty_body) => empty_body.shift(delta),
+            Self::TupleBody(tuple_body) => tuple_body.shift(delta),
+            Self::StructBody(struct_body) => struct_body.shift(delta),
+        }
+    }
+}
+
+impl Shift for EmptyBody {
+    fn shift(&mut self, _: isize) {}
+}
+
+impl Shift for TupleBody {
+    fn shift(&mut self, delta: isize) {
+        self.open.shift(delta);
+        self.fields.shift(delta);
+        self.close.shift(delta);
+    }
+}
+
+impl Shift for StructBody {
+    fn shift(&mut self, delta: isize) {
+        self.open.shift(delta);
+        self.fields.shift(delta);
+        self.close.shift(delta);
+    }
+}
+
+impl SyntaxEq for DeclStruct {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.struct_.syntax_eq(&other.struct_, source, other_source)
+            && self.ident.syntax_eq(&other.ident, source, other_source)
+            && self.body.syntax_eq(&other.body, source, other_source)
+    }
+}
+
+impl SyntaxEq for DeclStructBody {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        match (self, other) {
+            (Self::EmptyBody(a), Self::EmptyBody(b)) => a.syntax_eq(b, source, other_source),
+            (Self::TupleBody(a), Self::TupleBody(b)) => a.syntax_eq(b, source, other_source),
+            (Self::StructBody(a), Self::StructBody(b)) => a.syntax_eq(b, source, other_source),
+            _ => false,
+        }
+    }
+}
+
+impl SyntaxEq for EmptyBody {
+    fn syntax_eq(&self, _: &Self, _: &str, _: &str) -> bool {
+        true
+    }
+}
+
+impl SyntaxEq for TupleBody {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.open.syntax_eq(&other.open, source, other_source)
+            && self.fields.syntax_eq(&other.fields, source, other_source)
+            && self.close.syntax_eq(&other.close, source, other_source)
+    }
+}
+
+impl SyntaxEq for StructBody {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.open.syntax_eq(&other.open, source, other_source)
+            && self.fields.syntax_eq(&other.fields, source, other_source)
+            && self.close.syntax_eq(&other.close, source, other_source)
+    }
+}
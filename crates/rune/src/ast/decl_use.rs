@@ -1,8 +1,9 @@
 use crate::ast;
 use crate::ast::Kind;
+use crate::ast::SyntaxEq;
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::{Parse, Peek};
+use crate::traits::{Parse, Peek, Shift};
 use runestick::Span;
 
 /// An imported declaration.
@@ -94,3 +95,38 @@ impl Peek for DeclUseComponent {
         matches!(kind, Kind::Ident | Kind::Mul)
     }
 }
+
+impl Shift for DeclUse {
+    fn shift(&mut self, delta: isize) {
+        self.use_.shift(delta);
+        self.first.shift(delta);
+        self.rest.shift(delta);
+    }
+}
+
+impl Shift for DeclUseComponent {
+    fn shift(&mut self, delta: isize) {
+        match self {
+            Self::Ident(ident) => ident.shift(delta),
+            Self::Wildcard(mul) => mul.shift(delta),
+        }
+    }
+}
+
+impl SyntaxEq for DeclUse {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.use_.syntax_eq(&other.use_, source, other_source)
+            && self.first.syntax_eq(&other.first, source, other_source)
+            && self.rest.syntax_eq(&other.rest, source, other_source)
+    }
+}
+
+impl SyntaxEq for DeclUseComponent {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        match (self, other) {
+            (Self::Ident(a), Self::Ident(b)) => a.syntax_eq(b, source, other_source),
+            (Self::Wildcard(a), Self::Wildcard(b)) => a.syntax_eq(b, source, other_source),
+            _ => false,
+        }
+    }
+}
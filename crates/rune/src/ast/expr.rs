@@ -1,11 +1,15 @@
 use crate::ast;
-use crate::ast::{Delimiter, Kind, Token};
+use crate::ast::{Delimiter, Kind, SyntaxEq, Token};
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::{Parse, Peek};
+use crate::traits::{Parse, Peek, Shift};
 use runestick::Span;
 use std::ops;
 
+/// The precedence of the range operator `..`, which sits between assignments
+/// and every other binary operator.
+const RANGE_PRECEDENCE: usize = 1;
+
 /// Indicator that an expression should be parsed with an eager brace.
 #[derive(Debug, Clone, Copy)]
 pub(super) struct EagerBrace(pub(super) bool);
@@ -71,6 +75,8 @@ pub enum Expr {
     ExprYield(ast::ExprYield),
     /// A block as an expression.
     ExprBlock(ast::ExprBlock),
+    /// A range expression.
+    ExprRange(ast::ExprRange),
     /// A return statement.
     ExprReturn(ast::ExprReturn),
     /// An await expression.
@@ -110,7 +116,6 @@ impl Expr {
     pub fn produces_nothing(&self) -> bool {
         match self {
             Self::ExprWhile(..) => true,
-            Self::ExprLoop(..) => true,
             Self::ExprFor(..) => true,
             Self::ExprLet(..) => true,
             Self::ExprIndexSet(..) => true,
@@ -124,6 +129,12 @@ impl Expr {
         }
     }
 
+    /// Test if the expression unconditionally transfers control away from the
+    /// current block, so that anything following it is unreachable.
+    pub fn diverges(&self) -> bool {
+        matches!(self, Self::ExprBreak(..) | Self::ExprReturn(..))
+    }
+
     /// Test if expression should be chained by default.
     pub fn is_chainable(&self) -> bool {
         match self {
@@ -156,6 +167,7 @@ impl Expr {
             Self::ExprBreak(b) => b.span(),
             Self::ExprYield(b) => b.span(),
             Self::ExprBlock(b) => b.span(),
+            Self::ExprRange(expr) => expr.span(),
             Self::ExprReturn(ret) => ret.span(),
             Self::ExprAwait(ret) => ret.span(),
             Self::ExprTry(ret) => ret.span(),
@@ -190,6 +202,7 @@ impl Expr {
             Expr::LitObject(object) => object.is_const(),
             Expr::LitTuple(tuple) => tuple.is_const(),
             Expr::ExprBlock(b) => b.is_const(),
+            Expr::ExprRange(range) => range.is_const(),
             _ => false,
         }
     }
@@ -203,16 +216,61 @@ impl Expr {
         Self::parse_full(parser, EagerBrace(false), ExprChain(true))
     }
 
+    /// Parse an expression which stops at a bare `|` operator, or any operator
+    /// with a lower precedence.
+    ///
+    /// This is used for the default values of closure arguments, where a `|`
+    /// would otherwise be ambiguous with the end of the argument list.
+    pub(super) fn parse_without_pipe(parser: &mut Parser<'_>) -> Result<Self, ParseError> {
+        let min_precedence = ast::BinOp::BitOr.precedence() + 1;
+        let lhs = Self::parse_primary(parser, EagerBrace(true), ExprChain(true))?;
+        Self::parse_expr_binary(parser, lhs, min_precedence, EagerBrace(true))
+    }
+
     /// Full, configurable parsing of an expression.
     pub(super) fn parse_full(
         parser: &mut Parser<'_>,
         eager_brace: EagerBrace,
         expr_chain: ExprChain,
     ) -> Result<Self, ParseError> {
+        if parser.peek::<ast::DotDot>()? {
+            return Self::parse_expr_range(parser, None, eager_brace);
+        }
+
         let lhs = Self::parse_primary(parser, eager_brace, expr_chain)?;
         Ok(Self::parse_expr_binary(parser, lhs, 0, eager_brace)?)
     }
 
+    /// Parse a range expression, starting at the `..` operator.
+    ///
+    /// The end of the range is optional, and binds tighter than everything
+    /// except assignments.
+    fn parse_expr_range(
+        parser: &mut Parser<'_>,
+        from: Option<Self>,
+        eager_brace: EagerBrace,
+    ) -> Result<Self, ParseError> {
+        let limits = parser.parse::<ast::DotDot>()?;
+
+        let has_to = parser.peek::<Self>()?
+            && !parser.peek::<ast::DotDot>()?
+            && (*eager_brace || !parser.peek::<ast::OpenBrace>()?);
+
+        let to = if has_to {
+            let rhs = Self::parse_primary(parser, eager_brace, ExprChain(true))?;
+            let rhs = Self::parse_expr_binary(parser, rhs, RANGE_PRECEDENCE + 1, eager_brace)?;
+            Some(Box::new(rhs))
+        } else {
+            None
+        };
+
+        Ok(Self::ExprRange(ast::ExprRange {
+            from: from.map(Box::new),
+            limits,
+            to,
+        }))
+    }
+
     /// Parse expressions that start with an identifier.
     pub(super) fn parse_ident_start(
         parser: &mut Parser<'_>,
@@ -273,6 +331,7 @@ impl Expr {
                     }),
                     Self::ExprBlock(expr_block) => Self::ExprBlock(ast::ExprBlock {
                         async_: Some(async_),
+                        try_: expr_block.try_,
                         open: expr_block.open,
                         exprs: expr_block.exprs,
                         trailing_expr: expr_block.trailing_expr,
@@ -281,6 +340,7 @@ impl Expr {
                     _ => return Err(ParseError::UnsupportedAsyncExpr { span: expr.span() }),
                 }
             }
+            ast::Kind::TryKeyword => Self::ExprBlock(parser.parse()?),
             ast::Kind::Self_ => Self::Self_(parser.parse()?),
             ast::Kind::Select => Self::ExprSelect(parser.parse()?),
             ast::Kind::Or | Kind::Pipe => Self::ExprClosure(parser.parse()?),
@@ -370,7 +430,7 @@ impl Expr {
                 }
                 // Chained function call.
                 ast::Kind::Open(Delimiter::Parenthesis) if is_chainable => {
-                    let args = parser.parse::<ast::Parenthesized<ast::Expr, ast::Comma>>()?;
+                    let args = parser.parse::<ast::Parenthesized<ast::CallArg, ast::Comma>>()?;
 
                     expr = Expr::ExprCall(ast::ExprCall {
                         expr: Box::new(expr),
@@ -459,6 +519,20 @@ impl Expr {
                 parser.token_next()?;
             }
 
+            // NB: the right-hand side of an assignment might be a range.
+            if op.precedence() <= RANGE_PRECEDENCE && parser.peek::<ast::DotDot>()? {
+                let rhs = Self::parse_expr_range(parser, None, eager_brace)?;
+
+                lhs = Expr::ExprBinary(ast::ExprBinary {
+                    lhs: Box::new(lhs),
+                    op,
+                    rhs: Box::new(rhs),
+                });
+
+                lookahead_tok = parser.token_peek_pair()?;
+                continue;
+            }
+
             let mut rhs = Self::parse_primary(parser, eager_brace, ExprChain(true))?;
 
             lookahead_tok = parser.token_peek_pair()?;
@@ -467,6 +541,16 @@ impl Expr {
                 let (lh, _) = match lookahead_tok.and_then(ast::BinOp::from_token) {
                     Some((lh, _)) if lh.precedence() > op.precedence() => (lh, token),
                     Some((lh, _)) if lh.precedence() == op.precedence() && !lh.is_assoc(op) => {
+                        if op.is_comparison() && lh.is_comparison() {
+                            return Err(Self::chained_comparison(
+                                parser,
+                                &lhs,
+                                &rhs,
+                                lh,
+                                eager_brace,
+                            )?);
+                        }
+
                         return Err(ParseError::PrecedenceGroupRequired {
                             span: lhs.span().join(rhs.span()),
                         });
@@ -478,6 +562,11 @@ impl Expr {
                 lookahead_tok = parser.token_peek_pair()?;
             }
 
+            if op.precedence() <= RANGE_PRECEDENCE && parser.peek::<ast::DotDot>()? {
+                rhs = Self::parse_expr_range(parser, Some(rhs), eager_brace)?;
+                lookahead_tok = parser.token_peek_pair()?;
+            }
+
             lhs = Expr::ExprBinary(ast::ExprBinary {
                 lhs: Box::new(lhs),
                 op,
@@ -485,8 +574,39 @@ impl Expr {
             });
         }
 
+        if min_precedence <= RANGE_PRECEDENCE && parser.peek::<ast::DotDot>()? {
+            return Self::parse_expr_range(parser, Some(lhs), eager_brace);
+        }
+
         Ok(lhs)
     }
+
+    /// Construct the error for a chained comparison like `a < b < c`, which
+    /// can be suggested to be written as `a < b && b < c` instead.
+    ///
+    /// The parser is expected to be positioned at the second operator.
+    fn chained_comparison(
+        parser: &mut Parser<'_>,
+        lhs: &Self,
+        rhs: &Self,
+        op: ast::BinOp,
+        eager_brace: EagerBrace,
+    ) -> Result<ParseError, ParseError> {
+        for _ in 0..op.advance() {
+            parser.token_next()?;
+        }
+
+        let last = Self::parse_primary(parser, eager_brace, ExprChain(true))?;
+        let last = Self::parse_expr_binary(parser, last, op.precedence() + 1, eager_brace)?;
+
+        Ok(ParseError::ChainedComparison {
+            span: lhs.span().join(last.span()),
+            first: lhs.span().join(rhs.span()),
+            shared: rhs.span(),
+            op,
+            last: last.span(),
+        })
+    }
 }
 
 /// Parsing a block expression.
@@ -524,6 +644,14 @@ impl Expr {
 /// parse_all::<ast::Expr>("foo.bar()[0].baz()[1]").unwrap();
 ///
 /// parse_all::<ast::Expr>("42 is int::int").unwrap();
+///
+/// // Ranges.
+/// parse_all::<ast::Expr>("..").unwrap();
+/// parse_all::<ast::Expr>("1..").unwrap();
+/// parse_all::<ast::Expr>("..2").unwrap();
+/// parse_all::<ast::Expr>("1..2").unwrap();
+/// parse_all::<ast::Expr>("foo[1..2]").unwrap();
+/// parse_all::<ast::Expr>("var = 1 + 2..3 * 4").unwrap();
 /// ```
 impl Parse for Expr {
     fn parse(parser: &mut Parser<'_>) -> Result<Self, ParseError> {
@@ -540,6 +668,7 @@ impl Peek for Expr {
 
         match t1.kind {
             ast::Kind::Async => true,
+            ast::Kind::TryKeyword => true,
             ast::Kind::Self_ => true,
             ast::Kind::Select => true,
             ast::Kind::Label => matches!(t2.map(|t| t.kind), Some(Kind::Colon)),
@@ -563,6 +692,96 @@ impl Peek for Expr {
             ast::Kind::Ident => true,
             ast::Kind::Break => true,
             ast::Kind::Return => true,
+            ast::Kind::DotDot => true,
+            _ => false,
+        }
+    }
+}
+
+impl Shift for Expr {
+    fn shift(&mut self, delta: isize) {
+        match self {
+            Self::Self_(self_) => self_.shift(delta),
+            Self::Path(path) => path.shift(delta),
+            Self::Decl(decl) => decl.shift(delta),
+            Self::ExprWhile(expr_while) => expr_while.shift(delta),
+            Self::ExprLoop(expr_loop) => expr_loop.shift(delta),
+            Self::ExprFor(expr_for) => expr_for.shift(delta),
+            Self::ExprLet(expr_let) => expr_let.shift(delta),
+            Self::ExprIndexSet(expr_index_set) => expr_index_set.shift(delta),
+            Self::ExprIf(expr_if) => expr_if.shift(delta),
+            Self::ExprMatch(expr_match) => expr_match.shift(delta),
+            Self::ExprCall(expr_call) => expr_call.shift(delta),
+            Self::ExprFieldAccess(expr_field_access) => expr_field_access.shift(delta),
+            Self::ExprGroup(expr_group) => expr_group.shift(delta),
+            Self::ExprBinary(expr_binary) => expr_binary.shift(delta),
+            Self::ExprUnary(expr_unary) => expr_unary.shift(delta),
+            Self::ExprIndexGet(expr_index_get) => expr_index_get.shift(delta),
+            Self::ExprBreak(expr_break) => expr_break.shift(delta),
+            Self::ExprYield(expr_yield) => expr_yield.shift(delta),
+            Self::ExprBlock(expr_block) => expr_block.shift(delta),
+            Self::ExprRange(expr_range) => expr_range.shift(delta),
+            Self::ExprReturn(expr_return) => expr_return.shift(delta),
+            Self::ExprAwait(expr_await) => expr_await.shift(delta),
+            Self::ExprTry(expr_try) => expr_try.shift(delta),
+            Self::ExprSelect(expr_select) => expr_select.shift(delta),
+            Self::ExprClosure(expr_closure) => expr_closure.shift(delta),
+            Self::LitUnit(lit_unit) => lit_unit.shift(delta),
+            Self::LitBool(lit_bool) => lit_bool.shift(delta),
+            Self::LitChar(lit_char) => lit_char.shift(delta),
+            Self::LitByte(lit_byte) => lit_byte.shift(delta),
+            Self::LitNumber(lit_number) => lit_number.shift(delta),
+            Self::LitStr(lit_str) => lit_str.shift(delta),
+            Self::LitByteStr(lit_byte_str) => lit_byte_str.shift(delta),
+            Self::LitTemplate(lit_template) => lit_template.shift(delta),
+            Self::LitVec(lit_vec) => lit_vec.shift(delta),
+            Self::LitObject(lit_object) => lit_object.shift(delta),
+            Self::LitTuple(lit_tuple) => lit_tuple.shift(delta),
+        }
+    }
+}
+
+impl SyntaxEq for Expr {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        match (self, other) {
+            (Self::Self_(a), Self::Self_(b)) => a.syntax_eq(b, source, other_source),
+            (Self::Path(a), Self::Path(b)) => a.syntax_eq(b, source, other_source),
+            (Self::Decl(a), Self::Decl(b)) => a.syntax_eq(b, source, other_source),
+            (Self::ExprWhile(a), Self::ExprWhile(b)) => a.syntax_eq(b, source, other_source),
+            (Self::ExprLoop(a), Self::ExprLoop(b)) => a.syntax_eq(b, source, other_source),
+            (Self::ExprFor(a), Self::ExprFor(b)) => a.syntax_eq(b, source, other_source),
+            (Self::ExprLet(a), Self::ExprLet(b)) => a.syntax_eq(b, source, other_source),
+            (Self::ExprIndexSet(a), Self::ExprIndexSet(b)) => a.syntax_eq(b, source, other_source),
+            (Self::ExprIf(a), Self::ExprIf(b)) => a.syntax_eq(b, source, other_source),
+            (Self::ExprMatch(a), Self::ExprMatch(b)) => a.syntax_eq(b, source, other_source),
+            (Self::ExprCall(a), Self::ExprCall(b)) => a.syntax_eq(b, source, other_source),
+            (Self::ExprFieldAccess(a), Self::ExprFieldAccess(b)) => {
+                a.syntax_eq(b, source, other_source)
+            }
+            (Self::ExprGroup(a), Self::ExprGroup(b)) => a.syntax_eq(b, source, other_source),
+            (Self::ExprBinary(a), Self::ExprBinary(b)) => a.syntax_eq(b, source, other_source),
+            (Self::ExprUnary(a), Self::ExprUnary(b)) => a.syntax_eq(b, source, other_source),
+            (Self::ExprIndexGet(a), Self::ExprIndexGet(b)) => a.syntax_eq(b, source, other_source),
+            (Self::ExprBreak(a), Self::ExprBreak(b)) => a.syntax_eq(b, source, other_source),
+            (Self::ExprYield(a), Self::ExprYield(b)) => a.syntax_eq(b, source, other_source),
+            (Self::ExprBlock(a), Self::ExprBlock(b)) => a.syntax_eq(b, source, other_source),
+            (Self::ExprRange(a), Self::ExprRange(b)) => a.syntax_eq(b, source, other_source),
+            (Self::ExprReturn(a), Self::ExprReturn(b)) => a.syntax_eq(b, source, other_source),
+            (Self::ExprAwait(a), Self::ExprAwait(b)) => a.syntax_eq(b, source, other_source),
+            (Self::ExprTry(a), Self::ExprTry(b)) => a.syntax_eq(b, source, other_source),
+            (Self::ExprSelect(a), Self::ExprSelect(b)) => a.syntax_eq(b, source, other_source),
+            (Self::ExprClosure(a), Self::ExprClosure(b)) => a.syntax_eq(b, source, other_source),
+            (Self::LitUnit(a), Self::LitUnit(b)) => a.syntax_eq(b, source, other_source),
+            (Self::LitBool(a), Self::LitBool(b)) => a.syntax_eq(b, source, other_source),
+            (Self::LitChar(a), Self::LitChar(b)) => a.syntax_eq(b, source, other_source),
+            (Self::LitByte(a), Self::LitByte(b)) => a.syntax_eq(b, source, other_source),
+            (Self::LitNumber(a), Self::LitNumber(b)) => a.syntax_eq(b, source, other_source),
+            (Self::LitStr(a), Self::LitStr(b)) => a.syntax_eq(b, source, other_source),
+            (Self::LitByteStr(a), Self::LitByteStr(b)) => a.syntax_eq(b, source, other_source),
+            (Self::LitTemplate(a), Self::LitTemplate(b)) => a.syntax_eq(b, source, other_source),
+            (Self::LitVec(a), Self::LitVec(b)) => a.syntax_eq(b, source, other_source),
+            (Self::LitObject(a), Self::LitObject(b)) => a.syntax_eq(b, source, other_source),
+            (Self::LitTuple(a), Self::LitTuple(b)) => a.syntax_eq(b, source, other_source),
             _ => false,
         }
     }
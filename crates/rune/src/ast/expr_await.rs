@@ -1,7 +1,7 @@
-use crate::ast::{Await, Dot, Expr};
+use crate::ast::{Await, Dot, Expr, SyntaxEq};
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::Parse;
+use crate::traits::{Parse, Shift};
 use runestick::Span;
 
 /// A return statement `<expr>.await`.
@@ -31,3 +31,19 @@ impl Parse for ExprAwait {
         })
     }
 }
+
+impl Shift for ExprAwait {
+    fn shift(&mut self, delta: isize) {
+        self.expr.shift(delta);
+        self.dot.shift(delta);
+        self.await_.shift(delta);
+    }
+}
+
+impl SyntaxEq for ExprAwait {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.expr.syntax_eq(&other.expr, source, other_source)
+            && self.dot.syntax_eq(&other.dot, source, other_source)
+            && self.await_.syntax_eq(&other.await_, source, other_source)
+    }
+}
@@ -1,5 +1,6 @@
 use crate::ast;
-use crate::traits::Peek;
+use crate::ast::SyntaxEq;
+use crate::traits::{Peek, Shift};
 use runestick::Span;
 use std::fmt;
 
@@ -53,6 +54,28 @@ pub enum BinOp {
     MulAssign,
     /// Remainder operator.
     Rem,
+    /// Remainder assign operation.
+    RemAssign,
+    /// Bitwise and `&` operator.
+    BitAnd,
+    /// Bitwise and assign operation.
+    BitAndAssign,
+    /// Bitwise xor `^` operator.
+    BitXor,
+    /// Bitwise xor assign operation.
+    BitXorAssign,
+    /// Bitwise or `|` operator.
+    BitOr,
+    /// Bitwise or assign operation.
+    BitOrAssign,
+    /// Shift left `<<` operator.
+    Shl,
+    /// Shift left assign operation.
+    ShlAssign,
+    /// Shift right `>>` operator.
+    Shr,
+    /// Shift right assign operation.
+    ShrAssign,
     /// Equality check.
     Eq,
     /// Inequality check.
@@ -83,15 +106,32 @@ impl BinOp {
         match self {
             Self::Assign => 1,
             Self::AddAssign | Self::SubAssign | Self::MulAssign | Self::DivAssign => 1,
+            Self::RemAssign | Self::BitAndAssign | Self::BitXorAssign | Self::BitOrAssign => 1,
+            Self::ShlAssign | Self::ShrAssign => 1,
             Self::Or => 2,
             Self::And => 3,
             Self::Eq | Self::Neq | Self::Gt | Self::Lt | Self::Gte | Self::Lte => 4,
-            Self::Add | Self::Sub => 5,
-            Self::Div | Self::Mul | Self::Rem => 6,
-            Self::Is | Self::IsNot => 7,
+            // NB: type checks bind tighter than comparisons and boolean logic,
+            // but looser than arithmetic, so `a + 1 is int && b` groups as
+            // `((a + 1) is int) && b`.
+            Self::Is | Self::IsNot => 5,
+            Self::BitOr => 6,
+            Self::BitXor => 7,
+            Self::BitAnd => 8,
+            Self::Shl | Self::Shr => 9,
+            Self::Add | Self::Sub => 10,
+            Self::Div | Self::Mul | Self::Rem => 11,
         }
     }
 
+    /// Test if the operator is a comparison, like `<` or `==`.
+    pub(super) fn is_comparison(self) -> bool {
+        matches!(
+            self,
+            Self::Eq | Self::Neq | Self::Gt | Self::Lt | Self::Gte | Self::Lte
+        )
+    }
+
     /// Test if two operators are associative and can be applied in any order
     /// even if they have the same precedence.
     pub(super) fn is_assoc(self, other: Self) -> bool {
@@ -103,6 +143,11 @@ impl BinOp {
             (Self::Sub, Self::Sub) => true,
             (Self::Add, Self::Sub) => true,
             (Self::Sub, Self::Add) => true,
+            (Self::BitAnd, Self::BitAnd) => true,
+            (Self::BitXor, Self::BitXor) => true,
+            (Self::BitOr, Self::BitOr) => true,
+            (Self::Shl, Self::Shl) => true,
+            (Self::Shr, Self::Shr) => true,
             _ => false,
         }
     }
@@ -118,7 +163,18 @@ impl BinOp {
             ast::Kind::DivAssign => Self::DivAssign,
             ast::Kind::Mul => Self::Mul,
             ast::Kind::Rem => Self::Rem,
+            ast::Kind::RemAssign => Self::RemAssign,
             ast::Kind::MulAssign => Self::MulAssign,
+            ast::Kind::Ampersand => Self::BitAnd,
+            ast::Kind::AmpersandAssign => Self::BitAndAssign,
+            ast::Kind::Caret => Self::BitXor,
+            ast::Kind::CaretAssign => Self::BitXorAssign,
+            ast::Kind::Pipe => Self::BitOr,
+            ast::Kind::PipeAssign => Self::BitOrAssign,
+            ast::Kind::Shl => Self::Shl,
+            ast::Kind::ShlAssign => Self::ShlAssign,
+            ast::Kind::Shr => Self::Shr,
+            ast::Kind::ShrAssign => Self::ShrAssign,
             ast::Kind::EqEq => Self::Eq,
             ast::Kind::Neq => Self::Neq,
             ast::Kind::Lt => Self::Lt,
@@ -182,6 +238,39 @@ impl fmt::Display for BinOp {
             Self::Rem => {
                 write!(fmt, "%")?;
             }
+            Self::RemAssign => {
+                write!(fmt, "%=")?;
+            }
+            Self::BitAnd => {
+                write!(fmt, "&")?;
+            }
+            Self::BitAndAssign => {
+                write!(fmt, "&=")?;
+            }
+            Self::BitXor => {
+                write!(fmt, "^")?;
+            }
+            Self::BitXorAssign => {
+                write!(fmt, "^=")?;
+            }
+            Self::BitOr => {
+                write!(fmt, "|")?;
+            }
+            Self::BitOrAssign => {
+                write!(fmt, "|=")?;
+            }
+            Self::Shl => {
+                write!(fmt, "<<")?;
+            }
+            Self::ShlAssign => {
+                write!(fmt, "<<=")?;
+            }
+            Self::Shr => {
+                write!(fmt, ">>")?;
+            }
+            Self::ShrAssign => {
+                write!(fmt, ">>=")?;
+            }
             Self::Eq => {
                 write!(fmt, "==")?;
             }
@@ -230,6 +319,9 @@ impl Peek for BinOp {
                 ast::Kind::Mul => true,
                 ast::Kind::Rem => true,
                 ast::Kind::Div => true,
+                ast::Kind::Caret => true,
+                ast::Kind::Shl => true,
+                ast::Kind::Shr => true,
                 ast::Kind::EqEq => true,
                 ast::Kind::Neq => true,
                 ast::Kind::Gt => true,
@@ -244,3 +336,18 @@ impl Peek for BinOp {
         }
     }
 }
+
+impl Shift for ExprBinary {
+    fn shift(&mut self, delta: isize) {
+        self.lhs.shift(delta);
+        self.rhs.shift(delta);
+    }
+}
+
+impl SyntaxEq for ExprBinary {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.lhs.syntax_eq(&other.lhs, source, other_source)
+            && self.op == other.op
+            && self.rhs.syntax_eq(&other.rhs, source, other_source)
+    }
+}
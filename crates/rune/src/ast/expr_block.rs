@@ -1,7 +1,8 @@
 use crate::ast;
+use crate::ast::SyntaxEq;
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::Parse;
+use crate::traits::{Parse, Shift};
 use runestick::Span;
 
 /// A block of expressions.
@@ -9,7 +10,9 @@ use runestick::Span;
 pub struct ExprBlock {
     /// If the block is async or not.
     pub async_: Option<ast::Async>,
-    /// The close brace.
+    /// If the block is a `try` block or not.
+    pub try_: Option<ast::TryKeyword>,
+    /// The open brace.
     pub open: ast::OpenBrace,
     /// Expressions in the block.
     pub exprs: Vec<(ast::Expr, Option<ast::SemiColon>)>,
@@ -24,13 +27,21 @@ impl ExprBlock {
     pub fn span(&self) -> Span {
         if let Some(async_) = self.async_ {
             async_.span().join(self.close.span())
+        } else if let Some(try_) = self.try_ {
+            try_.span().join(self.close.span())
         } else {
             self.open.span().join(self.close.span())
         }
     }
 
     /// Test if the block is empty.
+    ///
+    /// A `try` block always produces a `Result`.
     pub fn produces_nothing(&self) -> bool {
+        if self.try_.is_some() {
+            return false;
+        }
+
         match &self.trailing_expr {
             Some(trailing) => trailing.produces_nothing(),
             None => true,
@@ -58,6 +69,10 @@ impl ExprBlock {
 /// assert!(block.trailing_expr.is_none());
 /// assert!(block.async_.is_some());
 ///
+/// let block = parse_all::<ast::ExprBlock>("try { foo()? }").unwrap();
+/// assert!(block.try_.is_some());
+/// assert!(block.trailing_expr.is_some());
+///
 /// let block = parse_all::<ast::ExprBlock>("{}").unwrap();
 /// assert_eq!(block.exprs.len(), 0);
 /// assert!(block.trailing_expr.is_none());
@@ -86,6 +101,7 @@ impl Parse for ExprBlock {
         let mut exprs = Vec::new();
 
         let async_ = parser.parse()?;
+        let try_ = parser.parse()?;
         let open = parser.parse()?;
         let mut trailing_expr = None;
 
@@ -122,6 +138,7 @@ impl Parse for ExprBlock {
 
         Ok(ExprBlock {
             async_,
+            try_,
             open,
             exprs,
             trailing_expr,
@@ -129,3 +146,27 @@ impl Parse for ExprBlock {
         })
     }
 }
+
+impl Shift for ExprBlock {
+    fn shift(&mut self, delta: isize) {
+        self.async_.shift(delta);
+        self.try_.shift(delta);
+        self.open.shift(delta);
+        self.exprs.shift(delta);
+        self.trailing_expr.shift(delta);
+        self.close.shift(delta);
+    }
+}
+
+impl SyntaxEq for ExprBlock {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.async_.syntax_eq(&other.async_, source, other_source)
+            && self.try_.syntax_eq(&other.try_, source, other_source)
+            && self.open.syntax_eq(&other.open, source, other_source)
+            && self.exprs.syntax_eq(&other.exprs, source, other_source)
+            && self
+                .trailing_expr
+                .syntax_eq(&other.trailing_expr, source, other_source)
+            && self.close.syntax_eq(&other.close, source, other_source)
+    }
+}
@@ -1,8 +1,8 @@
 use crate::ast;
-use crate::ast::{Kind, Token};
+use crate::ast::{Kind, SyntaxEq, Token};
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::{Parse, Peek};
+use crate::traits::{Parse, Peek, Shift};
 use runestick::Span;
 
 /// Things that we can break on.
@@ -72,3 +72,36 @@ impl Parse for ExprBreak {
         })
     }
 }
+
+impl Shift for ExprBreakValue {
+    fn shift(&mut self, delta: isize) {
+        match self {
+            Self::Expr(expr) => expr.shift(delta),
+            Self::Label(label) => label.shift(delta),
+        }
+    }
+}
+
+impl Shift for ExprBreak {
+    fn shift(&mut self, delta: isize) {
+        self.break_.shift(delta);
+        self.expr.shift(delta);
+    }
+}
+
+impl SyntaxEq for ExprBreakValue {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        match (self, other) {
+            (Self::Expr(a), Self::Expr(b)) => a.syntax_eq(b, source, other_source),
+            (Self::Label(a), Self::Label(b)) => a.syntax_eq(b, source, other_source),
+            _ => false,
+        }
+    }
+}
+
+impl SyntaxEq for ExprBreak {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.break_.syntax_eq(&other.break_, source, other_source)
+            && self.expr.syntax_eq(&other.expr, source, other_source)
+    }
+}
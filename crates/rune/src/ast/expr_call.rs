@@ -1,4 +1,6 @@
 use crate::ast;
+use crate::ast::SyntaxEq;
+use crate::traits::Shift;
 use runestick::Span;
 
 /// A function call `<expr>(<args>)`.
@@ -7,7 +9,7 @@ pub struct ExprCall {
     /// The name of the function being called.
     pub expr: Box<ast::Expr>,
     /// The arguments of the function call.
-    pub args: ast::Parenthesized<ast::Expr, ast::Comma>,
+    pub args: ast::Parenthesized<ast::CallArg, ast::Comma>,
 }
 
 impl ExprCall {
@@ -15,4 +17,23 @@ impl ExprCall {
     pub fn span(&self) -> Span {
         self.expr.span().join(self.args.span())
     }
+
+    /// Test if any arguments are passed by name.
+    pub fn has_named_args(&self) -> bool {
+        self.args.items.iter().any(|(arg, _)| arg.is_named())
+    }
+}
+
+impl Shift for ExprCall {
+    fn shift(&mut self, delta: isize) {
+        self.expr.shift(delta);
+        self.args.shift(delta);
+    }
+}
+
+impl SyntaxEq for ExprCall {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.expr.syntax_eq(&other.expr, source, other_source)
+            && self.args.syntax_eq(&other.args, source, other_source)
+    }
 }
@@ -1,15 +1,19 @@
 use crate::ast;
+use crate::ast::SyntaxEq;
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::Parse;
+use crate::traits::{Parse, Shift};
 use runestick::Span;
 
+/// The arguments of a closure.
 #[derive(Debug, Clone)]
 pub enum ExprClosureArgs {
+    /// Closure with no arguments, like `||`.
     Empty {
         /// The `||` token.
         token: ast::Or,
     },
+    /// Closure with a list of arguments, like `|a, b|`.
     List {
         /// The opening pipe for the argument group.
         open: ast::Pipe,
@@ -37,6 +41,25 @@ impl ExprClosureArgs {
         }
     }
 
+    /// Test if the closure is variadic, i.e. it ends with a rest argument.
+    pub fn is_variadic(&self) -> bool {
+        matches!(self.as_slice().last(), Some((ast::FnArg::Rest(..), _)))
+    }
+
+    /// The number of fixed arguments the closure takes, excluding any rest
+    /// argument.
+    pub fn fixed_args(&self) -> usize {
+        self.len() - self.is_variadic() as usize
+    }
+
+    /// The number of arguments which have default values.
+    pub fn default_args(&self) -> usize {
+        self.as_slice()
+            .iter()
+            .filter(|(a, _)| a.is_default())
+            .count()
+    }
+
     /// Iterate over all arguments.
     pub fn as_slice(&self) -> &[(ast::FnArg, Option<ast::Comma>)] {
         match self {
@@ -87,6 +110,9 @@ impl ExprClosure {
 /// parse_all::<ast::ExprClosure>("async || 42").unwrap();
 /// parse_all::<ast::ExprClosure>("|| 42").unwrap();
 /// parse_all::<ast::ExprClosure>("|| { 42 }").unwrap();
+/// parse_all::<ast::ExprClosure>("|a, ..rest| { 42 }").unwrap();
+/// parse_all::<ast::ExprClosure>("|a, b = 2| { a + b }").unwrap();
+/// parse_all::<ast::ExprClosure>("|a, b = (1 | 2)| a | b").unwrap();
 /// ```
 impl Parse for ExprClosure {
     fn parse(parser: &mut Parser<'_>) -> Result<Self, ParseError> {
@@ -99,7 +125,7 @@ impl Parse for ExprClosure {
             let mut args = Vec::new();
 
             while !parser.peek::<ast::Pipe>()? {
-                let arg = parser.parse()?;
+                let arg = ast::FnArg::parse_closure_arg(parser)?;
 
                 let comma = parser.parse::<Option<ast::Comma>>()?;
                 let is_end = comma.is_none();
@@ -122,3 +148,59 @@ impl Parse for ExprClosure {
         })
     }
 }
+
+impl Shift for ExprClosureArgs {
+    fn shift(&mut self, delta: isize) {
+        match self {
+            Self::Empty { token } => token.shift(delta),
+            Self::List { open, args, close } => {
+                open.shift(delta);
+                args.shift(delta);
+                close.shift(delta);
+            }
+        }
+    }
+}
+
+impl Shift for ExprClosure {
+    fn shift(&mut self, delta: isize) {
+        self.async_.shift(delta);
+        self.args.shift(delta);
+        self.body.shift(delta);
+    }
+}
+
+impl SyntaxEq for ExprClosureArgs {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        match (self, other) {
+            (Self::Empty { token: a_token }, Self::Empty { token: b_token }) => {
+                a_token.syntax_eq(b_token, source, other_source)
+            }
+            (
+                Self::List {
+                    open: a_open,
+                    args: a_args,
+                    close: a_close,
+                },
+                Self::List {
+                    open: b_open,
+                    args: b_args,
+                    close: b_close,
+                },
+            ) => {
+                a_open.syntax_eq(b_open, source, other_source)
+                    && a_args.syntax_eq(b_args, source, other_source)
+                    && a_close.syntax_eq(b_close, source, other_source)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl SyntaxEq for ExprClosure {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.async_.syntax_eq(&other.async_, source, other_source)
+            && self.args.syntax_eq(&other.args, source, other_source)
+            && self.body.syntax_eq(&other.body, source, other_source)
+    }
+}
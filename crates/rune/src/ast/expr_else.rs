@@ -1,7 +1,7 @@
-use crate::ast::{Else, ExprBlock};
+use crate::ast::{Else, ExprBlock, SyntaxEq};
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::Parse;
+use crate::traits::{Parse, Shift};
 use runestick::Span;
 
 /// An else branch of an if expression.
@@ -28,3 +28,17 @@ impl Parse for ExprElse {
         })
     }
 }
+
+impl Shift for ExprElse {
+    fn shift(&mut self, delta: isize) {
+        self.else_.shift(delta);
+        self.block.shift(delta);
+    }
+}
+
+impl SyntaxEq for ExprElse {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.else_.syntax_eq(&other.else_, source, other_source)
+            && self.block.syntax_eq(&other.block, source, other_source)
+    }
+}
@@ -1,7 +1,7 @@
-use crate::ast::{Condition, Else, ExprBlock, If};
+use crate::ast::{Condition, Else, ExprBlock, If, SyntaxEq};
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::Parse;
+use crate::traits::{Parse, Shift};
 use runestick::Span;
 
 /// An else branch of an if expression.
@@ -34,3 +34,23 @@ impl Parse for ExprElseIf {
         })
     }
 }
+
+impl Shift for ExprElseIf {
+    fn shift(&mut self, delta: isize) {
+        self.else_.shift(delta);
+        self.if_.shift(delta);
+        self.condition.shift(delta);
+        self.block.shift(delta);
+    }
+}
+
+impl SyntaxEq for ExprElseIf {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.else_.syntax_eq(&other.else_, source, other_source)
+            && self.if_.syntax_eq(&other.if_, source, other_source)
+            && self
+                .condition
+                .syntax_eq(&other.condition, source, other_source)
+            && self.block.syntax_eq(&other.block, source, other_source)
+    }
+}
@@ -1,4 +1,6 @@
 use crate::ast;
+use crate::ast::SyntaxEq;
+use crate::traits::Shift;
 use runestick::Span;
 
 /// The field being accessed.
@@ -37,3 +39,40 @@ impl ExprFieldAccess {
         self.expr.span().join(self.expr_field.span())
     }
 }
+
+impl Shift for ExprField {
+    fn shift(&mut self, delta: isize) {
+        match self {
+            Self::Ident(ident) => ident.shift(delta),
+            Self::LitNumber(lit_number) => lit_number.shift(delta),
+        }
+    }
+}
+
+impl Shift for ExprFieldAccess {
+    fn shift(&mut self, delta: isize) {
+        self.expr.shift(delta);
+        self.dot.shift(delta);
+        self.expr_field.shift(delta);
+    }
+}
+
+impl SyntaxEq for ExprField {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        match (self, other) {
+            (Self::Ident(a), Self::Ident(b)) => a.syntax_eq(b, source, other_source),
+            (Self::LitNumber(a), Self::LitNumber(b)) => a.syntax_eq(b, source, other_source),
+            _ => false,
+        }
+    }
+}
+
+impl SyntaxEq for ExprFieldAccess {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.expr.syntax_eq(&other.expr, source, other_source)
+            && self.dot.syntax_eq(&other.dot, source, other_source)
+            && self
+                .expr_field
+                .syntax_eq(&other.expr_field, source, other_source)
+    }
+}
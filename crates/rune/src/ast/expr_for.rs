@@ -1,19 +1,18 @@
-use crate::ast::{Colon, Expr, ExprBlock, For, Ident, In, Label};
+use crate::ast::{Colon, Expr, ExprBlock, For, In, Label, Pat, SyntaxEq};
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::Parse;
+use crate::traits::{Parse, Shift};
 use runestick::Span;
 
-/// A let expression `let <name> = <expr>;`
+/// A for loop `for <pat> in <expr> { <body> }`.
 #[derive(Debug, Clone)]
 pub struct ExprFor {
     /// The label of the loop.
     pub label: Option<(Label, Colon)>,
     /// The `for` keyword.
     pub for_: For,
-    /// The variable binding.
-    /// TODO: should be a pattern when that is supported.
-    pub var: Ident,
+    /// The pattern binding each item produced by the iterator.
+    pub var: Pat,
     /// The `in` keyword.
     pub in_: In,
     /// Expression producing the iterator.
@@ -44,6 +43,17 @@ impl ExprFor {
     }
 }
 
+/// Parse a for loop.
+///
+/// # Examples
+///
+/// ```rust
+/// use rune::{parse_all, ast};
+///
+/// parse_all::<ast::ExprFor>("for n in 0..10 { n }").unwrap();
+/// parse_all::<ast::ExprFor>("for (key, value) in object { }").unwrap();
+/// parse_all::<ast::ExprFor>("'outer: for _ in [] { }").unwrap();
+/// ```
 impl Parse for ExprFor {
     fn parse(parser: &mut Parser<'_>) -> Result<Self, ParseError> {
         let label = if parser.peek::<Label>()? {
@@ -55,3 +65,25 @@ impl Parse for ExprFor {
         Self::parse_with_label(parser, label)
     }
 }
+
+impl Shift for ExprFor {
+    fn shift(&mut self, delta: isize) {
+        self.label.shift(delta);
+        self.for_.shift(delta);
+        self.var.shift(delta);
+        self.in_.shift(delta);
+        self.iter.shift(delta);
+        self.body.shift(delta);
+    }
+}
+
+impl SyntaxEq for ExprFor {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.label.syntax_eq(&other.label, source, other_source)
+            && self.for_.syntax_eq(&other.for_, source, other_source)
+            && self.var.syntax_eq(&other.var, source, other_source)
+            && self.in_.syntax_eq(&other.in_, source, other_source)
+            && self.iter.syntax_eq(&other.iter, source, other_source)
+            && self.body.syntax_eq(&other.body, source, other_source)
+    }
+}
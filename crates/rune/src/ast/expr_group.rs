@@ -1,7 +1,7 @@
-use crate::ast::{CloseParen, Expr, OpenParen};
+use crate::ast::{CloseParen, Expr, OpenParen, SyntaxEq};
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::Parse;
+use crate::traits::{Parse, Shift};
 use runestick::Span;
 
 /// A prioritized expression group `(<expr>)`.
@@ -36,3 +36,19 @@ impl Parse for ExprGroup {
         })
     }
 }
+
+impl Shift for ExprGroup {
+    fn shift(&mut self, delta: isize) {
+        self.open.shift(delta);
+        self.expr.shift(delta);
+        self.close.shift(delta);
+    }
+}
+
+impl SyntaxEq for ExprGroup {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.open.syntax_eq(&other.open, source, other_source)
+            && self.expr.syntax_eq(&other.expr, source, other_source)
+            && self.close.syntax_eq(&other.close, source, other_source)
+    }
+}
@@ -1,7 +1,7 @@
-use crate::ast::{Condition, Else, ExprBlock, ExprElse, ExprElseIf, If};
+use crate::ast::{Condition, Else, ExprBlock, ExprElse, ExprElseIf, If, SyntaxEq};
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::Parse;
+use crate::traits::{Parse, Shift};
 use runestick::Span;
 
 /// An if expression.
@@ -35,6 +35,15 @@ impl ExprIf {
     pub fn produces_nothing(&self) -> bool {
         self.expr_else.is_none()
     }
+
+    /// Test if none of the conditional branches produce a value.
+    pub fn branches_produce_nothing(&self) -> bool {
+        self.block.produces_nothing()
+            && self
+                .expr_else_ifs
+                .iter()
+                .all(|expr_else_if| expr_else_if.block.produces_nothing())
+    }
 }
 
 /// Parse an if statement.
@@ -75,3 +84,29 @@ impl Parse for ExprIf {
         })
     }
 }
+
+impl Shift for ExprIf {
+    fn shift(&mut self, delta: isize) {
+        self.if_.shift(delta);
+        self.condition.shift(delta);
+        self.block.shift(delta);
+        self.expr_else_ifs.shift(delta);
+        self.expr_else.shift(delta);
+    }
+}
+
+impl SyntaxEq for ExprIf {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.if_.syntax_eq(&other.if_, source, other_source)
+            && self
+                .condition
+                .syntax_eq(&other.condition, source, other_source)
+            && self.block.syntax_eq(&other.block, source, other_source)
+            && self
+                .expr_else_ifs
+                .syntax_eq(&other.expr_else_ifs, source, other_source)
+            && self
+                .expr_else
+                .syntax_eq(&other.expr_else, source, other_source)
+    }
+}
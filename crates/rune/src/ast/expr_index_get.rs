@@ -1,4 +1,5 @@
-use crate::ast::{CloseBracket, Expr, OpenBracket};
+use crate::ast::{CloseBracket, Expr, OpenBracket, SyntaxEq};
+use crate::traits::Shift;
 use runestick::Span;
 
 /// An index get operation `<target>[<index>]`.
@@ -20,3 +21,21 @@ impl ExprIndexGet {
         self.target.span().join(self.close.span())
     }
 }
+
+impl Shift for ExprIndexGet {
+    fn shift(&mut self, delta: isize) {
+        self.target.shift(delta);
+        self.open.shift(delta);
+        self.index.shift(delta);
+        self.close.shift(delta);
+    }
+}
+
+impl SyntaxEq for ExprIndexGet {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.target.syntax_eq(&other.target, source, other_source)
+            && self.open.syntax_eq(&other.open, source, other_source)
+            && self.index.syntax_eq(&other.index, source, other_source)
+            && self.close.syntax_eq(&other.close, source, other_source)
+    }
+}
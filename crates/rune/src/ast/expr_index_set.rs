@@ -1,4 +1,5 @@
-use crate::ast::{CloseBracket, Eq, Expr, OpenBracket};
+use crate::ast::{CloseBracket, Eq, Expr, OpenBracket, SyntaxEq};
+use crate::traits::Shift;
 use runestick::Span;
 
 /// An index set operation `<target>[<index>] = <value>`.
@@ -24,3 +25,25 @@ impl ExprIndexSet {
         self.target.span().join(self.value.span())
     }
 }
+
+impl Shift for ExprIndexSet {
+    fn shift(&mut self, delta: isize) {
+        self.target.shift(delta);
+        self.open.shift(delta);
+        self.index.shift(delta);
+        self.close.shift(delta);
+        self.eq.shift(delta);
+        self.value.shift(delta);
+    }
+}
+
+impl SyntaxEq for ExprIndexSet {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.target.syntax_eq(&other.target, source, other_source)
+            && self.open.syntax_eq(&other.open, source, other_source)
+            && self.index.syntax_eq(&other.index, source, other_source)
+            && self.close.syntax_eq(&other.close, source, other_source)
+            && self.eq.syntax_eq(&other.eq, source, other_source)
+            && self.value.syntax_eq(&other.value, source, other_source)
+    }
+}
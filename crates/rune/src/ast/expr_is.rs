@@ -1,7 +1,8 @@
 use crate::ast;
+use crate::ast::SyntaxEq;
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::Parse;
+use crate::traits::{Parse, Shift};
 use runestick::Span;
 
 /// An is expression.
@@ -41,3 +42,19 @@ impl Parse for ExprIs {
         })
     }
 }
+
+impl Shift for ExprIs {
+    fn shift(&mut self, delta: isize) {
+        self.lhs.shift(delta);
+        self.is.shift(delta);
+        self.rhs.shift(delta);
+    }
+}
+
+impl SyntaxEq for ExprIs {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.lhs.syntax_eq(&other.lhs, source, other_source)
+            && self.is.syntax_eq(&other.is, source, other_source)
+            && self.rhs.syntax_eq(&other.rhs, source, other_source)
+    }
+}
@@ -1,7 +1,7 @@
-use crate::ast::{Eq, Expr, Let, Pat};
+use crate::ast::{Eq, Expr, Let, Pat, SyntaxEq};
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::Parse;
+use crate::traits::{Parse, Shift};
 use runestick::Span;
 
 /// A let expression `let <name> = <expr>;`
@@ -44,3 +44,21 @@ impl Parse for ExprLet {
         })
     }
 }
+
+impl Shift for ExprLet {
+    fn shift(&mut self, delta: isize) {
+        self.let_.shift(delta);
+        self.pat.shift(delta);
+        self.eq.shift(delta);
+        self.expr.shift(delta);
+    }
+}
+
+impl SyntaxEq for ExprLet {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.let_.syntax_eq(&other.let_, source, other_source)
+            && self.pat.syntax_eq(&other.pat, source, other_source)
+            && self.eq.syntax_eq(&other.eq, source, other_source)
+            && self.expr.syntax_eq(&other.expr, source, other_source)
+    }
+}
@@ -1,7 +1,7 @@
-use crate::ast::{Colon, ExprBlock, Label, Loop};
+use crate::ast::{Colon, ExprBlock, Label, Loop, SyntaxEq};
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::Parse;
+use crate::traits::{Parse, Shift};
 use runestick::Span;
 
 /// A let expression `let <name> = <expr>;`
@@ -45,3 +45,19 @@ impl Parse for ExprLoop {
         Self::parse_with_label(parser, label)
     }
 }
+
+impl Shift for ExprLoop {
+    fn shift(&mut self, delta: isize) {
+        self.label.shift(delta);
+        self.loop_.shift(delta);
+        self.body.shift(delta);
+    }
+}
+
+impl SyntaxEq for ExprLoop {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.label.syntax_eq(&other.label, source, other_source)
+            && self.loop_.syntax_eq(&other.loop_, source, other_source)
+            && self.body.syntax_eq(&other.body, source, other_source)
+    }
+}
@@ -1,8 +1,8 @@
 use crate::ast::utils;
-use crate::ast::{CloseBrace, Comma, Expr, If, Match, OpenBrace, Pat, Rocket};
+use crate::ast::{CloseBrace, Comma, Expr, If, Match, OpenBrace, Pat, Rocket, SyntaxEq};
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::Parse;
+use crate::traits::{Parse, Shift};
 use runestick::Span;
 
 /// A match branch.
@@ -126,3 +126,45 @@ impl Parse for ExprMatch {
         })
     }
 }
+
+impl Shift for ExprMatchBranch {
+    fn shift(&mut self, delta: isize) {
+        self.pat.shift(delta);
+        self.condition.shift(delta);
+        self.rocket.shift(delta);
+        self.body.shift(delta);
+    }
+}
+
+impl Shift for ExprMatch {
+    fn shift(&mut self, delta: isize) {
+        self.match_.shift(delta);
+        self.expr.shift(delta);
+        self.open.shift(delta);
+        self.branches.shift(delta);
+        self.close.shift(delta);
+    }
+}
+
+impl SyntaxEq for ExprMatchBranch {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.pat.syntax_eq(&other.pat, source, other_source)
+            && self
+                .condition
+                .syntax_eq(&other.condition, source, other_source)
+            && self.rocket.syntax_eq(&other.rocket, source, other_source)
+            && self.body.syntax_eq(&other.body, source, other_source)
+    }
+}
+
+impl SyntaxEq for ExprMatch {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.match_.syntax_eq(&other.match_, source, other_source)
+            && self.expr.syntax_eq(&other.expr, source, other_source)
+            && self.open.syntax_eq(&other.open, source, other_source)
+            && self
+                .branches
+                .syntax_eq(&other.branches, source, other_source)
+            && self.close.syntax_eq(&other.close, source, other_source)
+    }
+}
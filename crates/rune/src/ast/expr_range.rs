@@ -0,0 +1,52 @@
+use crate::ast;
+use crate::ast::SyntaxEq;
+use crate::traits::Shift;
+use runestick::Span;
+
+/// A range expression `a..b`, where both bounds are optional.
+#[derive(Debug, Clone)]
+pub struct ExprRange {
+    /// The start of the range.
+    pub from: Option<Box<ast::Expr>>,
+    /// The range operator.
+    pub limits: ast::DotDot,
+    /// The end of the range.
+    pub to: Option<Box<ast::Expr>>,
+}
+
+impl ExprRange {
+    /// Access the span of the expression.
+    pub fn span(&self) -> Span {
+        let start = match &self.from {
+            Some(from) => from.span(),
+            None => self.limits.span(),
+        };
+
+        match &self.to {
+            Some(to) => start.join(to.span()),
+            None => start.join(self.limits.span()),
+        }
+    }
+
+    /// Test if the expression is a constant expression.
+    pub fn is_const(&self) -> bool {
+        self.from.as_ref().map(|e| e.is_const()).unwrap_or(true)
+            && self.to.as_ref().map(|e| e.is_const()).unwrap_or(true)
+    }
+}
+
+impl Shift for ExprRange {
+    fn shift(&mut self, delta: isize) {
+        self.from.shift(delta);
+        self.limits.shift(delta);
+        self.to.shift(delta);
+    }
+}
+
+impl SyntaxEq for ExprRange {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.from.syntax_eq(&other.from, source, other_source)
+            && self.limits.syntax_eq(&other.limits, source, other_source)
+            && self.to.syntax_eq(&other.to, source, other_source)
+    }
+}
@@ -1,7 +1,8 @@
 use crate::ast;
+use crate::ast::SyntaxEq;
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::Parse;
+use crate::traits::{Parse, Shift};
 use runestick::Span;
 
 /// A return statement `return [expr]`.
@@ -37,3 +38,17 @@ impl Parse for ExprReturn {
         Ok(Self { return_, expr })
     }
 }
+
+impl Shift for ExprReturn {
+    fn shift(&mut self, delta: isize) {
+        self.return_.shift(delta);
+        self.expr.shift(delta);
+    }
+}
+
+impl SyntaxEq for ExprReturn {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.return_.syntax_eq(&other.return_, source, other_source)
+            && self.expr.syntax_eq(&other.expr, source, other_source)
+    }
+}
@@ -1,8 +1,9 @@
 use crate::ast;
 use crate::ast::utils;
+use crate::ast::SyntaxEq;
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::Parse;
+use crate::traits::{Parse, Shift};
 use runestick::Span;
 
 /// A single selection branch.
@@ -140,3 +141,63 @@ impl Parse for ExprSelect {
         })
     }
 }
+
+impl Shift for ExprSelectBranch {
+    fn shift(&mut self, delta: isize) {
+        self.pat.shift(delta);
+        self.eq.shift(delta);
+        self.expr.shift(delta);
+        self.rocket.shift(delta);
+        self.body.shift(delta);
+    }
+}
+
+impl Shift for ExprDefaultBranch {
+    fn shift(&mut self, delta: isize) {
+        self.default.shift(delta);
+        self.rocket.shift(delta);
+        self.body.shift(delta);
+    }
+}
+
+impl Shift for ExprSelect {
+    fn shift(&mut self, delta: isize) {
+        self.select.shift(delta);
+        self.open.shift(delta);
+        self.branches.shift(delta);
+        self.default_branch.shift(delta);
+        self.close.shift(delta);
+    }
+}
+
+impl SyntaxEq for ExprSelectBranch {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.pat.syntax_eq(&other.pat, source, other_source)
+            && self.eq.syntax_eq(&other.eq, source, other_source)
+            && self.expr.syntax_eq(&other.expr, source, other_source)
+            && self.rocket.syntax_eq(&other.rocket, source, other_source)
+            && self.body.syntax_eq(&other.body, source, other_source)
+    }
+}
+
+impl SyntaxEq for ExprDefaultBranch {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.default.syntax_eq(&other.default, source, other_source)
+            && self.rocket.syntax_eq(&other.rocket, source, other_source)
+            && self.body.syntax_eq(&other.body, source, other_source)
+    }
+}
+
+impl SyntaxEq for ExprSelect {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.select.syntax_eq(&other.select, source, other_source)
+            && self.open.syntax_eq(&other.open, source, other_source)
+            && self
+                .branches
+                .syntax_eq(&other.branches, source, other_source)
+            && self
+                .default_branch
+                .syntax_eq(&other.default_branch, source, other_source)
+            && self.close.syntax_eq(&other.close, source, other_source)
+    }
+}
@@ -1,4 +1,6 @@
 use crate::ast;
+use crate::ast::SyntaxEq;
+use crate::traits::Shift;
 use runestick::Span;
 
 /// A try expression `<expr>?`.
@@ -16,3 +18,17 @@ impl ExprTry {
         self.expr.span().join(self.try_.span())
     }
 }
+
+impl Shift for ExprTry {
+    fn shift(&mut self, delta: isize) {
+        self.expr.shift(delta);
+        self.try_.shift(delta);
+    }
+}
+
+impl SyntaxEq for ExprTry {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.expr.syntax_eq(&other.expr, source, other_source)
+            && self.try_.syntax_eq(&other.try_, source, other_source)
+    }
+}
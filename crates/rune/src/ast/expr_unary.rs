@@ -1,8 +1,9 @@
 use crate::ast;
 use crate::ast::expr::{EagerBrace, ExprChain};
+use crate::ast::SyntaxEq;
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::Parse;
+use crate::traits::{Parse, Shift};
 use runestick::Span;
 use std::fmt;
 
@@ -91,3 +92,17 @@ impl fmt::Display for UnaryOp {
         Ok(())
     }
 }
+
+impl Shift for ExprUnary {
+    fn shift(&mut self, delta: isize) {
+        self.token.shift(delta);
+        self.expr.shift(delta);
+    }
+}
+
+impl SyntaxEq for ExprUnary {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.token.syntax_eq(&other.token, source, other_source)
+            && self.expr.syntax_eq(&other.expr, source, other_source)
+    }
+}
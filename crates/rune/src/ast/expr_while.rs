@@ -1,7 +1,8 @@
 use crate::ast;
+use crate::ast::SyntaxEq;
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::Parse;
+use crate::traits::{Parse, Shift};
 use runestick::Span;
 
 /// A let expression `let <name> = <expr>;`
@@ -48,3 +49,23 @@ impl Parse for ExprWhile {
         Self::parse_with_label(parser, label)
     }
 }
+
+impl Shift for ExprWhile {
+    fn shift(&mut self, delta: isize) {
+        self.label.shift(delta);
+        self.while_.shift(delta);
+        self.condition.shift(delta);
+        self.body.shift(delta);
+    }
+}
+
+impl SyntaxEq for ExprWhile {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.label.syntax_eq(&other.label, source, other_source)
+            && self.while_.syntax_eq(&other.while_, source, other_source)
+            && self
+                .condition
+                .syntax_eq(&other.condition, source, other_source)
+            && self.body.syntax_eq(&other.body, source, other_source)
+    }
+}
@@ -1,7 +1,8 @@
 use crate::ast;
+use crate::ast::SyntaxEq;
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::Parse;
+use crate::traits::{Parse, Shift};
 use runestick::Span;
 
 /// A return statement `break [expr]`.
@@ -32,3 +33,17 @@ impl Parse for ExprYield {
         })
     }
 }
+
+impl Shift for ExprYield {
+    fn shift(&mut self, delta: isize) {
+        self.yield_.shift(delta);
+        self.expr.shift(delta);
+    }
+}
+
+impl SyntaxEq for ExprYield {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.yield_.syntax_eq(&other.yield_, source, other_source)
+            && self.expr.syntax_eq(&other.expr, source, other_source)
+    }
+}
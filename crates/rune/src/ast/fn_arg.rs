@@ -1,7 +1,8 @@
 use crate::ast;
+use crate::ast::SyntaxEq;
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::Parse;
+use crate::traits::{Parse, Shift};
 use runestick::Span;
 
 /// A single argument in a closure.
@@ -13,6 +14,12 @@ pub enum FnArg {
     Ignore(ast::Underscore),
     /// Binding the argument to an ident.
     Ident(ast::Ident),
+    /// Binding the argument to an ident, with a default value which is used
+    /// if the argument is omitted, like `greeting = "Hello"`.
+    Default(ast::Ident, ast::Eq, Box<ast::Expr>),
+    /// A rest argument like `..rest`, which collects any remaining arguments
+    /// into a vector.
+    Rest(ast::DotDot, ast::Ident),
 }
 
 impl FnArg {
@@ -22,19 +29,97 @@ impl FnArg {
             Self::Self_(s) => s.span(),
             Self::Ignore(ignore) => ignore.span(),
             Self::Ident(ident) => ident.span(),
+            Self::Default(ident, _, expr) => ident.span().join(expr.span()),
+            Self::Rest(dot_dot, ident) => dot_dot.span().join(ident.span()),
         }
     }
-}
 
-impl Parse for FnArg {
-    fn parse(parser: &mut Parser<'_>) -> Result<Self, ParseError> {
+    /// Test if this argument has a default value.
+    pub fn is_default(&self) -> bool {
+        matches!(self, Self::Default(..))
+    }
+
+    /// Test if this is a rest argument.
+    pub fn is_rest(&self) -> bool {
+        matches!(self, Self::Rest(..))
+    }
+
+    /// Parse an argument of a closure.
+    ///
+    /// Default values stop at a bare `|`, since it closes the argument list.
+    pub(super) fn parse_closure_arg(parser: &mut Parser<'_>) -> Result<Self, ParseError> {
+        Self::parse_with(parser, true)
+    }
+
+    fn parse_with(parser: &mut Parser<'_>, closure: bool) -> Result<Self, ParseError> {
         let token = parser.token_peek_eof()?;
 
         Ok(match token.kind {
             ast::Kind::Self_ => Self::Self_(parser.parse()?),
             ast::Kind::Underscore => Self::Ignore(parser.parse()?),
-            ast::Kind::Ident => Self::Ident(parser.parse()?),
+            ast::Kind::Ident => {
+                let ident = parser.parse()?;
+
+                match parser.parse::<Option<ast::Eq>>()? {
+                    Some(eq) => {
+                        let expr = if closure {
+                            ast::Expr::parse_without_pipe(parser)?
+                        } else {
+                            parser.parse()?
+                        };
+
+                        Self::Default(ident, eq, Box::new(expr))
+                    }
+                    None => Self::Ident(ident),
+                }
+            }
+            ast::Kind::DotDot => Self::Rest(parser.parse()?, parser.parse()?),
             _ => return Err(ParseError::ExpectedFunctionArgument { span: token.span }),
         })
     }
 }
+
+impl Parse for FnArg {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, ParseError> {
+        Self::parse_with(parser, false)
+    }
+}
+
+impl Shift for FnArg {
+    fn shift(&mut self, delta: isize) {
+        match self {
+            Self::Self_(self_) => self_.shift(delta),
+            Self::Ignore(underscore) => underscore.shift(delta),
+            Self::Ident(ident) => ident.shift(delta),
+            Self::Default(ident, eq, expr) => {
+                ident.shift(delta);
+                eq.shift(delta);
+                expr.shift(delta);
+            }
+            Self::Rest(dot_dot, ident) => {
+                dot_dot.shift(delta);
+                ident.shift(delta);
+            }
+        }
+    }
+}
+
+impl SyntaxEq for FnArg {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        match (self, other) {
+            (Self::Self_(a), Self::Self_(b)) => a.syntax_eq(b, source, other_source),
+            (Self::Ignore(a), Self::Ignore(b)) => a.syntax_eq(b, source, other_source),
+            (Self::Ident(a), Self::Ident(b)) => a.syntax_eq(b, source, other_source),
+            (Self::Default(a_ident, a_eq, a_expr), Self::Default(b_ident, b_eq, b_expr)) => {
+                a_ident.syntax_eq(b_ident, source, other_source)
+                    && a_eq.syntax_eq(b_eq, source, other_source)
+                    && a_expr.syntax_eq(b_expr, source, other_source)
+            }
+            (Self::Rest(a_dot_dot, a_ident), Self::Rest(b_dot_dot, b_ident)) => {
+                a_dot_dot.syntax_eq(b_dot_dot, source, other_source)
+                    && a_ident.syntax_eq(b_ident, source, other_source)
+            }
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,1141 @@
+//! Folding of a syntax tree by value.
+//!
+//! Every method of [Fold] defaults to rebuilding the node from its folded
+//! children through the corresponding `walk_*` function in this module, so
+//! implementors only need to override the nodes they want to rewrite. Leaf
+//! nodes like literals and identifiers are returned as-is.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rune::ast;
+//! use rune::ast::fold::{self, Fold};
+//!
+//! /// Strip all grouping parenthesis.
+//! struct Ungroup;
+//!
+//! impl Fold for Ungroup {
+//!     fn fold_expr(&mut self, expr: ast::Expr) -> ast::Expr {
+//!         match expr {
+//!             ast::Expr::ExprGroup(group) => self.fold_expr(*group.expr),
+//!             expr => fold::walk_expr(self, expr),
+//!         }
+//!     }
+//! }
+//!
+//! let expr = rune::parse_all::<ast::Expr>("((1) + (2 * 3))").unwrap();
+//!
+//! match Ungroup.fold_expr(expr) {
+//!     ast::Expr::ExprBinary(binary) => {
+//!         assert!(matches!(*binary.lhs, ast::Expr::LitNumber(..)));
+//!         assert!(matches!(*binary.rhs, ast::Expr::ExprBinary(..)));
+//!     }
+//!     expr => panic!("expected a binary expression, but got {:?}", expr),
+//! }
+//! ```
+
+use crate::ast;
+
+/// A folder over a syntax tree by value.
+///
+/// See the [module level documentation][self] for more.
+pub trait Fold {
+    /// Fold a file.
+    fn fold_decl_file(&mut self, decl_file: ast::DeclFile) -> ast::DeclFile {
+        walk_decl_file(self, decl_file)
+    }
+
+    /// Fold a declaration.
+    fn fold_decl(&mut self, decl: ast::Decl) -> ast::Decl {
+        walk_decl(self, decl)
+    }
+
+    /// Fold a use declaration.
+    fn fold_decl_use(&mut self, decl_use: ast::DeclUse) -> ast::DeclUse {
+        walk_decl_use(self, decl_use)
+    }
+
+    /// Fold a function declaration.
+    fn fold_decl_fn(&mut self, decl_fn: ast::DeclFn) -> ast::DeclFn {
+        walk_decl_fn(self, decl_fn)
+    }
+
+    /// Fold an enum declaration.
+    fn fold_decl_enum(&mut self, decl_enum: ast::DeclEnum) -> ast::DeclEnum {
+        walk_decl_enum(self, decl_enum)
+    }
+
+    /// Fold a struct declaration.
+    fn fold_decl_struct(&mut self, decl_struct: ast::DeclStruct) -> ast::DeclStruct {
+        walk_decl_struct(self, decl_struct)
+    }
+
+    /// Fold the body of a struct or an enum variant.
+    fn fold_decl_struct_body(&mut self, body: ast::DeclStructBody) -> ast::DeclStructBody {
+        walk_decl_struct_body(self, body)
+    }
+
+    /// Fold an impl declaration.
+    fn fold_decl_impl(&mut self, decl_impl: ast::DeclImpl) -> ast::DeclImpl {
+        walk_decl_impl(self, decl_impl)
+    }
+
+    /// Fold a function or closure argument.
+    fn fold_fn_arg(&mut self, fn_arg: ast::FnArg) -> ast::FnArg {
+        walk_fn_arg(self, fn_arg)
+    }
+
+    /// Fold an expression.
+    fn fold_expr(&mut self, expr: ast::Expr) -> ast::Expr {
+        walk_expr(self, expr)
+    }
+
+    /// Fold a while loop.
+    fn fold_expr_while(&mut self, expr: ast::ExprWhile) -> ast::ExprWhile {
+        walk_expr_while(self, expr)
+    }
+
+    /// Fold an unconditional loop.
+    fn fold_expr_loop(&mut self, expr: ast::ExprLoop) -> ast::ExprLoop {
+        walk_expr_loop(self, expr)
+    }
+
+    /// Fold a for loop.
+    fn fold_expr_for(&mut self, expr: ast::ExprFor) -> ast::ExprFor {
+        walk_expr_for(self, expr)
+    }
+
+    /// Fold a let expression.
+    fn fold_expr_let(&mut self, expr: ast::ExprLet) -> ast::ExprLet {
+        walk_expr_let(self, expr)
+    }
+
+    /// Fold an index set operation.
+    fn fold_expr_index_set(&mut self, expr: ast::ExprIndexSet) -> ast::ExprIndexSet {
+        walk_expr_index_set(self, expr)
+    }
+
+    /// Fold an if expression.
+    fn fold_expr_if(&mut self, expr: ast::ExprIf) -> ast::ExprIf {
+        walk_expr_if(self, expr)
+    }
+
+    /// Fold an else if branch.
+    fn fold_expr_else_if(&mut self, expr: ast::ExprElseIf) -> ast::ExprElseIf {
+        walk_expr_else_if(self, expr)
+    }
+
+    /// Fold an else branch.
+    fn fold_expr_else(&mut self, expr: ast::ExprElse) -> ast::ExprElse {
+        walk_expr_else(self, expr)
+    }
+
+    /// Fold the condition of an if or a while.
+    fn fold_condition(&mut self, condition: ast::Condition) -> ast::Condition {
+        walk_condition(self, condition)
+    }
+
+    /// Fold a match expression.
+    fn fold_expr_match(&mut self, expr: ast::ExprMatch) -> ast::ExprMatch {
+        walk_expr_match(self, expr)
+    }
+
+    /// Fold a match branch.
+    fn fold_expr_match_branch(&mut self, branch: ast::ExprMatchBranch) -> ast::ExprMatchBranch {
+        walk_expr_match_branch(self, branch)
+    }
+
+    /// Fold a function call.
+    fn fold_expr_call(&mut self, expr: ast::ExprCall) -> ast::ExprCall {
+        walk_expr_call(self, expr)
+    }
+
+    /// Fold an argument to a function call.
+    fn fold_call_arg(&mut self, call_arg: ast::CallArg) -> ast::CallArg {
+        walk_call_arg(self, call_arg)
+    }
+
+    /// Fold a field access.
+    fn fold_expr_field_access(&mut self, expr: ast::ExprFieldAccess) -> ast::ExprFieldAccess {
+        walk_expr_field_access(self, expr)
+    }
+
+    /// Fold a grouped expression.
+    fn fold_expr_group(&mut self, expr: ast::ExprGroup) -> ast::ExprGroup {
+        walk_expr_group(self, expr)
+    }
+
+    /// Fold a binary expression.
+    fn fold_expr_binary(&mut self, expr: ast::ExprBinary) -> ast::ExprBinary {
+        walk_expr_binary(self, expr)
+    }
+
+    /// Fold a unary expression.
+    fn fold_expr_unary(&mut self, expr: ast::ExprUnary) -> ast::ExprUnary {
+        walk_expr_unary(self, expr)
+    }
+
+    /// Fold an index get operation.
+    fn fold_expr_index_get(&mut self, expr: ast::ExprIndexGet) -> ast::ExprIndexGet {
+        walk_expr_index_get(self, expr)
+    }
+
+    /// Fold a break expression.
+    fn fold_expr_break(&mut self, expr: ast::ExprBreak) -> ast::ExprBreak {
+        walk_expr_break(self, expr)
+    }
+
+    /// Fold a yield expression.
+    fn fold_expr_yield(&mut self, expr: ast::ExprYield) -> ast::ExprYield {
+        walk_expr_yield(self, expr)
+    }
+
+    /// Fold a block.
+    fn fold_expr_block(&mut self, expr: ast::ExprBlock) -> ast::ExprBlock {
+        walk_expr_block(self, expr)
+    }
+
+    /// Fold a range expression.
+    fn fold_expr_range(&mut self, expr: ast::ExprRange) -> ast::ExprRange {
+        walk_expr_range(self, expr)
+    }
+
+    /// Fold a return expression.
+    fn fold_expr_return(&mut self, expr: ast::ExprReturn) -> ast::ExprReturn {
+        walk_expr_return(self, expr)
+    }
+
+    /// Fold an await expression.
+    fn fold_expr_await(&mut self, expr: ast::ExprAwait) -> ast::ExprAwait {
+        walk_expr_await(self, expr)
+    }
+
+    /// Fold a try expression.
+    fn fold_expr_try(&mut self, expr: ast::ExprTry) -> ast::ExprTry {
+        walk_expr_try(self, expr)
+    }
+
+    /// Fold a select expression.
+    fn fold_expr_select(&mut self, expr: ast::ExprSelect) -> ast::ExprSelect {
+        walk_expr_select(self, expr)
+    }
+
+    /// Fold a select branch.
+    fn fold_expr_select_branch(&mut self, branch: ast::ExprSelectBranch) -> ast::ExprSelectBranch {
+        walk_expr_select_branch(self, branch)
+    }
+
+    /// Fold the default branch of a select.
+    fn fold_expr_default_branch(
+        &mut self,
+        branch: ast::ExprDefaultBranch,
+    ) -> ast::ExprDefaultBranch {
+        walk_expr_default_branch(self, branch)
+    }
+
+    /// Fold a closure.
+    fn fold_expr_closure(&mut self, expr: ast::ExprClosure) -> ast::ExprClosure {
+        walk_expr_closure(self, expr)
+    }
+
+    /// Fold a unit literal.
+    fn fold_lit_unit(&mut self, lit: ast::LitUnit) -> ast::LitUnit {
+        lit
+    }
+
+    /// Fold a boolean literal.
+    fn fold_lit_bool(&mut self, lit: ast::LitBool) -> ast::LitBool {
+        lit
+    }
+
+    /// Fold a char literal.
+    fn fold_lit_char(&mut self, lit: ast::LitChar) -> ast::LitChar {
+        lit
+    }
+
+    /// Fold a byte literal.
+    fn fold_lit_byte(&mut self, lit: ast::LitByte) -> ast::LitByte {
+        lit
+    }
+
+    /// Fold a number literal.
+    fn fold_lit_number(&mut self, lit: ast::LitNumber) -> ast::LitNumber {
+        lit
+    }
+
+    /// Fold a string literal.
+    fn fold_lit_str(&mut self, lit: ast::LitStr) -> ast::LitStr {
+        lit
+    }
+
+    /// Fold a byte string literal.
+    fn fold_lit_byte_str(&mut self, lit: ast::LitByteStr) -> ast::LitByteStr {
+        lit
+    }
+
+    /// Fold a template string.
+    fn fold_lit_template(&mut self, lit: ast::LitTemplate) -> ast::LitTemplate {
+        lit
+    }
+
+    /// Fold a vector literal.
+    fn fold_lit_vec(&mut self, lit: ast::LitVec) -> ast::LitVec {
+        walk_lit_vec(self, lit)
+    }
+
+    /// Fold an object literal.
+    fn fold_lit_object(&mut self, lit: ast::LitObject) -> ast::LitObject {
+        walk_lit_object(self, lit)
+    }
+
+    /// Fold a field assignment in an object literal.
+    fn fold_lit_object_field_assign(
+        &mut self,
+        assign: ast::LitObjectFieldAssign,
+    ) -> ast::LitObjectFieldAssign {
+        walk_lit_object_field_assign(self, assign)
+    }
+
+    /// Fold the identifier of an object literal or pattern.
+    fn fold_lit_object_ident(&mut self, ident: ast::LitObjectIdent) -> ast::LitObjectIdent {
+        walk_lit_object_ident(self, ident)
+    }
+
+    /// Fold the key of an object literal or pattern.
+    fn fold_lit_object_key(&mut self, key: ast::LitObjectKey) -> ast::LitObjectKey {
+        walk_lit_object_key(self, key)
+    }
+
+    /// Fold a tuple literal.
+    fn fold_lit_tuple(&mut self, lit: ast::LitTuple) -> ast::LitTuple {
+        walk_lit_tuple(self, lit)
+    }
+
+    /// Fold a pattern.
+    fn fold_pat(&mut self, pat: ast::Pat) -> ast::Pat {
+        walk_pat(self, pat)
+    }
+
+    /// Fold a path pattern.
+    fn fold_pat_path(&mut self, pat: ast::PatPath) -> ast::PatPath {
+        walk_pat_path(self, pat)
+    }
+
+    /// Fold a vector pattern.
+    fn fold_pat_vec(&mut self, pat: ast::PatVec) -> ast::PatVec {
+        walk_pat_vec(self, pat)
+    }
+
+    /// Fold a tuple pattern.
+    fn fold_pat_tuple(&mut self, pat: ast::PatTuple) -> ast::PatTuple {
+        walk_pat_tuple(self, pat)
+    }
+
+    /// Fold an object pattern.
+    fn fold_pat_object(&mut self, pat: ast::PatObject) -> ast::PatObject {
+        walk_pat_object(self, pat)
+    }
+
+    /// Fold a field in an object pattern.
+    fn fold_pat_object_item(&mut self, item: ast::PatObjectItem) -> ast::PatObjectItem {
+        walk_pat_object_item(self, item)
+    }
+
+    /// Fold a path.
+    fn fold_path(&mut self, path: ast::Path) -> ast::Path {
+        walk_path(self, path)
+    }
+
+    /// Fold an identifier.
+    fn fold_ident(&mut self, ident: ast::Ident) -> ast::Ident {
+        ident
+    }
+
+    /// Fold a label.
+    fn fold_label(&mut self, label: ast::Label) -> ast::Label {
+        label
+    }
+}
+
+/// Fold a boxed expression.
+fn fold_boxed<F>(f: &mut F, mut expr: Box<ast::Expr>) -> Box<ast::Expr>
+where
+    F: ?Sized + Fold,
+{
+    *expr = f.fold_expr(*expr);
+    expr
+}
+
+/// Fold a boxed block.
+fn fold_boxed_block<F>(f: &mut F, mut block: Box<ast::ExprBlock>) -> Box<ast::ExprBlock>
+where
+    F: ?Sized + Fold,
+{
+    *block = f.fold_expr_block(*block);
+    block
+}
+
+/// Fold an optional loop label.
+fn fold_loop_label<F>(
+    f: &mut F,
+    label: Option<(ast::Label, ast::Colon)>,
+) -> Option<(ast::Label, ast::Colon)>
+where
+    F: ?Sized + Fold,
+{
+    label.map(|(label, colon)| (f.fold_label(label), colon))
+}
+
+/// Walk the declarations of a file.
+pub fn walk_decl_file<F>(f: &mut F, mut decl_file: ast::DeclFile) -> ast::DeclFile
+where
+    F: ?Sized + Fold,
+{
+    decl_file.decls = decl_file
+        .decls
+        .into_iter()
+        .map(|(decl, semi_colon)| (f.fold_decl(decl), semi_colon))
+        .collect();
+
+    decl_file
+}
+
+/// Walk a declaration.
+pub fn walk_decl<F>(f: &mut F, decl: ast::Decl) -> ast::Decl
+where
+    F: ?Sized + Fold,
+{
+    match decl {
+        ast::Decl::DeclUse(decl_use) => ast::Decl::DeclUse(f.fold_decl_use(decl_use)),
+        ast::Decl::DeclFn(decl_fn) => ast::Decl::DeclFn(f.fold_decl_fn(decl_fn)),
+        ast::Decl::DeclEnum(decl_enum) => ast::Decl::DeclEnum(f.fold_decl_enum(decl_enum)),
+        ast::Decl::DeclStruct(decl_struct) => {
+            ast::Decl::DeclStruct(f.fold_decl_struct(decl_struct))
+        }
+        ast::Decl::DeclImpl(decl_impl) => ast::Decl::DeclImpl(f.fold_decl_impl(decl_impl)),
+    }
+}
+
+/// Walk a use declaration.
+pub fn walk_decl_use<F>(f: &mut F, mut decl_use: ast::DeclUse) -> ast::DeclUse
+where
+    F: ?Sized + Fold,
+{
+    decl_use.first = f.fold_ident(decl_use.first);
+
+    decl_use.rest = decl_use
+        .rest
+        .into_iter()
+        .map(|(scope, component)| {
+            let component = match component {
+                ast::DeclUseComponent::Ident(ident) => {
+                    ast::DeclUseComponent::Ident(f.fold_ident(ident))
+                }
+                component => component,
+            };
+
+            (scope, component)
+        })
+        .collect();
+
+    decl_use
+}
+
+/// Walk a function declaration.
+pub fn walk_decl_fn<F>(f: &mut F, mut decl_fn: ast::DeclFn) -> ast::DeclFn
+where
+    F: ?Sized + Fold,
+{
+    decl_fn.name = f.fold_ident(decl_fn.name);
+
+    decl_fn.args.items = decl_fn
+        .args
+        .items
+        .into_iter()
+        .map(|(arg, comma)| (f.fold_fn_arg(arg), comma))
+        .collect();
+
+    decl_fn.body = f.fold_expr_block(decl_fn.body);
+    decl_fn
+}
+
+/// Walk an enum declaration.
+pub fn walk_decl_enum<F>(f: &mut F, mut decl_enum: ast::DeclEnum) -> ast::DeclEnum
+where
+    F: ?Sized + Fold,
+{
+    decl_enum.name = f.fold_ident(decl_enum.name);
+
+    decl_enum.variants = decl_enum
+        .variants
+        .into_iter()
+        .map(|(name, body, comma)| (f.fold_ident(name), f.fold_decl_struct_body(body), comma))
+        .collect();
+
+    decl_enum
+}
+
+/// Walk a struct declaration.
+pub fn walk_decl_struct<F>(f: &mut F, mut decl_struct: ast::DeclStruct) -> ast::DeclStruct
+where
+    F: ?Sized + Fold,
+{
+    decl_struct.ident = f.fold_ident(decl_struct.ident);
+    decl_struct.body = f.fold_decl_struct_body(decl_struct.body);
+    decl_struct
+}
+
+/// Walk the fields of a struct body.
+fn fold_fields<F>(
+    f: &mut F,
+    fields: Vec<(ast::Ident, Option<ast::Comma>)>,
+) -> Vec<(ast::Ident, Option<ast::Comma>)>
+where
+    F: ?Sized + Fold,
+{
+    fields
+        .into_iter()
+        .map(|(field, comma)| (f.fold_ident(field), comma))
+        .collect()
+}
+
+/// Walk the body of a struct or an enum variant.
+pub fn walk_decl_struct_body<F>(f: &mut F, body: ast::DeclStructBody) -> ast::DeclStructBody
+where
+    F: ?Sized + Fold,
+{
+    match body {
+        ast::DeclStructBody::EmptyBody(body) => ast::DeclStructBody::EmptyBody(body),
+        ast::DeclStructBody::TupleBody(mut body) => {
+            body.fields = fold_fields(f, body.fields);
+            ast::DeclStructBody::TupleBody(body)
+        }
+        ast::DeclStructBody::StructBody(mut body) => {
+            body.fields = fold_fields(f, body.fields);
+            ast::DeclStructBody::StructBody(body)
+        }
+    }
+}
+
+/// Walk an impl declaration.
+pub fn walk_decl_impl<F>(f: &mut F, mut decl_impl: ast::DeclImpl) -> ast::DeclImpl
+where
+    F: ?Sized + Fold,
+{
+    decl_impl.path = f.fold_path(decl_impl.path);
+
+    decl_impl.functions = decl_impl
+        .functions
+        .into_iter()
+        .map(|decl_fn| f.fold_decl_fn(decl_fn))
+        .collect();
+
+    decl_impl
+}
+
+/// Walk a function or closure argument.
+pub fn walk_fn_arg<F>(f: &mut F, fn_arg: ast::FnArg) -> ast::FnArg
+where
+    F: ?Sized + Fold,
+{
+    match fn_arg {
+        ast::FnArg::Ident(ident) => ast::FnArg::Ident(f.fold_ident(ident)),
+        ast::FnArg::Default(ident, eq, expr) => {
+            ast::FnArg::Default(f.fold_ident(ident), eq, fold_boxed(f, expr))
+        }
+        ast::FnArg::Rest(dot_dot, ident) => ast::FnArg::Rest(dot_dot, f.fold_ident(ident)),
+        fn_arg => fn_arg,
+    }
+}
+
+/// Walk an expression.
+pub fn walk_expr<F>(f: &mut F, expr: ast::Expr) -> ast::Expr
+where
+    F: ?Sized + Fold,
+{
+    match expr {
+        ast::Expr::Self_(self_) => ast::Expr::Self_(self_),
+        ast::Expr::Path(path) => ast::Expr::Path(f.fold_path(path)),
+        ast::Expr::Decl(decl) => ast::Expr::Decl(f.fold_decl(decl)),
+        ast::Expr::ExprWhile(expr) => ast::Expr::ExprWhile(f.fold_expr_while(expr)),
+        ast::Expr::ExprLoop(expr) => ast::Expr::ExprLoop(f.fold_expr_loop(expr)),
+        ast::Expr::ExprFor(expr) => ast::Expr::ExprFor(f.fold_expr_for(expr)),
+        ast::Expr::ExprLet(expr) => ast::Expr::ExprLet(f.fold_expr_let(expr)),
+        ast::Expr::ExprIndexSet(expr) => ast::Expr::ExprIndexSet(f.fold_expr_index_set(expr)),
+        ast::Expr::ExprIf(expr) => ast::Expr::ExprIf(f.fold_expr_if(expr)),
+        ast::Expr::ExprMatch(expr) => ast::Expr::ExprMatch(f.fold_expr_match(expr)),
+        ast::Expr::ExprCall(expr) => ast::Expr::ExprCall(f.fold_expr_call(expr)),
+        ast::Expr::ExprFieldAccess(expr) => {
+            ast::Expr::ExprFieldAccess(f.fold_expr_field_access(expr))
+        }
+        ast::Expr::ExprGroup(expr) => ast::Expr::ExprGroup(f.fold_expr_group(expr)),
+        ast::Expr::ExprBinary(expr) => ast::Expr::ExprBinary(f.fold_expr_binary(expr)),
+        ast::Expr::ExprUnary(expr) => ast::Expr::ExprUnary(f.fold_expr_unary(expr)),
+        ast::Expr::ExprIndexGet(expr) => ast::Expr::ExprIndexGet(f.fold_expr_index_get(expr)),
+        ast::Expr::ExprBreak(expr) => ast::Expr::ExprBreak(f.fold_expr_break(expr)),
+        ast::Expr::ExprYield(expr) => ast::Expr::ExprYield(f.fold_expr_yield(expr)),
+        ast::Expr::ExprBlock(expr) => ast::Expr::ExprBlock(f.fold_expr_block(expr)),
+        ast::Expr::ExprRange(expr) => ast::Expr::ExprRange(f.fold_expr_range(expr)),
+        ast::Expr::ExprReturn(expr) => ast::Expr::ExprReturn(f.fold_expr_return(expr)),
+        ast::Expr::ExprAwait(expr) => ast::Expr::ExprAwait(f.fold_expr_await(expr)),
+        ast::Expr::ExprTry(expr) => ast::Expr::ExprTry(f.fold_expr_try(expr)),
+        ast::Expr::ExprSelect(expr) => ast::Expr::ExprSelect(f.fold_expr_select(expr)),
+        ast::Expr::ExprClosure(expr) => ast::Expr::ExprClosure(f.fold_expr_closure(expr)),
+        ast::Expr::LitUnit(lit) => ast::Expr::LitUnit(f.fold_lit_unit(lit)),
+        ast::Expr::LitBool(lit) => ast::Expr::LitBool(f.fold_lit_bool(lit)),
+        ast::Expr::LitChar(lit) => ast::Expr::LitChar(f.fold_lit_char(lit)),
+        ast::Expr::LitByte(lit) => ast::Expr::LitByte(f.fold_lit_byte(lit)),
+        ast::Expr::LitNumber(lit) => ast::Expr::LitNumber(f.fold_lit_number(lit)),
+        ast::Expr::LitStr(lit) => ast::Expr::LitStr(f.fold_lit_str(lit)),
+        ast::Expr::LitByteStr(lit) => ast::Expr::LitByteStr(f.fold_lit_byte_str(lit)),
+        ast::Expr::LitTemplate(lit) => ast::Expr::LitTemplate(f.fold_lit_template(lit)),
+        ast::Expr::LitVec(lit) => ast::Expr::LitVec(f.fold_lit_vec(lit)),
+        ast::Expr::LitObject(lit) => ast::Expr::LitObject(f.fold_lit_object(lit)),
+        ast::Expr::LitTuple(lit) => ast::Expr::LitTuple(f.fold_lit_tuple(lit)),
+    }
+}
+
+/// Walk a while loop.
+pub fn walk_expr_while<F>(f: &mut F, mut expr: ast::ExprWhile) -> ast::ExprWhile
+where
+    F: ?Sized + Fold,
+{
+    expr.label = fold_loop_label(f, expr.label);
+    expr.condition = f.fold_condition(expr.condition);
+    expr.body = fold_boxed_block(f, expr.body);
+    expr
+}
+
+/// Walk an unconditional loop.
+pub fn walk_expr_loop<F>(f: &mut F, mut expr: ast::ExprLoop) -> ast::ExprLoop
+where
+    F: ?Sized + Fold,
+{
+    expr.label = fold_loop_label(f, expr.label);
+    expr.body = fold_boxed_block(f, expr.body);
+    expr
+}
+
+/// Walk a for loop.
+pub fn walk_expr_for<F>(f: &mut F, mut expr: ast::ExprFor) -> ast::ExprFor
+where
+    F: ?Sized + Fold,
+{
+    expr.label = fold_loop_label(f, expr.label);
+    expr.var = f.fold_pat(expr.var);
+    expr.iter = fold_boxed(f, expr.iter);
+    expr.body = fold_boxed_block(f, expr.body);
+    expr
+}
+
+/// Walk a let expression.
+pub fn walk_expr_let<F>(f: &mut F, mut expr: ast::ExprLet) -> ast::ExprLet
+where
+    F: ?Sized + Fold,
+{
+    expr.pat = f.fold_pat(expr.pat);
+    expr.expr = fold_boxed(f, expr.expr);
+    expr
+}
+
+/// Walk an index set operation.
+pub fn walk_expr_index_set<F>(f: &mut F, mut expr: ast::ExprIndexSet) -> ast::ExprIndexSet
+where
+    F: ?Sized + Fold,
+{
+    expr.target = fold_boxed(f, expr.target);
+    expr.index = fold_boxed(f, expr.index);
+    expr.value = fold_boxed(f, expr.value);
+    expr
+}
+
+/// Walk an if expression.
+pub fn walk_expr_if<F>(f: &mut F, mut expr: ast::ExprIf) -> ast::ExprIf
+where
+    F: ?Sized + Fold,
+{
+    expr.condition = f.fold_condition(expr.condition);
+    expr.block = fold_boxed_block(f, expr.block);
+
+    expr.expr_else_ifs = expr
+        .expr_else_ifs
+        .into_iter()
+        .map(|expr_else_if| f.fold_expr_else_if(expr_else_if))
+        .collect();
+
+    expr.expr_else = expr.expr_else.map(|expr_else| f.fold_expr_else(expr_else));
+    expr
+}
+
+/// Walk an else if branch.
+pub fn walk_expr_else_if<F>(f: &mut F, mut expr: ast::ExprElseIf) -> ast::ExprElseIf
+where
+    F: ?Sized + Fold,
+{
+    expr.condition = f.fold_condition(expr.condition);
+    expr.block = fold_boxed_block(f, expr.block);
+    expr
+}
+
+/// Walk an else branch.
+pub fn walk_expr_else<F>(f: &mut F, mut expr: ast::ExprElse) -> ast::ExprElse
+where
+    F: ?Sized + Fold,
+{
+    expr.block = fold_boxed_block(f, expr.block);
+    expr
+}
+
+/// Walk the condition of an if or a while.
+pub fn walk_condition<F>(f: &mut F, condition: ast::Condition) -> ast::Condition
+where
+    F: ?Sized + Fold,
+{
+    match condition {
+        ast::Condition::Expr(expr) => ast::Condition::Expr(fold_boxed(f, expr)),
+        ast::Condition::ExprLet(expr_let) => {
+            ast::Condition::ExprLet(Box::new(f.fold_expr_let(*expr_let)))
+        }
+    }
+}
+
+/// Walk a match expression.
+pub fn walk_expr_match<F>(f: &mut F, mut expr: ast::ExprMatch) -> ast::ExprMatch
+where
+    F: ?Sized + Fold,
+{
+    expr.expr = fold_boxed(f, expr.expr);
+
+    expr.branches = expr
+        .branches
+        .into_iter()
+        .map(|(branch, comma)| (f.fold_expr_match_branch(branch), comma))
+        .collect();
+
+    expr
+}
+
+/// Walk a match branch.
+pub fn walk_expr_match_branch<F>(
+    f: &mut F,
+    mut branch: ast::ExprMatchBranch,
+) -> ast::ExprMatchBranch
+where
+    F: ?Sized + Fold,
+{
+    branch.pat = f.fold_pat(branch.pat);
+
+    branch.condition = branch
+        .condition
+        .map(|(if_, condition)| (if_, fold_boxed(f, condition)));
+
+    branch.body = fold_boxed(f, branch.body);
+    branch
+}
+
+/// Walk a function call.
+pub fn walk_expr_call<F>(f: &mut F, mut expr: ast::ExprCall) -> ast::ExprCall
+where
+    F: ?Sized + Fold,
+{
+    expr.expr = fold_boxed(f, expr.expr);
+
+    expr.args.items = expr
+        .args
+        .items
+        .into_iter()
+        .map(|(arg, comma)| (f.fold_call_arg(arg), comma))
+        .collect();
+
+    expr
+}
+
+/// Walk an argument to a function call.
+pub fn walk_call_arg<F>(f: &mut F, mut call_arg: ast::CallArg) -> ast::CallArg
+where
+    F: ?Sized + Fold,
+{
+    call_arg.name = call_arg
+        .name
+        .map(|(name, colon)| (f.fold_ident(name), colon));
+
+    call_arg.expr = f.fold_expr(call_arg.expr);
+    call_arg
+}
+
+/// Walk a field access.
+pub fn walk_expr_field_access<F>(f: &mut F, mut expr: ast::ExprFieldAccess) -> ast::ExprFieldAccess
+where
+    F: ?Sized + Fold,
+{
+    expr.expr = fold_boxed(f, expr.expr);
+
+    expr.expr_field = match expr.expr_field {
+        ast::ExprField::Ident(ident) => ast::ExprField::Ident(f.fold_ident(ident)),
+        ast::ExprField::LitNumber(lit) => ast::ExprField::LitNumber(f.fold_lit_number(lit)),
+    };
+
+    expr
+}
+
+/// Walk a grouped expression.
+pub fn walk_expr_group<F>(f: &mut F, mut expr: ast::ExprGroup) -> ast::ExprGroup
+where
+    F: ?Sized + Fold,
+{
+    expr.expr = fold_boxed(f, expr.expr);
+    expr
+}
+
+/// Walk a binary expression.
+pub fn walk_expr_binary<F>(f: &mut F, mut expr: ast::ExprBinary) -> ast::ExprBinary
+where
+    F: ?Sized + Fold,
+{
+    expr.lhs = fold_boxed(f, expr.lhs);
+    expr.rhs = fold_boxed(f, expr.rhs);
+    expr
+}
+
+/// Walk a unary expression.
+pub fn walk_expr_unary<F>(f: &mut F, mut expr: ast::ExprUnary) -> ast::ExprUnary
+where
+    F: ?Sized + Fold,
+{
+    expr.expr = fold_boxed(f, expr.expr);
+    expr
+}
+
+/// Walk an index get operation.
+pub fn walk_expr_index_get<F>(f: &mut F, mut expr: ast::ExprIndexGet) -> ast::ExprIndexGet
+where
+    F: ?Sized + Fold,
+{
+    expr.target = fold_boxed(f, expr.target);
+    expr.index = fold_boxed(f, expr.index);
+    expr
+}
+
+/// Walk a break expression.
+pub fn walk_expr_break<F>(f: &mut F, mut expr: ast::ExprBreak) -> ast::ExprBreak
+where
+    F: ?Sized + Fold,
+{
+    expr.expr = expr.expr.map(|value| match value {
+        ast::ExprBreakValue::Expr(expr) => ast::ExprBreakValue::Expr(fold_boxed(f, expr)),
+        ast::ExprBreakValue::Label(label) => ast::ExprBreakValue::Label(f.fold_label(label)),
+    });
+
+    expr
+}
+
+/// Walk a yield expression.
+pub fn walk_expr_yield<F>(f: &mut F, mut expr: ast::ExprYield) -> ast::ExprYield
+where
+    F: ?Sized + Fold,
+{
+    expr.expr = expr.expr.map(|expr| fold_boxed(f, expr));
+    expr
+}
+
+/// Walk a block.
+pub fn walk_expr_block<F>(f: &mut F, mut expr: ast::ExprBlock) -> ast::ExprBlock
+where
+    F: ?Sized + Fold,
+{
+    expr.exprs = expr
+        .exprs
+        .into_iter()
+        .map(|(expr, semi_colon)| (f.fold_expr(expr), semi_colon))
+        .collect();
+
+    expr.trailing_expr = expr.trailing_expr.map(|expr| fold_boxed(f, expr));
+    expr
+}
+
+/// Walk a range expression.
+pub fn walk_expr_range<F>(f: &mut F, mut expr: ast::ExprRange) -> ast::ExprRange
+where
+    F: ?Sized + Fold,
+{
+    expr.from = expr.from.map(|from| fold_boxed(f, from));
+    expr.to = expr.to.map(|to| fold_boxed(f, to));
+    expr
+}
+
+/// Walk a return expression.
+pub fn walk_expr_return<F>(f: &mut F, mut expr: ast::ExprReturn) -> ast::ExprReturn
+where
+    F: ?Sized + Fold,
+{
+    expr.expr = expr.expr.map(|expr| fold_boxed(f, expr));
+    expr
+}
+
+/// Walk an await expression.
+pub fn walk_expr_await<F>(f: &mut F, mut expr: ast::ExprAwait) -> ast::ExprAwait
+where
+    F: ?Sized + Fold,
+{
+    expr.expr = fold_boxed(f, expr.expr);
+    expr
+}
+
+/// Walk a try expression.
+pub fn walk_expr_try<F>(f: &mut F, mut expr: ast::ExprTry) -> ast::ExprTry
+where
+    F: ?Sized + Fold,
+{
+    expr.expr = fold_boxed(f, expr.expr);
+    expr
+}
+
+/// Walk a select expression.
+pub fn walk_expr_select<F>(f: &mut F, mut expr: ast::ExprSelect) -> ast::ExprSelect
+where
+    F: ?Sized + Fold,
+{
+    expr.branches = expr
+        .branches
+        .into_iter()
+        .map(|(branch, comma)| (f.fold_expr_select_branch(branch), comma))
+        .collect();
+
+    expr.default_branch = expr
+        .default_branch
+        .map(|(branch, comma)| (f.fold_expr_default_branch(branch), comma));
+
+    expr
+}
+
+/// Walk a select branch.
+pub fn walk_expr_select_branch<F>(
+    f: &mut F,
+    mut branch: ast::ExprSelectBranch,
+) -> ast::ExprSelectBranch
+where
+    F: ?Sized + Fold,
+{
+    branch.pat = f.fold_pat(branch.pat);
+    branch.expr = fold_boxed(f, branch.expr);
+    branch.body = fold_boxed(f, branch.body);
+    branch
+}
+
+/// Walk the default branch of a select.
+pub fn walk_expr_default_branch<F>(
+    f: &mut F,
+    mut branch: ast::ExprDefaultBranch,
+) -> ast::ExprDefaultBranch
+where
+    F: ?Sized + Fold,
+{
+    branch.body = fold_boxed(f, branch.body);
+    branch
+}
+
+/// Walk a closure.
+pub fn walk_expr_closure<F>(f: &mut F, mut expr: ast::ExprClosure) -> ast::ExprClosure
+where
+    F: ?Sized + Fold,
+{
+    expr.args = match expr.args {
+        ast::ExprClosureArgs::List { open, args, close } => ast::ExprClosureArgs::List {
+            open,
+            args: args
+                .into_iter()
+                .map(|(arg, comma)| (f.fold_fn_arg(arg), comma))
+                .collect(),
+            close,
+        },
+        args => args,
+    };
+
+    expr.body = fold_boxed(f, expr.body);
+    expr
+}
+
+/// Walk a vector literal.
+pub fn walk_lit_vec<F>(f: &mut F, mut lit: ast::LitVec) -> ast::LitVec
+where
+    F: ?Sized + Fold,
+{
+    lit.items = lit
+        .items
+        .into_iter()
+        .map(|expr| f.fold_expr(expr))
+        .collect();
+    lit
+}
+
+/// Walk an object literal.
+pub fn walk_lit_object<F>(f: &mut F, mut lit: ast::LitObject) -> ast::LitObject
+where
+    F: ?Sized + Fold,
+{
+    lit.ident = f.fold_lit_object_ident(lit.ident);
+
+    lit.assignments = lit
+        .assignments
+        .into_iter()
+        .map(|assign| f.fold_lit_object_field_assign(assign))
+        .collect();
+
+    lit
+}
+
+/// Walk a field assignment in an object literal.
+pub fn walk_lit_object_field_assign<F>(
+    f: &mut F,
+    mut assign: ast::LitObjectFieldAssign,
+) -> ast::LitObjectFieldAssign
+where
+    F: ?Sized + Fold,
+{
+    assign.key = f.fold_lit_object_key(assign.key);
+
+    assign.assign = assign
+        .assign
+        .map(|(colon, expr)| (colon, f.fold_expr(expr)));
+
+    assign
+}
+
+/// Walk the identifier of an object literal or pattern.
+pub fn walk_lit_object_ident<F>(f: &mut F, ident: ast::LitObjectIdent) -> ast::LitObjectIdent
+where
+    F: ?Sized + Fold,
+{
+    match ident {
+        ast::LitObjectIdent::Named(path) => ast::LitObjectIdent::Named(f.fold_path(path)),
+        ident => ident,
+    }
+}
+
+/// Walk the key of an object literal or pattern.
+pub fn walk_lit_object_key<F>(f: &mut F, key: ast::LitObjectKey) -> ast::LitObjectKey
+where
+    F: ?Sized + Fold,
+{
+    match key {
+        ast::LitObjectKey::LitStr(lit) => ast::LitObjectKey::LitStr(f.fold_lit_str(lit)),
+        ast::LitObjectKey::Ident(ident) => ast::LitObjectKey::Ident(f.fold_ident(ident)),
+    }
+}
+
+/// Walk a tuple literal.
+pub fn walk_lit_tuple<F>(f: &mut F, mut lit: ast::LitTuple) -> ast::LitTuple
+where
+    F: ?Sized + Fold,
+{
+    lit.items = lit
+        .items
+        .into_iter()
+        .map(|(expr, comma)| (f.fold_expr(expr), comma))
+        .collect();
+
+    lit
+}
+
+/// Walk a pattern.
+pub fn walk_pat<F>(f: &mut F, pat: ast::Pat) -> ast::Pat
+where
+    F: ?Sized + Fold,
+{
+    match pat {
+        ast::Pat::PatIgnore(underscore) => ast::Pat::PatIgnore(underscore),
+        ast::Pat::PatPath(pat) => ast::Pat::PatPath(f.fold_pat_path(pat)),
+        ast::Pat::PatUnit(lit) => ast::Pat::PatUnit(f.fold_lit_unit(lit)),
+        ast::Pat::PatByte(lit) => ast::Pat::PatByte(f.fold_lit_byte(lit)),
+        ast::Pat::PatChar(lit) => ast::Pat::PatChar(f.fold_lit_char(lit)),
+        ast::Pat::PatNumber(lit) => ast::Pat::PatNumber(f.fold_lit_number(lit)),
+        ast::Pat::PatString(lit) => ast::Pat::PatString(f.fold_lit_str(lit)),
+        ast::Pat::PatVec(pat) => ast::Pat::PatVec(f.fold_pat_vec(pat)),
+        ast::Pat::PatTuple(pat) => ast::Pat::PatTuple(f.fold_pat_tuple(pat)),
+        ast::Pat::PatObject(pat) => ast::Pat::PatObject(f.fold_pat_object(pat)),
+    }
+}
+
+/// Walk a path pattern.
+pub fn walk_pat_path<F>(f: &mut F, mut pat: ast::PatPath) -> ast::PatPath
+where
+    F: ?Sized + Fold,
+{
+    pat.path = f.fold_path(pat.path);
+    pat
+}
+
+/// Walk the items of a vector or tuple pattern.
+fn fold_pat_items<F>(
+    f: &mut F,
+    items: Vec<(Box<ast::Pat>, Option<ast::Comma>)>,
+) -> Vec<(Box<ast::Pat>, Option<ast::Comma>)>
+where
+    F: ?Sized + Fold,
+{
+    items
+        .into_iter()
+        .map(|(pat, comma)| (Box::new(f.fold_pat(*pat)), comma))
+        .collect()
+}
+
+/// Walk a vector pattern.
+pub fn walk_pat_vec<F>(f: &mut F, mut pat: ast::PatVec) -> ast::PatVec
+where
+    F: ?Sized + Fold,
+{
+    pat.items = fold_pat_items(f, pat.items);
+    pat
+}
+
+/// Walk a tuple pattern.
+pub fn walk_pat_tuple<F>(f: &mut F, mut pat: ast::PatTuple) -> ast::PatTuple
+where
+    F: ?Sized + Fold,
+{
+    pat.path = pat.path.map(|path| f.fold_path(path));
+    pat.items = fold_pat_items(f, pat.items);
+    pat
+}
+
+/// Walk an object pattern.
+pub fn walk_pat_object<F>(f: &mut F, mut pat: ast::PatObject) -> ast::PatObject
+where
+    F: ?Sized + Fold,
+{
+    pat.ident = f.fold_lit_object_ident(pat.ident);
+
+    pat.fields = pat
+        .fields
+        .into_iter()
+        .map(|(item, comma)| (f.fold_pat_object_item(item), comma))
+        .collect();
+
+    pat
+}
+
+/// Walk a field in an object pattern.
+pub fn walk_pat_object_item<F>(f: &mut F, mut item: ast::PatObjectItem) -> ast::PatObjectItem
+where
+    F: ?Sized + Fold,
+{
+    item.key = f.fold_lit_object_key(item.key);
+    item.binding = item.binding.map(|(colon, pat)| (colon, f.fold_pat(pat)));
+    item
+}
+
+/// Walk a path.
+pub fn walk_path<F>(f: &mut F, mut path: ast::Path) -> ast::Path
+where
+    F: ?Sized + Fold,
+{
+    path.first = f.fold_ident(path.first);
+
+    path.rest = path
+        .rest
+        .into_iter()
+        .map(|(scope, ident)| (scope, f.fold_ident(ident)))
+        .collect();
+
+    path
+}
@@ -1,7 +1,8 @@
 use crate::ast;
+use crate::ast::SyntaxEq;
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::{Parse, Peek};
+use crate::traits::{Parse, Peek, Shift};
 use runestick::Span;
 
 /// The unit literal `()`.
@@ -63,3 +64,15 @@ impl Peek for LitBool {
         }
     }
 }
+
+impl Shift for LitBool {
+    fn shift(&mut self, delta: isize) {
+        self.token.shift(delta);
+    }
+}
+
+impl SyntaxEq for LitBool {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.value == other.value && self.token.syntax_eq(&other.token, source, other_source)
+    }
+}
@@ -1,7 +1,8 @@
 use crate::ast;
+use crate::ast::SyntaxEq;
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::{Parse, Resolve};
+use crate::traits::{Parse, Resolve, Shift};
 use runestick::{Source, Span};
 
 /// A byte literal.
@@ -85,3 +86,15 @@ impl<'a> Resolve<'a> for LitByte {
         Ok(c)
     }
 }
+
+impl Shift for LitByte {
+    fn shift(&mut self, delta: isize) {
+        self.token.shift(delta);
+    }
+}
+
+impl SyntaxEq for LitByte {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.token.syntax_eq(&other.token, source, other_source)
+    }
+}
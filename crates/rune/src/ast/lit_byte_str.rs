@@ -1,7 +1,8 @@
 use crate::ast;
+use crate::ast::SyntaxEq;
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::{Parse, Resolve};
+use crate::traits::{Parse, Resolve, Shift};
 use runestick::{Source, Span};
 use std::borrow::Cow;
 
@@ -81,3 +82,15 @@ impl Parse for LitByteStr {
         }
     }
 }
+
+impl Shift for LitByteStr {
+    fn shift(&mut self, delta: isize) {
+        self.token.shift(delta);
+    }
+}
+
+impl SyntaxEq for LitByteStr {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.token.syntax_eq(&other.token, source, other_source) && self.escaped == other.escaped
+    }
+}
@@ -1,7 +1,8 @@
 use crate::ast;
+use crate::ast::SyntaxEq;
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::{Parse, Resolve};
+use crate::traits::{Parse, Resolve, Shift};
 use runestick::{Source, Span};
 
 /// A resolved number literal.
@@ -10,6 +11,8 @@ pub enum Number {
     Float(f64),
     /// An integer literal number.
     Integer(i64),
+    /// A byte literal number, like `255u8`.
+    Byte(u8),
 }
 
 /// A number literal.
@@ -43,6 +46,8 @@ impl LitNumber {
 /// parse_all::<ast::LitNumber>("42.42").unwrap();
 /// parse_all::<ast::LitNumber>("0.42").unwrap();
 /// parse_all::<ast::LitNumber>("0.42e10").unwrap();
+/// parse_all::<ast::LitNumber>("255u8").unwrap();
+/// parse_all::<ast::LitNumber>("0xffi64").unwrap();
 /// ```
 impl Parse for LitNumber {
     fn parse(parser: &mut Parser<'_>) -> Result<Self, ParseError> {
@@ -90,8 +95,21 @@ impl<'a> Resolve<'a> for LitNumber {
             string
         };
 
+        // NB: hex digits never include `u` or `i`, so a suffix can't be
+        // confused with the digits of the number.
+        let (string, suffix) = match (string.strip_suffix("u8"), string.strip_suffix("i64")) {
+            (Some(string), _) => (string, Some(Suffix::U8)),
+            (_, Some(string)) => (string, Some(Suffix::I64)),
+            _ => (string, None),
+        };
+
         if self.is_fractional {
+            if suffix.is_some() {
+                return Err(ParseError::BadNumberLiteral { span });
+            }
+
             let number = f64::from_str(string).map_err(err_span(span))?;
+            let number = if self.is_negative { -number } else { number };
             return Ok(Number::Float(number));
         }
 
@@ -104,6 +122,17 @@ impl<'a> Resolve<'a> for LitNumber {
 
         let number = num::BigUint::from_str_radix(&string[s..], radix).map_err(err_span(span))?;
 
+        if let Some(Suffix::U8) = suffix {
+            if self.is_negative {
+                return Err(ParseError::BadByteOutOfBounds { span });
+            }
+
+            return match number.to_u8() {
+                Some(n) => Ok(Number::Byte(n)),
+                None => Err(ParseError::BadByteOutOfBounds { span }),
+            };
+        }
+
         let number = if self.is_negative {
             num::BigInt::from(number).neg().to_i64()
         } else {
@@ -122,3 +151,26 @@ impl<'a> Resolve<'a> for LitNumber {
         }
     }
 }
+
+/// The type suffix of a number literal.
+enum Suffix {
+    /// The `u8` suffix.
+    U8,
+    /// The `i64` suffix.
+    I64,
+}
+
+impl Shift for LitNumber {
+    fn shift(&mut self, delta: isize) {
+        self.token.shift(delta);
+    }
+}
+
+impl SyntaxEq for LitNumber {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.is_negative == other.is_negative
+            && self.is_fractional == other.is_fractional
+            && self.number == other.number
+            && self.token.syntax_eq(&other.token, source, other_source)
+    }
+}
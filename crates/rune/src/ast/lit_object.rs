@@ -1,7 +1,8 @@
 use crate::ast;
+use crate::ast::SyntaxEq;
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::{Parse, Resolve};
+use crate::traits::{Parse, Resolve, Shift};
 use runestick::{Source, Span};
 use std::borrow::Cow;
 
@@ -158,9 +159,6 @@ pub struct LitObject {
     pub assignments: Vec<LitObjectFieldAssign>,
     /// The close bracket.
     pub close: ast::CloseBrace,
-    /// Indicates if the object is completely literal and cannot have side
-    /// effects.
-    is_const: bool,
 }
 
 impl LitObject {
@@ -171,7 +169,7 @@ impl LitObject {
 
     /// Test if the entire expression is constant.
     pub fn is_const(&self) -> bool {
-        self.is_const
+        self.assignments.iter().all(LitObjectFieldAssign::is_const)
     }
 
     /// Parse a literal object with the given path.
@@ -183,15 +181,9 @@ impl LitObject {
 
         let mut assignments = Vec::new();
 
-        let mut is_const = true;
-
         while !parser.peek::<ast::CloseBrace>()? {
             let assign = parser.parse::<LitObjectFieldAssign>()?;
 
-            if !assign.is_const() {
-                is_const = false;
-            }
-
             assignments.push(assign);
 
             if parser.peek::<ast::Comma>()? {
@@ -208,7 +200,6 @@ impl LitObject {
             open,
             assignments,
             close,
-            is_const,
         })
     }
 }
@@ -230,3 +221,75 @@ impl Parse for LitObject {
         Self::parse_with_ident(parser, ident)
     }
 }
+
+impl Shift for LitObjectIdent {
+    fn shift(&mut self, delta: isize) {
+        match self {
+            Self::Anonymous(hash) => hash.shift(delta),
+            Self::Named(path) => path.shift(delta),
+        }
+    }
+}
+
+impl Shift for LitObjectFieldAssign {
+    fn shift(&mut self, delta: isize) {
+        self.key.shift(delta);
+        self.assign.shift(delta);
+    }
+}
+
+impl Shift for LitObjectKey {
+    fn shift(&mut self, delta: isize) {
+        match self {
+            Self::LitStr(lit_str) => lit_str.shift(delta),
+            Self::Ident(ident) => ident.shift(delta),
+        }
+    }
+}
+
+impl Shift for LitObject {
+    fn shift(&mut self, delta: isize) {
+        self.ident.shift(delta);
+        self.open.shift(delta);
+        self.assignments.shift(delta);
+        self.close.shift(delta);
+    }
+}
+
+impl SyntaxEq for LitObjectIdent {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        match (self, other) {
+            (Self::Anonymous(a), Self::Anonymous(b)) => a.syntax_eq(b, source, other_source),
+            (Self::Named(a), Self::Named(b)) => a.syntax_eq(b, source, other_source),
+            _ => false,
+        }
+    }
+}
+
+impl SyntaxEq for LitObjectFieldAssign {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.key.syntax_eq(&other.key, source, other_source)
+            && self.assign.syntax_eq(&other.assign, source, other_source)
+    }
+}
+
+impl SyntaxEq for LitObjectKey {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        match (self, other) {
+            (Self::LitStr(a), Self::LitStr(b)) => a.syntax_eq(b, source, other_source),
+            (Self::Ident(a), Self::Ident(b)) => a.syntax_eq(b, source, other_source),
+            _ => false,
+        }
+    }
+}
+
+impl SyntaxEq for LitObject {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.ident.syntax_eq(&other.ident, source, other_source)
+            && self.open.syntax_eq(&other.open, source, other_source)
+            && self
+                .assignments
+                .syntax_eq(&other.assignments, source, other_source)
+            && self.close.syntax_eq(&other.close, source, other_source)
+    }
+}
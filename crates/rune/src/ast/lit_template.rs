@@ -1,7 +1,8 @@
 use crate::ast;
+use crate::ast::SyntaxEq;
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::{Parse, Resolve};
+use crate::traits::{Parse, Resolve, Shift};
 use runestick::{Source, Span};
 
 /// A string literal.
@@ -27,6 +28,9 @@ pub enum TemplateComponent {
     String(String),
     /// An expression inside of the template. Like `{1 + 2}`.
     Expr(Box<ast::Expr>),
+    /// An expression formatted using its debug representation. Like
+    /// `{value:?}`, or `{value:#?}` to pretty print it.
+    DebugExpr(Box<ast::Expr>, bool),
 }
 
 /// A resolved and parsed string template.
@@ -78,8 +82,17 @@ impl<'a> Resolve<'a> for LitTemplate {
                     let source = &source.as_str()[..span.end];
 
                     let mut parser = Parser::new_with_start(source, span.start);
-                    let expr = ast::Expr::parse(&mut parser)?;
-                    components.push(TemplateComponent::Expr(Box::new(expr)));
+                    let expr = Box::new(ast::Expr::parse(&mut parser)?);
+
+                    if parser.peek::<ast::Colon>()? {
+                        parser.parse::<ast::Colon>()?;
+                        let pretty = parser.parse::<Option<ast::Hash>>()?.is_some();
+                        parser.parse::<ast::Try>()?;
+                        components.push(TemplateComponent::DebugExpr(expr, pretty));
+                    } else {
+                        components.push(TemplateComponent::Expr(expr));
+                    }
+
                     has_expansions = true;
                 }
                 c => {
@@ -125,3 +138,15 @@ impl Parse for LitTemplate {
         }
     }
 }
+
+impl Shift for LitTemplate {
+    fn shift(&mut self, delta: isize) {
+        self.token.shift(delta);
+    }
+}
+
+impl SyntaxEq for LitTemplate {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.token.syntax_eq(&other.token, source, other_source) && self.escaped == other.escaped
+    }
+}
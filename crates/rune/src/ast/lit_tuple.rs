@@ -1,7 +1,8 @@
 use crate::ast;
+use crate::ast::SyntaxEq;
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::Parse;
+use crate::traits::{Parse, Shift};
 use runestick::Span;
 
 /// An expression to construct a literal tuple.
@@ -13,8 +14,6 @@ pub struct LitTuple {
     pub items: Vec<(ast::Expr, Option<ast::Comma>)>,
     /// The close bracket.
     pub close: ast::CloseParen,
-    /// If the entire tuple is constant.
-    is_const: bool,
 }
 
 impl LitTuple {
@@ -25,7 +24,7 @@ impl LitTuple {
 
     /// If the tuple is constant.
     pub fn is_const(&self) -> bool {
-        self.is_const
+        self.items.iter().all(|(expr, _)| expr.is_const())
     }
 
     /// Start parsing literal tuple from the middle of an expression.
@@ -35,13 +34,8 @@ impl LitTuple {
         mut expr: ast::Expr,
     ) -> Result<Self, ParseError> {
         let mut items = Vec::new();
-        let mut is_const = true;
 
         loop {
-            if !expr.is_const() {
-                is_const = false;
-            }
-
             let comma = if parser.peek::<ast::Comma>()? {
                 Some(parser.parse::<ast::Comma>()?)
             } else {
@@ -60,12 +54,7 @@ impl LitTuple {
 
         let close = parser.parse()?;
 
-        Ok(Self {
-            open,
-            items,
-            close,
-            is_const,
-        })
+        Ok(Self { open, items, close })
     }
 }
 
@@ -85,15 +74,10 @@ impl Parse for LitTuple {
         let open = parser.parse()?;
 
         let mut items = Vec::new();
-        let mut is_const = true;
 
         while !parser.peek::<ast::CloseParen>()? {
             let expr = parser.parse::<ast::Expr>()?;
 
-            if !expr.is_const() {
-                is_const = false;
-            }
-
             let comma = if parser.peek::<ast::Comma>()? {
                 Some(parser.parse::<ast::Comma>()?)
             } else {
@@ -110,11 +94,22 @@ impl Parse for LitTuple {
 
         let close = parser.parse()?;
 
-        Ok(Self {
-            open,
-            items,
-            close,
-            is_const,
-        })
+        Ok(Self { open, items, close })
+    }
+}
+
+impl Shift for LitTuple {
+    fn shift(&mut self, delta: isize) {
+        self.open.shift(delta);
+        self.items.shift(delta);
+        self.close.shift(delta);
+    }
+}
+
+impl SyntaxEq for LitTuple {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.open.syntax_eq(&other.open, source, other_source)
+            && self.items.syntax_eq(&other.items, source, other_source)
+            && self.close.syntax_eq(&other.close, source, other_source)
     }
 }
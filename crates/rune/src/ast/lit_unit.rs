@@ -1,7 +1,8 @@
 use crate::ast;
+use crate::ast::SyntaxEq;
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::{Parse, Peek};
+use crate::traits::{Parse, Peek, Shift};
 use runestick::Span;
 
 /// The unit literal `()`.
@@ -54,3 +55,17 @@ impl Peek for LitUnit {
         }
     }
 }
+
+impl Shift for LitUnit {
+    fn shift(&mut self, delta: isize) {
+        self.open.shift(delta);
+        self.close.shift(delta);
+    }
+}
+
+impl SyntaxEq for LitUnit {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.open.syntax_eq(&other.open, source, other_source)
+            && self.close.syntax_eq(&other.close, source, other_source)
+    }
+}
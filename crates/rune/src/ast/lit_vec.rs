@@ -1,7 +1,7 @@
-use crate::ast::{CloseBracket, Comma, Expr, OpenBracket};
+use crate::ast::{CloseBracket, Comma, Expr, OpenBracket, SyntaxEq};
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::Parse;
+use crate::traits::{Parse, Shift};
 use runestick::Span;
 
 /// A number literal.
@@ -13,8 +13,6 @@ pub struct LitVec {
     pub items: Vec<Expr>,
     /// The close bracket.
     pub close: CloseBracket,
-    /// If the entire array is constant.
-    is_const: bool,
 }
 
 impl LitVec {
@@ -25,7 +23,7 @@ impl LitVec {
 
     /// Test if the entire expression is constant.
     pub fn is_const(&self) -> bool {
-        self.is_const
+        self.items.iter().all(Expr::is_const)
     }
 }
 
@@ -45,15 +43,10 @@ impl Parse for LitVec {
         let open = parser.parse()?;
 
         let mut items = Vec::new();
-        let mut is_const = true;
 
         while !parser.peek::<CloseBracket>()? {
             let expr = parser.parse::<Expr>()?;
 
-            if !expr.is_const() {
-                is_const = false;
-            }
-
             items.push(expr);
 
             if parser.peek::<Comma>()? {
@@ -64,11 +57,22 @@ impl Parse for LitVec {
         }
 
         let close = parser.parse()?;
-        Ok(Self {
-            open,
-            items,
-            close,
-            is_const,
-        })
+        Ok(Self { open, items, close })
+    }
+}
+
+impl Shift for LitVec {
+    fn shift(&mut self, delta: isize) {
+        self.open.shift(delta);
+        self.items.shift(delta);
+        self.close.shift(delta);
+    }
+}
+
+impl SyntaxEq for LitVec {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.open.syntax_eq(&other.open, source, other_source)
+            && self.items.syntax_eq(&other.items, source, other_source)
+            && self.close.syntax_eq(&other.close, source, other_source)
     }
 }
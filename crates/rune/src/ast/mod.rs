@@ -2,9 +2,10 @@
 
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::{Parse, Peek, Resolve};
+use crate::traits::{Parse, Peek, Resolve, Shift};
 use runestick::{Source, Span};
 
+mod call_arg;
 mod condition;
 mod decl;
 mod decl_enum;
@@ -33,6 +34,7 @@ mod expr_is_not;
 mod expr_let;
 mod expr_loop;
 mod expr_match;
+mod expr_range;
 mod expr_return;
 mod expr_select;
 mod expr_try;
@@ -40,6 +42,7 @@ mod expr_unary;
 mod expr_while;
 mod expr_yield;
 mod fn_arg;
+pub mod fold;
 mod lit_bool;
 mod lit_byte;
 mod lit_byte_str;
@@ -58,9 +61,12 @@ mod pat_path;
 mod pat_tuple;
 mod pat_vec;
 mod path;
+mod syntax_eq;
 mod token;
 pub(super) mod utils;
+pub mod visit;
 
+pub use self::call_arg::CallArg;
 pub use self::condition::Condition;
 pub use self::decl::Decl;
 pub use self::decl_enum::DeclEnum;
@@ -75,7 +81,7 @@ pub use self::expr_binary::{BinOp, ExprBinary};
 pub use self::expr_block::ExprBlock;
 pub use self::expr_break::{ExprBreak, ExprBreakValue};
 pub use self::expr_call::ExprCall;
-pub use self::expr_closure::ExprClosure;
+pub use self::expr_closure::{ExprClosure, ExprClosureArgs};
 pub use self::expr_else::ExprElse;
 pub use self::expr_else_if::ExprElseIf;
 pub use self::expr_field_access::{ExprField, ExprFieldAccess};
@@ -89,8 +95,9 @@ pub use self::expr_is_not::ExprIsNot;
 pub use self::expr_let::ExprLet;
 pub use self::expr_loop::ExprLoop;
 pub use self::expr_match::{ExprMatch, ExprMatchBranch};
+pub use self::expr_range::ExprRange;
 pub use self::expr_return::ExprReturn;
-pub use self::expr_select::ExprSelect;
+pub use self::expr_select::{ExprDefaultBranch, ExprSelect, ExprSelectBranch};
 pub use self::expr_try::ExprTry;
 pub use self::expr_unary::{ExprUnary, UnaryOp};
 pub use self::expr_while::ExprWhile;
@@ -114,6 +121,7 @@ pub use self::pat_path::PatPath;
 pub use self::pat_tuple::PatTuple;
 pub use self::pat_vec::PatVec;
 pub use self::path::Path;
+pub use self::syntax_eq::{Syntax, SyntaxEq};
 pub use self::token::{Delimiter, Kind, NumberKind, Token};
 
 macro_rules! decl_tokens {
@@ -158,6 +166,18 @@ macro_rules! decl_tokens {
                     }
                 }
             }
+
+            impl Shift for $parser {
+                fn shift(&mut self, delta: isize) {
+                    self.token.shift(delta);
+                }
+            }
+
+            impl SyntaxEq for $parser {
+                fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+                    self.token.syntax_eq(&other.token, source, other_source)
+                }
+            }
         )*
     }
 }
@@ -202,6 +222,7 @@ decl_tokens! {
     (DotDot, Kind::DotDot),
     (Await, Kind::Await),
     (Async, Kind::Async),
+    (TryKeyword, Kind::TryKeyword),
     (Select, Kind::Select),
     (Default, Kind::Default),
     (Try, Kind::Try),
@@ -274,4 +295,36 @@ mod tests {
 
         parse_all::<ast::Expr>("42 is int::int").unwrap();
     }
+
+    /// Destructure a binary expression, panicking if it isn't one.
+    fn binary(expr: &ast::Expr) -> (&ast::Expr, ast::BinOp, &ast::Expr) {
+        match expr {
+            ast::Expr::ExprBinary(b) => (&*b.lhs, b.op, &*b.rhs),
+            expr => panic!("expected binary expression, but got {:?}", expr),
+        }
+    }
+
+    #[test]
+    fn test_is_precedence() {
+        let expr = parse_all::<ast::Expr>("a is String && b is Int").unwrap();
+        let (lhs, op, rhs) = binary(&expr);
+        assert_eq!(op, ast::BinOp::And);
+        assert_eq!(binary(lhs).1, ast::BinOp::Is);
+        assert_eq!(binary(rhs).1, ast::BinOp::Is);
+
+        let expr = parse_all::<ast::Expr>("a is not String || b").unwrap();
+        let (lhs, op, _) = binary(&expr);
+        assert_eq!(op, ast::BinOp::Or);
+        assert_eq!(binary(lhs).1, ast::BinOp::IsNot);
+
+        let expr = parse_all::<ast::Expr>("a == b is Int").unwrap();
+        let (_, op, rhs) = binary(&expr);
+        assert_eq!(op, ast::BinOp::Eq);
+        assert_eq!(binary(rhs).1, ast::BinOp::Is);
+
+        let expr = parse_all::<ast::Expr>("a + 1 is Int").unwrap();
+        let (lhs, op, _) = binary(&expr);
+        assert_eq!(op, ast::BinOp::Is);
+        assert_eq!(binary(lhs).1, ast::BinOp::Add);
+    }
 }
@@ -1,7 +1,8 @@
 use crate::ast;
+use crate::ast::SyntaxEq;
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::{Parse, Peek};
+use crate::traits::{Parse, Peek, Shift};
 use runestick::Span;
 
 /// Something parenthesized and comma separated `(<T,>*)`.
@@ -58,3 +59,27 @@ where
         Ok(Self { open, items, close })
     }
 }
+
+impl<T, S> Shift for Parenthesized<T, S>
+where
+    T: Shift,
+    S: Shift,
+{
+    fn shift(&mut self, delta: isize) {
+        self.open.shift(delta);
+        self.items.shift(delta);
+        self.close.shift(delta);
+    }
+}
+
+impl<T, S> SyntaxEq for Parenthesized<T, S>
+where
+    T: SyntaxEq,
+    S: SyntaxEq,
+{
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.open.syntax_eq(&other.open, source, other_source)
+            && self.items.syntax_eq(&other.items, source, other_source)
+            && self.close.syntax_eq(&other.close, source, other_source)
+    }
+}
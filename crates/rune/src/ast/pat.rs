@@ -1,8 +1,8 @@
 use crate::ast;
-use crate::ast::{Delimiter, Kind, Token};
+use crate::ast::{Delimiter, Kind, SyntaxEq, Token};
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::{Parse, Peek};
+use crate::traits::{Parse, Peek, Shift};
 use runestick::Span;
 
 /// A pattern match.
@@ -158,3 +158,38 @@ impl Peek for Pat {
         }
     }
 }
+
+impl Shift for Pat {
+    fn shift(&mut self, delta: isize) {
+        match self {
+            Self::PatIgnore(underscore) => underscore.shift(delta),
+            Self::PatPath(pat_path) => pat_path.shift(delta),
+            Self::PatUnit(lit_unit) => lit_unit.shift(delta),
+            Self::PatByte(lit_byte) => lit_byte.shift(delta),
+            Self::PatChar(lit_char) => lit_char.shift(delta),
+            Self::PatNumber(lit_number) => lit_number.shift(delta),
+            Self::PatString(lit_str) => lit_str.shift(delta),
+            Self::PatVec(pat_vec) => pat_vec.shift(delta),
+            Self::PatTuple(pat_tuple) => pat_tuple.shift(delta),
+            Self::PatObject(pat_object) => pat_object.shift(delta),
+        }
+    }
+}
+
+impl SyntaxEq for Pat {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        match (self, other) {
+            (Self::PatIgnore(a), Self::PatIgnore(b)) => a.syntax_eq(b, source, other_source),
+            (Self::PatPath(a), Self::PatPath(b)) => a.syntax_eq(b, source, other_source),
+            (Self::PatUnit(a), Self::PatUnit(b)) => a.syntax_eq(b, source, other_source),
+            (Self::PatByte(a), Self::PatByte(b)) => a.syntax_eq(b, source, other_source),
+            (Self::PatChar(a), Self::PatChar(b)) => a.syntax_eq(b, source, other_source),
+            (Self::PatNumber(a), Self::PatNumber(b)) => a.syntax_eq(b, source, other_source),
+            (Self::PatString(a), Self::PatString(b)) => a.syntax_eq(b, source, other_source),
+            (Self::PatVec(a), Self::PatVec(b)) => a.syntax_eq(b, source, other_source),
+            (Self::PatTuple(a), Self::PatTuple(b)) => a.syntax_eq(b, source, other_source),
+            (Self::PatObject(a), Self::PatObject(b)) => a.syntax_eq(b, source, other_source),
+            _ => false,
+        }
+    }
+}
@@ -1,7 +1,8 @@
 use crate::ast;
+use crate::ast::SyntaxEq;
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::Parse;
+use crate::traits::{Parse, Shift};
 use runestick::Span;
 /// An object pattern.
 #[derive(Debug, Clone)]
@@ -109,3 +110,39 @@ impl Parse for PatObjectItem {
         Ok(Self { key, binding })
     }
 }
+
+impl Shift for PatObject {
+    fn shift(&mut self, delta: isize) {
+        self.ident.shift(delta);
+        self.open.shift(delta);
+        self.fields.shift(delta);
+        self.open_pattern.shift(delta);
+        self.close.shift(delta);
+    }
+}
+
+impl Shift for PatObjectItem {
+    fn shift(&mut self, delta: isize) {
+        self.key.shift(delta);
+        self.binding.shift(delta);
+    }
+}
+
+impl SyntaxEq for PatObject {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.ident.syntax_eq(&other.ident, source, other_source)
+            && self.open.syntax_eq(&other.open, source, other_source)
+            && self.fields.syntax_eq(&other.fields, source, other_source)
+            && self
+                .open_pattern
+                .syntax_eq(&other.open_pattern, source, other_source)
+            && self.close.syntax_eq(&other.close, source, other_source)
+    }
+}
+
+impl SyntaxEq for PatObjectItem {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.key.syntax_eq(&other.key, source, other_source)
+            && self.binding.syntax_eq(&other.binding, source, other_source)
+    }
+}
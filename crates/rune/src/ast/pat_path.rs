@@ -1,4 +1,6 @@
 use crate::ast;
+use crate::ast::SyntaxEq;
+use crate::traits::Shift;
 use runestick::Span;
 
 /// A tuple pattern.
@@ -14,3 +16,15 @@ impl PatPath {
         self.path.span()
     }
 }
+
+impl Shift for PatPath {
+    fn shift(&mut self, delta: isize) {
+        self.path.shift(delta);
+    }
+}
+
+impl SyntaxEq for PatPath {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.path.syntax_eq(&other.path, source, other_source)
+    }
+}
@@ -1,7 +1,8 @@
 use crate::ast;
+use crate::ast::SyntaxEq;
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::Parse;
+use crate::traits::{Parse, Shift};
 use runestick::Span;
 
 /// A tuple pattern.
@@ -81,3 +82,25 @@ impl Parse for PatTuple {
         Self::parse_with_path(parser, path)
     }
 }
+
+impl Shift for PatTuple {
+    fn shift(&mut self, delta: isize) {
+        self.path.shift(delta);
+        self.open.shift(delta);
+        self.items.shift(delta);
+        self.open_pattern.shift(delta);
+        self.close.shift(delta);
+    }
+}
+
+impl SyntaxEq for PatTuple {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.path.syntax_eq(&other.path, source, other_source)
+            && self.open.syntax_eq(&other.open, source, other_source)
+            && self.items.syntax_eq(&other.items, source, other_source)
+            && self
+                .open_pattern
+                .syntax_eq(&other.open_pattern, source, other_source)
+            && self.close.syntax_eq(&other.close, source, other_source)
+    }
+}
@@ -1,7 +1,7 @@
-use crate::ast::{CloseBracket, Comma, DotDot, OpenBracket, Pat};
+use crate::ast::{CloseBracket, Comma, DotDot, OpenBracket, Pat, SyntaxEq};
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::Parse;
+use crate::traits::{Parse, Shift};
 use runestick::Span;
 
 /// An array pattern.
@@ -60,3 +60,23 @@ impl Parse for PatVec {
         })
     }
 }
+
+impl Shift for PatVec {
+    fn shift(&mut self, delta: isize) {
+        self.open.shift(delta);
+        self.items.shift(delta);
+        self.open_pattern.shift(delta);
+        self.close.shift(delta);
+    }
+}
+
+impl SyntaxEq for PatVec {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.open.syntax_eq(&other.open, source, other_source)
+            && self.items.syntax_eq(&other.items, source, other_source)
+            && self
+                .open_pattern
+                .syntax_eq(&other.open_pattern, source, other_source)
+            && self.close.syntax_eq(&other.close, source, other_source)
+    }
+}
@@ -1,8 +1,8 @@
 use crate::ast;
-use crate::ast::{Kind, Token};
+use crate::ast::{Kind, SyntaxEq, Token};
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::traits::{Parse, Peek, Resolve};
+use crate::traits::{Parse, Peek, Resolve, Shift};
 use runestick::{Source, Span};
 
 /// A path, where each element is separated by a `::`.
@@ -101,3 +101,17 @@ impl<'a> Resolve<'a> for Path {
         Ok(output)
     }
 }
+
+impl Shift for Path {
+    fn shift(&mut self, delta: isize) {
+        self.first.shift(delta);
+        self.rest.shift(delta);
+    }
+}
+
+impl SyntaxEq for Path {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.first.syntax_eq(&other.first, source, other_source)
+            && self.rest.syntax_eq(&other.rest, source, other_source)
+    }
+}
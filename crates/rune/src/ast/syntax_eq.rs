@@ -0,0 +1,139 @@
+use crate::ast::Token;
+use std::fmt;
+
+/// Structural equality between syntax tree nodes which ignores spans.
+///
+/// Since the text of identifiers and literals only lives in the source, each
+/// node is compared alongside the source it was parsed from. Whitespace and
+/// comments don't affect the comparison.
+///
+/// See [Syntax] for a wrapper implementing [PartialEq] in terms of this.
+pub trait SyntaxEq {
+    /// Test if this node, parsed from `source`, is syntactically equal to
+    /// `other`, parsed from `other_source`.
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool;
+}
+
+/// A syntax tree node together with the source it was parsed from.
+///
+/// Two wrapped nodes compare equal if they are syntactically equal, regardless
+/// of where they are located in their respective sources.
+///
+/// # Examples
+///
+/// ```rust
+/// use rune::{ast, parse_all};
+/// use rune::ast::Syntax;
+///
+/// let a = "foo(1, bar)";
+/// let b = "foo(\n    1,\n    bar\n)";
+///
+/// let a_expr = parse_all::<ast::Expr>(a).unwrap();
+/// let b_expr = parse_all::<ast::Expr>(b).unwrap();
+///
+/// assert_eq!(Syntax::new(&a_expr, a), Syntax::new(&b_expr, b));
+///
+/// let c = "foo(1, baz)";
+/// let c_expr = parse_all::<ast::Expr>(c).unwrap();
+///
+/// assert_ne!(Syntax::new(&a_expr, a), Syntax::new(&c_expr, c));
+/// ```
+#[derive(Clone, Copy)]
+pub struct Syntax<'a, T> {
+    node: &'a T,
+    source: &'a str,
+}
+
+impl<'a, T> Syntax<'a, T> {
+    /// Wrap the given node, which was parsed from `source`.
+    pub fn new(node: &'a T, source: &'a str) -> Self {
+        Self { node, source }
+    }
+}
+
+impl<T> PartialEq for Syntax<'_, T>
+where
+    T: SyntaxEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.node.syntax_eq(other.node, self.source, other.source)
+    }
+}
+
+impl<T> Eq for Syntax<'_, T> where T: SyntaxEq {}
+
+impl<T> fmt::Debug for Syntax<'_, T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.node.fmt(fmt)
+    }
+}
+
+impl SyntaxEq for Token {
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.kind == other.kind
+            && source.get(self.span.start..self.span.end)
+                == other_source.get(other.span.start..other.span.end)
+    }
+}
+
+impl<T> SyntaxEq for Option<T>
+where
+    T: SyntaxEq,
+{
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        match (self, other) {
+            (Some(a), Some(b)) => a.syntax_eq(b, source, other_source),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<T> SyntaxEq for Box<T>
+where
+    T: SyntaxEq,
+{
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        (**self).syntax_eq(&**other, source, other_source)
+    }
+}
+
+impl<T> SyntaxEq for Vec<T>
+where
+    T: SyntaxEq,
+{
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other)
+                .all(|(a, b)| a.syntax_eq(b, source, other_source))
+    }
+}
+
+impl<A, B> SyntaxEq for (A, B)
+where
+    A: SyntaxEq,
+    B: SyntaxEq,
+{
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.0.syntax_eq(&other.0, source, other_source)
+            && self.1.syntax_eq(&other.1, source, other_source)
+    }
+}
+
+impl<A, B, C> SyntaxEq for (A, B, C)
+where
+    A: SyntaxEq,
+    B: SyntaxEq,
+    C: SyntaxEq,
+{
+    fn syntax_eq(&self, other: &Self, source: &str, other_source: &str) -> bool {
+        self.0.syntax_eq(&other.0, source, other_source)
+            && self.1.syntax_eq(&other.1, source, other_source)
+            && self.2.syntax_eq(&other.2, source, other_source)
+    }
+}
@@ -112,6 +112,8 @@ pub enum Kind {
     Await,
     /// The `async` keyword.
     Async,
+    /// The `try` keyword.
+    TryKeyword,
     /// The `select` keyword.
     Select,
     /// The `default` keyword.
@@ -216,6 +218,24 @@ pub enum Kind {
     Pipe,
     /// A `%` operator.
     Rem,
+    /// A remainder assign operator `%=`.
+    RemAssign,
+    /// A bitwise and assign operator `&=`.
+    AmpersandAssign,
+    /// A bitwise or assign operator `|=`.
+    PipeAssign,
+    /// A caret `^`.
+    Caret,
+    /// A bitwise xor assign operator `^=`.
+    CaretAssign,
+    /// A shift left operator `<<`.
+    Shl,
+    /// A shift left assign operator `<<=`.
+    ShlAssign,
+    /// A shift right operator `>>`.
+    Shr,
+    /// A shift right assign operator `>>=`.
+    ShrAssign,
 }
 
 impl fmt::Display for Kind {
@@ -243,6 +263,7 @@ impl fmt::Display for Kind {
             Self::Return => write!(fmt, "return")?,
             Self::Await => write!(fmt, "await")?,
             Self::Async => write!(fmt, "async")?,
+            Self::TryKeyword => write!(fmt, "try")?,
             Self::Select => write!(fmt, "select")?,
             Self::Default => write!(fmt, "default")?,
             Self::Impl => write!(fmt, "impl")?,
@@ -287,6 +308,15 @@ impl fmt::Display for Kind {
             Self::Or => write!(fmt, "||")?,
             Self::Pipe => write!(fmt, "|")?,
             Self::Rem => write!(fmt, "%")?,
+            Self::RemAssign => write!(fmt, "%=")?,
+            Self::AmpersandAssign => write!(fmt, "&=")?,
+            Self::PipeAssign => write!(fmt, "|=")?,
+            Self::Caret => write!(fmt, "^")?,
+            Self::CaretAssign => write!(fmt, "^=")?,
+            Self::Shl => write!(fmt, "<<")?,
+            Self::ShlAssign => write!(fmt, "<<=")?,
+            Self::Shr => write!(fmt, ">>")?,
+            Self::ShrAssign => write!(fmt, ">>=")?,
         }
 
         Ok(())
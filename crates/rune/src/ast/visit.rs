@@ -0,0 +1,972 @@
+//! Visiting of a syntax tree by reference.
+//!
+//! Every method of [Visit] defaults to walking into the children of the node
+//! through the corresponding `walk_*` function in this module, so implementors
+//! only need to override the nodes they are interested in. An overriding
+//! method can call the `walk_*` function itself to keep visiting the children
+//! of the node.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rune::ast;
+//! use rune::ast::visit::{self, Visit};
+//!
+//! #[derive(Default)]
+//! struct Returns(usize);
+//!
+//! impl Visit for Returns {
+//!     fn visit_expr_return(&mut self, expr: &ast::ExprReturn) {
+//!         self.0 += 1;
+//!         visit::walk_expr_return(self, expr);
+//!     }
+//! }
+//!
+//! let file = rune::parse_all::<ast::DeclFile>(r#"
+//! fn main(n) {
+//!     if n > 1 {
+//!         return 1;
+//!     }
+//!
+//!     return 2;
+//! }
+//! "#).unwrap();
+//!
+//! let mut returns = Returns::default();
+//! returns.visit_decl_file(&file);
+//! assert_eq!(returns.0, 2);
+//! ```
+
+use crate::ast;
+
+/// A visitor over a syntax tree by reference.
+///
+/// See the [module level documentation][self] for more.
+pub trait Visit {
+    /// Visit a file.
+    fn visit_decl_file(&mut self, decl_file: &ast::DeclFile) {
+        walk_decl_file(self, decl_file);
+    }
+
+    /// Visit a declaration.
+    fn visit_decl(&mut self, decl: &ast::Decl) {
+        walk_decl(self, decl);
+    }
+
+    /// Visit a use declaration.
+    fn visit_decl_use(&mut self, decl_use: &ast::DeclUse) {
+        walk_decl_use(self, decl_use);
+    }
+
+    /// Visit a function declaration.
+    fn visit_decl_fn(&mut self, decl_fn: &ast::DeclFn) {
+        walk_decl_fn(self, decl_fn);
+    }
+
+    /// Visit an enum declaration.
+    fn visit_decl_enum(&mut self, decl_enum: &ast::DeclEnum) {
+        walk_decl_enum(self, decl_enum);
+    }
+
+    /// Visit a struct declaration.
+    fn visit_decl_struct(&mut self, decl_struct: &ast::DeclStruct) {
+        walk_decl_struct(self, decl_struct);
+    }
+
+    /// Visit the body of a struct or an enum variant.
+    fn visit_decl_struct_body(&mut self, body: &ast::DeclStructBody) {
+        walk_decl_struct_body(self, body);
+    }
+
+    /// Visit an impl declaration.
+    fn visit_decl_impl(&mut self, decl_impl: &ast::DeclImpl) {
+        walk_decl_impl(self, decl_impl);
+    }
+
+    /// Visit a function or closure argument.
+    fn visit_fn_arg(&mut self, fn_arg: &ast::FnArg) {
+        walk_fn_arg(self, fn_arg);
+    }
+
+    /// Visit an expression.
+    fn visit_expr(&mut self, expr: &ast::Expr) {
+        walk_expr(self, expr);
+    }
+
+    /// Visit a while loop.
+    fn visit_expr_while(&mut self, expr: &ast::ExprWhile) {
+        walk_expr_while(self, expr);
+    }
+
+    /// Visit an unconditional loop.
+    fn visit_expr_loop(&mut self, expr: &ast::ExprLoop) {
+        walk_expr_loop(self, expr);
+    }
+
+    /// Visit a for loop.
+    fn visit_expr_for(&mut self, expr: &ast::ExprFor) {
+        walk_expr_for(self, expr);
+    }
+
+    /// Visit a let expression.
+    fn visit_expr_let(&mut self, expr: &ast::ExprLet) {
+        walk_expr_let(self, expr);
+    }
+
+    /// Visit an index set operation.
+    fn visit_expr_index_set(&mut self, expr: &ast::ExprIndexSet) {
+        walk_expr_index_set(self, expr);
+    }
+
+    /// Visit an if expression.
+    fn visit_expr_if(&mut self, expr: &ast::ExprIf) {
+        walk_expr_if(self, expr);
+    }
+
+    /// Visit an else if branch.
+    fn visit_expr_else_if(&mut self, expr: &ast::ExprElseIf) {
+        walk_expr_else_if(self, expr);
+    }
+
+    /// Visit an else branch.
+    fn visit_expr_else(&mut self, expr: &ast::ExprElse) {
+        walk_expr_else(self, expr);
+    }
+
+    /// Visit the condition of an if or a while.
+    fn visit_condition(&mut self, condition: &ast::Condition) {
+        walk_condition(self, condition);
+    }
+
+    /// Visit a match expression.
+    fn visit_expr_match(&mut self, expr: &ast::ExprMatch) {
+        walk_expr_match(self, expr);
+    }
+
+    /// Visit a match branch.
+    fn visit_expr_match_branch(&mut self, branch: &ast::ExprMatchBranch) {
+        walk_expr_match_branch(self, branch);
+    }
+
+    /// Visit a function call.
+    fn visit_expr_call(&mut self, expr: &ast::ExprCall) {
+        walk_expr_call(self, expr);
+    }
+
+    /// Visit an argument to a function call.
+    fn visit_call_arg(&mut self, call_arg: &ast::CallArg) {
+        walk_call_arg(self, call_arg);
+    }
+
+    /// Visit a field access.
+    fn visit_expr_field_access(&mut self, expr: &ast::ExprFieldAccess) {
+        walk_expr_field_access(self, expr);
+    }
+
+    /// Visit a grouped expression.
+    fn visit_expr_group(&mut self, expr: &ast::ExprGroup) {
+        walk_expr_group(self, expr);
+    }
+
+    /// Visit a binary expression.
+    fn visit_expr_binary(&mut self, expr: &ast::ExprBinary) {
+        walk_expr_binary(self, expr);
+    }
+
+    /// Visit a unary expression.
+    fn visit_expr_unary(&mut self, expr: &ast::ExprUnary) {
+        walk_expr_unary(self, expr);
+    }
+
+    /// Visit an index get operation.
+    fn visit_expr_index_get(&mut self, expr: &ast::ExprIndexGet) {
+        walk_expr_index_get(self, expr);
+    }
+
+    /// Visit a break expression.
+    fn visit_expr_break(&mut self, expr: &ast::ExprBreak) {
+        walk_expr_break(self, expr);
+    }
+
+    /// Visit a yield expression.
+    fn visit_expr_yield(&mut self, expr: &ast::ExprYield) {
+        walk_expr_yield(self, expr);
+    }
+
+    /// Visit a block.
+    fn visit_expr_block(&mut self, expr: &ast::ExprBlock) {
+        walk_expr_block(self, expr);
+    }
+
+    /// Visit a range expression.
+    fn visit_expr_range(&mut self, expr: &ast::ExprRange) {
+        walk_expr_range(self, expr);
+    }
+
+    /// Visit a return expression.
+    fn visit_expr_return(&mut self, expr: &ast::ExprReturn) {
+        walk_expr_return(self, expr);
+    }
+
+    /// Visit an await expression.
+    fn visit_expr_await(&mut self, expr: &ast::ExprAwait) {
+        walk_expr_await(self, expr);
+    }
+
+    /// Visit a try expression.
+    fn visit_expr_try(&mut self, expr: &ast::ExprTry) {
+        walk_expr_try(self, expr);
+    }
+
+    /// Visit a select expression.
+    fn visit_expr_select(&mut self, expr: &ast::ExprSelect) {
+        walk_expr_select(self, expr);
+    }
+
+    /// Visit a select branch.
+    fn visit_expr_select_branch(&mut self, branch: &ast::ExprSelectBranch) {
+        walk_expr_select_branch(self, branch);
+    }
+
+    /// Visit the default branch of a select.
+    fn visit_expr_default_branch(&mut self, branch: &ast::ExprDefaultBranch) {
+        walk_expr_default_branch(self, branch);
+    }
+
+    /// Visit a closure.
+    fn visit_expr_closure(&mut self, expr: &ast::ExprClosure) {
+        walk_expr_closure(self, expr);
+    }
+
+    /// Visit a unit literal.
+    fn visit_lit_unit(&mut self, _: &ast::LitUnit) {}
+
+    /// Visit a boolean literal.
+    fn visit_lit_bool(&mut self, _: &ast::LitBool) {}
+
+    /// Visit a char literal.
+    fn visit_lit_char(&mut self, _: &ast::LitChar) {}
+
+    /// Visit a byte literal.
+    fn visit_lit_byte(&mut self, _: &ast::LitByte) {}
+
+    /// Visit a number literal.
+    fn visit_lit_number(&mut self, _: &ast::LitNumber) {}
+
+    /// Visit a string literal.
+    fn visit_lit_str(&mut self, _: &ast::LitStr) {}
+
+    /// Visit a byte string literal.
+    fn visit_lit_byte_str(&mut self, _: &ast::LitByteStr) {}
+
+    /// Visit a template string.
+    fn visit_lit_template(&mut self, _: &ast::LitTemplate) {}
+
+    /// Visit a vector literal.
+    fn visit_lit_vec(&mut self, lit: &ast::LitVec) {
+        walk_lit_vec(self, lit);
+    }
+
+    /// Visit an object literal.
+    fn visit_lit_object(&mut self, lit: &ast::LitObject) {
+        walk_lit_object(self, lit);
+    }
+
+    /// Visit a field assignment in an object literal.
+    fn visit_lit_object_field_assign(&mut self, assign: &ast::LitObjectFieldAssign) {
+        walk_lit_object_field_assign(self, assign);
+    }
+
+    /// Visit the identifier of an object literal or pattern.
+    fn visit_lit_object_ident(&mut self, ident: &ast::LitObjectIdent) {
+        walk_lit_object_ident(self, ident);
+    }
+
+    /// Visit the key of an object literal or pattern.
+    fn visit_lit_object_key(&mut self, key: &ast::LitObjectKey) {
+        walk_lit_object_key(self, key);
+    }
+
+    /// Visit a tuple literal.
+    fn visit_lit_tuple(&mut self, lit: &ast::LitTuple) {
+        walk_lit_tuple(self, lit);
+    }
+
+    /// Visit a pattern.
+    fn visit_pat(&mut self, pat: &ast::Pat) {
+        walk_pat(self, pat);
+    }
+
+    /// Visit a path pattern.
+    fn visit_pat_path(&mut self, pat: &ast::PatPath) {
+        walk_pat_path(self, pat);
+    }
+
+    /// Visit a vector pattern.
+    fn visit_pat_vec(&mut self, pat: &ast::PatVec) {
+        walk_pat_vec(self, pat);
+    }
+
+    /// Visit a tuple pattern.
+    fn visit_pat_tuple(&mut self, pat: &ast::PatTuple) {
+        walk_pat_tuple(self, pat);
+    }
+
+    /// Visit an object pattern.
+    fn visit_pat_object(&mut self, pat: &ast::PatObject) {
+        walk_pat_object(self, pat);
+    }
+
+    /// Visit a field in an object pattern.
+    fn visit_pat_object_item(&mut self, item: &ast::PatObjectItem) {
+        walk_pat_object_item(self, item);
+    }
+
+    /// Visit a path.
+    fn visit_path(&mut self, path: &ast::Path) {
+        walk_path(self, path);
+    }
+
+    /// Visit an identifier.
+    fn visit_ident(&mut self, _: &ast::Ident) {}
+
+    /// Visit a label.
+    fn visit_label(&mut self, _: &ast::Label) {}
+}
+
+/// Walk the declarations of a file.
+pub fn walk_decl_file<V>(v: &mut V, decl_file: &ast::DeclFile)
+where
+    V: ?Sized + Visit,
+{
+    for (decl, _) in &decl_file.decls {
+        v.visit_decl(decl);
+    }
+}
+
+/// Walk a declaration.
+pub fn walk_decl<V>(v: &mut V, decl: &ast::Decl)
+where
+    V: ?Sized + Visit,
+{
+    match decl {
+        ast::Decl::DeclUse(decl_use) => v.visit_decl_use(decl_use),
+        ast::Decl::DeclFn(decl_fn) => v.visit_decl_fn(decl_fn),
+        ast::Decl::DeclEnum(decl_enum) => v.visit_decl_enum(decl_enum),
+        ast::Decl::DeclStruct(decl_struct) => v.visit_decl_struct(decl_struct),
+        ast::Decl::DeclImpl(decl_impl) => v.visit_decl_impl(decl_impl),
+    }
+}
+
+/// Walk a use declaration.
+pub fn walk_decl_use<V>(v: &mut V, decl_use: &ast::DeclUse)
+where
+    V: ?Sized + Visit,
+{
+    v.visit_ident(&decl_use.first);
+
+    for (_, component) in &decl_use.rest {
+        if let ast::DeclUseComponent::Ident(ident) = component {
+            v.visit_ident(ident);
+        }
+    }
+}
+
+/// Walk a function declaration.
+pub fn walk_decl_fn<V>(v: &mut V, decl_fn: &ast::DeclFn)
+where
+    V: ?Sized + Visit,
+{
+    v.visit_ident(&decl_fn.name);
+
+    for (arg, _) in &decl_fn.args.items {
+        v.visit_fn_arg(arg);
+    }
+
+    v.visit_expr_block(&decl_fn.body);
+}
+
+/// Walk an enum declaration.
+pub fn walk_decl_enum<V>(v: &mut V, decl_enum: &ast::DeclEnum)
+where
+    V: ?Sized + Visit,
+{
+    v.visit_ident(&decl_enum.name);
+
+    for (name, body, _) in &decl_enum.variants {
+        v.visit_ident(name);
+        v.visit_decl_struct_body(body);
+    }
+}
+
+/// Walk a struct declaration.
+pub fn walk_decl_struct<V>(v: &mut V, decl_struct: &ast::DeclStruct)
+where
+    V: ?Sized + Visit,
+{
+    v.visit_ident(&decl_struct.ident);
+    v.visit_decl_struct_body(&decl_struct.body);
+}
+
+/// Walk the body of a struct or an enum variant.
+pub fn walk_decl_struct_body<V>(v: &mut V, body: &ast::DeclStructBody)
+where
+    V: ?Sized + Visit,
+{
+    let fields = match body {
+        ast::DeclStructBody::EmptyBody(..) => return,
+        ast::DeclStructBody::TupleBody(body) => &body.fields,
+        ast::DeclStructBody::StructBody(body) => &body.fields,
+    };
+
+    for (field, _) in fields {
+        v.visit_ident(field);
+    }
+}
+
+/// Walk an impl declaration.
+pub fn walk_decl_impl<V>(v: &mut V, decl_impl: &ast::DeclImpl)
+where
+    V: ?Sized + Visit,
+{
+    v.visit_path(&decl_impl.path);
+
+    for decl_fn in &decl_impl.functions {
+        v.visit_decl_fn(decl_fn);
+    }
+}
+
+/// Walk a function or closure argument.
+pub fn walk_fn_arg<V>(v: &mut V, fn_arg: &ast::FnArg)
+where
+    V: ?Sized + Visit,
+{
+    match fn_arg {
+        ast::FnArg::Self_(..) | ast::FnArg::Ignore(..) => (),
+        ast::FnArg::Ident(ident) | ast::FnArg::Rest(_, ident) => v.visit_ident(ident),
+        ast::FnArg::Default(ident, _, expr) => {
+            v.visit_ident(ident);
+            v.visit_expr(expr);
+        }
+    }
+}
+
+/// Walk an expression.
+pub fn walk_expr<V>(v: &mut V, expr: &ast::Expr)
+where
+    V: ?Sized + Visit,
+{
+    match expr {
+        ast::Expr::Self_(..) => (),
+        ast::Expr::Path(path) => v.visit_path(path),
+        ast::Expr::Decl(decl) => v.visit_decl(decl),
+        ast::Expr::ExprWhile(expr) => v.visit_expr_while(expr),
+        ast::Expr::ExprLoop(expr) => v.visit_expr_loop(expr),
+        ast::Expr::ExprFor(expr) => v.visit_expr_for(expr),
+        ast::Expr::ExprLet(expr) => v.visit_expr_let(expr),
+        ast::Expr::ExprIndexSet(expr) => v.visit_expr_index_set(expr),
+        ast::Expr::ExprIf(expr) => v.visit_expr_if(expr),
+        ast::Expr::ExprMatch(expr) => v.visit_expr_match(expr),
+        ast::Expr::ExprCall(expr) => v.visit_expr_call(expr),
+        ast::Expr::ExprFieldAccess(expr) => v.visit_expr_field_access(expr),
+        ast::Expr::ExprGroup(expr) => v.visit_expr_group(expr),
+        ast::Expr::ExprBinary(expr) => v.visit_expr_binary(expr),
+        ast::Expr::ExprUnary(expr) => v.visit_expr_unary(expr),
+        ast::Expr::ExprIndexGet(expr) => v.visit_expr_index_get(expr),
+        ast::Expr::ExprBreak(expr) => v.visit_expr_break(expr),
+        ast::Expr::ExprYield(expr) => v.visit_expr_yield(expr),
+        ast::Expr::ExprBlock(expr) => v.visit_expr_block(expr),
+        ast::Expr::ExprRange(expr) => v.visit_expr_range(expr),
+        ast::Expr::ExprReturn(expr) => v.visit_expr_return(expr),
+        ast::Expr::ExprAwait(expr) => v.visit_expr_await(expr),
+        ast::Expr::ExprTry(expr) => v.visit_expr_try(expr),
+        ast::Expr::ExprSelect(expr) => v.visit_expr_select(expr),
+        ast::Expr::ExprClosure(expr) => v.visit_expr_closure(expr),
+        ast::Expr::LitUnit(lit) => v.visit_lit_unit(lit),
+        ast::Expr::LitBool(lit) => v.visit_lit_bool(lit),
+        ast::Expr::LitChar(lit) => v.visit_lit_char(lit),
+        ast::Expr::LitByte(lit) => v.visit_lit_byte(lit),
+        ast::Expr::LitNumber(lit) => v.visit_lit_number(lit),
+        ast::Expr::LitStr(lit) => v.visit_lit_str(lit),
+        ast::Expr::LitByteStr(lit) => v.visit_lit_byte_str(lit),
+        ast::Expr::LitTemplate(lit) => v.visit_lit_template(lit),
+        ast::Expr::LitVec(lit) => v.visit_lit_vec(lit),
+        ast::Expr::LitObject(lit) => v.visit_lit_object(lit),
+        ast::Expr::LitTuple(lit) => v.visit_lit_tuple(lit),
+    }
+}
+
+/// Walk an optional loop label.
+fn walk_loop_label<V>(v: &mut V, label: &Option<(ast::Label, ast::Colon)>)
+where
+    V: ?Sized + Visit,
+{
+    if let Some((label, _)) = label {
+        v.visit_label(label);
+    }
+}
+
+/// Walk a while loop.
+pub fn walk_expr_while<V>(v: &mut V, expr: &ast::ExprWhile)
+where
+    V: ?Sized + Visit,
+{
+    walk_loop_label(v, &expr.label);
+    v.visit_condition(&expr.condition);
+    v.visit_expr_block(&expr.body);
+}
+
+/// Walk an unconditional loop.
+pub fn walk_expr_loop<V>(v: &mut V, expr: &ast::ExprLoop)
+where
+    V: ?Sized + Visit,
+{
+    walk_loop_label(v, &expr.label);
+    v.visit_expr_block(&expr.body);
+}
+
+/// Walk a for loop.
+pub fn walk_expr_for<V>(v: &mut V, expr: &ast::ExprFor)
+where
+    V: ?Sized + Visit,
+{
+    walk_loop_label(v, &expr.label);
+    v.visit_pat(&expr.var);
+    v.visit_expr(&expr.iter);
+    v.visit_expr_block(&expr.body);
+}
+
+/// Walk a let expression.
+pub fn walk_expr_let<V>(v: &mut V, expr: &ast::ExprLet)
+where
+    V: ?Sized + Visit,
+{
+    v.visit_pat(&expr.pat);
+    v.visit_expr(&expr.expr);
+}
+
+/// Walk an index set operation.
+pub fn walk_expr_index_set<V>(v: &mut V, expr: &ast::ExprIndexSet)
+where
+    V: ?Sized + Visit,
+{
+    v.visit_expr(&expr.target);
+    v.visit_expr(&expr.index);
+    v.visit_expr(&expr.value);
+}
+
+/// Walk an if expression.
+pub fn walk_expr_if<V>(v: &mut V, expr: &ast::ExprIf)
+where
+    V: ?Sized + Visit,
+{
+    v.visit_condition(&expr.condition);
+    v.visit_expr_block(&expr.block);
+
+    for expr_else_if in &expr.expr_else_ifs {
+        v.visit_expr_else_if(expr_else_if);
+    }
+
+    if let Some(expr_else) = &expr.expr_else {
+        v.visit_expr_else(expr_else);
+    }
+}
+
+/// Walk an else if branch.
+pub fn walk_expr_else_if<V>(v: &mut V, expr: &ast::ExprElseIf)
+where
+    V: ?Sized + Visit,
+{
+    v.visit_condition(&expr.condition);
+    v.visit_expr_block(&expr.block);
+}
+
+/// Walk an else branch.
+pub fn walk_expr_else<V>(v: &mut V, expr: &ast::ExprElse)
+where
+    V: ?Sized + Visit,
+{
+    v.visit_expr_block(&expr.block);
+}
+
+/// Walk the condition of an if or a while.
+pub fn walk_condition<V>(v: &mut V, condition: &ast::Condition)
+where
+    V: ?Sized + Visit,
+{
+    match condition {
+        ast::Condition::Expr(expr) => v.visit_expr(expr),
+        ast::Condition::ExprLet(expr_let) => v.visit_expr_let(expr_let),
+    }
+}
+
+/// Walk a match expression.
+pub fn walk_expr_match<V>(v: &mut V, expr: &ast::ExprMatch)
+where
+    V: ?Sized + Visit,
+{
+    v.visit_expr(&expr.expr);
+
+    for (branch, _) in &expr.branches {
+        v.visit_expr_match_branch(branch);
+    }
+}
+
+/// Walk a match branch.
+pub fn walk_expr_match_branch<V>(v: &mut V, branch: &ast::ExprMatchBranch)
+where
+    V: ?Sized + Visit,
+{
+    v.visit_pat(&branch.pat);
+
+    if let Some((_, condition)) = &branch.condition {
+        v.visit_expr(condition);
+    }
+
+    v.visit_expr(&branch.body);
+}
+
+/// Walk a function call.
+pub fn walk_expr_call<V>(v: &mut V, expr: &ast::ExprCall)
+where
+    V: ?Sized + Visit,
+{
+    v.visit_expr(&expr.expr);
+
+    for (arg, _) in &expr.args.items {
+        v.visit_call_arg(arg);
+    }
+}
+
+/// Walk an argument to a function call.
+pub fn walk_call_arg<V>(v: &mut V, call_arg: &ast::CallArg)
+where
+    V: ?Sized + Visit,
+{
+    if let Some((name, _)) = &call_arg.name {
+        v.visit_ident(name);
+    }
+
+    v.visit_expr(&call_arg.expr);
+}
+
+/// Walk a field access.
+pub fn walk_expr_field_access<V>(v: &mut V, expr: &ast::ExprFieldAccess)
+where
+    V: ?Sized + Visit,
+{
+    v.visit_expr(&expr.expr);
+
+    match &expr.expr_field {
+        ast::ExprField::Ident(ident) => v.visit_ident(ident),
+        ast::ExprField::LitNumber(lit) => v.visit_lit_number(lit),
+    }
+}
+
+/// Walk a grouped expression.
+pub fn walk_expr_group<V>(v: &mut V, expr: &ast::ExprGroup)
+where
+    V: ?Sized + Visit,
+{
+    v.visit_expr(&expr.expr);
+}
+
+/// Walk a binary expression.
+pub fn walk_expr_binary<V>(v: &mut V, expr: &ast::ExprBinary)
+where
+    V: ?Sized + Visit,
+{
+    v.visit_expr(&expr.lhs);
+    v.visit_expr(&expr.rhs);
+}
+
+/// Walk a unary expression.
+pub fn walk_expr_unary<V>(v: &mut V, expr: &ast::ExprUnary)
+where
+    V: ?Sized + Visit,
+{
+    v.visit_expr(&expr.expr);
+}
+
+/// Walk an index get operation.
+pub fn walk_expr_index_get<V>(v: &mut V, expr: &ast::ExprIndexGet)
+where
+    V: ?Sized + Visit,
+{
+    v.visit_expr(&expr.target);
+    v.visit_expr(&expr.index);
+}
+
+/// Walk a break expression.
+pub fn walk_expr_break<V>(v: &mut V, expr: &ast::ExprBreak)
+where
+    V: ?Sized + Visit,
+{
+    match &expr.expr {
+        Some(ast::ExprBreakValue::Expr(expr)) => v.visit_expr(expr),
+        Some(ast::ExprBreakValue::Label(label)) => v.visit_label(label),
+        None => (),
+    }
+}
+
+/// Walk a yield expression.
+pub fn walk_expr_yield<V>(v: &mut V, expr: &ast::ExprYield)
+where
+    V: ?Sized + Visit,
+{
+    if let Some(expr) = &expr.expr {
+        v.visit_expr(expr);
+    }
+}
+
+/// Walk a block.
+pub fn walk_expr_block<V>(v: &mut V, expr: &ast::ExprBlock)
+where
+    V: ?Sized + Visit,
+{
+    for (expr, _) in &expr.exprs {
+        v.visit_expr(expr);
+    }
+
+    if let Some(expr) = &expr.trailing_expr {
+        v.visit_expr(expr);
+    }
+}
+
+/// Walk a range expression.
+pub fn walk_expr_range<V>(v: &mut V, expr: &ast::ExprRange)
+where
+    V: ?Sized + Visit,
+{
+    if let Some(from) = &expr.from {
+        v.visit_expr(from);
+    }
+
+    if let Some(to) = &expr.to {
+        v.visit_expr(to);
+    }
+}
+
+/// Walk a return expression.
+pub fn walk_expr_return<V>(v: &mut V, expr: &ast::ExprReturn)
+where
+    V: ?Sized + Visit,
+{
+    if let Some(expr) = &expr.expr {
+        v.visit_expr(expr);
+    }
+}
+
+/// Walk an await expression.
+pub fn walk_expr_await<V>(v: &mut V, expr: &ast::ExprAwait)
+where
+    V: ?Sized + Visit,
+{
+    v.visit_expr(&expr.expr);
+}
+
+/// Walk a try expression.
+pub fn walk_expr_try<V>(v: &mut V, expr: &ast::ExprTry)
+where
+    V: ?Sized + Visit,
+{
+    v.visit_expr(&expr.expr);
+}
+
+/// Walk a select expression.
+pub fn walk_expr_select<V>(v: &mut V, expr: &ast::ExprSelect)
+where
+    V: ?Sized + Visit,
+{
+    for (branch, _) in &expr.branches {
+        v.visit_expr_select_branch(branch);
+    }
+
+    if let Some((branch, _)) = &expr.default_branch {
+        v.visit_expr_default_branch(branch);
+    }
+}
+
+/// Walk a select branch.
+pub fn walk_expr_select_branch<V>(v: &mut V, branch: &ast::ExprSelectBranch)
+where
+    V: ?Sized + Visit,
+{
+    v.visit_pat(&branch.pat);
+    v.visit_expr(&branch.expr);
+    v.visit_expr(&branch.body);
+}
+
+/// Walk the default branch of a select.
+pub fn walk_expr_default_branch<V>(v: &mut V, branch: &ast::ExprDefaultBranch)
+where
+    V: ?Sized + Visit,
+{
+    v.visit_expr(&branch.body);
+}
+
+/// Walk a closure.
+pub fn walk_expr_closure<V>(v: &mut V, expr: &ast::ExprClosure)
+where
+    V: ?Sized + Visit,
+{
+    if let ast::ExprClosureArgs::List { args, .. } = &expr.args {
+        for (arg, _) in args {
+            v.visit_fn_arg(arg);
+        }
+    }
+
+    v.visit_expr(&expr.body);
+}
+
+/// Walk a vector literal.
+pub fn walk_lit_vec<V>(v: &mut V, lit: &ast::LitVec)
+where
+    V: ?Sized + Visit,
+{
+    for expr in &lit.items {
+        v.visit_expr(expr);
+    }
+}
+
+/// Walk an object literal.
+pub fn walk_lit_object<V>(v: &mut V, lit: &ast::LitObject)
+where
+    V: ?Sized + Visit,
+{
+    v.visit_lit_object_ident(&lit.ident);
+
+    for assign in &lit.assignments {
+        v.visit_lit_object_field_assign(assign);
+    }
+}
+
+/// Walk a field assignment in an object literal.
+pub fn walk_lit_object_field_assign<V>(v: &mut V, assign: &ast::LitObjectFieldAssign)
+where
+    V: ?Sized + Visit,
+{
+    v.visit_lit_object_key(&assign.key);
+
+    if let Some((_, expr)) = &assign.assign {
+        v.visit_expr(expr);
+    }
+}
+
+/// Walk the identifier of an object literal or pattern.
+pub fn walk_lit_object_ident<V>(v: &mut V, ident: &ast::LitObjectIdent)
+where
+    V: ?Sized + Visit,
+{
+    if let ast::LitObjectIdent::Named(path) = ident {
+        v.visit_path(path);
+    }
+}
+
+/// Walk the key of an object literal or pattern.
+pub fn walk_lit_object_key<V>(v: &mut V, key: &ast::LitObjectKey)
+where
+    V: ?Sized + Visit,
+{
+    match key {
+        ast::LitObjectKey::LitStr(lit) => v.visit_lit_str(lit),
+        ast::LitObjectKey::Ident(ident) => v.visit_ident(ident),
+    }
+}
+
+/// Walk a tuple literal.
+pub fn walk_lit_tuple<V>(v: &mut V, lit: &ast::LitTuple)
+where
+    V: ?Sized + Visit,
+{
+    for (expr, _) in &lit.items {
+        v.visit_expr(expr);
+    }
+}
+
+/// Walk a pattern.
+pub fn walk_pat<V>(v: &mut V, pat: &ast::Pat)
+where
+    V: ?Sized + Visit,
+{
+    match pat {
+        ast::Pat::PatIgnore(..) => (),
+        ast::Pat::PatPath(pat) => v.visit_pat_path(pat),
+        ast::Pat::PatUnit(lit) => v.visit_lit_unit(lit),
+        ast::Pat::PatByte(lit) => v.visit_lit_byte(lit),
+        ast::Pat::PatChar(lit) => v.visit_lit_char(lit),
+        ast::Pat::PatNumber(lit) => v.visit_lit_number(lit),
+        ast::Pat::PatString(lit) => v.visit_lit_str(lit),
+        ast::Pat::PatVec(pat) => v.visit_pat_vec(pat),
+        ast::Pat::PatTuple(pat) => v.visit_pat_tuple(pat),
+        ast::Pat::PatObject(pat) => v.visit_pat_object(pat),
+    }
+}
+
+/// Walk a path pattern.
+pub fn walk_pat_path<V>(v: &mut V, pat: &ast::PatPath)
+where
+    V: ?Sized + Visit,
+{
+    v.visit_path(&pat.path);
+}
+
+/// Walk a vector pattern.
+pub fn walk_pat_vec<V>(v: &mut V, pat: &ast::PatVec)
+where
+    V: ?Sized + Visit,
+{
+    for (pat, _) in &pat.items {
+        v.visit_pat(pat);
+    }
+}
+
+/// Walk a tuple pattern.
+pub fn walk_pat_tuple<V>(v: &mut V, pat: &ast::PatTuple)
+where
+    V: ?Sized + Visit,
+{
+    if let Some(path) = &pat.path {
+        v.visit_path(path);
+    }
+
+    for (pat, _) in &pat.items {
+        v.visit_pat(pat);
+    }
+}
+
+/// Walk an object pattern.
+pub fn walk_pat_object<V>(v: &mut V, pat: &ast::PatObject)
+where
+    V: ?Sized + Visit,
+{
+    v.visit_lit_object_ident(&pat.ident);
+
+    for (item, _) in &pat.fields {
+        v.visit_pat_object_item(item);
+    }
+}
+
+/// Walk a field in an object pattern.
+pub fn walk_pat_object_item<V>(v: &mut V, item: &ast::PatObjectItem)
+where
+    V: ?Sized + Visit,
+{
+    v.visit_lit_object_key(&item.key);
+
+    if let Some((_, pat)) = &item.binding {
+        v.visit_pat(pat);
+    }
+}
+
+/// Walk a path.
+pub fn walk_path<V>(v: &mut V, path: &ast::Path)
+where
+    V: ?Sized + Visit,
+{
+    v.visit_ident(&path.first);
+
+    for (_, ident) in &path.rest {
+        v.visit_ident(ident);
+    }
+}
@@ -12,8 +12,11 @@ impl Compile<(ast::DeclFn, bool)> for Compiler<'_, '_> {
         let _guard = self.items.push_block();
 
         let mut first = true;
+        let mut defaults = false;
 
-        for (arg, _) in fn_decl.args.items.iter() {
+        let args = fn_decl.args.items.len();
+
+        for (index, (arg, _)) in fn_decl.args.items.iter().enumerate() {
             let span = arg.span();
 
             match arg {
@@ -23,38 +26,74 @@ impl Compile<(ast::DeclFn, bool)> for Compiler<'_, '_> {
                     }
 
                     let span = s.span();
-                    self.scopes.last_mut(span)?.new_var("self", span)?;
+                    self.new_arg_var("self", span)?;
                 }
                 ast::FnArg::Ident(ident) => {
+                    if defaults {
+                        return Err(CompileError::RequiredArgumentAfterDefault { span });
+                    }
+
                     let span = ident.span();
                     let name = ident.resolve(self.source)?;
-                    self.scopes.last_mut(span)?.new_var(name, span)?;
+                    self.new_arg_var(name, span)?;
+                }
+                ast::FnArg::Default(ident, ..) => {
+                    defaults = true;
+                    let name = ident.resolve(self.source)?;
+                    self.new_arg_var(name, ident.span())?;
                 }
                 ast::FnArg::Ignore(ignore) => {
+                    if defaults {
+                        return Err(CompileError::RequiredArgumentAfterDefault { span });
+                    }
+
                     let span = ignore.span();
                     self.scopes.decl_anon(span)?;
                 }
+                ast::FnArg::Rest(_, ident) => {
+                    if index + 1 != args {
+                        return Err(CompileError::UnsupportedRestArgument { span });
+                    }
+
+                    let name = ident.resolve(self.source)?;
+                    self.new_arg_var(name, ident.span())?;
+                }
             }
 
             first = false;
         }
 
+        self.box_arg_cells(&fn_decl.args.items)?;
+
+        if defaults {
+            let argc = self.scopes.decl_anon(span)?;
+            self.compile_default_args(&fn_decl.args.items, argc)?;
+        }
+
         if fn_decl.body.exprs.is_empty() && fn_decl.body.trailing_expr.is_none() {
             self.asm.push(Inst::ReturnUnit, span);
             return Ok(());
         }
 
+        self.warn_unreachable_code(&fn_decl.body);
+
         for (expr, _) in &fn_decl.body.exprs {
             self.compile((expr, Needs::None))?;
         }
 
-        if let Some(expr) = &fn_decl.body.trailing_expr {
-            self.compile((&**expr, Needs::Value))?;
+        let trailing_expr = fn_decl.body.trailing_expr.as_deref();
+
+        if let Some(expr) = trailing_expr.filter(|expr| !expr.produces_nothing()) {
+            self.compile((expr, Needs::Value))?;
 
             let total_var_count = self.scopes.last(span)?.total_var_count;
             self.locals_clean(total_var_count, span);
             self.asm.push(Inst::Return, span);
         } else {
+            if let Some(expr) = trailing_expr {
+                self.compile((expr, Needs::None))?;
+            }
+
             let total_var_count = self.scopes.last(span)?.total_var_count;
             self.locals_pop(total_var_count, span);
             self.asm.push(Inst::ReturnUnit, span);
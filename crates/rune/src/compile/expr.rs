@@ -56,6 +56,9 @@ impl Compile<(&ast::Expr, Needs)> for Compiler<'_, '_> {
             ast::Expr::ExprBlock(expr_block) => {
                 self.compile((expr_block, needs))?;
             }
+            ast::Expr::ExprRange(expr_range) => {
+                self.compile((expr_range, needs))?;
+            }
             ast::Expr::ExprReturn(expr_return) => {
                 self.compile((expr_return, needs))?;
             }
@@ -1,9 +1,10 @@
 use crate::ast;
 use crate::compiler::{Compiler, Needs};
 use crate::error::CompileResult;
+use crate::scopes::Var;
 use crate::traits::{Compile, Resolve as _};
 use crate::CompileError;
-use runestick::Inst;
+use runestick::{Inst, Span};
 
 /// Compile a binary expression.
 impl Compile<(&ast::ExprBinary, Needs)> for Compiler<'_, '_> {
@@ -17,7 +18,13 @@ impl Compile<(&ast::ExprBinary, Needs)> for Compiler<'_, '_> {
             | ast::BinOp::AddAssign
             | ast::BinOp::SubAssign
             | ast::BinOp::MulAssign
-            | ast::BinOp::DivAssign => {
+            | ast::BinOp::DivAssign
+            | ast::BinOp::RemAssign
+            | ast::BinOp::BitAndAssign
+            | ast::BinOp::BitXorAssign
+            | ast::BinOp::BitOrAssign
+            | ast::BinOp::ShlAssign
+            | ast::BinOp::ShrAssign => {
                 compile_assign_binop(
                     self,
                     &*expr_binary.lhs,
@@ -54,6 +61,21 @@ impl Compile<(&ast::ExprBinary, Needs)> for Compiler<'_, '_> {
             ast::BinOp::Rem { .. } => {
                 self.asm.push(Inst::Rem, span);
             }
+            ast::BinOp::BitAnd => {
+                self.asm.push(Inst::BitAnd, span);
+            }
+            ast::BinOp::BitXor => {
+                self.asm.push(Inst::BitXor, span);
+            }
+            ast::BinOp::BitOr => {
+                self.asm.push(Inst::BitOr, span);
+            }
+            ast::BinOp::Shl => {
+                self.asm.push(Inst::Shl, span);
+            }
+            ast::BinOp::Shr => {
+                self.asm.push(Inst::Shr, span);
+            }
             ast::BinOp::Eq { .. } => {
                 self.asm.push(Inst::Eq, span);
             }
@@ -120,7 +142,7 @@ fn compile_assign_binop(
 
     // NB: this loop is actually useful in breaking early.
     #[allow(clippy::never_loop)]
-    let offset = loop {
+    let var = loop {
         match lhs {
             ast::Expr::ExprFieldAccess(get) => match (&*get.expr, &get.expr_field) {
                 (ast::Expr::Path(ast::Path { first, rest }), expr_field) if rest.is_empty() => {
@@ -172,13 +194,20 @@ fn compile_assign_binop(
                     compiler.asm.push(Inst::IndexSet, span);
                     return Ok(());
                 }
-                _ => (),
+                (expr, expr_field) => {
+                    compile_field_assign(compiler, expr, expr_field, rhs, bin_op, span)?;
+
+                    if needs.value() {
+                        compiler.asm.push(Inst::Unit, span);
+                    }
+
+                    return Ok(());
+                }
             },
             ast::Expr::Path(ast::Path { first, rest }) if rest.is_empty() => {
                 let span = first.span();
                 let first = first.resolve(compiler.source)?;
-                let var = compiler.scopes.get_var(first, span)?;
-                break var.offset;
+                break compiler.scopes.get_var(first, span)?.clone();
             }
             _ => (),
         };
@@ -186,31 +215,108 @@ fn compile_assign_binop(
         return Err(CompileError::UnsupportedAssignExpr { span });
     };
 
+    if var.cell {
+        compile_cell_assign(compiler, &var, rhs, bin_op, span)?;
+    } else {
+        compiler.compile((rhs, Needs::Value))?;
+        let inst = assign_inst(bin_op, var.offset, span)?;
+        compiler.asm.push(inst, span);
+    }
+
+    if needs.value() {
+        compiler.asm.push(Inst::Unit, span);
+    }
+
+    Ok(())
+}
+
+/// Compile an assignment to a variable which is stored in a shared cell.
+///
+/// The value is loaded out of the cell into a temporary slot, which is
+/// assigned to and then stored back into the cell.
+fn compile_cell_assign(
+    compiler: &mut Compiler<'_, '_>,
+    var: &Var,
+    rhs: &ast::Expr,
+    bin_op: ast::BinOp,
+    span: Span,
+) -> CompileResult<()> {
+    let scope = compiler.scopes.child(span)?;
+    let guard = compiler.scopes.push(scope);
+
+    var.copy(compiler.asm, span, "load cell");
+    let offset = compiler.scopes.decl_anon(span)?;
+
     compiler.compile((rhs, Needs::Value))?;
+    let inst = assign_inst(bin_op, offset, span)?;
+    compiler.asm.push(inst, span);
 
-    match bin_op {
-        ast::BinOp::Assign => {
-            compiler.asm.push(Inst::Replace { offset }, span);
-        }
-        ast::BinOp::AddAssign => {
-            compiler.asm.push(Inst::AddAssign { offset }, span);
-        }
-        ast::BinOp::SubAssign => {
-            compiler.asm.push(Inst::SubAssign { offset }, span);
-        }
-        ast::BinOp::MulAssign => {
-            compiler.asm.push(Inst::MulAssign { offset }, span);
-        }
-        ast::BinOp::DivAssign => {
-            compiler.asm.push(Inst::DivAssign { offset }, span);
-        }
+    compiler.asm.push(Inst::Copy { offset: var.offset }, span);
+    compiler
+        .asm
+        .push_with_comment(Inst::TupleIndexSet { index: 0 }, span, "store cell");
+
+    compiler.scopes.pop(guard, span)?;
+    Ok(())
+}
+
+/// Get the instruction which assigns the value on top of the stack to the
+/// given slot using the given operator.
+fn assign_inst(bin_op: ast::BinOp, offset: usize, span: Span) -> CompileResult<Inst> {
+    Ok(match bin_op {
+        ast::BinOp::Assign => Inst::Replace { offset },
+        ast::BinOp::AddAssign => Inst::AddAssign { offset },
+        ast::BinOp::SubAssign => Inst::SubAssign { offset },
+        ast::BinOp::MulAssign => Inst::MulAssign { offset },
+        ast::BinOp::DivAssign => Inst::DivAssign { offset },
+        ast::BinOp::RemAssign => Inst::RemAssign { offset },
+        ast::BinOp::BitAndAssign => Inst::BitAndAssign { offset },
+        ast::BinOp::BitXorAssign => Inst::BitXorAssign { offset },
+        ast::BinOp::BitOrAssign => Inst::BitOrAssign { offset },
+        ast::BinOp::ShlAssign => Inst::ShlAssign { offset },
+        ast::BinOp::ShrAssign => Inst::ShrAssign { offset },
         op => {
             return Err(CompileError::UnsupportedAssignBinOp { span, op });
         }
+    })
+}
+
+/// Compile an assignment to the field of an arbitrary expression, like
+/// `a.b.c = value` or `v[0].c = value`.
+///
+/// The expression is evaluated to the value holding the field, which is then
+/// assigned to in place.
+fn compile_field_assign(
+    compiler: &mut Compiler<'_, '_>,
+    expr: &ast::Expr,
+    expr_field: &ast::ExprField,
+    rhs: &ast::Expr,
+    bin_op: ast::BinOp,
+    span: Span,
+) -> CompileResult<()> {
+    if !matches!(bin_op, ast::BinOp::Assign) {
+        return Err(CompileError::UnsupportedAssignBinOp { span, op: bin_op });
     }
 
-    if needs.value() {
-        compiler.asm.push(Inst::Unit, span);
+    compiler.compile((rhs, Needs::Value))?;
+
+    match expr_field {
+        ast::ExprField::Ident(field) => {
+            let field = field.resolve(compiler.source)?;
+            let slot = compiler.unit.borrow_mut().new_static_string(field)?;
+            compiler.asm.push(Inst::String { slot }, span);
+            compiler.compile((expr, Needs::Value))?;
+            compiler.asm.push(Inst::IndexSet, span);
+        }
+        ast::ExprField::LitNumber(n) => {
+            let index = match n.resolve(compiler.source)? {
+                ast::Number::Integer(n) if n >= 0 => n as usize,
+                _ => return Err(CompileError::UnsupportedFieldAccess { span: n.span() }),
+            };
+
+            compiler.compile((expr, Needs::Value))?;
+            compiler.asm.push(Inst::TupleIndexSet { index }, span);
+        }
     }
 
     Ok(())
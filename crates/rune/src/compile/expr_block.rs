@@ -1,5 +1,5 @@
 use crate::ast;
-use crate::compiler::{Compiler, Needs};
+use crate::compiler::{Compiler, Needs, TryBlock};
 use crate::error::CompileResult;
 use crate::traits::Compile;
 use crate::CompileError;
@@ -7,6 +7,7 @@ use runestick::{Hash, Inst, Meta, MetaClosureCapture};
 
 struct CallAsync(());
 struct BlockBody(());
+struct TryBlockBody(());
 
 /// Compile the async block.
 impl Compile<(ast::ExprBlock, &[MetaClosureCapture])> for Compiler<'_, '_> {
@@ -20,11 +21,17 @@ impl Compile<(ast::ExprBlock, &[MetaClosureCapture])> for Compiler<'_, '_> {
         let scope = self.scopes.last(span)?.child();
         let guard = self.scopes.push(scope);
 
+        // NB: captured variables are always stored in shared cells.
         for capture in captures {
-            self.scopes.new_var(&capture.ident, span)?;
+            self.scopes.new_cell(&capture.ident, span)?;
+        }
+
+        if expr_block.try_.is_some() {
+            self.compile((TryBlockBody(()), &expr_block, Needs::Value))?;
+        } else {
+            self.compile((BlockBody(()), &expr_block, Needs::Value))?;
         }
 
-        self.compile((BlockBody(()), &expr_block, Needs::Value))?;
         self.clean_last_scope(span, guard, Needs::Value)?;
         self.asm.push(Inst::Return, span);
         Ok(())
@@ -37,8 +44,10 @@ impl Compile<(ast::ExprBlock, &[MetaClosureCapture])> for Compiler<'_, '_> {
 /// an item in them which does.
 impl Compile<(&ast::ExprBlock, Needs)> for Compiler<'_, '_> {
     fn compile(&mut self, (expr_block, needs): (&ast::ExprBlock, Needs)) -> CompileResult<()> {
-        if let Some(..) = expr_block.async_ {
+        if expr_block.async_.is_some() {
             self.compile((CallAsync(()), expr_block))?;
+        } else if expr_block.try_.is_some() {
+            self.compile((TryBlockBody(()), expr_block, needs))?;
         } else {
             self.compile((BlockBody(()), expr_block, needs))?;
         }
@@ -71,7 +80,7 @@ impl Compile<(CallAsync, &ast::ExprBlock)> for Compiler<'_, '_> {
 
         for ident in &**captures {
             let var = self.scopes.get_var(&ident.ident, span)?;
-            var.copy(&mut self.asm, span, format!("captures `{}`", ident.ident));
+            var.capture(self.asm, span, format!("captures `{}`", ident.ident));
         }
 
         let item = meta.item();
@@ -107,19 +116,32 @@ impl Compile<(BlockBody, &ast::ExprBlock, Needs)> for Compiler<'_, '_> {
         let new_scope = self.scopes.child(span)?;
         let scopes_count = self.scopes.push(new_scope);
 
+        self.warn_unreachable_code(expr_block);
+
         for (expr, _) in &expr_block.exprs {
             // NB: terminated expressions do not need to produce a value.
             self.compile((expr, Needs::None))?;
         }
 
         if let Some(expr) = &expr_block.trailing_expr {
-            self.compile((&**expr, needs))?;
+            // NB: trailing expressions which produce nothing are compiled as
+            // statements, and the block evaluates to unit.
+            if expr.produces_nothing() {
+                self.compile((&**expr, Needs::None))?;
+            } else {
+                self.compile((&**expr, needs))?;
+            }
         }
 
         let scope = self.scopes.pop(scopes_count, span)?;
 
+        let produces_nothing = match &expr_block.trailing_expr {
+            Some(trailing) => trailing.produces_nothing(),
+            None => true,
+        };
+
         if needs.value() {
-            if expr_block.trailing_expr.is_none() {
+            if produces_nothing {
                 self.locals_pop(scope.local_var_count, span);
                 self.asm.push(Inst::Unit, span);
             } else {
@@ -136,3 +158,53 @@ impl Compile<(BlockBody, &ast::ExprBlock, Needs)> for Compiler<'_, '_> {
         Ok(())
     }
 }
+
+/// Call a `try` block.
+///
+/// The block evaluates to `Ok` with the value of its body, or to the error of
+/// the first `?` which fails inside of it. If panics are caught, a catch
+/// boundary is pushed so that a panic inside of the block evaluates to an
+/// `Err` with its message instead.
+impl Compile<(TryBlockBody, &ast::ExprBlock, Needs)> for Compiler<'_, '_> {
+    fn compile(
+        &mut self,
+        (_, expr_block, needs): (TryBlockBody, &ast::ExprBlock, Needs),
+    ) -> CompileResult<()> {
+        let span = expr_block.span();
+        log::trace!("ExprBlock (try) => {:?}", self.source.source(span));
+
+        let error_label = self.asm.new_label("try_block_error");
+        let catch = self.options.catch_panics;
+
+        if catch {
+            self.asm.push_catch(error_label, span);
+        }
+
+        self.try_blocks.push(TryBlock {
+            error_label,
+            total_var_count: self.scopes.last(span)?.total_var_count,
+            catch,
+        });
+
+        self.compile((BlockBody(()), expr_block, Needs::Value))?;
+        self.try_blocks.pop();
+
+        if catch {
+            self.asm.push(Inst::PopCatch, span);
+        }
+
+        let ok = Hash::type_hash(["std", "result", "Result", "Ok"]);
+        self.asm
+            .push_with_comment(Inst::Call { hash: ok, args: 1 }, span, "fn `Ok`");
+
+        // NB: failing `?` expressions and caught panics jump here with the
+        // error on top of the stack.
+        self.asm.label(error_label)?;
+
+        if !needs.value() {
+            self.asm.push(Inst::Pop, span);
+        }
+
+        Ok(())
+    }
+}
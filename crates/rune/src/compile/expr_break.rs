@@ -39,6 +39,13 @@ impl Compile<&ast::ExprBreak> for Compiler<'_, '_> {
             self.asm.push(Inst::Drop { offset }, span);
         }
 
+        // Leave the catch boundaries of any `try` blocks we break out of.
+        for try_block in &self.try_blocks[last_loop.try_blocks..] {
+            if try_block.catch {
+                self.asm.push(Inst::PopCatch, span);
+            }
+        }
+
         let vars = self
             .scopes
             .last(span)?
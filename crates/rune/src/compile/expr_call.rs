@@ -3,7 +3,18 @@ use crate::compiler::{Compiler, Needs};
 use crate::error::CompileResult;
 use crate::traits::{Compile, Resolve as _};
 use crate::CompileError;
-use runestick::{Hash, Inst, Meta};
+use runestick::{Hash, Inst, Meta, Source};
+
+/// The name of the builtin which yields a value to the host.
+///
+/// Unlike `yield`, calling `yield_host(value)` doesn't turn the enclosing
+/// function into a generator. Instead it halts the whole execution, handing the
+/// value to the host which can later resume it with a value that becomes the
+/// result of the call. It can be shadowed by a function with the same name.
+///
+/// Generators, streams and functions called from native code can't reach the
+/// host, so yielding to it from there causes an error.
+const YIELD_HOST: &str = "yield_host";
 
 /// Compile a call expression.
 impl Compile<(&ast::ExprCall, Needs)> for Compiler<'_, '_> {
@@ -36,10 +47,12 @@ impl Compile<(&ast::ExprCall, Needs)> for Compiler<'_, '_> {
                     );
 
                     let ident = ident.resolve(self.source)?;
+                    check_positional_args(expr_call)?;
                     self.compile((&**expr, Needs::Value))?;
+                    self.scopes.decl_anon(span)?;
 
-                    for (expr, _) in expr_call.args.items.iter() {
-                        self.compile((expr, Needs::Value))?;
+                    for (arg, _) in &expr_call.args.items {
+                        self.compile((&arg.expr, Needs::Value))?;
                         self.scopes.decl_anon(span)?;
                     }
 
@@ -49,8 +62,10 @@ impl Compile<(&ast::ExprCall, Needs)> for Compiler<'_, '_> {
                 expr => {
                     log::trace!("ExprCall(Other) => {:?}", self.source.source(span));
 
-                    for (expr, _) in expr_call.args.items.iter() {
-                        self.compile((expr, Needs::Value))?;
+                    check_positional_args(expr_call)?;
+
+                    for (arg, _) in &expr_call.args.items {
+                        self.compile((&arg.expr, Needs::Value))?;
                         self.scopes.decl_anon(span)?;
                     }
 
@@ -67,15 +82,22 @@ impl Compile<(&ast::ExprCall, Needs)> for Compiler<'_, '_> {
             return Ok(());
         };
 
-        for (expr, _) in expr_call.args.items.iter() {
-            self.compile((expr, Needs::Value))?;
-            self.scopes.decl_anon(span)?;
+        // NB: named arguments can only be reordered once we know which function
+        // is being called, so they are compiled after it's been looked up.
+        let named = expr_call.has_named_args();
+
+        if !named {
+            for (arg, _) in &expr_call.args.items {
+                self.compile((&arg.expr, Needs::Value))?;
+                self.scopes.decl_anon(span)?;
+            }
         }
 
         let item = self.convert_path_to_item(path)?;
 
         if let Some(name) = item.as_local() {
             if let Some(var) = self.scopes.try_get_var(name)? {
+                check_positional_args(expr_call)?;
                 var.copy(&mut self.asm, span, format!("var `{}`", name));
                 self.asm.push(Inst::CallFn { args }, span);
 
@@ -90,13 +112,43 @@ impl Compile<(&ast::ExprCall, Needs)> for Compiler<'_, '_> {
 
         let meta = match self.lookup_meta(&item, path.span())? {
             Some(meta) => meta,
+            None if item.as_local() == Some(YIELD_HOST) => {
+                check_positional_args(expr_call)?;
+
+                match args {
+                    0 => self.asm.push(Inst::Unit, span),
+                    1 => (),
+                    actual => {
+                        return Err(CompileError::ArgumentCountMismatch {
+                            span,
+                            expected: 1,
+                            actual,
+                        });
+                    }
+                }
+
+                self.asm.push(Inst::YieldHost, span);
+
+                if !needs.value() {
+                    self.asm.push(Inst::Pop, span);
+                }
+
+                self.scopes.pop(guard, span)?;
+                return Ok(());
+            }
             None => {
                 return Err(CompileError::MissingFunction { span, item });
             }
         };
 
+        // Temporaries holding named arguments which have to be cleaned up
+        // after the call.
+        let mut temporaries = 0;
+
         let item = match &meta {
             Meta::MetaTuple { tuple, .. } | Meta::MetaVariantTuple { tuple, .. } => {
+                check_positional_args(expr_call)?;
+
                 if tuple.args != expr_call.args.items.len() {
                     return Err(CompileError::UnsupportedArgumentCount {
                         span,
@@ -118,7 +170,58 @@ impl Compile<(&ast::ExprCall, Needs)> for Compiler<'_, '_> {
 
                 tuple.item.clone()
             }
-            Meta::MetaFunction { item, .. } => item.clone(),
+            Meta::MetaFunction {
+                item,
+                args,
+                defaults,
+                variadic,
+                arg_names,
+                ..
+            } => {
+                if named {
+                    let arg_names = arg_names
+                        .as_ref()
+                        .ok_or_else(|| CompileError::UnsupportedNamedArgument { span })?;
+
+                    let required = arg_names.len() - defaults;
+
+                    let order = named_args(self.source, expr_call, arg_names, required)?;
+                    let mut offsets = Vec::with_capacity(order.len());
+
+                    for (arg, _) in &expr_call.args.items {
+                        self.compile((&arg.expr, Needs::Value))?;
+                        offsets.push(self.scopes.decl_anon(span)?);
+                    }
+
+                    // NB: arguments are evaluated in the order they're
+                    // written, so if that's not the order in which they're
+                    // declared they're copied into place.
+                    if order.iter().enumerate().any(|(n, index)| n != *index) {
+                        for index in order {
+                            let offset = offsets[index];
+                            self.asm.push(Inst::Copy { offset }, span);
+                            self.scopes.decl_anon(span)?;
+                        }
+
+                        temporaries = offsets.len();
+                    }
+                }
+
+                if let Some(args) = *args {
+                    let actual = expr_call.args.items.len();
+                    let required = args - defaults;
+
+                    if actual < required || !variadic && actual > args {
+                        return Err(CompileError::ArgumentCountMismatch {
+                            span,
+                            expected: if actual < required { required } else { args },
+                            actual,
+                        });
+                    }
+                }
+
+                item.clone()
+            }
             _ => {
                 return Err(CompileError::MissingFunction { span, item });
             }
@@ -128,6 +231,10 @@ impl Compile<(&ast::ExprCall, Needs)> for Compiler<'_, '_> {
         self.asm
             .push_with_comment(Inst::Call { hash, args }, span, format!("fn `{}`", item));
 
+        if temporaries > 0 {
+            self.asm.push(Inst::Clean { count: temporaries }, span);
+        }
+
         // NB: we put it here to preserve the call in case it has side effects.
         // But if we don't need the value, then pop it from the stack.
         if !needs.value() {
@@ -138,3 +245,96 @@ impl Compile<(&ast::ExprCall, Needs)> for Compiler<'_, '_> {
         Ok(())
     }
 }
+
+/// Check that a call which doesn't support named arguments has none.
+fn check_positional_args(expr_call: &ast::ExprCall) -> CompileResult<()> {
+    for (arg, _) in &expr_call.args.items {
+        if let Some((ident, _)) = &arg.name {
+            return Err(CompileError::UnsupportedNamedArgument { span: ident.span() });
+        }
+    }
+
+    Ok(())
+}
+
+/// Order the arguments of a call with named arguments by the positions of the
+/// declared arguments of the function being called.
+///
+/// Returns the index of the argument in the call for each argument passed to
+/// the function. Arguments with default values can only be omitted from the
+/// end.
+fn named_args(
+    source: &Source,
+    expr_call: &ast::ExprCall,
+    arg_names: &[String],
+    required: usize,
+) -> CompileResult<Vec<usize>> {
+    let mut slots = vec![None; arg_names.len()];
+    let mut rest = Vec::new();
+    let mut seen_named = false;
+
+    for (n, (arg, _)) in expr_call.args.items.iter().enumerate() {
+        let (ident, _) = match &arg.name {
+            Some(name) => name,
+            None => {
+                if seen_named {
+                    return Err(CompileError::PositionalArgumentAfterNamed { span: arg.span() });
+                }
+
+                match slots.get_mut(n) {
+                    Some(slot) => *slot = Some(n),
+                    None => rest.push(n),
+                }
+
+                continue;
+            }
+        };
+
+        seen_named = true;
+        let span = ident.span();
+        let name = ident.resolve(source)?;
+
+        let index = match arg_names.iter().position(|n| n == name) {
+            Some(index) => index,
+            None => {
+                return Err(CompileError::UnknownNamedArgument {
+                    span,
+                    name: name.to_owned(),
+                });
+            }
+        };
+
+        if slots[index].is_some() {
+            return Err(CompileError::DuplicateArgument {
+                span,
+                name: name.to_owned(),
+            });
+        }
+
+        slots[index] = Some(n);
+    }
+
+    let provided = slots
+        .iter()
+        .rposition(Option::is_some)
+        .map(|index| index + 1)
+        .unwrap_or_default()
+        .max(required);
+
+    let mut args = Vec::with_capacity(provided + rest.len());
+
+    for (slot, name) in slots.into_iter().take(provided).zip(arg_names) {
+        match slot {
+            Some(n) => args.push(n),
+            None => {
+                return Err(CompileError::MissingArgument {
+                    span: expr_call.span(),
+                    name: name.clone(),
+                });
+            }
+        }
+    }
+
+    args.extend(rest);
+    Ok(args)
+}
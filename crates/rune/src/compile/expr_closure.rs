@@ -14,37 +14,70 @@ impl Compile<(ast::ExprClosure, &[MetaClosureCapture])> for Compiler<'_, '_> {
         let span = expr_closure.span();
         log::trace!("ExprClosure => {:?}", self.source.source(span));
 
-        let count = {
-            let scope = self.scopes.last_mut(span)?;
+        let mut defaults = false;
 
-            for (arg, _) in expr_closure.args.as_slice() {
-                let span = arg.span();
+        let args = expr_closure.args.len();
 
-                match arg {
-                    ast::FnArg::Self_(s) => {
-                        return Err(CompileError::UnsupportedSelf { span: s.span() })
+        for (index, (arg, _)) in expr_closure.args.as_slice().iter().enumerate() {
+            let span = arg.span();
+
+            match arg {
+                ast::FnArg::Self_(s) => {
+                    return Err(CompileError::UnsupportedSelf { span: s.span() })
+                }
+                ast::FnArg::Ident(ident) => {
+                    if defaults {
+                        return Err(CompileError::RequiredArgumentAfterDefault { span });
                     }
-                    ast::FnArg::Ident(ident) => {
-                        let ident = ident.resolve(self.source)?;
-                        scope.new_var(ident, span)?;
+
+                    let name = ident.resolve(self.source)?;
+                    self.new_arg_var(name, ident.span())?;
+                }
+                ast::FnArg::Default(ident, ..) => {
+                    defaults = true;
+                    let name = ident.resolve(self.source)?;
+                    self.new_arg_var(name, ident.span())?;
+                }
+                ast::FnArg::Ignore(..) => {
+                    if defaults {
+                        return Err(CompileError::RequiredArgumentAfterDefault { span });
                     }
-                    ast::FnArg::Ignore(..) => {
-                        // Ignore incoming variable.
-                        let _ = scope.decl_anon(span);
+
+                    // Ignore incoming variable.
+                    self.scopes.decl_anon(span)?;
+                }
+                ast::FnArg::Rest(_, ident) => {
+                    if index + 1 != args {
+                        return Err(CompileError::UnsupportedRestArgument { span });
                     }
+
+                    let name = ident.resolve(self.source)?;
+                    self.new_arg_var(name, ident.span())?;
                 }
             }
+        }
 
-            if !captures.is_empty() {
-                self.asm.push(Inst::PushTuple, span);
+        let argc = if defaults {
+            Some(self.scopes.decl_anon(span)?)
+        } else {
+            None
+        };
 
-                for capture in captures {
-                    scope.new_var(&capture.ident, span)?;
-                }
+        if !captures.is_empty() {
+            self.asm.push(Inst::PushTuple, span);
+
+            // NB: captured variables are always stored in shared cells.
+            for capture in captures {
+                self.scopes.new_cell(&capture.ident, span)?;
             }
+        }
 
-            scope.total_var_count
-        };
+        let count = self.scopes.last(span)?.total_var_count;
+        self.box_arg_cells(expr_closure.args.as_slice())?;
+
+        if let Some(argc) = argc {
+            self.compile_default_args(expr_closure.args.as_slice(), argc)?;
+        }
 
         self.compile((&*expr_closure.body, Needs::Value))?;
 
@@ -95,12 +128,12 @@ impl Compile<(&ast::ExprClosure, Needs)> for Compiler<'_, '_> {
             // NB: if closure doesn't capture the environment it acts like a regular
             // function. No need to store and load the environment.
             self.asm
-                .push_with_comment(Inst::Type { hash }, span, format!("closure `{}`", item));
+                .push_with_comment(Inst::Fn { hash }, span, format!("closure `{}`", item));
         } else {
             // Construct a closure environment.
             for capture in &*captures {
                 let var = self.scopes.get_var(&capture.ident, span)?;
-                var.copy(&mut self.asm, span, format!("capture `{}`", capture.ident));
+                var.capture(self.asm, span, format!("capture `{}`", capture.ident));
             }
 
             self.asm.push_with_comment(
@@ -98,8 +98,8 @@ fn try_immediate_field_access_optimization(
     };
 
     let var = match this.scopes.try_get_var(ident)? {
-        Some(var) => var,
-        None => return Ok(false),
+        Some(var) if !var.cell => var,
+        _ => return Ok(false),
     };
 
     this.asm.push(
@@ -3,7 +3,7 @@ use crate::compiler::{Compiler, Needs};
 use crate::error::CompileResult;
 use crate::loops::Loop;
 use crate::traits::{Compile, Resolve as _};
-use runestick::Inst;
+use runestick::{Assembly, Inst};
 
 /// Compile a for loop.
 impl Compile<(&ast::ExprFor, Needs)> for Compiler<'_, '_> {
@@ -41,15 +41,34 @@ impl Compile<(&ast::ExprFor, Needs)> for Compiler<'_, '_> {
             total_var_count,
             needs,
             drop: Some(iter_offset),
+            try_blocks: self.try_blocks.len(),
         });
 
+        let var_span = expr_for.var.span();
+
+        // NB: a plain variable binds each item directly, any other pattern is
+        // matched against an anonymous binding in each iteration.
+        let ident = match &expr_for.var {
+            ast::Pat::PatPath(pat_path) => pat_path.path.try_as_ident(),
+            _ => None,
+        };
+
         // Declare named loop variable.
-        let binding_offset = {
+        let (binding_offset, binding_cell) = {
             self.asm.push(Inst::Unit, expr_for.iter.span());
-            let name = expr_for.var.resolve(self.source)?;
-            self.scopes
-                .last_mut(span)?
-                .decl_var(name, expr_for.var.span())
+
+            match ident {
+                Some(ident) => {
+                    let name = ident.resolve(self.source)?;
+
+                    if self.is_cell(var_span) {
+                        (self.scopes.last_mut(span)?.decl_cell(name, var_span), true)
+                    } else {
+                        (self.scopes.last_mut(span)?.decl_var(name, var_span), false)
+                    }
+                }
+                None => (self.scopes.decl_anon(var_span)?, false),
+            }
         };
 
         // Declare storage for memoized `next` instance fn.
@@ -106,7 +125,7 @@ impl Compile<(&ast::ExprFor, Needs)> for Compiler<'_, '_> {
                 Inst::Replace {
                     offset: binding_offset,
                 },
-                expr_for.var.span(),
+                var_span,
             );
         } else {
             // call the `next` function to get the next level of iteration, bind the
@@ -130,7 +149,7 @@ impl Compile<(&ast::ExprFor, Needs)> for Compiler<'_, '_> {
                 Inst::Replace {
                     offset: binding_offset,
                 },
-                expr_for.var.span(),
+                var_span,
             );
         }
 
@@ -141,7 +160,7 @@ impl Compile<(&ast::ExprFor, Needs)> for Compiler<'_, '_> {
                 Inst::Copy {
                     offset: binding_offset,
                 },
-                expr_for.var.span(),
+                var_span,
             );
             self.asm.push(Inst::IsValue, expr_for.span());
             self.asm.jump_if_not(end_label, expr_for.span());
@@ -149,7 +168,7 @@ impl Compile<(&ast::ExprFor, Needs)> for Compiler<'_, '_> {
                 Inst::Copy {
                     offset: binding_offset,
                 },
-                expr_for.var.span(),
+                var_span,
             );
             // unwrap the optional value.
             self.asm.push(Inst::Unwrap, expr_for.span());
@@ -157,11 +176,62 @@ impl Compile<(&ast::ExprFor, Needs)> for Compiler<'_, '_> {
                 Inst::Replace {
                     offset: binding_offset,
                 },
-                expr_for.var.span(),
+                var_span,
             );
         }
 
+        // NB: each iteration binds the loop variable to a new cell, so closures
+        // capture the value of the iteration they were created in.
+        if binding_cell {
+            self.box_cell(binding_offset, var_span);
+        }
+
+        let pat_scope_expected = if ident.is_none() {
+            let mut scope = self.scopes.child(var_span)?;
+
+            let load = |asm: &mut Assembly| {
+                asm.push(
+                    Inst::Copy {
+                        offset: binding_offset,
+                    },
+                    var_span,
+                );
+            };
+
+            let false_label = self.asm.new_label("for_panic");
+
+            if self.compile_pat(&mut scope, &expr_for.var, false_label, &load)? {
+                // NB: patterns like `(k, v)` are only checked for their shape,
+                // so only warn about patterns which might not match.
+                if !self.is_irrefutable(&expr_for.var)? {
+                    self.warnings
+                        .let_pattern_might_panic(self.source_id, var_span, self.context());
+                }
+
+                let ok_label = self.asm.new_label("for_ok");
+                self.asm.jump(ok_label, var_span);
+                self.asm.label(false_label)?;
+                self.asm.push(
+                    Inst::Panic {
+                        reason: runestick::PanicReason::UnmatchedPattern,
+                    },
+                    var_span,
+                );
+
+                self.asm.label(ok_label)?;
+            }
+
+            Some(self.scopes.push(scope))
+        } else {
+            None
+        };
+
         self.compile((&*expr_for.body, Needs::None))?;
+
+        if let Some(pat_scope_expected) = pat_scope_expected {
+            self.clean_last_scope(var_span, pat_scope_expected, Needs::None)?;
+        }
+
         self.asm.jump(start_label, span);
         self.asm.label(end_label)?;
 
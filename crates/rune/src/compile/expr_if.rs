@@ -1,6 +1,6 @@
 use crate::ast;
 use crate::compiler::{Compiler, Needs};
-use crate::error::CompileResult;
+use crate::error::{CompileError, CompileResult};
 use crate::traits::Compile;
 use runestick::Inst;
 
@@ -10,6 +10,10 @@ impl Compile<(&ast::ExprIf, Needs)> for Compiler<'_, '_> {
         let span = expr_if.span();
         log::trace!("ExprIf => {:?}", self.source.source(span));
 
+        if needs.value() && expr_if.expr_else.is_none() && !expr_if.branches_produce_nothing() {
+            return Err(CompileError::IfMissingElse { span });
+        }
+
         let then_label = self.asm.new_label("if_then");
         let end_label = self.asm.new_label("if_end");
 
@@ -47,7 +51,7 @@ impl Compile<(&ast::ExprIf, Needs)> for Compiler<'_, '_> {
 
         let mut it = branches.into_iter().peekable();
 
-        if let Some((branch, label, scope)) = it.next() {
+        while let Some((branch, label, scope)) = it.next() {
             let span = branch.span();
 
             self.asm.label(label)?;
@@ -36,6 +36,12 @@ impl Compile<(&ast::ExprLet, Needs)> for Compiler<'_, '_> {
             self.asm.label(ok_label)?;
         }
 
+        // NB: an ignore pattern doesn't bind the value, so it needs to be
+        // popped to keep the stack balanced.
+        if let ast::Pat::PatIgnore(..) = &expr_let.pat {
+            self.asm.push(Inst::Pop, span);
+        }
+
         let _ = self.scopes.push(scope);
 
         // If a value is needed for a let expression, it is evaluated as a unit.
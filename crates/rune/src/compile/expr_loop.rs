@@ -21,6 +21,7 @@ impl Compile<(&ast::ExprLoop, Needs)> for Compiler<'_, '_> {
             total_var_count: self.scopes.last(span)?.total_var_count,
             needs,
             drop: None,
+            try_blocks: self.try_blocks.len(),
         });
 
         self.asm.label(start_label)?;
@@ -0,0 +1,47 @@
+use crate::ast;
+use crate::compiler::{Compiler, Needs};
+use crate::error::CompileResult;
+use crate::traits::Compile;
+use runestick::Inst;
+
+/// Compile a range expression.
+impl Compile<(&ast::ExprRange, Needs)> for Compiler<'_, '_> {
+    fn compile(&mut self, (expr_range, needs): (&ast::ExprRange, Needs)) -> CompileResult<()> {
+        let span = expr_range.span();
+        log::trace!("ExprRange => {:?}", self.source.source(span));
+
+        // If the value is not needed, no need to encode it.
+        if !needs.value() && expr_range.is_const() {
+            self.warnings.not_used(self.source_id, span, self.context());
+            return Ok(());
+        }
+
+        let scope = self.scopes.last(span)?.child();
+        let guard = self.scopes.push(scope);
+
+        if let Some(from) = &expr_range.from {
+            self.compile((&**from, Needs::Value))?;
+            self.scopes.decl_anon(span)?;
+        }
+
+        if let Some(to) = &expr_range.to {
+            self.compile((&**to, Needs::Value))?;
+            self.scopes.decl_anon(span)?;
+        }
+
+        self.asm.push(
+            Inst::Range {
+                start: expr_range.from.is_some(),
+                end: expr_range.to.is_some(),
+            },
+            span,
+        );
+
+        if !needs.value() {
+            self.asm.push(Inst::Pop, span);
+        }
+
+        self.scopes.pop(guard, span)?;
+        Ok(())
+    }
+}
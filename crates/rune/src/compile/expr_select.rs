@@ -58,7 +58,7 @@ impl Compile<(&ast::ExprSelect, Needs)> for Compiler<'_, '_> {
                         let item = self.convert_path_to_item(&path.path)?;
 
                         if let Some(local) = item.as_local() {
-                            scope.decl_var(local, span);
+                            self.decl_pat_var(&mut scope, local, path.span());
                             break;
                         }
                     }
@@ -2,6 +2,7 @@ use crate::ast;
 use crate::compiler::{Compiler, Needs};
 use crate::error::CompileResult;
 use crate::traits::Compile;
+use crate::CompileError;
 use runestick::Inst;
 
 /// Compile a try expression.
@@ -13,20 +14,33 @@ impl Compile<(&ast::ExprTry, Needs)> for Compiler<'_, '_> {
         let not_error = self.asm.new_label("try_not_error");
 
         self.compile((&*expr_try.expr, Needs::Value))?;
-        self.asm.push(Inst::Dup, span);
-        self.asm.push(Inst::IsValue, span);
+        self.asm.push(Inst::Try, span);
         self.asm.jump_if(not_error, span);
 
-        // Clean up all locals so far and return from the current function.
         let total_var_count = self.scopes.last(span)?.total_var_count;
-        self.locals_clean(total_var_count, span);
-        self.asm.push(Inst::Return, span);
 
-        self.asm.label(not_error)?;
+        if let Some(try_block) = self.try_blocks.last().copied() {
+            // Clean up all locals in the `try` block and hand the error to it.
+            let vars = total_var_count
+                .checked_sub(try_block.total_var_count)
+                .ok_or_else(|| CompileError::internal("var count should be larger", span))?;
+
+            self.locals_clean(vars, span);
 
-        if needs.value() {
-            self.asm.push(Inst::Unwrap, span);
+            if try_block.catch {
+                self.asm.push(Inst::PopCatch, span);
+            }
+
+            self.asm.jump(try_block.error_label, span);
         } else {
+            // Clean up all locals so far and return from the current function.
+            self.locals_clean(total_var_count, span);
+            self.asm.push(Inst::Return, span);
+        }
+
+        self.asm.label(not_error)?;
+
+        if !needs.value() {
             self.asm.push(Inst::Pop, span);
         }
 
@@ -25,6 +25,9 @@ impl Compile<(&ast::LitNumber, Needs)> for Compiler<'_, '_> {
             ast::Number::Integer(number) => {
                 self.asm.push(Inst::Integer { number }, span);
             }
+            ast::Number::Byte(b) => {
+                self.asm.push(Inst::Byte { b }, span);
+            }
         }
 
         Ok(())
@@ -33,11 +33,14 @@ impl Compile<(&ast::LitObject, Needs)> for Compiler<'_, '_> {
                 return Err(CompileError::DuplicateObjectKey {
                     span,
                     existing,
-                    object: span,
+                    object: lit_object.span(),
                 });
             }
         }
 
+        let scope = self.scopes.child(span)?;
+        let guard = self.scopes.push(scope);
+
         for assign in lit_object.assignments.iter() {
             let span = assign.span();
 
@@ -46,7 +49,9 @@ impl Compile<(&ast::LitObject, Needs)> for Compiler<'_, '_> {
 
                 // Evaluate the expressions one by one, then pop them to cause any
                 // side effects (without creating an object).
-                if !needs.value() {
+                if needs.value() {
+                    self.scopes.decl_anon(span)?;
+                } else {
                     self.asm.push(Inst::Pop, span);
                 }
             } else {
@@ -55,10 +60,13 @@ impl Compile<(&ast::LitObject, Needs)> for Compiler<'_, '_> {
 
                 if needs.value() {
                     var.copy(&mut self.asm, span, format!("name `{}`", key));
+                    self.scopes.decl_anon(span)?;
                 }
             }
         }
 
+        self.scopes.pop(guard, span)?;
+
         // No need to encode an object since the value is not needed.
         if !needs.value() {
             self.warnings.not_used(self.source_id, span, self.context());
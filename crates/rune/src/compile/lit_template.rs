@@ -37,6 +37,11 @@ impl Compile<(&ast::LitTemplate, Needs)> for Compiler<'_, '_> {
                     self.compile((&**expr, Needs::Value))?;
                     self.scopes.decl_anon(span)?;
                 }
+                ast::TemplateComponent::DebugExpr(expr, pretty) => {
+                    self.compile((&**expr, Needs::Value))?;
+                    self.asm.push(Inst::StringDebug { pretty: *pretty }, span);
+                    self.scopes.decl_anon(span)?;
+                }
             }
         }
 
@@ -16,10 +16,16 @@ impl Compile<(&ast::LitTuple, Needs)> for Compiler<'_, '_> {
             return Ok(());
         }
 
+        let scope = self.scopes.child(span)?;
+        let guard = self.scopes.push(scope);
+
         for (expr, _) in lit_tuple.items.iter() {
             self.compile((expr, Needs::Value))?;
+            self.scopes.decl_anon(span)?;
         }
 
+        self.scopes.pop(guard, span)?;
+
         self.asm.push(
             Inst::Tuple {
                 count: lit_tuple.items.len(),
@@ -17,16 +17,23 @@ impl Compile<(&ast::LitVec, Needs)> for Compiler<'_, '_> {
 
         let count = lit_vec.items.len();
 
+        let scope = self.scopes.child(span)?;
+        let guard = self.scopes.push(scope);
+
         for expr in lit_vec.items.iter() {
             self.compile((expr, Needs::Value))?;
 
             // Evaluate the expressions one by one, then pop them to cause any
             // side effects (without creating an object).
-            if !needs.value() {
+            if needs.value() {
+                self.scopes.decl_anon(span)?;
+            } else {
                 self.asm.push(Inst::Pop, span);
             }
         }
 
+        self.scopes.pop(guard, span)?;
+
         // No need to create a vector if it's not needed.
         if !needs.value() {
             self.warnings.not_used(self.source_id, span, self.context());
@@ -15,6 +15,7 @@ mod expr_let;
 mod expr_loop;
 mod expr_match;
 mod expr_path;
+mod expr_range;
 mod expr_return;
 mod expr_select;
 mod expr_self;
@@ -3,7 +3,8 @@ use crate::collections::HashMap;
 use crate::error::CompileError;
 use crate::traits::{Compile as _, Resolve as _};
 use runestick::{
-    Assembly, Component, Context, ImportKey, Inst, Item, Label, Meta, Source, Span, TypeCheck, Unit,
+    Assembly, Component, ConstValue, Context, ImportKey, Inst, Item, Label, Meta, Source, Span,
+    TypeCheck, Unit, UnitFnSignature,
 };
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -34,6 +35,18 @@ impl Needs {
     }
 }
 
+/// A `try` block we are currently inside of.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TryBlock {
+    /// The label to jump to with the error when a `?` fails.
+    pub(crate) error_label: Label,
+    /// The number of variables observed at the start of the block.
+    pub(crate) total_var_count: usize,
+    /// If the block has pushed a catch boundary which must be popped when
+    /// leaving it.
+    pub(crate) catch: bool,
+}
+
 /// Compile the given source with default options.
 pub fn compile(
     context: &Context,
@@ -79,6 +92,7 @@ pub fn compile_with_options(
             contexts: vec![],
             source,
             loops: Loops::new(),
+            try_blocks: Vec::new(),
             options,
             warnings,
         };
@@ -86,15 +100,25 @@ pub fn compile_with_options(
         match build {
             Build::Function(f) => {
                 let span = f.ast.span();
-                let count = f.ast.args.items.len();
+                let signature = UnitFnSignature {
+                    path: item,
+                    args: f.ast.fixed_args(),
+                    defaults: f.ast.default_args(),
+                    variadic: f.ast.is_variadic(),
+                };
                 compiler.contexts.push(span);
                 compiler.compile((f.ast, false))?;
                 unit.borrow_mut()
-                    .new_function(source_id, item, count, asm, f.call)?;
+                    .new_function(source_id, signature, asm, f.call)?;
             }
             Build::InstanceFunction(f) => {
                 let span = f.ast.span();
-                let count = f.ast.args.items.len();
+                let signature = UnitFnSignature {
+                    path: item,
+                    args: f.ast.fixed_args(),
+                    defaults: f.ast.default_args(),
+                    variadic: f.ast.is_variadic(),
+                };
                 compiler.contexts.push(span);
 
                 let name = f.ast.name.resolve(&source)?;
@@ -115,23 +139,28 @@ pub fn compile_with_options(
 
                 compiler.compile((f.ast, true))?;
                 unit.borrow_mut()
-                    .new_instance_function(source_id, item, value_type, name, count, asm, f.call)?;
+                    .new_instance_function(source_id, signature, value_type, name, asm, f.call)?;
             }
             Build::Closure(c) => {
                 let span = c.ast.span();
-                let count = c.ast.args.len();
+                let signature = UnitFnSignature {
+                    path: item,
+                    args: c.ast.args.fixed_args(),
+                    defaults: c.ast.args.default_args(),
+                    variadic: c.ast.args.is_variadic(),
+                };
                 compiler.contexts.push(span);
                 compiler.compile((c.ast, &c.captures[..]))?;
                 unit.borrow_mut()
-                    .new_function(source_id, item, count, asm, c.call)?;
+                    .new_function(source_id, signature, asm, c.call)?;
             }
             Build::AsyncBlock(async_block) => {
                 let span = async_block.ast.span();
-                let args = async_block.captures.len();
+                let signature = UnitFnSignature::new(item, async_block.captures.len());
                 compiler.contexts.push(span);
                 compiler.compile((async_block.ast, &async_block.captures[..]))?;
                 unit.borrow_mut()
-                    .new_function(source_id, item, args, asm, async_block.call)?;
+                    .new_function(source_id, signature, asm, async_block.call)?;
             }
         }
     }
@@ -201,15 +230,14 @@ fn process_imports(
             continue;
         }
 
+        // NB: the prelude refers to default modules which might not have been
+        // installed in the context. Using them results in a missing function
+        // error instead.
         if let Some(span) = entry.span {
             return Err(CompileError::MissingModule {
                 span,
                 item: entry.item.clone(),
             });
-        } else {
-            return Err(CompileError::MissingPreludeModule {
-                item: entry.item.clone(),
-            });
         }
     }
 
@@ -236,6 +264,8 @@ pub(crate) struct Compiler<'a, 'source> {
     pub(crate) source: &'source Source,
     /// The nesting of loop we are currently in.
     pub(crate) loops: Loops,
+    /// The `try` blocks we are currently inside of.
+    pub(crate) try_blocks: Vec<TryBlock>,
     /// Enabled optimizations.
     pub(crate) options: &'a Options,
     /// Compilation warnings.
@@ -354,6 +384,23 @@ impl<'a, 'source> Compiler<'a, 'source> {
                     self.asm
                         .push_with_comment(Inst::Fn { hash }, span, format!("fn `{}`", item));
                 }
+                Meta::MetaConstant { item, value } => {
+                    let inst = match value {
+                        ConstValue::Unit => Inst::Unit,
+                        ConstValue::Bool(value) => Inst::Bool { value: *value },
+                        ConstValue::Byte(b) => Inst::Byte { b: *b },
+                        ConstValue::Char(c) => Inst::Char { c: *c },
+                        ConstValue::Integer(number) => Inst::Integer { number: *number },
+                        ConstValue::Float(number) => Inst::Float { number: *number },
+                        ConstValue::String(string) => {
+                            let slot = self.unit.borrow_mut().new_static_string(string)?;
+                            Inst::String { slot }
+                        }
+                    };
+
+                    self.asm
+                        .push_with_comment(inst, span, format!("const `{}`", item));
+                }
                 meta => {
                     return Err(CompileError::UnsupportedValue {
                         span,
@@ -386,8 +433,18 @@ impl<'a, 'source> Compiler<'a, 'source> {
         loop {
             let key = ImportKey::new(base.clone(), local.clone());
 
+            let declared = self.query.contains_item(&base.extended(local.clone()));
+
+            // NB: items declared in the source shadow imports further out and
+            // imports from the prelude, which are the ones without a span.
             if let Some(entry) = unit.lookup_import(&key) {
-                return Some(entry.item.clone());
+                if entry.span.is_some() || !declared {
+                    return Some(entry.item.clone());
+                }
+            }
+
+            if declared {
+                return None;
             }
 
             if base.pop().is_none() {
@@ -718,7 +775,7 @@ impl<'a, 'source> Compiler<'a, 'source> {
 
             load(&mut self.asm);
             let name = ident.resolve(self.source)?;
-            scope.decl_var(name, span);
+            self.decl_pat_var(scope, name, span);
         }
 
         Ok(())
@@ -800,7 +857,7 @@ impl<'a, 'source> Compiler<'a, 'source> {
                 };
 
                 load(&mut self.asm);
-                scope.decl_var(&ident, span);
+                self.decl_pat_var(scope, ident, span);
                 return Ok(false);
             }
             ast::Pat::PatIgnore(..) => {
@@ -825,15 +882,19 @@ impl<'a, 'source> Compiler<'a, 'source> {
                 let span = number_literal.span();
                 let number = number_literal.resolve(self.source)?;
 
-                let integer = match number {
-                    ast::Number::Integer(integer) => integer,
+                load(self.asm);
+
+                match number {
+                    ast::Number::Integer(integer) => {
+                        self.asm.push(Inst::EqInteger { integer }, span);
+                    }
+                    ast::Number::Byte(byte) => {
+                        self.asm.push(Inst::EqByte { byte }, span);
+                    }
                     ast::Number::Float(..) => {
                         return Err(CompileError::MatchFloatInPattern { span });
                     }
-                };
-
-                load(&mut self.asm);
-                self.asm.push(Inst::EqInteger { integer }, span);
+                }
             }
             ast::Pat::PatString(pat_string) => {
                 let span = pat_string.span();
@@ -861,6 +922,44 @@ impl<'a, 'source> Compiler<'a, 'source> {
         Ok(true)
     }
 
+    /// Test if the given pattern always matches, which is the case for
+    /// bindings, ignored bindings, and anonymous tuples or objects which only
+    /// contain irrefutable patterns.
+    pub(crate) fn is_irrefutable(&mut self, pat: &ast::Pat) -> CompileResult<bool> {
+        Ok(match pat {
+            ast::Pat::PatIgnore(..) => true,
+            ast::Pat::PatPath(path) => {
+                let item = self.convert_path_to_item(&path.path)?;
+                item.as_local().is_some() && self.lookup_meta(&item, path.span())?.is_none()
+            }
+            ast::Pat::PatTuple(pat_tuple) if pat_tuple.path.is_none() => {
+                for (pat, _) in &pat_tuple.items {
+                    if !self.is_irrefutable(pat)? {
+                        return Ok(false);
+                    }
+                }
+
+                true
+            }
+            ast::Pat::PatObject(pat_object) => {
+                if let ast::LitObjectIdent::Named(..) = pat_object.ident {
+                    return Ok(false);
+                }
+
+                for (item, _) in &pat_object.fields {
+                    if let Some((_, pat)) = &item.binding {
+                        if !self.is_irrefutable(pat)? {
+                            return Ok(false);
+                        }
+                    }
+                }
+
+                true
+            }
+            _ => false,
+        })
+    }
+
     /// Clean the last scope.
     pub(crate) fn clean_last_scope(
         &mut self,
@@ -883,4 +982,136 @@ impl<'a, 'source> Compiler<'a, 'source> {
     pub(crate) fn context(&self) -> Option<Span> {
         self.contexts.last().copied()
     }
+
+    /// Compile the evaluation of default arguments which were omitted by the
+    /// caller.
+    ///
+    /// The caller passes the number of arguments it was called with as a
+    /// trailing argument, which is stored in the `argc` slot.
+    pub(crate) fn compile_default_args(
+        &mut self,
+        args: &[(ast::FnArg, Option<ast::Comma>)],
+        argc: usize,
+    ) -> CompileResult<()> {
+        for (index, (arg, _)) in args.iter().enumerate() {
+            let (ident, expr) = match arg {
+                ast::FnArg::Default(ident, _, expr) => (ident, expr),
+                _ => continue,
+            };
+
+            let span = arg.span();
+            let name = ident.resolve(self.source)?;
+            let offset = self.scopes.get_var(name, span)?.offset;
+            let provided = self.asm.new_label("default_arg_provided");
+
+            self.asm.push(Inst::Copy { offset: argc }, span);
+            self.asm.push(
+                Inst::Integer {
+                    number: index as i64,
+                },
+                span,
+            );
+            self.asm.push(Inst::Gt, span);
+            self.asm.jump_if(provided, span);
+
+            self.compile((&**expr, Needs::Value))?;
+            self.asm.push(Inst::Replace { offset }, span);
+            self.asm.label(provided)?;
+
+            if self.scopes.get_var(name, span)?.cell {
+                self.box_cell(offset, span);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Test if the variable declared at the given span is captured by a
+    /// closure, and therefore has to be stored in a shared cell.
+    pub(crate) fn is_cell(&self, span: Span) -> bool {
+        self.query.cells.contains(&span)
+    }
+
+    /// Box the value in the given slot into a shared cell.
+    pub(crate) fn box_cell(&mut self, offset: usize, span: Span) {
+        self.asm.push(Inst::Copy { offset }, span);
+        self.asm.push(Inst::Tuple { count: 1 }, span);
+        self.asm.push(Inst::Replace { offset }, span);
+    }
+
+    /// Declare a new argument variable at the given span, which is stored in
+    /// a shared cell if it's captured by a closure.
+    pub(crate) fn new_arg_var(&mut self, name: &str, span: Span) -> CompileResult<usize> {
+        if self.is_cell(span) {
+            self.scopes.new_cell(name, span)
+        } else {
+            self.scopes.new_var(name, span)
+        }
+    }
+
+    /// Declare the variable bound by a pattern to the value on top of the
+    /// stack, boxing it into a shared cell if it's captured by a closure.
+    pub(crate) fn decl_pat_var(&mut self, scope: &mut Scope, name: &str, span: Span) {
+        if self.is_cell(span) {
+            self.asm.push(Inst::Tuple { count: 1 }, span);
+            scope.decl_cell(name, span);
+        } else {
+            scope.decl_var(name, span);
+        }
+    }
+
+    /// Box the arguments of a function or closure which are captured by a
+    /// closure into shared cells.
+    ///
+    /// Arguments with default values are boxed by
+    /// [compile_default_args][Self::compile_default_args] once they've been
+    /// evaluated.
+    pub(crate) fn box_arg_cells(
+        &mut self,
+        args: &[(ast::FnArg, Option<ast::Comma>)],
+    ) -> CompileResult<()> {
+        for (arg, _) in args {
+            let span = arg.span();
+
+            let name = match arg {
+                ast::FnArg::Self_(..) => "self",
+                ast::FnArg::Ident(ident) | ast::FnArg::Rest(_, ident) => {
+                    ident.resolve(self.source)?
+                }
+                ast::FnArg::Default(..) | ast::FnArg::Ignore(..) => continue,
+            };
+
+            let var = self.scopes.get_var(name, span)?;
+
+            if var.cell {
+                let offset = var.offset;
+                self.box_cell(offset, span);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Warn about the first statement in the block which follows an
+    /// unconditional `return` or `break`.
+    pub(crate) fn warn_unreachable_code(&mut self, block: &ast::ExprBlock) {
+        let mut exprs = block
+            .exprs
+            .iter()
+            .map(|(expr, _)| expr)
+            .chain(block.trailing_expr.as_deref());
+
+        while let Some(expr) = exprs.next() {
+            if !expr.diverges() {
+                continue;
+            }
+
+            if let Some(unreachable) = exprs.next() {
+                self.warnings
+                    .unreachable_code(self.source_id, unreachable.span(), expr.span());
+            }
+
+            break;
+        }
+    }
 }
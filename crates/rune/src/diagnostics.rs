@@ -5,6 +5,7 @@ use runestick::{LinkerError, Unit, VmError};
 use std::error::Error as _;
 use std::fmt;
 use std::io;
+use std::ops::Range;
 use thiserror::Error;
 
 use codespan_reporting::diagnostic::{Diagnostic, Label};
@@ -14,6 +15,9 @@ use codespan_reporting::term::termcolor::WriteColor;
 
 pub use codespan_reporting::term::termcolor;
 
+/// The number of columns a tab is expanded to when rendering a source.
+const TAB_WIDTH: usize = 4;
+
 /// Errors that can be raised when formatting diagnostics.
 #[derive(Debug, Error)]
 pub enum DiagnosticsError {
@@ -38,8 +42,7 @@ where
 {
     use std::fmt::Write as _;
 
-    let config = codespan_reporting::term::Config::default();
-    let mut files = SimpleFiles::new();
+    let mut files = Files::new();
 
     if let Some(debug_info) = unit.debug_info() {
         for (source_id, source) in debug_info.sources() {
@@ -48,10 +51,10 @@ where
         }
     }
 
-    let mut labels = Vec::new();
-    let mut notes = Vec::new();
-
     for w in warnings {
+        let mut labels = Vec::new();
+        let mut notes = Vec::new();
+
         let context = match &w.kind {
             WarningKind::NotUsed { span, context } => {
                 labels.push(
@@ -121,6 +124,19 @@ where
                         .with_message("unnecessary semicolon"),
                 );
 
+                None
+            }
+            WarningKind::UnreachableCode { span, cause } => {
+                labels.push(
+                    Label::primary(w.source_id, span.start..span.end)
+                        .with_message("unreachable code"),
+                );
+
+                labels.push(
+                    Label::secondary(w.source_id, cause.start..cause.end)
+                        .with_message("any code following this expression is unreachable"),
+                );
+
                 None
             }
         };
@@ -131,14 +147,16 @@ where
                     .with_message("in this context"),
             );
         }
-    }
 
-    let diagnostic = Diagnostic::warning()
-        .with_message("warning")
-        .with_labels(labels)
-        .with_notes(notes);
+        let diagnostic = Diagnostic::warning()
+            .with_message("warning")
+            .with_code(w.kind.lint().code())
+            .with_labels(labels)
+            .with_notes(notes);
+
+        emit(out, &files, diagnostic)?;
+    }
 
-    term::emit(out, &config, &files, &diagnostic)?;
     Ok(())
 }
 
@@ -206,9 +224,7 @@ impl EmitDiagnostics for VmError {
             }
         };
 
-        let config = codespan_reporting::term::Config::default();
-
-        let mut files = SimpleFiles::new();
+        let mut files = Files::new();
         let id = files.add(source.name(), source.as_str());
 
         let mut labels = Vec::new();
@@ -218,9 +234,10 @@ impl EmitDiagnostics for VmError {
 
         let diagnostic = Diagnostic::error()
             .with_message("virtual machine error")
+            .with_code(error.kind().code())
             .with_labels(labels);
 
-        term::emit(out, &config, &files, &diagnostic)?;
+        emit(out, &files, diagnostic)?;
         Ok(())
     }
 }
@@ -230,11 +247,10 @@ impl EmitDiagnostics for LoadError {
     where
         O: WriteColor,
     {
-        let config = codespan_reporting::term::Config::default();
-
         let mut labels = Vec::new();
+        let mut notes = Vec::new();
 
-        let (span, source) = match self.kind() {
+        let (span, source, code) = match self.kind() {
             LoadErrorKind::ReadFile { error, path } => {
                 writeln!(out, "failed to read file: {}: {}", path.display(), error)?;
                 return Ok(());
@@ -243,7 +259,7 @@ impl EmitDiagnostics for LoadError {
                 errors,
                 code_source: source,
             } => {
-                let mut files = SimpleFiles::new();
+                let mut files = Files::new();
                 let source_id = files.add(source.name(), source.as_str());
 
                 for error in errors {
@@ -262,67 +278,45 @@ impl EmitDiagnostics for LoadError {
                                 .with_message(format!("missing function with hash `{}`", hash))
                                 .with_labels(labels);
 
-                            term::emit(out, &config, &files, &diagnostic)?;
+                            emit(out, &files, diagnostic)?;
                         }
                     }
                 }
 
                 return Ok(());
             }
-            LoadErrorKind::CompileError {
-                error,
+            LoadErrorKind::DeniedWarning {
+                warning,
                 code_source: source,
             } => {
-                let span = match error {
-                    CompileError::ReturnLocalReferences {
-                        block,
-                        references_at,
-                        span,
-                        ..
-                    } => {
-                        for ref_span in references_at {
-                            if span.overlaps(*ref_span) {
-                                continue;
-                            }
+                let span = warning.kind.span();
 
-                            labels.push(
-                                Label::secondary(0, ref_span.start..ref_span.end)
-                                    .with_message("reference created here"),
-                            );
-                        }
+                labels.push(
+                    Label::primary(0, span.start..span.end)
+                        .with_message(format!("`{}` is denied", warning.kind.lint())),
+                );
 
-                        labels.push(
-                            Label::secondary(0, block.start..block.end)
-                                .with_message("block returned from"),
-                        );
+                (span, source, warning.kind.lint().code())
+            }
+            LoadErrorKind::CompileError {
+                error,
+                code_source: source,
+            } => {
+                for (span, message) in error.related() {
+                    labels.push(Label::secondary(0, span.start..span.end).with_message(message));
+                }
 
-                        *span
+                if let CompileError::ParseError { error } = error {
+                    if let Some(suggestion) = error.suggestion(source.as_str()) {
+                        notes.push(format!("Consider rewriting to `{}`", suggestion));
                     }
-                    CompileError::DuplicateObjectKey {
-                        span,
-                        existing,
-                        object,
-                    } => {
-                        labels.push(
-                            Label::secondary(0, existing.start..existing.end)
-                                .with_message("previously defined here"),
-                        );
-
-                        labels.push(
-                            Label::secondary(0, object.start..object.end)
-                                .with_message("object being defined here"),
-                        );
-
-                        *span
-                    }
-                    error => error.span(),
-                };
+                }
 
-                (span, source)
+                (error.span(), source, error.code())
             }
         };
 
-        let mut files = SimpleFiles::new();
+        let mut files = Files::new();
         let source_id = files.add(source.name(), source.as_str());
 
         if let Some(e) = self.source() {
@@ -332,9 +326,97 @@ impl EmitDiagnostics for LoadError {
 
         let diagnostic = Diagnostic::error()
             .with_message(self.to_string())
-            .with_labels(labels);
+            .with_code(code)
+            .with_labels(labels)
+            .with_notes(notes);
 
-        term::emit(out, &config, &files, &diagnostic)?;
+        emit(out, &files, diagnostic)?;
         Ok(())
     }
 }
+
+/// Emit the given diagnostic, translating its labels to the sources in
+/// `files`.
+fn emit<O>(out: &mut O, files: &Files, mut diagnostic: Diagnostic<usize>) -> io::Result<()>
+where
+    O: WriteColor,
+{
+    let config = term::Config::default();
+
+    for label in &mut diagnostic.labels {
+        label.range = files.range(label.file_id, label.range.clone());
+    }
+
+    term::emit(out, &config, &files.files, &diagnostic)
+}
+
+/// The sources diagnostics are rendered from.
+///
+/// Tabs are expanded into spaces as sources are added, since they would
+/// otherwise be rendered with a different width than the carets underlining
+/// them.
+struct Files {
+    files: SimpleFiles<String, String>,
+    /// The tabs in each source, as the byte offset of the tab and the number
+    /// of bytes it grew by when expanded.
+    tabs: Vec<Vec<(usize, usize)>>,
+}
+
+impl Files {
+    fn new() -> Self {
+        Self {
+            files: SimpleFiles::new(),
+            tabs: Vec::new(),
+        }
+    }
+
+    /// Add the given source, returning its file id.
+    fn add(&mut self, name: &str, source: &str) -> usize {
+        let mut expanded = String::with_capacity(source.len());
+        let mut tabs = Vec::new();
+        let mut column = 0;
+
+        for (n, c) in source.char_indices() {
+            match c {
+                '\t' => {
+                    let width = TAB_WIDTH - column % TAB_WIDTH;
+                    for _ in 0..width {
+                        expanded.push(' ');
+                    }
+
+                    tabs.push((n, width - 1));
+                    column += width;
+                }
+                '\n' => {
+                    expanded.push(c);
+                    column = 0;
+                }
+                c => {
+                    expanded.push(c);
+                    column += 1;
+                }
+            }
+        }
+
+        self.tabs.push(tabs);
+        self.files.add(name.to_owned(), expanded)
+    }
+
+    /// Translate a range in an original source into its expanded source.
+    fn range(&self, file_id: usize, range: Range<usize>) -> Range<usize> {
+        let tabs = match self.tabs.get(file_id) {
+            Some(tabs) => tabs,
+            None => return range,
+        };
+
+        let translate = |pos: usize| {
+            pos + tabs
+                .iter()
+                .take_while(|(n, _)| *n < pos)
+                .map(|(_, grown)| grown)
+                .sum::<usize>()
+        };
+
+        translate(range.start)..translate(range.end)
+    }
+}
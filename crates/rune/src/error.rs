@@ -243,6 +243,12 @@ pub enum ParseError {
         /// Span of the illegal number literal.
         span: Span,
     },
+    /// Byte number out of bounds.
+    #[error("number literal out of bounds `0` to `255`")]
+    BadByteOutOfBounds {
+        /// Span of the illegal number literal.
+        span: Span,
+    },
     /// A bad character literal.
     #[error("bad character literal")]
     BadCharLiteral {
@@ -319,6 +325,23 @@ pub enum ParseError {
         /// Where the expression is.
         span: Span,
     },
+    /// Comparison operators were chained, like `a < b < c`.
+    ///
+    /// See [suggestion][Self::suggestion] for how to get the suggested
+    /// rewrite.
+    #[error("comparison operators cannot be chained")]
+    ChainedComparison {
+        /// Span of the chained comparison.
+        span: Span,
+        /// The first comparison, like `a < b`.
+        first: Span,
+        /// The operand shared by both comparisons, like `b`.
+        shared: Span,
+        /// The second comparison operator.
+        op: ast::BinOp,
+        /// The last operand, like `c`.
+        last: Span,
+    },
 }
 
 impl ParseError {
@@ -355,6 +378,7 @@ impl ParseError {
             Self::BadEscapeSequence { span, .. } => span,
             Self::BadNumberLiteral { span, .. } => span,
             Self::BadNumberOutOfBounds { span, .. } => span,
+            Self::BadByteOutOfBounds { span, .. } => span,
             Self::BadCharLiteral { span, .. } => span,
             Self::BadByteLiteral { span, .. } => span,
             Self::UnicodeEscapeNotSupported { span, .. } => span,
@@ -368,6 +392,84 @@ impl ParseError {
             Self::ExpectedFunctionArgument { span, .. } => span,
             Self::ExpectedDeclUseImportComponent { span, .. } => span,
             Self::UnsupportedAsyncExpr { span, .. } => span,
+            Self::ChainedComparison { span, .. } => span,
+        }
+    }
+
+    /// Get the stable code identifying the kind of parse error, like
+    /// `P0001`.
+    ///
+    /// Codes are never reused or changed once assigned, new kinds of errors
+    /// are given new codes.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::UnexpectedEof { .. } => "P0001",
+            Self::ExpectedEof { .. } => "P0002",
+            Self::ExpectedDeclUseImportComponent { .. } => "P0003",
+            Self::ExpectedStringEscape { .. } => "P0004",
+            Self::UnterminatedStrLit { .. } => "P0005",
+            Self::UnterminatedCharLit { .. } => "P0006",
+            Self::UnterminatedByteLit { .. } => "P0007",
+            Self::ExpectedCharClose { .. } => "P0008",
+            Self::ExpectedByteClose { .. } => "P0009",
+            Self::ExpectedTemplateClose { .. } => "P0010",
+            Self::ExpectedCharEscape { .. } => "P0011",
+            Self::TokenMismatch { .. } => "P0012",
+            Self::ExpectedPatError { .. } => "P0013",
+            Self::ExpectedExpr { .. } => "P0014",
+            Self::ExpectedEnumVariant { .. } => "P0015",
+            Self::ExpectedLoop { .. } => "P0016",
+            Self::ExpectedBlockExpr { .. } => "P0017",
+            Self::UnexpectedChar { .. } => "P0018",
+            Self::ExpectedNumber { .. } => "P0019",
+            Self::ExpectedByte { .. } => "P0020",
+            Self::ExpectedChar { .. } => "P0021",
+            Self::ExpectedString { .. } => "P0022",
+            Self::ExpectedOperator { .. } => "P0023",
+            Self::ExpectedBool { .. } => "P0024",
+            Self::ExpectedLitObjectKey { .. } => "P0025",
+            Self::ExpectedUnaryOperator { .. } => "P0026",
+            Self::PrecedenceGroupRequired { .. } => "P0027",
+            Self::BadSlice { .. } => "P0028",
+            Self::BadEscapeSequence { .. } => "P0029",
+            Self::BadNumberLiteral { .. } => "P0030",
+            Self::BadNumberOutOfBounds { .. } => "P0031",
+            Self::BadCharLiteral { .. } => "P0032",
+            Self::BadByteLiteral { .. } => "P0033",
+            Self::UnicodeEscapeNotSupported { .. } => "P0034",
+            Self::BadUnicodeEscape { .. } => "P0035",
+            Self::UnsupportedUnicodeByteEscape { .. } => "P0036",
+            Self::UnsupportedByteEscape { .. } => "P0037",
+            Self::BadByteEscape { .. } => "P0038",
+            Self::InvalidTemplateLiteral { .. } => "P0039",
+            Self::UnexpectedCloseBrace { .. } => "P0040",
+            Self::UnsupportedFieldAccess { .. } => "P0041",
+            Self::ExpectedFunctionArgument { .. } => "P0042",
+            Self::UnsupportedAsyncExpr { .. } => "P0043",
+            Self::ChainedComparison { .. } => "P0044",
+            Self::BadByteOutOfBounds { .. } => "P0045",
+        }
+    }
+
+    /// Get a suggested rewrite of the code which caused the error, given the
+    /// source it was parsed from.
+    ///
+    /// For a chained comparison like `a < b < c` this is `a < b && b < c`.
+    pub fn suggestion(&self, source: &str) -> Option<String> {
+        match *self {
+            Self::ChainedComparison {
+                first,
+                shared,
+                op,
+                last,
+                ..
+            } => {
+                let first = source.get(first.start..first.end)?;
+                let shared = source.get(shared.start..shared.end)?;
+                let last = source.get(last.start..last.end)?;
+                Some(format!("{} && {} {} {}", first, shared, op, last))
+            }
+            _ => None,
         }
     }
 }
@@ -490,6 +592,18 @@ pub enum CompileError {
         /// Where it occured.
         span: Span,
     },
+    /// A rest argument occured in an unsupported position.
+    #[error("rest argument must be the last argument")]
+    UnsupportedRestArgument {
+        /// Where it occured.
+        span: Span,
+    },
+    /// A required argument followed an argument with a default value.
+    #[error("required argument follows an argument with a default value")]
+    RequiredArgumentAfterDefault {
+        /// Where it occured.
+        span: Span,
+    },
     /// Encountered a unary operator we can't encode.
     #[error("unsupported unary operator `{op}`")]
     UnsupportedUnaryOp {
@@ -585,6 +699,54 @@ pub enum CompileError {
         /// The actual number of arguments.
         actual: usize,
     },
+    /// Tried to call a function with the wrong number of arguments.
+    #[error(
+        "wrong number of arguments in function call, expected `{expected}` but got `{actual}`"
+    )]
+    ArgumentCountMismatch {
+        /// The span of the call.
+        span: Span,
+        /// The number of arguments the function accepts.
+        expected: usize,
+        /// The number of arguments the function was called with.
+        actual: usize,
+    },
+    /// Named arguments used in a call which isn't to a declared function.
+    #[error("named arguments are only supported when calling a declared function")]
+    UnsupportedNamedArgument {
+        /// The span of the named argument.
+        span: Span,
+    },
+    /// A positional argument followed a named argument.
+    #[error("positional argument follows a named argument")]
+    PositionalArgumentAfterNamed {
+        /// The span of the positional argument.
+        span: Span,
+    },
+    /// A named argument which doesn't match any argument of the function.
+    #[error("function has no argument named `{name}`")]
+    UnknownNamedArgument {
+        /// The span of the named argument.
+        span: Span,
+        /// The name of the argument.
+        name: String,
+    },
+    /// The same argument was provided more than once.
+    #[error("argument `{name}` provided more than once")]
+    DuplicateArgument {
+        /// The span of the duplicate argument.
+        span: Span,
+        /// The name of the argument.
+        name: String,
+    },
+    /// An argument was missing from a call with named arguments.
+    #[error("missing argument `{name}`")]
+    MissingArgument {
+        /// The span of the call.
+        span: Span,
+        /// The name of the missing argument.
+        name: String,
+    },
     /// A meta item that is not supported in the given pattern position.
     #[error("`{meta}` is not supported in a pattern like this")]
     UnsupportedMetaPattern {
@@ -685,6 +847,13 @@ pub enum CompileError {
         /// Where the expression is.
         span: Span,
     },
+    /// Trying to use an `if` expression with a value-producing branch as a
+    /// value, without an `else` branch to fall back to.
+    #[error("`if` expression used as a value is missing an `else` branch")]
+    IfMissingElse {
+        /// Where the `if` expression is.
+        span: Span,
+    },
 }
 
 impl CompileError {
@@ -718,6 +887,8 @@ impl CompileError {
             Self::UnsupportedValue { span, .. } => span,
             Self::UnsupportedType { span, .. } => span,
             Self::UnsupportedSelf { span, .. } => span,
+            Self::UnsupportedRestArgument { span, .. } => span,
+            Self::RequiredArgumentAfterDefault { span, .. } => span,
             Self::UnsupportedUnaryOp { span, .. } => span,
             Self::UnsupportedBinaryOp { span, .. } => span,
             Self::UnsupportedLitObject { span, .. } => span,
@@ -726,6 +897,12 @@ impl CompileError {
             Self::UnsupportedSelectPattern { span, .. } => span,
             Self::UnsupportedFieldAccess { span, .. } => span,
             Self::UnsupportedArgumentCount { span, .. } => span,
+            Self::ArgumentCountMismatch { span, .. } => span,
+            Self::UnsupportedNamedArgument { span, .. } => span,
+            Self::PositionalArgumentAfterNamed { span, .. } => span,
+            Self::UnknownNamedArgument { span, .. } => span,
+            Self::DuplicateArgument { span, .. } => span,
+            Self::MissingArgument { span, .. } => span,
             Self::UnsupportedMetaPattern { span, .. } => span,
             Self::UnsupportedMetaClosure { span, .. } => span,
             Self::UnsupportedPattern { span, .. } => span,
@@ -742,6 +919,102 @@ impl CompileError {
             Self::InstanceFunctionOutsideImpl { span, .. } => span,
             Self::MissingPreludeModule { .. } => Span::empty(),
             Self::UnsupportedAsyncExpr { span, .. } => span,
+            Self::IfMissingElse { span, .. } => span,
+        }
+    }
+
+    /// Get the related locations of the error.
+    ///
+    /// These are secondary spans with a message describing how they relate to
+    /// the error, in addition to the primary span returned by
+    /// [span][Self::span].
+    pub fn related(&self) -> Vec<(Span, &'static str)> {
+        match self {
+            Self::VariableConflict { existing_span, .. } => {
+                vec![(*existing_span, "previously defined here")]
+            }
+            Self::ReturnLocalReferences {
+                block,
+                span,
+                references_at,
+            } => {
+                let mut related = references_at
+                    .iter()
+                    .filter(|reference| !span.overlaps(**reference))
+                    .map(|reference| (*reference, "reference created here"))
+                    .collect::<Vec<_>>();
+
+                related.push((*block, "block returned from"));
+                related
+            }
+            Self::DuplicateObjectKey {
+                existing, object, ..
+            } => vec![
+                (*existing, "previously defined here"),
+                (*object, "object being defined here"),
+            ],
+            _ => Vec::new(),
+        }
+    }
+
+    /// Get the stable code identifying the kind of compile error, like
+    /// `E0001`.
+    ///
+    /// Parse errors use the code of the underlying [ParseError]. Codes are
+    /// never reused or changed once assigned, new kinds of errors are given
+    /// new codes.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Internal { .. } => "E0001",
+            Self::UnitError { .. } => "E0002",
+            Self::ParseError { error } => error.code(),
+            Self::ItemConflict { .. } => "E0004",
+            Self::VariableConflict { .. } => "E0005",
+            Self::MissingLocal { .. } => "E0006",
+            Self::MissingType { .. } => "E0007",
+            Self::MissingModule { .. } => "E0008",
+            Self::MissingLabel { .. } => "E0009",
+            Self::UnsupportedWildcard { .. } => "E0010",
+            Self::UnsupportedAsyncBlock { .. } => "E0011",
+            Self::UnsupportedInstanceFunction { .. } => "E0012",
+            Self::UnsupportedValue { .. } => "E0013",
+            Self::UnsupportedType { .. } => "E0014",
+            Self::UnsupportedSelf { .. } => "E0015",
+            Self::UnsupportedRestArgument { .. } => "E0016",
+            Self::RequiredArgumentAfterDefault { .. } => "E0017",
+            Self::UnsupportedUnaryOp { .. } => "E0018",
+            Self::UnsupportedBinaryOp { .. } => "E0019",
+            Self::UnsupportedLitObject { .. } => "E0020",
+            Self::LitObjectMissingField { .. } => "E0021",
+            Self::LitObjectNotField { .. } => "E0022",
+            Self::UnsupportedAssignExpr { .. } => "E0023",
+            Self::UnsupportedAssignBinOp { .. } => "E0024",
+            Self::UnsupportedRef { .. } => "E0025",
+            Self::UnsupportedAwait { .. } => "E0026",
+            Self::UnsupportedSelectPattern { .. } => "E0027",
+            Self::UnsupportedFieldAccess { .. } => "E0028",
+            Self::UnsupportedArgumentCount { .. } => "E0029",
+            Self::ArgumentCountMismatch { .. } => "E0030",
+            Self::UnsupportedNamedArgument { .. } => "E0031",
+            Self::PositionalArgumentAfterNamed { .. } => "E0032",
+            Self::UnknownNamedArgument { .. } => "E0033",
+            Self::DuplicateArgument { .. } => "E0034",
+            Self::MissingArgument { .. } => "E0035",
+            Self::UnsupportedMetaPattern { .. } => "E0036",
+            Self::UnsupportedMetaClosure { .. } => "E0037",
+            Self::UnsupportedPattern { .. } => "E0038",
+            Self::UnsupportedBinding { .. } => "E0039",
+            Self::BreakOutsideOfLoop { .. } => "E0040",
+            Self::ReturnLocalReferences { .. } => "E0041",
+            Self::MatchFloatInPattern { .. } => "E0042",
+            Self::DuplicateObjectKey { .. } => "E0043",
+            Self::MissingFunction { .. } => "E0044",
+            Self::YieldOutsideFunction { .. } => "E0045",
+            Self::AwaitOutsideFunction { .. } => "E0046",
+            Self::InstanceFunctionOutsideImpl { .. } => "E0047",
+            Self::MissingPreludeModule { .. } => "E0048",
+            Self::UnsupportedAsyncExpr { .. } => "E0049",
+            Self::IfMissingElse { .. } => "E0050",
         }
     }
 }
@@ -0,0 +1,965 @@
+use crate::ast;
+use crate::error::ParseError;
+use crate::lexer::Lexer;
+use runestick::Span;
+
+/// A single line comment in the source being formatted.
+#[derive(Debug, Clone, Copy)]
+struct Comment {
+    /// The span of the comment, excluding trailing whitespace.
+    span: Span,
+    /// If the comment trails some other code on the same line.
+    trailing: bool,
+}
+
+/// Format the given source into canonical Rune source.
+///
+/// Blocks, match arms, and declaration bodies are laid out one item per line
+/// using four spaces of indentation, and operators and separators are spaced
+/// consistently. Vectors, tuples, objects, and call arguments are kept on a
+/// single line, unless the source breaks the line directly after the opening
+/// delimiter, in which case each item goes on a separate line with a trailing
+/// comma.
+///
+/// Comments are kept on their own line or at the end of the line they trail. A
+/// comment in the middle of an expression which is laid out on a single line is
+/// moved to after that expression.
+///
+/// Blank lines between items are preserved, but collapsed into one.
+///
+/// Formatting is idempotent, so formatting an already formatted source leaves
+/// it as-is.
+///
+/// # Examples
+///
+/// ```rust
+/// let source = r#"
+/// fn main(  ) { let a=[1,2 ,3]; // the values
+///   a[0]+a[1]
+/// }
+/// "#;
+///
+/// let formatted = rune::format(source).unwrap();
+///
+/// assert_eq!(formatted, "fn main() {\n    let a = [1, 2, 3]; // the values\n    a[0] + a[1]\n}\n");
+/// assert_eq!(rune::format(&formatted).unwrap(), formatted);
+/// ```
+pub fn format(source: &str) -> Result<String, ParseError> {
+    let file = crate::parse_all::<ast::DeclFile>(source)?;
+
+    let mut formatter = Formatter {
+        source,
+        comments: comments(source)?,
+        next_comment: 0,
+        out: String::new(),
+        level: 0,
+        at_start: true,
+    };
+
+    formatter.file(&file);
+    Ok(formatter.out)
+}
+
+/// Collect all comments in the given source.
+///
+/// Comments can only appear between tokens, so this only looks at the gaps
+/// between the tokens produced by the lexer.
+fn comments(source: &str) -> Result<Vec<Comment>, ParseError> {
+    let mut lexer = Lexer::new(source);
+    let mut comments = Vec::new();
+    let mut last = 0;
+
+    loop {
+        let token = lexer.next()?;
+        let end = token.map(|t| t.span.start).unwrap_or_else(|| source.len());
+
+        let mut cursor = last;
+
+        while let Some(n) = source[cursor..end].find("//") {
+            let start = cursor + n;
+            let line = source[start..end].find('\n').unwrap_or(end - start);
+            let text = source[start..start + line].trim_end();
+
+            let line_start = source[..start].rfind('\n').map(|n| n + 1).unwrap_or(0);
+            let trailing = !source[line_start..start].trim().is_empty();
+
+            comments.push(Comment {
+                span: Span::new(start, start + text.len()),
+                trailing,
+            });
+
+            cursor = start + line;
+        }
+
+        match token {
+            Some(token) => last = token.span.end,
+            None => break,
+        }
+    }
+
+    Ok(comments)
+}
+
+/// The state of formatting a single source.
+struct Formatter<'a> {
+    /// The source being formatted.
+    source: &'a str,
+    /// All comments in the source.
+    comments: Vec<Comment>,
+    /// The next comment which has not been emitted.
+    next_comment: usize,
+    /// The formatted output.
+    out: String,
+    /// The current indentation level.
+    level: usize,
+    /// If we are at the start of a file or block, where blank lines are not
+    /// preserved.
+    at_start: bool,
+}
+
+impl<'a> Formatter<'a> {
+    /// Access the source text of the given span.
+    fn text(&self, span: Span) -> &'a str {
+        &self.source[span.start..span.end]
+    }
+
+    /// Push the given source text to the output.
+    fn push_span(&mut self, span: Span) {
+        let text = self.text(span);
+        self.out.push_str(text);
+    }
+
+    /// Indent the current line.
+    fn indent(&mut self) {
+        for _ in 0..self.level {
+            self.out.push_str("    ");
+        }
+    }
+
+    /// Iterate over the whitespace directly preceding the given offset.
+    fn whitespace_before(&self, offset: usize) -> impl Iterator<Item = char> + 'a {
+        self.source[..offset]
+            .chars()
+            .rev()
+            .take_while(|c| c.is_whitespace())
+    }
+
+    /// Test if there's a line break directly before the given offset.
+    fn newline_before(&self, offset: usize) -> bool {
+        self.whitespace_before(offset).any(|c| c == '\n')
+    }
+
+    /// Test if there's a blank line directly before the given offset.
+    fn blank_before(&self, offset: usize) -> bool {
+        self.whitespace_before(offset)
+            .filter(|c| *c == '\n')
+            .count()
+            > 1
+    }
+
+    /// Test if there are any comments left to emit before the given offset.
+    fn has_comments_before(&self, offset: usize) -> bool {
+        match self.comments.get(self.next_comment) {
+            Some(comment) => comment.span.start < offset,
+            None => false,
+        }
+    }
+
+    /// Emit all comments before the given offset.
+    ///
+    /// Returns `true` if a forced blank line was not consumed by a comment.
+    fn comments_before(&mut self, offset: usize, mut blank: bool) -> bool {
+        while let Some(comment) = self.comments.get(self.next_comment).copied() {
+            if comment.span.start >= offset {
+                break;
+            }
+
+            self.next_comment += 1;
+
+            if comment.trailing && self.out.ends_with('\n') {
+                self.out.pop();
+                self.out.push(' ');
+                self.push_span(comment.span);
+                self.out.push('\n');
+                continue;
+            }
+
+            if (blank || self.blank_before(comment.span.start)) && !self.at_start {
+                self.out.push('\n');
+            }
+
+            blank = false;
+            self.at_start = false;
+            self.indent();
+            self.push_span(comment.span);
+            self.out.push('\n');
+        }
+
+        blank
+    }
+
+    /// Start a new line for an item starting at the given offset, emitting any
+    /// comments preceding it.
+    fn item(&mut self, offset: usize, blank: bool) {
+        let blank = self.comments_before(offset, blank);
+
+        if (blank || self.blank_before(offset)) && !self.at_start {
+            self.out.push('\n');
+        }
+
+        self.at_start = false;
+        self.indent();
+    }
+
+    /// Open a block of lines.
+    fn open(&mut self, open: &str) {
+        self.out.push_str(open);
+        self.out.push('\n');
+        self.level += 1;
+        self.at_start = true;
+    }
+
+    /// Close a block of lines, emitting any comments before the end of it.
+    fn close(&mut self, close: Span, delim: &str) {
+        self.comments_before(close.start, false);
+        self.level -= 1;
+        self.at_start = false;
+        self.indent();
+        self.out.push_str(delim);
+    }
+
+    /// Format a comma-separated sequence of items.
+    ///
+    /// The sequence is laid out over multiple lines if the source has a line
+    /// break before the first item. The caller is responsible for emitting the
+    /// close delimiter, and the return value indicates if the sequence was
+    /// multiline.
+    fn seq<T, S, F>(
+        &mut self,
+        open: &str,
+        items: &[T],
+        close: Span,
+        pad: bool,
+        span: S,
+        mut f: F,
+    ) -> bool
+    where
+        S: Fn(&T) -> Span,
+        F: FnMut(&mut Self, &T),
+    {
+        let multiline = match items.first() {
+            Some(first) => self.newline_before(span(first).start),
+            None => false,
+        };
+
+        if !multiline {
+            self.out.push_str(open);
+
+            if pad && !items.is_empty() {
+                self.out.push(' ');
+            }
+
+            for (n, item) in items.iter().enumerate() {
+                if n > 0 {
+                    self.out.push_str(", ");
+                }
+
+                f(self, item);
+            }
+
+            if pad && !items.is_empty() {
+                self.out.push(' ');
+            }
+
+            return false;
+        }
+
+        self.open(open);
+
+        for item in items {
+            self.item(span(item).start, false);
+            f(self, item);
+            self.out.push_str(",\n");
+        }
+
+        self.close(close, "");
+        true
+    }
+
+    /// Format a whole file.
+    fn file(&mut self, file: &ast::DeclFile) {
+        let mut last_use = None;
+
+        for (decl, _) in &file.decls {
+            let is_use = matches!(decl, ast::Decl::DeclUse(..));
+            let blank = matches!(last_use, Some(last_use) if !(last_use && is_use));
+
+            self.item(decl.span().start, blank);
+            self.decl(decl);
+
+            let needs_semi_colon = match decl {
+                ast::Decl::DeclStruct(decl_struct) => {
+                    !matches!(decl_struct.body, ast::DeclStructBody::StructBody(..))
+                }
+                decl => decl.needs_semi_colon(),
+            };
+
+            if needs_semi_colon {
+                self.out.push(';');
+            }
+
+            self.out.push('\n');
+            last_use = Some(is_use);
+        }
+
+        self.comments_before(self.source.len(), false);
+    }
+
+    /// Format a declaration.
+    fn decl(&mut self, decl: &ast::Decl) {
+        match decl {
+            ast::Decl::DeclUse(decl_use) => {
+                self.out.push_str("use ");
+                self.push_span(decl_use.first.span());
+
+                for (_, component) in &decl_use.rest {
+                    self.out.push_str("::");
+                    self.push_span(component.span());
+                }
+            }
+            ast::Decl::DeclFn(decl_fn) => self.decl_fn(decl_fn),
+            ast::Decl::DeclEnum(decl_enum) => {
+                self.out.push_str("enum ");
+                self.push_span(decl_enum.name.span());
+                self.out.push(' ');
+
+                if decl_enum.variants.is_empty()
+                    && !self.has_comments_before(decl_enum.close.span().start)
+                {
+                    self.out.push_str("{}");
+                    return;
+                }
+
+                self.open("{");
+
+                for (name, body, _) in &decl_enum.variants {
+                    self.item(name.span().start, false);
+                    self.push_span(name.span());
+                    self.struct_body(body);
+                    self.out.push_str(",\n");
+                }
+
+                self.close(decl_enum.close.span(), "}");
+            }
+            ast::Decl::DeclStruct(decl_struct) => {
+                self.out.push_str("struct ");
+                self.push_span(decl_struct.ident.span());
+
+                match &decl_struct.body {
+                    ast::DeclStructBody::StructBody(body) => {
+                        self.out.push(' ');
+
+                        if body.fields.is_empty()
+                            && !self.has_comments_before(body.close.span().start)
+                        {
+                            self.out.push_str("{}");
+                            return;
+                        }
+
+                        self.open("{");
+
+                        for (field, _) in &body.fields {
+                            self.item(field.span().start, false);
+                            self.push_span(field.span());
+                            self.out.push_str(",\n");
+                        }
+
+                        self.close(body.close.span(), "}");
+                    }
+                    body => self.struct_body(body),
+                }
+            }
+            ast::Decl::DeclImpl(decl_impl) => {
+                self.out.push_str("impl ");
+                self.path(&decl_impl.path);
+                self.out.push(' ');
+
+                if decl_impl.functions.is_empty()
+                    && !self.has_comments_before(decl_impl.close.span().start)
+                {
+                    self.out.push_str("{}");
+                    return;
+                }
+
+                self.open("{");
+
+                for (n, decl_fn) in decl_impl.functions.iter().enumerate() {
+                    self.item(decl_fn.span().start, n > 0);
+                    self.decl_fn(decl_fn);
+                    self.out.push('\n');
+                }
+
+                self.close(decl_impl.close.span(), "}");
+            }
+        }
+    }
+
+    /// Format a function declaration.
+    fn decl_fn(&mut self, decl_fn: &ast::DeclFn) {
+        if decl_fn.async_.is_some() {
+            self.out.push_str("async ");
+        }
+
+        self.out.push_str("fn ");
+        self.push_span(decl_fn.name.span());
+        self.out.push('(');
+
+        for (n, (arg, _)) in decl_fn.args.items.iter().enumerate() {
+            if n > 0 {
+                self.out.push_str(", ");
+            }
+
+            self.fn_arg(arg);
+        }
+
+        self.out.push_str(") ");
+        self.block(&decl_fn.body);
+    }
+
+    /// Format the body of a struct or an enum variant, as it appears on a
+    /// single line.
+    fn struct_body(&mut self, body: &ast::DeclStructBody) {
+        let (open, fields, close) = match body {
+            ast::DeclStructBody::EmptyBody(..) => return,
+            ast::DeclStructBody::TupleBody(body) => ("(", &body.fields, ")"),
+            ast::DeclStructBody::StructBody(body) => (" { ", &body.fields, " }"),
+        };
+
+        if fields.is_empty() {
+            self.out.push_str(open.trim_end());
+            self.out.push_str(close.trim_start());
+            return;
+        }
+
+        self.out.push_str(open);
+
+        for (n, (field, _)) in fields.iter().enumerate() {
+            if n > 0 {
+                self.out.push_str(", ");
+            }
+
+            self.push_span(field.span());
+        }
+
+        self.out.push_str(close);
+    }
+
+    /// Format a function or closure argument.
+    fn fn_arg(&mut self, arg: &ast::FnArg) {
+        match arg {
+            ast::FnArg::Default(ident, _, expr) => {
+                self.push_span(ident.span());
+                self.out.push_str(" = ");
+                self.expr(expr);
+            }
+            ast::FnArg::Rest(_, ident) => {
+                self.out.push_str("..");
+                self.push_span(ident.span());
+            }
+            arg => self.push_span(arg.span()),
+        }
+    }
+
+    /// Format a path.
+    fn path(&mut self, path: &ast::Path) {
+        self.push_span(path.first.span());
+
+        for (_, ident) in &path.rest {
+            self.out.push_str("::");
+            self.push_span(ident.span());
+        }
+    }
+
+    /// Format a block.
+    fn block(&mut self, block: &ast::ExprBlock) {
+        if block.async_.is_some() {
+            self.out.push_str("async ");
+        }
+
+        if block.try_.is_some() {
+            self.out.push_str("try ");
+        }
+
+        let close = block.close.span();
+
+        if block.exprs.is_empty()
+            && block.trailing_expr.is_none()
+            && !self.has_comments_before(close.start)
+        {
+            self.out.push_str("{}");
+            return;
+        }
+
+        self.open("{");
+
+        for (expr, semi_colon) in &block.exprs {
+            self.item(expr_start(expr), false);
+            self.expr(expr);
+
+            if semi_colon.is_some() {
+                self.out.push(';');
+            }
+
+            self.out.push('\n');
+        }
+
+        if let Some(expr) = &block.trailing_expr {
+            self.item(expr_start(expr), false);
+            self.expr(expr);
+            self.out.push('\n');
+        }
+
+        self.close(close, "}");
+    }
+
+    /// Format a label, if present.
+    fn label(&mut self, label: &Option<(ast::Label, ast::Colon)>) {
+        if let Some((label, _)) = label {
+            self.push_span(label.span());
+            self.out.push_str(": ");
+        }
+    }
+
+    /// Format a condition.
+    fn condition(&mut self, condition: &ast::Condition) {
+        match condition {
+            ast::Condition::Expr(expr) => self.expr(expr),
+            ast::Condition::ExprLet(expr_let) => self.expr_let(expr_let),
+        }
+    }
+
+    /// Format a let expression.
+    fn expr_let(&mut self, expr_let: &ast::ExprLet) {
+        self.out.push_str("let ");
+        self.pat(&expr_let.pat);
+        self.out.push_str(" = ");
+        self.expr(&expr_let.expr);
+    }
+
+    /// Format a keyword followed by an optional expression, like `return`.
+    fn keyword_expr(&mut self, keyword: &str, expr: &Option<Box<ast::Expr>>) {
+        self.out.push_str(keyword);
+
+        if let Some(expr) = expr {
+            self.out.push(' ');
+            self.expr(expr);
+        }
+    }
+
+    /// Format an expression.
+    fn expr(&mut self, expr: &ast::Expr) {
+        match expr {
+            ast::Expr::Self_(..) => self.out.push_str("self"),
+            ast::Expr::Path(path) => self.path(path),
+            ast::Expr::Decl(decl) => self.decl(decl),
+            ast::Expr::ExprWhile(expr) => {
+                self.label(&expr.label);
+                self.out.push_str("while ");
+                self.condition(&expr.condition);
+                self.out.push(' ');
+                self.block(&expr.body);
+            }
+            ast::Expr::ExprLoop(expr) => {
+                self.label(&expr.label);
+                self.out.push_str("loop ");
+                self.block(&expr.body);
+            }
+            ast::Expr::ExprFor(expr) => {
+                self.label(&expr.label);
+                self.out.push_str("for ");
+                self.pat(&expr.var);
+                self.out.push_str(" in ");
+                self.expr(&expr.iter);
+                self.out.push(' ');
+                self.block(&expr.body);
+            }
+            ast::Expr::ExprLet(expr) => self.expr_let(expr),
+            ast::Expr::ExprIndexSet(expr) => {
+                self.expr(&expr.target);
+                self.out.push('[');
+                self.expr(&expr.index);
+                self.out.push_str("] = ");
+                self.expr(&expr.value);
+            }
+            ast::Expr::ExprIf(expr) => {
+                self.out.push_str("if ");
+                self.condition(&expr.condition);
+                self.out.push(' ');
+                self.block(&expr.block);
+
+                for expr_else_if in &expr.expr_else_ifs {
+                    self.out.push_str(" else if ");
+                    self.condition(&expr_else_if.condition);
+                    self.out.push(' ');
+                    self.block(&expr_else_if.block);
+                }
+
+                if let Some(expr_else) = &expr.expr_else {
+                    self.out.push_str(" else ");
+                    self.block(&expr_else.block);
+                }
+            }
+            ast::Expr::ExprMatch(expr) => {
+                self.out.push_str("match ");
+                self.expr(&expr.expr);
+                self.out.push(' ');
+
+                if expr.branches.is_empty() && !self.has_comments_before(expr.close.span().start) {
+                    self.out.push_str("{}");
+                    return;
+                }
+
+                self.open("{");
+
+                for (branch, _) in &expr.branches {
+                    self.item(branch.span().start, false);
+                    self.pat(&branch.pat);
+
+                    if let Some((_, condition)) = &branch.condition {
+                        self.out.push_str(" if ");
+                        self.expr(condition);
+                    }
+
+                    self.out.push_str(" => ");
+                    self.expr(&branch.body);
+                    self.out.push_str(",\n");
+                }
+
+                self.close(expr.close.span(), "}");
+            }
+            ast::Expr::ExprCall(expr) => {
+                self.expr(&expr.expr);
+
+                let args = &expr.args;
+
+                self.seq(
+                    "(",
+                    &args.items,
+                    args.close.span(),
+                    false,
+                    |(arg, _)| arg.span(),
+                    |this, (arg, _)| {
+                        if let Some((name, _)) = &arg.name {
+                            this.push_span(name.span());
+                            this.out.push_str(": ");
+                        }
+
+                        this.expr(&arg.expr);
+                    },
+                );
+
+                self.out.push(')');
+            }
+            ast::Expr::ExprFieldAccess(expr) => {
+                self.expr(&expr.expr);
+                self.out.push('.');
+                self.push_span(expr.expr_field.span());
+            }
+            ast::Expr::ExprGroup(expr) => {
+                self.out.push('(');
+                self.expr(&expr.expr);
+                self.out.push(')');
+            }
+            ast::Expr::ExprBinary(expr) => {
+                self.expr(&expr.lhs);
+                self.out.push(' ');
+                self.out.push_str(&expr.op.to_string());
+                self.out.push(' ');
+                self.expr(&expr.rhs);
+            }
+            ast::Expr::ExprUnary(expr) => {
+                self.out.push_str(&expr.op.to_string());
+                self.expr(&expr.expr);
+            }
+            ast::Expr::ExprIndexGet(expr) => {
+                self.expr(&expr.target);
+                self.out.push('[');
+                self.expr(&expr.index);
+                self.out.push(']');
+            }
+            ast::Expr::ExprBreak(expr) => {
+                self.out.push_str("break");
+
+                match &expr.expr {
+                    Some(ast::ExprBreakValue::Expr(expr)) => {
+                        self.out.push(' ');
+                        self.expr(expr);
+                    }
+                    Some(ast::ExprBreakValue::Label(label)) => {
+                        self.out.push(' ');
+                        self.push_span(label.span());
+                    }
+                    None => (),
+                }
+            }
+            ast::Expr::ExprYield(expr) => self.keyword_expr("yield", &expr.expr),
+            ast::Expr::ExprBlock(expr) => self.block(expr),
+            ast::Expr::ExprRange(expr) => {
+                if let Some(from) = &expr.from {
+                    self.expr(from);
+                }
+
+                self.out.push_str("..");
+
+                if let Some(to) = &expr.to {
+                    self.expr(to);
+                }
+            }
+            ast::Expr::ExprReturn(expr) => self.keyword_expr("return", &expr.expr),
+            ast::Expr::ExprAwait(expr) => {
+                self.expr(&expr.expr);
+                self.out.push_str(".await");
+            }
+            ast::Expr::ExprTry(expr) => {
+                self.expr(&expr.expr);
+                self.out.push('?');
+            }
+            ast::Expr::ExprSelect(expr) => {
+                self.out.push_str("select ");
+
+                if expr.branches.is_empty()
+                    && expr.default_branch.is_none()
+                    && !self.has_comments_before(expr.close.span().start)
+                {
+                    self.out.push_str("{}");
+                    return;
+                }
+
+                self.open("{");
+
+                for (branch, _) in &expr.branches {
+                    self.item(branch.span().start, false);
+                    self.pat(&branch.pat);
+                    self.out.push_str(" = ");
+                    self.expr(&branch.expr);
+                    self.out.push_str(" => ");
+                    self.expr(&branch.body);
+                    self.out.push_str(",\n");
+                }
+
+                if let Some((branch, _)) = &expr.default_branch {
+                    self.item(branch.span().start, false);
+                    self.out.push_str("default => ");
+                    self.expr(&branch.body);
+                    self.out.push_str(",\n");
+                }
+
+                self.close(expr.close.span(), "}");
+            }
+            ast::Expr::ExprClosure(expr) => {
+                if expr.async_.is_some() {
+                    self.out.push_str("async ");
+                }
+
+                match &expr.args {
+                    ast::ExprClosureArgs::Empty { .. } => self.out.push_str("||"),
+                    ast::ExprClosureArgs::List { args, .. } => {
+                        self.out.push('|');
+
+                        for (n, (arg, _)) in args.iter().enumerate() {
+                            if n > 0 {
+                                self.out.push_str(", ");
+                            }
+
+                            self.fn_arg(arg);
+                        }
+
+                        self.out.push('|');
+                    }
+                }
+
+                self.out.push(' ');
+                self.expr(&expr.body);
+            }
+            ast::Expr::LitUnit(..) => self.out.push_str("()"),
+            ast::Expr::LitBool(lit) => self.push_span(lit.span()),
+            ast::Expr::LitChar(lit) => self.push_span(lit.span()),
+            ast::Expr::LitByte(lit) => self.push_span(lit.span()),
+            ast::Expr::LitNumber(lit) => self.push_span(lit.span()),
+            ast::Expr::LitStr(lit) => self.push_span(lit.span()),
+            ast::Expr::LitByteStr(lit) => self.push_span(lit.span()),
+            ast::Expr::LitTemplate(lit) => self.push_span(lit.span()),
+            ast::Expr::LitVec(lit) => {
+                self.seq(
+                    "[",
+                    &lit.items,
+                    lit.close.span(),
+                    false,
+                    |expr| expr.span(),
+                    |this, expr| this.expr(expr),
+                );
+
+                self.out.push(']');
+            }
+            ast::Expr::LitObject(lit) => {
+                let pad = match &lit.ident {
+                    ast::LitObjectIdent::Anonymous(..) => {
+                        self.out.push('#');
+                        false
+                    }
+                    ast::LitObjectIdent::Named(path) => {
+                        self.path(path);
+                        self.out.push(' ');
+                        true
+                    }
+                };
+
+                self.seq(
+                    "{",
+                    &lit.assignments,
+                    lit.close.span(),
+                    pad,
+                    |assign| assign.span(),
+                    |this, assign| {
+                        this.push_span(assign.key.span());
+
+                        if let Some((_, expr)) = &assign.assign {
+                            this.out.push_str(": ");
+                            this.expr(expr);
+                        }
+                    },
+                );
+
+                self.out.push('}');
+            }
+            ast::Expr::LitTuple(lit) => {
+                let multiline = self.seq(
+                    "(",
+                    &lit.items,
+                    lit.close.span(),
+                    false,
+                    |(expr, _)| expr.span(),
+                    |this, (expr, _)| this.expr(expr),
+                );
+
+                if !multiline && lit.items.len() == 1 {
+                    self.out.push(',');
+                }
+
+                self.out.push(')');
+            }
+        }
+    }
+
+    /// Format a pattern.
+    fn pat(&mut self, pat: &ast::Pat) {
+        match pat {
+            ast::Pat::PatIgnore(..) => self.out.push('_'),
+            ast::Pat::PatPath(pat) => self.path(&pat.path),
+            ast::Pat::PatUnit(..) => self.out.push_str("()"),
+            ast::Pat::PatByte(lit) => self.push_span(lit.span()),
+            ast::Pat::PatChar(lit) => self.push_span(lit.span()),
+            ast::Pat::PatNumber(lit) => self.push_span(lit.span()),
+            ast::Pat::PatString(lit) => self.push_span(lit.span()),
+            ast::Pat::PatVec(pat) => {
+                self.out.push('[');
+                self.pat_items(&pat.items, pat.open_pattern.is_some());
+                self.out.push(']');
+            }
+            ast::Pat::PatTuple(pat) => {
+                if let Some(path) = &pat.path {
+                    self.path(path);
+                }
+
+                self.out.push('(');
+                self.pat_items(&pat.items, pat.open_pattern.is_some());
+
+                if pat.path.is_none() && pat.items.len() == 1 && pat.open_pattern.is_none() {
+                    self.out.push(',');
+                }
+
+                self.out.push(')');
+            }
+            ast::Pat::PatObject(pat) => {
+                let pad = match &pat.ident {
+                    ast::LitObjectIdent::Anonymous(..) => {
+                        self.out.push_str("#{");
+                        false
+                    }
+                    ast::LitObjectIdent::Named(path) => {
+                        self.path(path);
+                        self.out.push_str(" {");
+                        true
+                    }
+                };
+
+                let pad = pad && (!pat.fields.is_empty() || pat.open_pattern.is_some());
+
+                if pad {
+                    self.out.push(' ');
+                }
+
+                for (n, (item, _)) in pat.fields.iter().enumerate() {
+                    if n > 0 {
+                        self.out.push_str(", ");
+                    }
+
+                    self.push_span(item.key.span());
+
+                    if let Some((_, pat)) = &item.binding {
+                        self.out.push_str(": ");
+                        self.pat(pat);
+                    }
+                }
+
+                if pat.open_pattern.is_some() {
+                    if !pat.fields.is_empty() {
+                        self.out.push_str(", ");
+                    }
+
+                    self.out.push_str("..");
+                }
+
+                if pad {
+                    self.out.push(' ');
+                }
+
+                self.out.push('}');
+            }
+        }
+    }
+
+    /// Format the items of a vector or tuple pattern.
+    fn pat_items(&mut self, items: &[(Box<ast::Pat>, Option<ast::Comma>)], open_pattern: bool) {
+        for (n, (pat, _)) in items.iter().enumerate() {
+            if n > 0 {
+                self.out.push_str(", ");
+            }
+
+            self.pat(pat);
+        }
+
+        if open_pattern {
+            if !items.is_empty() {
+                self.out.push_str(", ");
+            }
+
+            self.out.push_str("..");
+        }
+    }
+}
+
+/// The offset at which an expression starts, including any loop label.
+fn expr_start(expr: &ast::Expr) -> usize {
+    let label = match expr {
+        ast::Expr::ExprWhile(expr) => &expr.label,
+        ast::Expr::ExprLoop(expr) => &expr.label,
+        ast::Expr::ExprFor(expr) => &expr.label,
+        expr => return expr.span().start,
+    };
+
+    match label {
+        Some((label, _)) => label.span().start,
+        None => expr.span().start,
+    }
+}
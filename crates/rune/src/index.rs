@@ -88,30 +88,54 @@ impl Index<ast::DeclFn> for Indexer<'_, '_> {
         let item = self.items.item();
 
         let guard = self.scopes.push_function(decl_fn.async_.is_some());
+        let mut arg_names = Vec::new();
 
         for (arg, _) in &decl_fn.args.items {
             match arg {
                 ast::FnArg::Self_(s) => {
                     let span = s.span();
                     self.scopes.declare("self", span)?;
+                    arg_names.push(String::from("self"));
                 }
                 ast::FnArg::Ident(ident) => {
                     let span = ident.span();
                     let ident = ident.resolve(self.source)?;
                     self.scopes.declare(ident, span)?;
+                    arg_names.push(ident.to_owned());
                 }
-                _ => (),
+                ast::FnArg::Default(ident, _, expr) => {
+                    let span = ident.span();
+                    let ident = ident.resolve(self.source)?;
+                    self.scopes.declare(ident, span)?;
+                    arg_names.push(ident.to_owned());
+                    self.index(&**expr)?;
+                }
+                ast::FnArg::Rest(_, ident) => {
+                    let span = ident.span();
+                    let ident = ident.resolve(self.source)?;
+                    self.scopes.declare(ident, span)?;
+                }
+                // NB: an ignored argument can't be named, so we give it a
+                // name which can never match.
+                ast::FnArg::Ignore(..) => arg_names.push(String::from("_")),
             }
         }
 
+        let arg_names = Arc::new(arg_names);
+
         self.index(&decl_fn.body)?;
 
         let f = guard.into_function(span)?;
         let call = Self::call(f.generator, f.is_async);
 
+        let args = decl_fn.fixed_args();
+        let defaults = decl_fn.default_args();
+        let variadic = decl_fn.is_variadic();
+
         let fun = Function {
             ast: decl_fn.clone(),
             call,
+            arg_names: arg_names.clone(),
         };
 
         if decl_fn.is_instance() {
@@ -137,6 +161,10 @@ impl Index<ast::DeclFn> for Indexer<'_, '_> {
             let meta = Meta::MetaFunction {
                 value_type: Type::Hash(Hash::type_hash(&item)),
                 item: item.clone(),
+                args: Some(args),
+                defaults,
+                variadic,
+                arg_names: Some(arg_names),
             };
 
             self.query.unit.borrow_mut().insert_meta(meta)?;
@@ -152,6 +180,10 @@ impl Index<ast::DeclFn> for Indexer<'_, '_> {
                 .insert_meta(Meta::MetaFunction {
                     value_type: Type::Hash(Hash::type_hash(&item)),
                     item,
+                    args: Some(args),
+                    defaults,
+                    variadic,
+                    arg_names: Some(arg_names),
                 })?;
         } else {
             // NB: non toplevel functions can be indexed for later construction.
@@ -291,7 +323,9 @@ impl Index<ast::Expr> for Indexer<'_, '_> {
     fn index(&mut self, expr: &ast::Expr) -> Result<(), CompileError> {
         match expr {
             ast::Expr::Self_(..) => {
-                self.scopes.mark_use("self");
+                if let Some(span) = self.scopes.mark_use("self") {
+                    self.query.cells.insert(span);
+                }
             }
             ast::Expr::Path(path) => {
                 self.index(path)?;
@@ -347,6 +381,9 @@ impl Index<ast::Expr> for Indexer<'_, '_> {
             ast::Expr::ExprYield(expr_yield) => {
                 self.index(expr_yield)?;
             }
+            ast::Expr::ExprRange(expr_range) => {
+                self.index(expr_range)?;
+            }
             ast::Expr::ExprReturn(expr_return) => {
                 self.index(expr_return)?;
             }
@@ -371,11 +408,17 @@ impl Index<ast::Expr> for Indexer<'_, '_> {
             ast::Expr::LitByte(..) => (),
             ast::Expr::LitChar(..) => (),
             ast::Expr::LitNumber(..) => (),
-            ast::Expr::LitObject(..) => (),
+            ast::Expr::LitObject(lit_object) => {
+                self.index(lit_object)?;
+            }
+            ast::Expr::LitTuple(lit_tuple) => {
+                self.index(lit_tuple)?;
+            }
+            ast::Expr::LitVec(lit_vec) => {
+                self.index(lit_vec)?;
+            }
             ast::Expr::LitStr(..) => (),
             ast::Expr::LitByteStr(..) => (),
-            ast::Expr::LitTuple(..) => (),
-            ast::Expr::LitVec(..) => (),
         }
 
         Ok(())
@@ -384,18 +427,25 @@ impl Index<ast::Expr> for Indexer<'_, '_> {
 
 impl Index<ast::ExprIf> for Indexer<'_, '_> {
     fn index(&mut self, expr_if: &ast::ExprIf) -> Result<(), CompileError> {
+        // NB: this must visit the blocks in the same order as they are
+        // compiled, since that's what determines the names of the items
+        // declared in them.
         self.index(&expr_if.condition)?;
-        self.index(&*expr_if.block)?;
 
         for expr_else_if in &expr_if.expr_else_ifs {
             self.index(&expr_else_if.condition)?;
-            self.index(&*expr_else_if.block)?;
         }
 
         if let Some(expr_else) = &expr_if.expr_else {
             self.index(&*expr_else.block)?;
         }
 
+        self.index(&*expr_if.block)?;
+
+        for expr_else_if in &expr_if.expr_else_ifs {
+            self.index(&*expr_else_if.block)?;
+        }
+
         Ok(())
     }
 }
@@ -502,7 +552,10 @@ impl Index<ast::Path> for Indexer<'_, '_> {
     fn index(&mut self, path: &ast::Path) -> Result<(), CompileError> {
         if let Some(ident) = path.try_as_ident() {
             let ident = ident.resolve(self.source)?;
-            self.scopes.mark_use(ident);
+
+            if let Some(span) = self.scopes.mark_use(ident) {
+                self.query.cells.insert(span);
+            }
         }
 
         Ok(())
@@ -549,9 +602,16 @@ impl Index<ast::ExprClosure> for Indexer<'_, '_> {
                 ast::FnArg::Self_(s) => {
                     return Err(CompileError::UnsupportedSelf { span: s.span() });
                 }
-                ast::FnArg::Ident(ident) => {
+                ast::FnArg::Ident(ident) | ast::FnArg::Rest(_, ident) => {
+                    let span = ident.span();
+                    let ident = ident.resolve(self.source)?;
+                    self.scopes.declare(ident, span)?;
+                }
+                ast::FnArg::Default(ident, _, expr) => {
+                    let span = ident.span();
                     let ident = ident.resolve(self.source)?;
                     self.scopes.declare(ident, span)?;
+                    self.index(&**expr)?;
                 }
                 ast::FnArg::Ignore(..) => (),
             }
@@ -630,6 +690,20 @@ impl Index<ast::ExprYield> for Indexer<'_, '_> {
     }
 }
 
+impl Index<ast::ExprRange> for Indexer<'_, '_> {
+    fn index(&mut self, expr_range: &ast::ExprRange) -> Result<(), CompileError> {
+        if let Some(from) = expr_range.from.as_deref() {
+            self.index(from)?;
+        }
+
+        if let Some(to) = expr_range.to.as_deref() {
+            self.index(to)?;
+        }
+
+        Ok(())
+    }
+}
+
 impl Index<ast::ExprReturn> for Indexer<'_, '_> {
     fn index(&mut self, expr_return: &ast::ExprReturn) -> Result<(), CompileError> {
         if let Some(expr) = expr_return.expr.as_deref() {
@@ -680,8 +754,8 @@ impl Index<ast::ExprSelect> for Indexer<'_, '_> {
 
 impl Index<ast::ExprCall> for Indexer<'_, '_> {
     fn index(&mut self, expr_call: &ast::ExprCall) -> Result<(), CompileError> {
-        for (expr, _) in expr_call.args.items.iter() {
-            self.index(expr)?;
+        for (arg, _) in expr_call.args.items.iter() {
+            self.index(&arg.expr)?;
         }
 
         self.index(&*expr_call.expr)?;
@@ -695,7 +769,7 @@ impl Index<ast::LitTemplate> for Indexer<'_, '_> {
 
         for c in &template.components {
             match c {
-                ast::TemplateComponent::Expr(expr) => {
+                ast::TemplateComponent::Expr(expr) | ast::TemplateComponent::DebugExpr(expr, _) => {
                     self.index(&**expr)?;
                 }
                 ast::TemplateComponent::String(..) => (),
@@ -705,3 +779,46 @@ impl Index<ast::LitTemplate> for Indexer<'_, '_> {
         Ok(())
     }
 }
+
+impl Index<ast::LitObject> for Indexer<'_, '_> {
+    fn index(&mut self, lit_object: &ast::LitObject) -> Result<(), CompileError> {
+        for assign in &lit_object.assignments {
+            match (&assign.key, &assign.assign) {
+                (_, Some((_, expr))) => {
+                    self.index(expr)?;
+                }
+                // NB: a shorthand field like `#{a}` uses the variable `a`.
+                (ast::LitObjectKey::Ident(ident), None) => {
+                    let ident = ident.resolve(self.source)?;
+
+                    if let Some(span) = self.scopes.mark_use(ident) {
+                        self.query.cells.insert(span);
+                    }
+                }
+                (ast::LitObjectKey::LitStr(..), None) => (),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Index<ast::LitTuple> for Indexer<'_, '_> {
+    fn index(&mut self, lit_tuple: &ast::LitTuple) -> Result<(), CompileError> {
+        for (expr, _) in &lit_tuple.items {
+            self.index(expr)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Index<ast::LitVec> for Indexer<'_, '_> {
+    fn index(&mut self, lit_vec: &ast::LitVec) -> Result<(), CompileError> {
+        for expr in &lit_vec.items {
+            self.index(expr)?;
+        }
+
+        Ok(())
+    }
+}
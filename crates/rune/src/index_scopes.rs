@@ -182,18 +182,23 @@ impl IndexScopes {
     }
 
     /// Mark that the given variable is used.
-    pub fn mark_use(&mut self, var: &str) {
+    ///
+    /// Returns the span of the declaration of the variable if it's captured by
+    /// a closure, in which case it has to be stored in a shared cell.
+    pub fn mark_use(&mut self, var: &str) -> Option<Span> {
         let mut levels = self.levels.borrow_mut();
         let iter = levels.iter_mut().rev();
 
         let mut closures = Vec::new();
         let mut found = false;
+        let mut declared = None;
 
         for level in iter {
             match level {
                 IndexScopeLevel::IndexScope(scope) => {
-                    if scope.locals.get(var).is_some() {
+                    if let Some(span) = scope.locals.get(var) {
                         found = true;
+                        declared = Some(*span);
                         break;
                     }
                 }
@@ -203,8 +208,9 @@ impl IndexScopes {
                         break;
                     }
 
-                    if closure.scope.locals.get(var).is_some() {
+                    if let Some(span) = closure.scope.locals.get(var) {
                         found = true;
+                        declared = Some(*span);
                         break;
                     }
 
@@ -212,12 +218,17 @@ impl IndexScopes {
                 }
                 // NB: cannot capture variables outside of functions.
                 IndexScopeLevel::IndexFunction(scope) => {
-                    found = scope.scope.locals.get(var).is_some();
+                    declared = scope.scope.locals.get(var).copied();
+                    found = declared.is_some();
                     break;
                 }
             }
         }
 
+        if closures.is_empty() {
+            return None;
+        }
+
         // mark all traversed closures to capture the given variable.
         if found {
             for closure in closures {
@@ -232,6 +243,8 @@ impl IndexScopes {
                 debug_assert!(inserted);
             }
         }
+
+        declared
     }
 
     /// Mark that a yield was used, meaning the encapsulating function is a
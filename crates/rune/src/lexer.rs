@@ -106,6 +106,7 @@ impl<'a> Lexer<'a> {
             "return" => ast::Kind::Return,
             "await" => ast::Kind::Await,
             "async" => ast::Kind::Async,
+            "try" => ast::Kind::TryKeyword,
             "select" => ast::Kind::Select,
             "default" => ast::Kind::Default,
             "impl" => ast::Kind::Impl,
@@ -162,12 +163,12 @@ impl<'a> Lexer<'a> {
             match c {
                 c if char::is_alphanumeric(c) => (),
                 '.' if !is_fractional => {
-                    is_fractional = true;
-
                     // char immediately following a dot should be numerical.
                     if !it.next().map(|(_, c)| c.is_numeric()).unwrap_or_default() {
                         break self.cursor + n;
                     }
+
+                    is_fractional = true;
                 }
                 _ => break self.cursor + n,
             }
@@ -536,6 +537,42 @@ impl<'a> Lexer<'a> {
                             it.next();
                             break ast::Kind::Scope;
                         }
+                        ('%', '=') => {
+                            it.next();
+                            break ast::Kind::RemAssign;
+                        }
+                        ('&', '=') => {
+                            it.next();
+                            break ast::Kind::AmpersandAssign;
+                        }
+                        ('|', '=') => {
+                            it.next();
+                            break ast::Kind::PipeAssign;
+                        }
+                        ('^', '=') => {
+                            it.next();
+                            break ast::Kind::CaretAssign;
+                        }
+                        ('<', '<') => {
+                            it.next();
+
+                            if let Some((_, '=')) = it.clone().next() {
+                                it.next();
+                                break ast::Kind::ShlAssign;
+                            }
+
+                            break ast::Kind::Shl;
+                        }
+                        ('>', '>') => {
+                            it.next();
+
+                            if let Some((_, '=')) = it.clone().next() {
+                                it.next();
+                                break ast::Kind::ShrAssign;
+                            }
+
+                            break ast::Kind::Shr;
+                        }
                         ('<', '=') => {
                             it.next();
                             break ast::Kind::Lte;
@@ -611,6 +648,7 @@ impl<'a> Lexer<'a> {
                     '?' => ast::Kind::Try,
                     '|' => ast::Kind::Pipe,
                     '%' => ast::Kind::Rem,
+                    '^' => ast::Kind::Caret,
                     'a'..='z' | 'A'..='Z' => {
                         return self.next_ident(&mut it, start);
                     }
@@ -162,6 +162,7 @@ mod compiler;
 #[cfg(feature = "diagnostics")]
 mod diagnostics;
 mod error;
+mod format;
 mod index;
 mod index_scopes;
 mod items;
@@ -172,6 +173,8 @@ mod loops;
 mod options;
 mod parser;
 mod query;
+mod reparse;
+mod rewrite;
 mod scopes;
 mod traits;
 mod warning;
@@ -183,12 +186,15 @@ mod collections {
 }
 
 pub use crate::error::{CompileError, ParseError};
+pub use crate::format::format;
 pub use crate::lexer::Lexer;
 pub use crate::load::{load_path, load_source};
 pub use crate::load_error::{LoadError, LoadErrorKind};
 pub use crate::options::Options;
 pub use crate::parser::Parser;
-pub use crate::warning::{Warning, WarningKind, Warnings};
+pub use crate::reparse::{reparse_source, Reparse};
+pub use crate::rewrite::{rewrite, RewriteError};
+pub use crate::warning::{Warning, WarningKind, WarningLevel, WarningLint, Warnings};
 pub use compiler::compile;
 
 #[cfg(feature = "diagnostics")]
@@ -196,28 +202,114 @@ pub use diagnostics::{emit_warning_diagnostics, termcolor, DiagnosticsError, Emi
 
 /// Construct a a default context runestick context.
 ///
-/// If built with the `modules` feature, this includes all available native
-/// modules.
+/// No capabilities are granted, so scripts can't interact with the host. If
+/// built with the `modules` feature, this includes the native modules which
+/// don't require a capability. Use [default_context_with] to grant
+/// capabilities, which also installs the modules requiring them.
 ///
 /// See [load_path](crate::load_path) for how to use.
 pub fn default_context() -> Result<runestick::Context, runestick::ContextError> {
-    #[allow(unused_mut)]
+    default_context_with(runestick::Capabilities::none())
+}
+
+/// Construct a default runestick context which has been granted the given
+/// capabilities.
+///
+/// If built with the `modules` feature, this includes all available native
+/// modules whose capabilities have been granted.
+///
+/// # Examples
+///
+/// ```rust
+/// use runestick::{Capabilities, Capability};
+///
+/// # fn main() -> runestick::Result<()> {
+/// let context = rune::default_context_with(Capabilities::none().with(Capability::Fs))?;
+/// assert!(context.capabilities().contains(Capability::Fs));
+/// assert!(!context.capabilities().contains(Capability::Net));
+/// # Ok(())
+/// # }
+/// ```
+pub fn default_context_with(
+    capabilities: runestick::Capabilities,
+) -> Result<runestick::Context, runestick::ContextError> {
     let mut context = runestick::Context::with_default_modules()?;
 
+    for capability in capabilities.iter() {
+        context.grant(capability);
+    }
+
     #[cfg(feature = "modules")]
     {
-        context.install(&rune_modules::http::module()?)?;
+        use runestick::Capability;
+
+        if capabilities.contains(Capability::Stdin) {
+            let input = context.input().clone();
+            context.install(&runestick::modules::io::input_module_with_input(input)?)?;
+        }
+
+        if capabilities.contains(Capability::Net) {
+            context.install(&rune_modules::http::module()?)?;
+        }
+
         context.install(&rune_modules::json::module()?)?;
         context.install(&rune_modules::toml::module()?)?;
         context.install(&rune_modules::time::module()?)?;
-        context.install(&rune_modules::process::module()?)?;
-        context.install(&rune_modules::fs::module()?)?;
-        context.install(&rune_modules::signal::module()?)?;
+
+        if capabilities.contains(Capability::Process) {
+            context.install(&rune_modules::process::module()?)?;
+            context.install(&rune_modules::signal::module()?)?;
+        }
+
+        if capabilities.contains(Capability::Fs) {
+            context.install(&rune_modules::fs::module()?)?;
+        }
+
+        if capabilities.contains(Capability::Env) {
+            context.install(&rune_modules::env::module()?)?;
+        }
+
+        context.install(&rune_modules::log::module()?)?;
     }
 
     Ok(context)
 }
 
+/// Parse the given source into a file of declarations, without compiling it.
+///
+/// This is intended for tools like formatters and linters which need the
+/// syntax tree of a source. Spans in the tree are byte offsets into the source,
+/// and the whole tree can be printed with its [Debug][std::fmt::Debug]
+/// implementation, like `{:#?}`.
+///
+/// # Examples
+///
+/// ```rust
+/// use rune::ast;
+/// use runestick::Source;
+///
+/// let source = Source::new("main", r#"
+/// struct Point { x, y }
+///
+/// fn main() {
+///     Point { x: 1, y: 2 }
+/// }
+/// "#);
+///
+/// let file = rune::parse_source(&source).unwrap();
+/// assert_eq!(file.decls.len(), 2);
+///
+/// match &file.decls[1].0 {
+///     ast::Decl::DeclFn(decl_fn) => {
+///         assert_eq!(source.source(decl_fn.name.span()), Some("main"));
+///     }
+///     decl => panic!("expected a function, but got {:?}", decl),
+/// }
+/// ```
+pub fn parse_source(source: &runestick::Source) -> Result<ast::DeclFile, ParseError> {
+    parse_all(source.as_str())
+}
+
 /// Parse the given input as the given type that implements
 /// [Parse][crate::traits::Parse].
 pub fn parse_all<T>(source: &str) -> Result<T, ParseError>
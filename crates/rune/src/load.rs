@@ -138,6 +138,13 @@ pub fn load_source(
         }));
     }
 
+    if let Some(warning) = warnings.denied().next() {
+        return Err(LoadError::from(LoadErrorKind::DeniedWarning {
+            warning: *warning,
+            code_source,
+        }));
+    }
+
     let unit = match Rc::try_unwrap(unit) {
         Ok(unit) => unit.into_inner(),
         Err(..) => {
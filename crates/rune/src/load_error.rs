@@ -1,4 +1,4 @@
-use crate::CompileError;
+use crate::{CompileError, Warning};
 use runestick::{LinkerErrors, Source};
 use std::io;
 use std::path::PathBuf;
@@ -33,6 +33,14 @@ pub enum LoadErrorKind {
         /// The source file we tried to compile.
         code_source: Source,
     },
+    /// A warning was raised which has been configured to be denied.
+    #[error("denied warning `{}`", .warning.kind.lint())]
+    DeniedWarning {
+        /// The warning which was denied.
+        warning: Warning,
+        /// The source file the warning was raised in.
+        code_source: Source,
+    },
     /// A linker error occured.
     #[error("linker error")]
     LinkError {
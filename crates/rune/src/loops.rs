@@ -29,6 +29,8 @@ pub(crate) struct Loop {
     pub(crate) needs: Needs,
     /// Locals to drop when breaking.
     pub(crate) drop: Option<usize>,
+    /// The number of `try` blocks we were inside of at the start of the loop.
+    pub(crate) try_blocks: usize,
 }
 
 pub(crate) struct Loops {
@@ -6,6 +6,8 @@ pub struct Options {
     pub(crate) link_checks: bool,
     /// Memoize the instance function in a loop.
     pub(crate) memoize_instance_fn: bool,
+    /// Catch panics raised inside of `try` blocks.
+    pub(crate) catch_panics: bool,
 }
 
 impl Options {
@@ -20,6 +22,9 @@ impl Options {
             Some("memoize-instance-fn") => {
                 self.memoize_instance_fn = it.next() != Some("false");
             }
+            Some("catch-panics") => {
+                self.catch_panics = it.next() != Some("false");
+            }
             _ => {
                 return Err(ConfigurationError::UnsupportedOptimizationOption {
                     option: option.to_owned(),
@@ -36,6 +41,7 @@ impl Default for Options {
         Self {
             link_checks: true,
             memoize_instance_fn: true,
+            catch_panics: false,
         }
     }
 }
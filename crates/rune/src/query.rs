@@ -50,6 +50,8 @@ pub(crate) struct Function {
     /// Ast for declaration.
     pub(crate) ast: ast::DeclFn,
     pub(crate) call: Call,
+    /// The names of the fixed arguments of the function.
+    pub(crate) arg_names: Arc<Vec<String>>,
 }
 
 pub(crate) struct InstanceFunction {
@@ -93,6 +95,9 @@ pub(crate) struct Query<'a> {
     pub(crate) queue: VecDeque<(Item, Build)>,
     indexed: HashMap<Item, Indexed>,
     pub(crate) unit: Rc<RefCell<Unit>>,
+    /// The spans of variable declarations which are captured by closures, and
+    /// therefore have to be stored in shared cells.
+    pub(crate) cells: HashSet<Span>,
 }
 
 impl<'a> Query<'a> {
@@ -103,9 +108,16 @@ impl<'a> Query<'a> {
             queue: VecDeque::new(),
             indexed: HashMap::new(),
             unit,
+            cells: HashSet::new(),
         }
     }
 
+    /// Test if the given item is declared in the source, whether or not it's
+    /// been built yet.
+    pub(crate) fn contains_item(&self, item: &Item) -> bool {
+        self.indexed.contains_key(item) || self.unit.borrow().lookup_meta(item).is_some()
+    }
+
     /// Add a new enum item.
     pub fn index_enum(&mut self, item: Item, span: Span) -> Result<(), CompileError> {
         log::trace!("new enum: {}", item);
@@ -222,11 +234,19 @@ impl<'a> Query<'a> {
             }
             Indexed::Struct(st) => self.ast_into_item_decl(&item, st.ast.body, None)?,
             Indexed::Function(f) => {
+                let args = f.ast.fixed_args();
+                let defaults = f.ast.default_args();
+                let variadic = f.ast.is_variadic();
+                let arg_names = f.arg_names.clone();
                 self.queue.push_back((item.clone(), Build::Function(f)));
 
                 Meta::MetaFunction {
                     value_type: Type::Hash(Hash::type_hash(&item)),
                     item: item.clone(),
+                    args: Some(args),
+                    defaults,
+                    variadic,
+                    arg_names: Some(arg_names),
                 }
             }
             Indexed::Closure(c) => {
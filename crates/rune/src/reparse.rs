@@ -0,0 +1,140 @@
+use crate::ast;
+use crate::error::ParseError;
+use crate::lexer::Lexer;
+use crate::parser::Parser;
+use crate::traits::Shift;
+use runestick::{Source, Span};
+use std::ops::Range;
+
+/// The result of an incremental parse through [reparse_source].
+#[derive(Debug, Clone)]
+pub struct Reparse {
+    /// The updated file.
+    pub file: ast::DeclFile,
+    /// The range of declarations in `file` which were parsed again. Every
+    /// declaration outside of this range was reused from the previous parse.
+    pub reparsed: Range<usize>,
+}
+
+/// Incrementally parse a source after it has been edited.
+///
+/// `file` is the previous parse, `edit` is the span of the previous source
+/// which was replaced, and `len` is the length in bytes of the text it was
+/// replaced with. `source` is the source after the edit has been applied.
+///
+/// Only the declarations touched by the edit are parsed again, the rest are
+/// reused with their spans adjusted. If the edited region doesn't parse as a
+/// sequence of declarations on its own, like when a delimiter is removed, this
+/// falls back to parsing the whole source.
+///
+/// # Examples
+///
+/// ```rust
+/// use runestick::{Source, Span};
+///
+/// let before = Source::new("main", "fn foo() { 1 }\nfn bar() { 2 }\n");
+/// let file = rune::parse_source(&before).unwrap();
+///
+/// // Replace `1` with `100`.
+/// let after = Source::new("main", "fn foo() { 100 }\nfn bar() { 2 }\n");
+/// let reparse = rune::reparse_source(&after, file, Span::new(11, 12), 3).unwrap();
+///
+/// assert_eq!(reparse.reparsed, 0..1);
+/// assert_eq!(reparse.file.decls.len(), 2);
+/// ```
+pub fn reparse_source(
+    source: &Source,
+    file: ast::DeclFile,
+    edit: Span,
+    len: usize,
+) -> Result<Reparse, ParseError> {
+    let mut decls = file.decls;
+    let delta = len as isize - edit.len() as isize;
+
+    // Declarations which touch the edit are parsed again, since the edit
+    // might've extended one of their tokens.
+    let start = decls
+        .iter()
+        .position(|decl| decl_span(decl).end >= edit.start)
+        .unwrap_or(decls.len());
+
+    let end = decls[start..]
+        .iter()
+        .position(|decl| decl_span(decl).start > edit.end)
+        .map(|n| start + n)
+        .unwrap_or(decls.len());
+
+    let mut after = decls.split_off(end);
+    decls.truncate(start);
+
+    for decl in &mut after {
+        decl.shift(delta);
+    }
+
+    let from = decls.last().map(|d| decl_span(d).end).unwrap_or_default();
+    let to = after
+        .first()
+        .map(|d| decl_span(d).start)
+        .unwrap_or_else(|| source.end());
+
+    let parsed = match parse_region(source.as_str(), from, to) {
+        Some(parsed) => parsed,
+        None => {
+            let file = crate::parse_source(source)?;
+            let reparsed = 0..file.decls.len();
+            return Ok(Reparse { file, reparsed });
+        }
+    };
+
+    let reparsed = start..start + parsed.len();
+    decls.extend(parsed);
+    decls.extend(after);
+
+    Ok(Reparse {
+        file: ast::DeclFile { decls },
+        reparsed,
+    })
+}
+
+/// Parse the declarations in the given region of the source.
+///
+/// Returns `None` if the region doesn't consist of complete declarations,
+/// including the case where a comment or token at its end would run into the
+/// declaration following it.
+fn parse_region(
+    source: &str,
+    from: usize,
+    to: usize,
+) -> Option<Vec<(ast::Decl, Option<ast::SemiColon>)>> {
+    let mut parser = Parser::new_with_start(source.get(..to)?, from);
+    let mut decls = Vec::new();
+
+    while !parser.is_eof().ok()? {
+        let decl: ast::Decl = parser.parse().ok()?;
+
+        let semi_colon = if decl.needs_semi_colon() || parser.peek::<ast::SemiColon>().ok()? {
+            Some(parser.parse::<ast::SemiColon>().ok()?)
+        } else {
+            None
+        };
+
+        decls.push((decl, semi_colon));
+    }
+
+    let end = decls.last().map(|d| decl_span(d).end).unwrap_or(from);
+    let next = Lexer::new_with_start(source, end).next().ok()?;
+
+    match next {
+        Some(token) if token.span.start != to => None,
+        None if to != source.len() => None,
+        _ => Some(decls),
+    }
+}
+
+/// The span of a declaration, including its trailing semi-colon.
+fn decl_span((decl, semi_colon): &(ast::Decl, Option<ast::SemiColon>)) -> Span {
+    match semi_colon {
+        Some(semi_colon) => decl.span().join(semi_colon.span()),
+        None => decl.span(),
+    }
+}
@@ -0,0 +1,82 @@
+use runestick::{Source, Span};
+use thiserror::Error;
+
+/// Error raised when rewriting a source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum RewriteError {
+    /// The span of an edit doesn't correspond to a range in the source.
+    #[error("span `{span}` is not a valid range in the source")]
+    BadSpan {
+        /// The span of the edit.
+        span: Span,
+    },
+    /// Two edits touch the same range of the source.
+    #[error("edit at `{span}` overlaps with edit at `{other}`")]
+    Overlapping {
+        /// The span of the edit.
+        span: Span,
+        /// The span of the edit it overlaps with.
+        other: Span,
+    },
+}
+
+/// Rewrite the given source by replacing each span with its replacement.
+///
+/// Spans refer to the original source, so they can be taken straight from a
+/// parsed syntax tree. Edits are applied right-to-left, which means that an
+/// earlier edit never shifts the spans of the ones that come after it.
+///
+/// Edits are not allowed to overlap. An empty span inserts its replacement at
+/// the given position, but two edits can't start at the same position since
+/// the resulting order would be ambiguous.
+///
+/// # Examples
+///
+/// ```rust
+/// use runestick::{Source, Span};
+///
+/// let source = Source::new("main", "fn main() { foo(bar) }");
+///
+/// let out = rune::rewrite(&source, vec![
+///     (Span::new(12, 15), "baz"),
+///     (Span::new(16, 19), "qux"),
+/// ]).unwrap();
+///
+/// assert_eq!(out, "fn main() { baz(qux) }");
+///
+/// assert!(rune::rewrite(&source, vec![
+///     (Span::new(12, 17), "baz"),
+///     (Span::new(16, 19), "qux"),
+/// ]).is_err());
+/// ```
+pub fn rewrite<I, S>(source: &Source, edits: I) -> Result<String, RewriteError>
+where
+    I: IntoIterator<Item = (Span, S)>,
+    S: AsRef<str>,
+{
+    let mut edits = edits.into_iter().collect::<Vec<_>>();
+
+    for (span, _) in &edits {
+        if span.start > span.end || source.source(*span).is_none() {
+            return Err(RewriteError::BadSpan { span: *span });
+        }
+    }
+
+    edits.sort_by_key(|(span, _)| *span);
+
+    for pair in edits.windows(2) {
+        let (other, span) = (pair[0].0, pair[1].0);
+
+        if span.start < other.end || span.start == other.start {
+            return Err(RewriteError::Overlapping { span, other });
+        }
+    }
+
+    let mut out = source.as_str().to_owned();
+
+    for (span, replacement) in edits.iter().rev() {
+        out.replace_range(span.start..span.end, replacement.as_ref());
+    }
+
+    Ok(out)
+}
@@ -9,6 +9,12 @@ pub(crate) struct Var {
     pub(crate) offset: usize,
     /// Token assocaited with the variable.
     span: Span,
+    /// If the variable is stored in a shared cell, because it's captured by a
+    /// closure.
+    ///
+    /// The cell is a tuple with a single element, which the closure and the
+    /// declaring scope both hold a reference to.
+    pub(crate) cell: bool,
 }
 
 impl Var {
@@ -19,6 +25,35 @@ impl Var {
 
     /// Copy the declared variable.
     pub fn copy<C>(&self, asm: &mut Assembly, span: Span, comment: C)
+    where
+        C: AsRef<str>,
+    {
+        if self.cell {
+            asm.push_with_comment(
+                Inst::TupleIndexGetAt {
+                    offset: self.offset,
+                    index: 0,
+                },
+                span,
+                comment,
+            );
+        } else {
+            asm.push_with_comment(
+                Inst::Copy {
+                    offset: self.offset,
+                },
+                span,
+                comment,
+            );
+        }
+    }
+
+    /// Copy the cell of the declared variable so that it can be captured by a
+    /// closure.
+    ///
+    /// If the variable isn't stored in a cell, its current value is wrapped in
+    /// a new one.
+    pub fn capture<C>(&self, asm: &mut Assembly, span: Span, comment: C)
     where
         C: AsRef<str>,
     {
@@ -29,6 +64,10 @@ impl Var {
             span,
             comment,
         );
+
+        if !self.cell {
+            asm.push(Inst::Tuple { count: 1 }, span);
+        }
     }
 }
 
@@ -76,9 +115,19 @@ impl Scope {
 
     /// Insert a new local, and return the old one if there's a conflict.
     pub(crate) fn new_var(&mut self, name: &str, span: Span) -> CompileResult<usize> {
+        self.new_local(name, span, false)
+    }
+
+    /// Insert a new local which is stored in a shared cell, and return the old
+    /// one if there's a conflict.
+    pub(crate) fn new_cell(&mut self, name: &str, span: Span) -> CompileResult<usize> {
+        self.new_local(name, span, true)
+    }
+
+    fn new_local(&mut self, name: &str, span: Span, cell: bool) -> CompileResult<usize> {
         let offset = self.total_var_count;
 
-        let local = Var { offset, span };
+        let local = Var { offset, span, cell };
 
         self.total_var_count += 1;
         self.local_var_count += 1;
@@ -96,11 +145,21 @@ impl Scope {
 
     /// Insert a new local, and return the old one if there's a conflict.
     pub(crate) fn decl_var(&mut self, name: &str, span: Span) -> usize {
+        self.decl_local(name, span, false)
+    }
+
+    /// Insert a new local which is stored in a shared cell.
+    pub(crate) fn decl_cell(&mut self, name: &str, span: Span) -> usize {
+        self.decl_local(name, span, true)
+    }
+
+    fn decl_local(&mut self, name: &str, span: Span, cell: bool) -> usize {
         let offset = self.total_var_count;
 
         log::trace!("decl {} => {}", name, offset);
 
-        self.locals.insert(name.to_owned(), Var { offset, span });
+        self.locals
+            .insert(name.to_owned(), Var { offset, span, cell });
 
         self.total_var_count += 1;
         self.local_var_count += 1;
@@ -199,6 +258,11 @@ impl Scopes {
         self.last_mut(span)?.new_var(name, span)
     }
 
+    /// Construct a new variable which is stored in a shared cell.
+    pub(crate) fn new_cell(&mut self, name: &str, span: Span) -> CompileResult<usize> {
+        self.last_mut(span)?.new_cell(name, span)
+    }
+
     /// Get the local with the given name.
     pub(crate) fn last(&self, span: Span) -> CompileResult<&Scope> {
         Ok(self
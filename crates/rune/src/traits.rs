@@ -99,6 +99,77 @@ pub trait Resolve<'a> {
     fn resolve(&self, source: &'a Source) -> Result<Self::Output, ParseError>;
 }
 
+/// Implemented by nodes whose spans can be moved.
+///
+/// This is used to reuse parsed nodes after an edit to an earlier part of the
+/// source has changed their position.
+pub(crate) trait Shift {
+    /// Shift all spans in the node by the given number of bytes.
+    fn shift(&mut self, delta: isize);
+}
+
+impl Shift for Token {
+    fn shift(&mut self, delta: isize) {
+        self.span.start = (self.span.start as isize + delta) as usize;
+        self.span.end = (self.span.end as isize + delta) as usize;
+    }
+}
+
+impl<T> Shift for Option<T>
+where
+    T: Shift,
+{
+    fn shift(&mut self, delta: isize) {
+        if let Some(value) = self {
+            value.shift(delta);
+        }
+    }
+}
+
+impl<T> Shift for Box<T>
+where
+    T: Shift,
+{
+    fn shift(&mut self, delta: isize) {
+        (**self).shift(delta);
+    }
+}
+
+impl<T> Shift for Vec<T>
+where
+    T: Shift,
+{
+    fn shift(&mut self, delta: isize) {
+        for value in self {
+            value.shift(delta);
+        }
+    }
+}
+
+impl<A, B> Shift for (A, B)
+where
+    A: Shift,
+    B: Shift,
+{
+    fn shift(&mut self, delta: isize) {
+        self.0.shift(delta);
+        self.1.shift(delta);
+    }
+}
+
+impl<A, B, C> Shift for (A, B, C)
+where
+    A: Shift,
+    B: Shift,
+    C: Shift,
+{
+    fn shift(&mut self, delta: isize) {
+        self.0.shift(delta);
+        self.1.shift(delta);
+        self.2.shift(delta);
+    }
+}
+
 pub(crate) trait Compile<T> {
     /// Walk the current type with the given item.
     fn compile(&mut self, item: T) -> CompileResult<()>;
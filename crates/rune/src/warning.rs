@@ -1,4 +1,6 @@
+use crate::collections::HashMap;
 use runestick::Span;
+use std::fmt;
 
 /// Compilation warning.
 #[derive(Debug, Clone, Copy)]
@@ -48,11 +50,110 @@ pub enum WarningKind {
         /// Span where the semi-colon is.
         span: Span,
     },
+    /// Code that can never be reached because it follows an unconditional
+    /// `return` or `break`.
+    UnreachableCode {
+        /// The span of the first unreachable statement.
+        span: Span,
+        /// The span of the expression which causes the code to be unreachable.
+        cause: Span,
+    },
+}
+
+impl WarningKind {
+    /// Get the lint which this warning belongs to.
+    pub fn lint(&self) -> WarningLint {
+        match self {
+            Self::NotUsed { .. } => WarningLint::NotUsed,
+            Self::LetPatternMightPanic { .. } => WarningLint::LetPatternMightPanic,
+            Self::TemplateWithoutExpansions { .. } => WarningLint::TemplateWithoutExpansions,
+            Self::RemoveTupleCallParams { .. } => WarningLint::RemoveTupleCallParams,
+            Self::UnecessarySemiColon { .. } => WarningLint::UnecessarySemiColon,
+            Self::UnreachableCode { .. } => WarningLint::UnreachableCode,
+        }
+    }
+
+    /// Get the span which caused the warning.
+    pub fn span(&self) -> Span {
+        match self {
+            Self::NotUsed { span, .. } => *span,
+            Self::LetPatternMightPanic { span, .. } => *span,
+            Self::TemplateWithoutExpansions { span, .. } => *span,
+            Self::RemoveTupleCallParams { span, .. } => *span,
+            Self::UnecessarySemiColon { span } => *span,
+            Self::UnreachableCode { span, .. } => *span,
+        }
+    }
+}
+
+/// Identifies a kind of warning, independently of where it was raised.
+///
+/// Used to configure the [WarningLevel] of each kind of warning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WarningLint {
+    /// See [WarningKind::NotUsed].
+    NotUsed,
+    /// See [WarningKind::LetPatternMightPanic].
+    LetPatternMightPanic,
+    /// See [WarningKind::TemplateWithoutExpansions].
+    TemplateWithoutExpansions,
+    /// See [WarningKind::RemoveTupleCallParams].
+    RemoveTupleCallParams,
+    /// See [WarningKind::UnecessarySemiColon].
+    UnecessarySemiColon,
+    /// See [WarningKind::UnreachableCode].
+    UnreachableCode,
+}
+
+impl WarningLint {
+    /// Get the stable code identifying the lint, like `W0001`.
+    ///
+    /// Codes are never reused or changed once assigned, new lints are given
+    /// new codes.
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::NotUsed => "W0001",
+            Self::LetPatternMightPanic => "W0002",
+            Self::TemplateWithoutExpansions => "W0003",
+            Self::RemoveTupleCallParams => "W0004",
+            Self::UnecessarySemiColon => "W0005",
+            Self::UnreachableCode => "W0006",
+        }
+    }
+}
+
+impl fmt::Display for WarningLint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::NotUsed => "not_used",
+            Self::LetPatternMightPanic => "let_pattern_might_panic",
+            Self::TemplateWithoutExpansions => "template_without_expansions",
+            Self::RemoveTupleCallParams => "remove_tuple_call_params",
+            Self::UnecessarySemiColon => "unecessary_semi_colon",
+            Self::UnreachableCode => "unreachable_code",
+        };
+
+        f.write_str(name)
+    }
+}
+
+/// The level at which warnings of a given kind are reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WarningLevel {
+    /// The warning is ignored.
+    Allow,
+    /// The warning is reported. This is the default.
+    Warn,
+    /// The warning is reported, and causes loading to fail with a
+    /// [LoadError][crate::LoadError].
+    Deny,
 }
+
 /// Compilation warnings.
 #[derive(Debug, Clone, Default)]
 pub struct Warnings {
     warnings: Option<Vec<Warning>>,
+    levels: HashMap<WarningLint, WarningLevel>,
 }
 
 impl Warnings {
@@ -70,7 +171,10 @@ impl Warnings {
     /// warnings.not_used(0, Span::empty(), None);
     /// ```
     pub fn disabled() -> Self {
-        Self { warnings: None }
+        Self {
+            warnings: None,
+            levels: HashMap::new(),
+        }
     }
 
     /// Construct a new, empty collection of compilation warnings.
@@ -91,9 +195,50 @@ impl Warnings {
     pub fn new() -> Self {
         Self {
             warnings: Some(Vec::new()),
+            levels: HashMap::new(),
         }
     }
 
+    /// Set the level at which warnings for the given lint are reported.
+    ///
+    /// Warnings which are allowed are not collected at all, while denied
+    /// warnings cause functions like [load_source][crate::load_source] to
+    /// fail. Levels have no effect if warnings are [disabled][Self::disabled].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rune::{Warnings, WarningLevel, WarningLint};
+    /// use runestick::Span;
+    ///
+    /// let mut warnings = Warnings::new();
+    /// warnings.set_level(WarningLint::NotUsed, WarningLevel::Allow);
+    /// warnings.set_level(WarningLint::UnecessarySemiColon, WarningLevel::Deny);
+    ///
+    /// warnings.not_used(0, Span::empty(), None);
+    /// assert!(warnings.is_empty());
+    ///
+    /// warnings.uneccessary_semi_colon(0, Span::empty());
+    /// assert_eq!(warnings.denied().count(), 1);
+    /// ```
+    pub fn set_level(&mut self, lint: WarningLint, level: WarningLevel) {
+        self.levels.insert(lint, level);
+    }
+
+    /// Get the level at which warnings for the given lint are reported.
+    pub fn level(&self, lint: WarningLint) -> WarningLevel {
+        self.levels
+            .get(&lint)
+            .copied()
+            .unwrap_or(WarningLevel::Warn)
+    }
+
+    /// Get an iterator over all the warnings which have been denied.
+    pub fn denied(&self) -> impl Iterator<Item = &'_ Warning> {
+        self.iter()
+            .filter(move |w| self.level(w.kind.lint()) == WarningLevel::Deny)
+    }
+
     /// Indicate if there are warnings or not.
     pub fn is_empty(&self) -> bool {
         self.warnings.as_ref().map(Vec::is_empty).unwrap_or(true)
@@ -106,24 +251,17 @@ impl Warnings {
 
     /// Indicate that a value is produced but never used.
     pub fn not_used(&mut self, source_id: usize, span: Span, context: Option<Span>) {
-        if let Some(w) = &mut self.warnings {
-            w.push(Warning {
-                source_id,
-                kind: WarningKind::NotUsed { span, context },
-            });
-        }
+        self.push(source_id, WarningKind::NotUsed { span, context });
     }
 
     /// Indicate that a binding pattern might panic.
     ///
     /// Like `let (a, b) = value`.
     pub fn let_pattern_might_panic(&mut self, source_id: usize, span: Span, context: Option<Span>) {
-        if let Some(w) = &mut self.warnings {
-            w.push(Warning {
-                source_id,
-                kind: WarningKind::LetPatternMightPanic { span, context },
-            });
-        }
+        self.push(
+            source_id,
+            WarningKind::LetPatternMightPanic { span, context },
+        );
     }
 
     /// Indicate that we encountered a template string without any expansion
@@ -136,12 +274,10 @@ impl Warnings {
         span: Span,
         context: Option<Span>,
     ) {
-        if let Some(w) = &mut self.warnings {
-            w.push(Warning {
-                source_id,
-                kind: WarningKind::TemplateWithoutExpansions { span, context },
-            });
-        }
+        self.push(
+            source_id,
+            WarningKind::TemplateWithoutExpansions { span, context },
+        );
     }
 
     /// Add a warning indicating that the parameters of an empty tuple can be
@@ -155,25 +291,36 @@ impl Warnings {
         variant: Span,
         context: Option<Span>,
     ) {
-        if let Some(w) = &mut self.warnings {
-            w.push(Warning {
-                source_id,
-                kind: WarningKind::RemoveTupleCallParams {
-                    span,
-                    variant,
-                    context,
-                },
-            });
-        }
+        self.push(
+            source_id,
+            WarningKind::RemoveTupleCallParams {
+                span,
+                variant,
+                context,
+            },
+        );
     }
 
     /// Add a warning about an unecessary semi-colon.
     pub fn uneccessary_semi_colon(&mut self, source_id: usize, span: Span) {
+        self.push(source_id, WarningKind::UnecessarySemiColon { span });
+    }
+
+    /// Add a warning about unreachable code.
+    pub fn unreachable_code(&mut self, source_id: usize, span: Span, cause: Span) {
+        self.push(source_id, WarningKind::UnreachableCode { span, cause });
+    }
+}
+
+impl Warnings {
+    /// Add a warning unless it's been disabled or allowed.
+    fn push(&mut self, source_id: usize, kind: WarningKind) {
+        if self.level(kind.lint()) == WarningLevel::Allow {
+            return;
+        }
+
         if let Some(w) = &mut self.warnings {
-            w.push(Warning {
-                source_id,
-                kind: WarningKind::UnecessarySemiColon { span },
-            });
+            w.push(Warning { source_id, kind });
         }
     }
 }
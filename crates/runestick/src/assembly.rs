@@ -25,6 +25,7 @@ pub(crate) enum AssemblyInst {
     JumpIfBranch { branch: i64, label: Label },
     PopAndJumpIf { count: usize, label: Label },
     PopAndJumpIfNot { count: usize, label: Label },
+    PushCatch { label: Label },
     Raw { raw: Inst },
 }
 
@@ -116,6 +117,13 @@ impl Assembly {
             .push((AssemblyInst::PopAndJumpIfNot { count, label }, span));
     }
 
+    /// Add a catch boundary which jumps to the given label if an error is
+    /// caught.
+    pub fn push_catch(&mut self, label: Label, span: Span) {
+        self.instructions
+            .push((AssemblyInst::PushCatch { label }, span));
+    }
+
     /// Push a raw instruction.
     pub fn push(&mut self, raw: Inst, span: Span) {
         if let Inst::Call { hash, .. } = raw {
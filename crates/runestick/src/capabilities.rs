@@ -0,0 +1,111 @@
+use std::fmt;
+
+/// A capability which lets scripts interact with the host.
+///
+/// Modules declare the capabilities they need with [Module::require], and can
+/// only be installed into a [Context] which has been granted them.
+///
+/// [Module::require]: crate::Module::require
+/// [Context]: crate::Context
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Capability {
+    /// Access to the network, like performing http requests.
+    Net,
+    /// Access to the filesystem.
+    Fs,
+    /// Access to environment variables.
+    Env,
+    /// Spawning and signalling processes.
+    Process,
+    /// Reading from the input of the host, like stdin.
+    Stdin,
+}
+
+impl Capability {
+    /// All capabilities.
+    pub const ALL: [Capability; 5] = [Self::Net, Self::Fs, Self::Env, Self::Process, Self::Stdin];
+}
+
+impl fmt::Display for Capability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Net => write!(f, "net"),
+            Self::Fs => write!(f, "fs"),
+            Self::Env => write!(f, "env"),
+            Self::Process => write!(f, "process"),
+            Self::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+/// A set of capabilities.
+///
+/// By default no capabilities are included, so a [Context][crate::Context]
+/// refuses to install modules which need to interact with the host unless
+/// they've been explicitly granted.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Capabilities {
+    /// Access to the network.
+    pub net: bool,
+    /// Access to the filesystem.
+    pub fs: bool,
+    /// Access to environment variables.
+    pub env: bool,
+    /// Spawning and signalling processes.
+    pub process: bool,
+    /// Reading from the input of the host.
+    pub stdin: bool,
+}
+
+impl Capabilities {
+    /// Construct a set without any capabilities.
+    pub fn none() -> Self {
+        Self::default()
+    }
+
+    /// Construct a set with all capabilities.
+    pub fn all() -> Self {
+        Self {
+            net: true,
+            fs: true,
+            env: true,
+            process: true,
+            stdin: true,
+        }
+    }
+
+    /// Include the given capability.
+    pub fn with(mut self, capability: Capability) -> Self {
+        *self.slot(capability) = true;
+        self
+    }
+
+    /// Test if the given capability is included.
+    pub fn contains(&self, capability: Capability) -> bool {
+        match capability {
+            Capability::Net => self.net,
+            Capability::Fs => self.fs,
+            Capability::Env => self.env,
+            Capability::Process => self.process,
+            Capability::Stdin => self.stdin,
+        }
+    }
+
+    /// Iterate over all included capabilities.
+    pub fn iter(&self) -> impl Iterator<Item = Capability> + '_ {
+        Capability::ALL
+            .iter()
+            .copied()
+            .filter(move |capability| self.contains(*capability))
+    }
+
+    fn slot(&mut self, capability: Capability) -> &mut bool {
+        match capability {
+            Capability::Net => &mut self.net,
+            Capability::Fs => &mut self.fs,
+            Capability::Env => &mut self.env,
+            Capability::Process => &mut self.process,
+            Capability::Stdin => &mut self.stdin,
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use crate::{TypeInfo, Value, VmError};
+
+/// A value which is known at compile time, like a constant registered with
+/// [Module::constant][crate::Module::constant].
+///
+/// Constants are inlined into the unit by the compiler where they're used.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstValue {
+    /// The unit value.
+    Unit,
+    /// A boolean.
+    Bool(bool),
+    /// A byte.
+    Byte(u8),
+    /// A character.
+    Char(char),
+    /// An integer.
+    Integer(i64),
+    /// A float.
+    Float(f64),
+    /// A string.
+    String(String),
+}
+
+impl ConstValue {
+    /// Convert a value into a constant value.
+    ///
+    /// Returns the type information of the value as an error if the value
+    /// can't be represented as a constant.
+    pub fn from_value(value: Value) -> Result<Result<Self, TypeInfo>, VmError> {
+        Ok(Ok(match value {
+            Value::Unit => Self::Unit,
+            Value::Bool(b) => Self::Bool(b),
+            Value::Byte(b) => Self::Byte(b),
+            Value::Char(c) => Self::Char(c),
+            Value::Integer(n) => Self::Integer(n),
+            Value::Float(n) => Self::Float(n),
+            Value::StaticString(s) => Self::String((**s).clone()),
+            Value::String(s) => Self::String(s.borrow_ref()?.clone()),
+            value => return Ok(Err(value.type_info()?)),
+        }))
+    }
+}
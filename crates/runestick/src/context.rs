@@ -1,8 +1,9 @@
 use crate::collections::{HashMap, HashSet};
 use crate::module::{ModuleAssociatedFn, ModuleFn, ModuleInternalEnum, ModuleType, ModuleUnitType};
 use crate::{
-    Component, Hash, Item, Meta, MetaStruct, MetaTuple, Module, Names, Stack, StaticType, Type,
-    TypeCheck, TypeInfo, ValueType, VmError,
+    Capabilities, Capability, Component, ContextConfig, DefaultModule, Hash, Input, Item, Meta,
+    MetaStruct, MetaTuple, Module, Names, Output, Stack, StaticType, Type, TypeCheck, TypeInfo,
+    ValueType, VmError,
 };
 use std::fmt;
 use std::sync::Arc;
@@ -91,6 +92,29 @@ pub enum ContextError {
         /// The instance type.
         instance_type: TypeInfo,
     },
+    /// Error raised when attempting to register a conflicting constant.
+    #[error("constant with name `{name}` already exists")]
+    ConflictingConstantName {
+        /// The name of the conflicting constant.
+        name: Item,
+    },
+    /// Error raised when the value of a constant couldn't be converted.
+    #[error("failed to convert value of constant `{name}`: {error}")]
+    ConstantValue {
+        /// The name of the constant.
+        name: Item,
+        /// The error raised when converting the value.
+        error: VmError,
+    },
+    /// Error raised when the value of a constant is of a type which isn't
+    /// supported in constants.
+    #[error("constant `{name}` has unsupported type `{type_info}`")]
+    UnsupportedConstant {
+        /// The name of the constant.
+        name: Item,
+        /// The type of the value.
+        type_info: TypeInfo,
+    },
     /// Error raised when attempting to register a type that doesn't have a type
     /// hash into a context.
     #[error("type `{value_type}` cannot be defined dynamically")]
@@ -98,6 +122,17 @@ pub enum ContextError {
         /// The type we tried to register.
         value_type: Type,
     },
+    /// Error raised when installing a module which requires a capability that
+    /// hasn't been granted to the context.
+    #[error(
+        "module `{module}` requires the `{capability}` capability, which has not been granted"
+    )]
+    MissingCapability {
+        /// The module being installed.
+        module: Item,
+        /// The missing capability.
+        capability: Capability,
+    },
 }
 
 /// A function handler.
@@ -239,6 +274,12 @@ pub struct Context {
     internal_enums: HashSet<&'static StaticType>,
     /// All available names in the context.
     names: Names,
+    /// Capabilities granted to the context.
+    capabilities: Capabilities,
+    /// The sink which print functions installed by the context write to.
+    output: Output,
+    /// The source which input functions installed by the context read from.
+    input: Input,
 }
 
 impl Context {
@@ -254,24 +295,38 @@ impl Context {
     }
 
     /// Construct a new collection of functions with default packages installed.
+    ///
+    /// This includes modules capable of IO, like `println`. Use
+    /// [with_config][Self::with_config] to pick which modules to install.
     pub fn with_default_modules() -> Result<Self, ContextError> {
+        Self::with_config(&ContextConfig::all())
+    }
+
+    /// Construct a new collection of functions with the default modules
+    /// included in the given configuration installed.
+    ///
+    /// Scripts referencing functions from modules which are not installed fail
+    /// to compile with a missing function error.
+    pub fn with_config(config: &ContextConfig) -> Result<Self, ContextError> {
         let mut this = Self::new();
-        this.install(&crate::modules::core::module()?)?;
-        this.install(&crate::modules::generator::module()?)?;
-        this.install(&crate::modules::bytes::module()?)?;
-        this.install(&crate::modules::string::module()?)?;
-        this.install(&crate::modules::int::module()?)?;
-        this.install(&crate::modules::float::module()?)?;
-        this.install(&crate::modules::test::module()?)?;
-        this.install(&crate::modules::iter::module()?)?;
-        this.install(&crate::modules::vec::module()?)?;
-        this.install(&crate::modules::object::module()?)?;
-        this.install(&crate::modules::result::module()?)?;
-        this.install(&crate::modules::option::module()?)?;
-        this.install(&crate::modules::future::module()?)?;
-        this.install(&crate::modules::stream::module()?)?;
-        this.install(&crate::modules::io::module()?)?;
-        this.install(&crate::modules::fmt::module()?)?;
+        this.capabilities = config.capabilities();
+
+        for module in DefaultModule::ALL.iter().copied() {
+            if !config.contains(module) {
+                continue;
+            }
+
+            if let Some(capability) = module.capability() {
+                if !this.capabilities.contains(capability) {
+                    continue;
+                }
+            }
+
+            for module in module.modules(&this.output, &this.input)? {
+                this.install(&module)?;
+            }
+        }
+
         Ok(this)
     }
 
@@ -314,6 +369,12 @@ impl Context {
         })
     }
 
+    /// Lookup the type information for the given value type.
+    pub fn lookup_type(&self, value_type: Type) -> Option<&ContextTypeInfo> {
+        let hash = self.types_rev.get(&value_type)?;
+        self.types.get(hash)
+    }
+
     /// Iterate over all available types.
     pub fn iter_types(&self) -> impl Iterator<Item = (Hash, &ContextTypeInfo)> {
         let mut it = self.types.iter();
@@ -324,8 +385,81 @@ impl Context {
         })
     }
 
+    /// Grant the given capability, allowing modules which require it to be
+    /// installed.
+    ///
+    /// No capabilities are granted by default.
+    pub fn grant(&mut self, capability: Capability) {
+        self.capabilities = self.capabilities.with(capability);
+    }
+
+    /// Access the capabilities granted to the context.
+    pub fn capabilities(&self) -> Capabilities {
+        self.capabilities
+    }
+
+    /// Access the sink which the print functions installed through
+    /// [DefaultModule::Io] write to, like `println` and `dbg`.
+    pub fn output(&self) -> &Output {
+        &self.output
+    }
+
+    /// Redirect the output of the print functions installed through
+    /// [DefaultModule::Io] to the given writer. This defaults to stdout.
+    ///
+    /// Since this only takes a shared reference, it can also be used once the
+    /// context is in use by a virtual machine.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::{Context, ContextConfig, DefaultModule};
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let context = Context::with_config(&ContextConfig::default().with(DefaultModule::Io))?;
+    /// context.set_output(std::io::sink());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_output<W>(&self, writer: W)
+    where
+        W: 'static + std::io::Write + Send,
+    {
+        self.output.set(writer);
+    }
+
+    /// Access the source which the input functions installed through
+    /// [DefaultModule::Input] read from, like `std::io::read_line`.
+    pub fn input(&self) -> &Input {
+        &self.input
+    }
+
+    /// Make the input functions installed through [DefaultModule::Input] read
+    /// from the given reader. This defaults to stdin.
+    ///
+    /// Like [set_output][Context::set_output], this can also be used once the
+    /// context is in use by a virtual machine.
+    pub fn set_input<R>(&self, reader: R)
+    where
+        R: 'static + std::io::BufRead + Send,
+    {
+        self.input.set(reader);
+    }
+
     /// Install the specified module.
+    ///
+    /// Errors if the module requires a capability which hasn't been granted to
+    /// the context, see [grant][Self::grant].
     pub fn install(&mut self, module: &Module) -> Result<(), ContextError> {
+        for capability in module.capabilities.iter() {
+            if !self.capabilities.contains(capability) {
+                return Err(ContextError::MissingCapability {
+                    module: module.path.clone(),
+                    capability,
+                });
+            }
+        }
+
         for (value_type, ty) in &module.types {
             self.install_type(&module, *value_type, ty)?;
         }
@@ -342,6 +476,19 @@ impl Context {
             self.install_internal_enum(module, internal_enum)?;
         }
 
+        for (name, value) in &module.constants {
+            let name = module.path.join(name);
+            self.names.insert(&name);
+
+            self.install_meta(
+                name.clone(),
+                Meta::MetaConstant {
+                    item: name,
+                    value: value.clone(),
+                },
+            )?;
+        }
+
         for (key, inst) in &module.associated_functions {
             self.install_associated_function(
                 key.value_type,
@@ -450,6 +597,10 @@ impl Context {
             Meta::MetaFunction {
                 value_type: Type::Hash(hash),
                 item: name.clone(),
+                args: f.args,
+                defaults: 0,
+                variadic: false,
+                arg_names: None,
             },
         );
 
@@ -0,0 +1,221 @@
+use crate::collections::HashSet;
+use crate::{Capabilities, Capability, ContextError, Input, Module, Output};
+
+/// A default module which can be installed with
+/// [Context::with_config][crate::Context::with_config].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DefaultModule {
+    /// The core `std` module, with primitive types and functions like `panic`
+    /// and `drop`.
+    Core,
+    /// The `std::generator` module.
+    Generator,
+    /// The `std::bytes` module.
+    Bytes,
+    /// The `std::string` module.
+    String,
+    /// The `std::int` module.
+    Int,
+    /// The `std::float` module.
+    Float,
+    /// The `std::test` module.
+    Test,
+    /// The `std::iter` module.
+    Iter,
+    /// The `std::ops` module.
+    Ops,
+    /// The `std::cmp` module.
+    Cmp,
+    /// The `std::vec` module.
+    Vec,
+    /// The `std::object` module.
+    Object,
+    /// The `std::collections` module.
+    Collections,
+    /// The `std::result` module.
+    Result,
+    /// The `std::option` module.
+    Option,
+    /// The `std::future` module.
+    Future,
+    /// The `std::stream` module.
+    Stream,
+    /// The `std::io` module, and the `print`, `println`, `dbg` and `dbg_pretty`
+    /// functions which write to stdout.
+    Io,
+    /// The `std::io::read_line` function, which reads from the input of the
+    /// context. Requires the [Capability::Stdin] capability.
+    Input,
+    /// The `std::error` module.
+    Error,
+    /// The `std::fmt` module.
+    Fmt,
+}
+
+impl DefaultModule {
+    /// All default modules, in the order in which they are installed.
+    pub const ALL: [DefaultModule; 21] = [
+        Self::Core,
+        Self::Generator,
+        Self::Bytes,
+        Self::String,
+        Self::Int,
+        Self::Float,
+        Self::Test,
+        Self::Iter,
+        Self::Ops,
+        Self::Cmp,
+        Self::Vec,
+        Self::Object,
+        Self::Collections,
+        Self::Result,
+        Self::Option,
+        Self::Future,
+        Self::Stream,
+        Self::Io,
+        Self::Input,
+        Self::Error,
+        Self::Fmt,
+    ];
+
+    /// Test if the module lets scripts interact with the host, like writing to
+    /// stdout.
+    pub fn is_io(self) -> bool {
+        matches!(self, Self::Io | Self::Input)
+    }
+
+    /// The capability which has to be granted for the module to be installed,
+    /// if any.
+    pub fn capability(self) -> Option<Capability> {
+        match self {
+            Self::Input => Some(Capability::Stdin),
+            _ => None,
+        }
+    }
+
+    /// Construct the modules making up this default module.
+    pub(crate) fn modules(
+        self,
+        output: &Output,
+        input: &Input,
+    ) -> Result<Vec<Module>, ContextError> {
+        use crate::modules;
+
+        Ok(match self {
+            Self::Core => vec![modules::core::module()?],
+            Self::Generator => vec![modules::generator::module()?],
+            Self::Bytes => vec![modules::bytes::module()?],
+            Self::String => vec![modules::string::module()?],
+            Self::Int => vec![modules::int::module()?],
+            Self::Float => vec![modules::float::module()?],
+            Self::Test => vec![modules::test::module()?],
+            Self::Iter => vec![modules::iter::module()?],
+            Self::Ops => vec![modules::ops::module()?],
+            Self::Cmp => vec![modules::cmp::module()?],
+            Self::Vec => vec![modules::vec::module()?],
+            Self::Object => vec![modules::object::module()?],
+            Self::Collections => vec![modules::collections::module()?],
+            Self::Result => vec![modules::result::module()?],
+            Self::Option => vec![modules::option::module()?],
+            Self::Future => vec![modules::future::module()?],
+            Self::Stream => vec![modules::stream::module()?],
+            Self::Io => vec![
+                modules::io::module()?,
+                modules::io::print_module_with_output(output.clone())?,
+            ],
+            Self::Input => vec![modules::io::input_module_with_input(input.clone())?],
+            Self::Error => vec![modules::error::module()?],
+            Self::Fmt => vec![modules::fmt::module()?],
+        })
+    }
+}
+
+/// Configuration for which default modules to install with
+/// [Context::with_config][crate::Context::with_config].
+///
+/// The default configuration includes all default modules, except the ones
+/// which let scripts interact with the host as determined by
+/// [DefaultModule::is_io]. Modules which require a capability, as determined
+/// by [DefaultModule::capability], are only installed if the configuration
+/// grants it.
+///
+/// # Examples
+///
+/// ```rust
+/// use runestick::{Context, ContextConfig, DefaultModule};
+///
+/// # fn main() -> runestick::Result<()> {
+/// let config = ContextConfig::default()
+///     .without(DefaultModule::Collections)
+///     .with(DefaultModule::Io);
+///
+/// let context = Context::with_config(&config)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ContextConfig {
+    modules: HashSet<DefaultModule>,
+    capabilities: Capabilities,
+}
+
+impl ContextConfig {
+    /// Construct a configuration without any default modules.
+    pub fn empty() -> Self {
+        Self {
+            modules: HashSet::new(),
+            capabilities: Capabilities::none(),
+        }
+    }
+
+    /// Construct a configuration with all default modules, including the ones
+    /// capable of IO.
+    pub fn all() -> Self {
+        Self {
+            modules: DefaultModule::ALL.iter().copied().collect(),
+            capabilities: Capabilities::none(),
+        }
+    }
+
+    /// Include the given module.
+    pub fn with(mut self, module: DefaultModule) -> Self {
+        self.modules.insert(module);
+        self
+    }
+
+    /// Exclude the given module.
+    pub fn without(mut self, module: DefaultModule) -> Self {
+        self.modules.remove(&module);
+        self
+    }
+
+    /// Test if the given module is included.
+    pub fn contains(&self, module: DefaultModule) -> bool {
+        self.modules.contains(&module)
+    }
+
+    /// Grant the given capability to the constructed context, see
+    /// [Context::grant][crate::Context::grant].
+    pub fn grant(mut self, capability: Capability) -> Self {
+        self.capabilities = self.capabilities.with(capability);
+        self
+    }
+
+    /// Access the capabilities granted to the constructed context.
+    pub fn capabilities(&self) -> Capabilities {
+        self.capabilities
+    }
+}
+
+impl Default for ContextConfig {
+    fn default() -> Self {
+        Self {
+            modules: DefaultModule::ALL
+                .iter()
+                .copied()
+                .filter(|module| !module.is_io())
+                .collect(),
+            capabilities: Capabilities::none(),
+        }
+    }
+}
@@ -0,0 +1,57 @@
+//! Access to the environment of the virtual machine which is currently
+//! running on this thread.
+//!
+//! Native functions are only handed the stack, so this is how they get hold of
+//! the context and unit they're being called from, like when they need to
+//! call protocol functions on values.
+
+use crate::{Context, Unit, VmError, VmErrorKind};
+use std::cell::RefCell;
+use std::sync::Arc;
+
+thread_local!(static ENV: RefCell<Option<Env>> = const { RefCell::new(None) });
+
+#[derive(Clone)]
+struct Env {
+    context: Arc<Context>,
+    unit: Arc<Unit>,
+}
+
+/// Get the context and unit of the virtual machine which is currently running
+/// on this thread.
+///
+/// Errors if no virtual machine is running.
+pub(crate) fn current() -> Result<(Arc<Context>, Arc<Unit>), VmError> {
+    let env = ENV.with(|env| env.borrow().clone());
+
+    match env {
+        Some(env) => Ok((env.context, env.unit)),
+        None => Err(VmError::from(VmErrorKind::NoRunningVm)),
+    }
+}
+
+/// Guard which makes the given context and unit the current environment until
+/// it is dropped, at which point the previous environment is restored.
+pub(crate) struct Guard {
+    previous: Option<Env>,
+}
+
+impl Guard {
+    /// Set up the environment for the given context and unit.
+    pub(crate) fn new(context: &Arc<Context>, unit: &Arc<Unit>) -> Self {
+        let env = Env {
+            context: context.clone(),
+            unit: unit.clone(),
+        };
+
+        let previous = ENV.with(|current| current.borrow_mut().replace(env));
+        Self { previous }
+    }
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        ENV.with(|current| *current.borrow_mut() = previous);
+    }
+}
@@ -2,7 +2,7 @@ use crate::context::Handler;
 use crate::VmErrorKind;
 use crate::{
     Args, Call, Context, FromValue, Future, Generator, Hash, OwnedRef, RawOwnedRef, Shared, Stack,
-    Stream, Tuple, Unit, UnsafeFromValue, Value, Vm, VmCall, VmError, VmHalt,
+    Stream, Tuple, Unit, UnitFnSignature, UnsafeFromValue, Value, Vm, VmCall, VmError, VmHalt,
 };
 use std::fmt;
 use std::sync::Arc;
@@ -19,19 +19,30 @@ impl Function {
         A: Args,
         T: FromValue,
     {
+        let mut stack = Stack::with_capacity(A::count());
+        args.into_stack(&mut stack)?;
+        let value = self.call_with_stack(stack, A::count())?;
+        Ok(T::from_value(value)?)
+    }
+
+    /// Perform a call with the given number of arguments already pushed onto
+    /// the given stack.
+    fn call_with_stack(&self, mut stack: Stack, args: usize) -> Result<Value, VmError> {
         let value = match &self.inner {
             Inner::FnHandler(handler) => {
-                let mut stack = Stack::with_capacity(A::count());
-                args.into_stack(&mut stack)?;
-                (handler.handler)(&mut stack, A::count())?;
+                (handler.handler)(&mut stack, args)?;
                 stack.pop()?
             }
             Inner::FnOffset(offset) => {
-                Self::check_args(A::count(), offset.args)?;
-
-                let mut vm = Vm::new(offset.context.clone(), offset.unit.clone());
+                let mut vm = Vm::new_with_stack(offset.context.clone(), offset.unit.clone(), stack);
                 vm.set_ip(offset.offset);
-                args.into_stack(vm.stack_mut())?;
+                Vm::prepare_args(
+                    vm.stack_mut(),
+                    args,
+                    offset.args,
+                    offset.defaults,
+                    offset.variadic,
+                )?;
 
                 match offset.call {
                     Call::Stream => Value::from(Stream::new(vm)),
@@ -41,11 +52,16 @@ impl Function {
                 }
             }
             Inner::FnClosureOffset(closure) => {
-                Self::check_args(A::count(), closure.args)?;
-
-                let mut vm = Vm::new(closure.context.clone(), closure.unit.clone());
+                let mut vm =
+                    Vm::new_with_stack(closure.context.clone(), closure.unit.clone(), stack);
                 vm.set_ip(closure.offset);
-                args.into_stack(vm.stack_mut())?;
+                Vm::prepare_args(
+                    vm.stack_mut(),
+                    args,
+                    closure.args,
+                    closure.defaults,
+                    closure.variadic,
+                )?;
                 vm.stack_mut().push(closure.environment.clone());
 
                 match closure.call {
@@ -56,18 +72,24 @@ impl Function {
                 }
             }
             Inner::FnTuple(tuple) => {
-                Self::check_args(A::count(), tuple.args)?;
+                Self::check_args(args, tuple.args)?;
 
-                Value::typed_tuple(tuple.hash, args.into_vec()?)
+                Value::typed_tuple(tuple.hash, stack.pop_sequence(args)?)
             }
             Inner::FnVariantTuple(tuple) => {
-                Self::check_args(A::count(), tuple.args)?;
+                Self::check_args(args, tuple.args)?;
 
-                Value::variant_tuple(tuple.enum_hash, tuple.hash, args.into_vec()?)
+                Value::variant_tuple(tuple.enum_hash, tuple.hash, stack.pop_sequence(args)?)
+            }
+            Inner::FnBound(bound) => {
+                let mut bound_stack = Stack::with_capacity(args + 1);
+                bound_stack.push(bound.instance.clone());
+                bound_stack.extend(stack.drain_stack_top(args)?);
+                bound.function.call_with_stack(bound_stack, args + 1)?
             }
         };
 
-        Ok(T::from_value(value)?)
+        Ok(value)
     }
 
     /// Create a function pointer from a handler.
@@ -83,7 +105,7 @@ impl Function {
         unit: Arc<Unit>,
         offset: usize,
         call: Call,
-        args: usize,
+        signature: &UnitFnSignature,
     ) -> Self {
         Self {
             inner: Inner::FnOffset(FnOffset {
@@ -91,7 +113,9 @@ impl Function {
                 unit,
                 offset,
                 call,
-                args,
+                args: signature.args,
+                defaults: signature.defaults,
+                variadic: signature.variadic,
             }),
         }
     }
@@ -103,7 +127,7 @@ impl Function {
         environment: Shared<Tuple>,
         offset: usize,
         call: Call,
-        args: usize,
+        signature: &UnitFnSignature,
     ) -> Self {
         Self {
             inner: Inner::FnClosureOffset(FnClosureOffset {
@@ -112,7 +136,9 @@ impl Function {
                 environment,
                 offset,
                 call,
-                args,
+                args: signature.args,
+                defaults: signature.defaults,
+                variadic: signature.variadic,
             }),
         }
     }
@@ -135,6 +161,17 @@ impl Function {
         }
     }
 
+    /// Bind the given function to an instance, so that the instance is
+    /// passed in as the first argument when it is called.
+    pub(crate) fn bind(self, instance: Value) -> Self {
+        Self {
+            inner: Inner::FnBound(FnBound {
+                instance,
+                function: Box::new(self),
+            }),
+        }
+    }
+
     /// Call with the given virtual machine. This allows for certain
     /// optimizations, like avoiding the allocation of a new vm state in case
     /// the call is internal.
@@ -148,7 +185,13 @@ impl Function {
                 None
             }
             Inner::FnOffset(offset) => {
-                Self::check_args(args, offset.args)?;
+                let args = Vm::prepare_args(
+                    vm.stack_mut(),
+                    args,
+                    offset.args,
+                    offset.defaults,
+                    offset.variadic,
+                )?;
 
                 // Fast past, just allocate a call frame and keep running.
                 if let Call::Immediate = offset.call {
@@ -165,7 +208,13 @@ impl Function {
                 Some(VmHalt::VmCall(VmCall::new(offset.call, vm)))
             }
             Inner::FnClosureOffset(offset) => {
-                Self::check_args(args, offset.args)?;
+                let args = Vm::prepare_args(
+                    vm.stack_mut(),
+                    args,
+                    offset.args,
+                    offset.defaults,
+                    offset.variadic,
+                )?;
 
                 // Fast past, just allocate a call frame, push the environment
                 // onto the stack and keep running.
@@ -205,6 +254,12 @@ impl Function {
                 vm.stack_mut().push(value);
                 None
             }
+            Inner::FnBound(bound) => {
+                let rest = vm.stack_mut().drain_stack_top(args)?.collect::<Vec<_>>();
+                vm.stack_mut().push(bound.instance.clone());
+                vm.stack_mut().extend(rest);
+                return bound.function.call_with_vm(vm, args + 1);
+            }
         };
 
         Ok(reason)
@@ -249,6 +304,13 @@ impl fmt::Debug for Function {
                     tuple.enum_hash, tuple.hash
                 )?;
             }
+            Inner::FnBound(bound) => {
+                write!(
+                    f,
+                    "bound method (instance: {:?}, function: {:?})",
+                    bound.instance, bound.function
+                )?;
+            }
         }
 
         Ok(())
@@ -262,6 +324,7 @@ enum Inner {
     FnClosureOffset(FnClosureOffset),
     FnTuple(FnTuple),
     FnVariantTuple(FnVariantTuple),
+    FnBound(FnBound),
 }
 
 struct FnHandler {
@@ -285,6 +348,10 @@ struct FnOffset {
     call: Call,
     /// The number of arguments the function takes.
     args: usize,
+    /// The number of trailing arguments which have default values.
+    defaults: usize,
+    /// If the function is variadic.
+    variadic: bool,
 }
 
 impl fmt::Debug for FnOffset {
@@ -295,6 +362,8 @@ impl fmt::Debug for FnOffset {
             .field("offset", &self.offset)
             .field("call", &self.call)
             .field("args", &self.args)
+            .field("defaults", &self.defaults)
+            .field("variadic", &self.variadic)
             .finish()
     }
 }
@@ -311,6 +380,10 @@ struct FnClosureOffset {
     call: Call,
     /// The number of arguments the function takes.
     args: usize,
+    /// The number of trailing arguments which have default values.
+    defaults: usize,
+    /// If the function is variadic.
+    variadic: bool,
 }
 
 impl fmt::Debug for FnClosureOffset {
@@ -322,6 +395,8 @@ impl fmt::Debug for FnClosureOffset {
             .field("offset", &self.offset)
             .field("call", &self.call)
             .field("args", &self.args)
+            .field("defaults", &self.defaults)
+            .field("variadic", &self.variadic)
             .finish()
     }
 }
@@ -344,6 +419,14 @@ struct FnVariantTuple {
     args: usize,
 }
 
+#[derive(Debug)]
+struct FnBound {
+    /// The instance the function is bound to.
+    instance: Value,
+    /// The instance function to call.
+    function: Box<Function>,
+}
+
 impl FromValue for Function {
     fn from_value(value: Value) -> Result<Self, VmError> {
         Ok(value.into_function()?.take()?)
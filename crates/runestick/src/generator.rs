@@ -5,22 +5,75 @@ use crate::{
 use std::fmt;
 use std::mem;
 
-/// A generator with a stored virtual machine.
+/// dyn generator function alias.
+type DynGeneratorFn = dyn FnMut(Value) -> Result<GeneratorState, VmError> + 'static;
+
+/// A generator with a stored virtual machine, or a generator driven by native
+/// code.
 pub struct Generator {
-    execution: Option<VmExecution>,
-    first: bool,
+    inner: Inner,
+}
+
+enum Inner {
+    Vm {
+        execution: Option<VmExecution>,
+        first: bool,
+    },
+    Native(Option<Box<DynGeneratorFn>>),
 }
 
 impl Generator {
     /// Construct a generator from a virtual machine.
     pub(crate) fn new(vm: Vm) -> Self {
         Self {
-            execution: Some(VmExecution::of(vm)),
-            first: true,
+            inner: Inner::Vm {
+                execution: Some(VmExecution::of_generator(vm)),
+                first: true,
+            },
         }
     }
 
-    /// Get the next value produced by this stream.
+    /// Construct a generator driven by a Rust function.
+    ///
+    /// The function is called each time the generator is resumed with the
+    /// value passed to `resume`, or `()` when it is advanced with `next`. The
+    /// generator is completed once the function returns
+    /// [GeneratorState::Complete], after which it is no longer called.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::{Generator, GeneratorState, Value};
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut module = runestick::Module::new(&["numbers"]);
+    ///
+    /// module.function(&["countdown"], |n: i64| {
+    ///     let mut n = n;
+    ///
+    ///     Generator::from_fn(move |_| {
+    ///         n -= 1;
+    ///
+    ///         Ok(if n < 0 {
+    ///             GeneratorState::Complete(Value::Unit)
+    ///         } else {
+    ///             GeneratorState::Yielded(Value::from(n))
+    ///         })
+    ///     })
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_fn<F>(f: F) -> Self
+    where
+        F: 'static + FnMut(Value) -> Result<GeneratorState, VmError>,
+    {
+        Self {
+            inner: Inner::Native(Some(Box::new(f))),
+        }
+    }
+
+    /// Get the next value produced by this generator.
     pub fn next(&mut self) -> Result<Option<Value>, VmError> {
         Ok(match self.resume(Value::Unit)? {
             GeneratorState::Yielded(value) => Some(value),
@@ -28,33 +81,66 @@ impl Generator {
         })
     }
 
-    /// Get the next value produced by this stream.
+    /// Resume the generator with the given value, which becomes the result of the
+    /// `yield` expression it's currently suspended on.
+    ///
+    /// For generators defined in a script, the value passed in when it's first
+    /// resumed is discarded, since it is not yet suspended on a `yield` at that
+    /// point.
     pub fn resume(&mut self, value: Value) -> Result<GeneratorState, VmError> {
-        let execution = match &mut self.execution {
-            Some(execution) => execution,
-            None => {
-                return Err(VmError::from(VmErrorKind::GeneratorComplete));
+        match &mut self.inner {
+            Inner::Vm { execution, first } => {
+                let vm_execution = match execution {
+                    Some(execution) => execution,
+                    None => {
+                        return Err(VmError::from(VmErrorKind::GeneratorComplete));
+                    }
+                };
+
+                if !mem::take(first) {
+                    vm_execution.vm_mut()?.stack_mut().push(value);
+                }
+
+                let state = vm_execution.resume()?;
+
+                if state.is_complete() {
+                    *execution = None;
+                }
+
+                Ok(state)
             }
-        };
+            Inner::Native(generator) => {
+                let f = match generator {
+                    Some(f) => f,
+                    None => {
+                        return Err(VmError::from(VmErrorKind::GeneratorComplete));
+                    }
+                };
 
-        if !mem::take(&mut self.first) {
-            execution.vm_mut()?.stack_mut().push(value);
-        }
+                let state = f(value)?;
 
-        let state = execution.resume()?;
+                if state.is_complete() {
+                    *generator = None;
+                }
 
-        if state.is_complete() {
-            self.execution = None;
+                Ok(state)
+            }
         }
+    }
 
-        Ok(state)
+    /// Test if the generator is completed.
+    fn is_completed(&self) -> bool {
+        match &self.inner {
+            Inner::Vm { execution, .. } => execution.is_none(),
+            Inner::Native(generator) => generator.is_none(),
+        }
     }
 }
 
 impl fmt::Debug for Generator {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Generator")
-            .field("completed", &self.execution.is_none())
+            .field("completed", &self.is_completed())
             .finish()
     }
 }
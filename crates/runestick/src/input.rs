@@ -0,0 +1,83 @@
+use std::fmt;
+use std::io;
+use std::sync::{Arc, Mutex};
+
+/// The source which functions like `std::io::read_line` read from.
+///
+/// This is a shared handle, so the source can be replaced with [Input::set]
+/// after the functions reading from it have been installed. By default it
+/// reads from the stdin of the process.
+///
+/// # Examples
+///
+/// ```rust
+/// use runestick::Input;
+///
+/// # fn main() -> std::io::Result<()> {
+/// let input = Input::stdin();
+/// input.set(std::io::Cursor::new("first\nsecond\n"));
+///
+/// let mut line = String::new();
+/// input.read_line(&mut line)?;
+/// assert_eq!(line, "first\n");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Input {
+    inner: Arc<Mutex<Box<dyn io::BufRead + Send>>>,
+}
+
+impl Input {
+    /// Construct an input reading from the given reader.
+    pub fn new<R>(reader: R) -> Self
+    where
+        R: 'static + io::BufRead + Send,
+    {
+        Self {
+            inner: Arc::new(Mutex::new(Box::new(reader))),
+        }
+    }
+
+    /// Construct an input reading from the stdin of the process.
+    pub fn stdin() -> Self {
+        Self::new(io::BufReader::new(io::stdin()))
+    }
+
+    /// Replace the reader of this input, affecting all handles to it.
+    pub fn set<R>(&self, reader: R)
+    where
+        R: 'static + io::BufRead + Send,
+    {
+        *self.lock() = Box::new(reader);
+    }
+
+    /// Read a line, including its trailing newline, and append it to the
+    /// given buffer. See [BufRead::read_line][io::BufRead::read_line].
+    ///
+    /// Returns the number of bytes read, which is zero at the end of input.
+    pub fn read_line(&self, buf: &mut String) -> io::Result<usize> {
+        self.lock().read_line(buf)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Box<dyn io::BufRead + Send>> {
+        // NB: a poisoned reader is still usable, since it holds no invariants
+        // of its own.
+        match self.inner.lock() {
+            Ok(guard) => guard,
+            Err(e) => e.into_inner(),
+        }
+    }
+}
+
+impl Default for Input {
+    fn default() -> Self {
+        Self::stdin()
+    }
+}
+
+impl fmt::Debug for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Input").finish()
+    }
+}
@@ -8,6 +8,8 @@ use std::fmt;
 pub enum PanicReason {
     /// Not implemented.
     NotImplemented,
+    /// Functionality which is yet to be written.
+    Todo,
     /// A pattern didn't match where it unconditionally has to.
     UnmatchedPattern,
     /// Tried to poll a future that has already been completed.
@@ -19,6 +21,7 @@ impl PanicReason {
     fn ident(&self) -> &'static str {
         match *self {
             Self::NotImplemented => "not implemented",
+            Self::Todo => "todo",
             Self::UnmatchedPattern => "unmatched pattern",
             Self::FutureCompleted => "future completed",
         }
@@ -29,6 +32,7 @@ impl fmt::Display for PanicReason {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             Self::NotImplemented => write!(fmt, "functionality has not been implemented yet")?,
+            Self::Todo => write!(fmt, "not yet implemented")?,
             Self::UnmatchedPattern => write!(fmt, "pattern did not match")?,
             Self::FutureCompleted => {
                 write!(fmt, "tried to poll future that has already been completed")?
@@ -147,6 +151,68 @@ pub enum Inst {
     /// => <value>
     /// ```
     Rem,
+    /// Remainder of dividing the value at the given frame offset.
+    ///
+    /// This is the result of an `<offset> %= <b>` expression.
+    RemAssign {
+        /// The frame offset to assign to.
+        offset: usize,
+    },
+    /// Bitwise and of two things.
+    ///
+    /// This is the result of an `<a> & <b>` expression.
+    BitAnd,
+    /// Bitwise and a value into the given frame offset.
+    ///
+    /// This is the result of an `<offset> &= <b>` expression.
+    BitAndAssign {
+        /// The frame offset to assign to.
+        offset: usize,
+    },
+    /// Bitwise xor of two things.
+    ///
+    /// This is the result of an `<a> ^ <b>` expression.
+    BitXor,
+    /// Bitwise xor a value into the given frame offset.
+    ///
+    /// This is the result of an `<offset> ^= <b>` expression.
+    BitXorAssign {
+        /// The frame offset to assign to.
+        offset: usize,
+    },
+    /// Bitwise or of two things.
+    ///
+    /// This is the result of an `<a> | <b>` expression.
+    BitOr,
+    /// Bitwise or a value into the given frame offset.
+    ///
+    /// This is the result of an `<offset> |= <b>` expression.
+    BitOrAssign {
+        /// The frame offset to assign to.
+        offset: usize,
+    },
+    /// Shift a value left.
+    ///
+    /// This is the result of an `<a> << <b>` expression.
+    Shl,
+    /// Shift the value at the given frame offset left.
+    ///
+    /// This is the result of an `<offset> <<= <b>` expression.
+    ShlAssign {
+        /// The frame offset to assign to.
+        offset: usize,
+    },
+    /// Shift a value right.
+    ///
+    /// This is the result of an `<a> >> <b>` expression.
+    Shr,
+    /// Shift the value at the given frame offset right.
+    ///
+    /// This is the result of an `<offset> >>= <b>` expression.
+    ShrAssign {
+        /// The frame offset to assign to.
+        offset: usize,
+    },
     /// Encode a function pointer on the stack.
     ///
     /// # Operation
@@ -597,6 +663,23 @@ pub enum Inst {
         /// The size of the tuple.
         count: usize,
     },
+    /// Construct a range value and push it onto the stack. The bounds of the
+    /// range which are present are popped from the stack, with the end bound
+    /// being on top.
+    ///
+    /// # Operation
+    ///
+    /// ```text
+    /// <end?>
+    /// <start?>
+    /// => <range>
+    /// ```
+    Range {
+        /// If the range has a start bound.
+        start: bool,
+        /// If the range has an end bound.
+        end: bool,
+    },
     /// Take the tuple that is on top of the stack and push its content onto the
     /// stack.
     ///
@@ -725,6 +808,22 @@ pub enum Inst {
         /// The minimum string size used.
         size_hint: usize,
     },
+    /// Pop the value at the top of the stack and format it into a string
+    /// using its debug representation.
+    ///
+    /// Values which are not built in are formatted using the
+    /// [STRING_DEBUG][crate::STRING_DEBUG] protocol.
+    ///
+    /// # Operation
+    ///
+    /// ```text
+    /// <value>
+    /// => <string>
+    /// ```
+    StringDebug {
+        /// If the value should be pretty printed over multiple lines.
+        pretty: bool,
+    },
     /// Test if the top of the stack is an instance of the second item on the
     /// stack.
     ///
@@ -799,6 +898,44 @@ pub enum Inst {
     /// => <value>
     /// ```
     Unwrap,
+    /// Branch on the value at the top of the stack for the try operator `?`.
+    ///
+    /// A `Result` which is `Ok` or an `Option` which is `Some` is unwrapped and
+    /// followed by `true`. Otherwise it is left as-is and followed by `false`,
+    /// indicating that it should be returned from the current function.
+    ///
+    /// Any other value is branched on using the [TRY][crate::TRY] protocol.
+    ///
+    /// # Operation
+    ///
+    /// ```text
+    /// <value>
+    /// => <boolean> <value>
+    /// ```
+    Try,
+    /// Push a catch boundary. If a panic is raised before the boundary is
+    /// popped, the stack and call frames are unwound to what they were when it
+    /// was pushed, an `Err` with the reason of the panic is pushed, and
+    /// execution jumps to `offset` relative to the current instruction pointer.
+    /// Other errors are not caught.
+    ///
+    /// # Operation
+    ///
+    /// ```text
+    /// => *nothing*
+    /// ```
+    PushCatch {
+        /// Offset to jump to if a panic is caught.
+        offset: isize,
+    },
+    /// Pop the last catch boundary pushed by [PushCatch][Inst::PushCatch].
+    ///
+    /// # Operation
+    ///
+    /// ```text
+    /// => *nothing*
+    /// ```
+    PopCatch,
     /// Test if the top of the stack is a specific byte.
     ///
     /// # Operation
@@ -916,6 +1053,21 @@ pub enum Inst {
     /// => <unit>
     /// ```
     YieldUnit,
+    /// Yield the value at the top of the stack to the host, which is the
+    /// result of a `yield_host(..)` call.
+    ///
+    /// Unlike [Yield][Inst::Yield] this always suspends the whole execution,
+    /// even when it's used inside of a function called by the current one.
+    /// Generators and streams can't yield to the host, since they're driven by
+    /// whoever consumes them.
+    ///
+    /// # Operation
+    ///
+    /// ```text
+    /// <value>
+    /// => <value>
+    /// ```
+    YieldHost,
     /// Cause the VM to panic and error out without a reason.
     ///
     /// This should only be used during testing or extreme scenarios that are
@@ -926,6 +1078,33 @@ pub enum Inst {
     },
 }
 
+impl Inst {
+    /// Test if the instruction might grow the memory held by the virtual
+    /// machine, either by constructing a collection or by calling a function
+    /// which might modify one.
+    pub fn may_allocate(&self) -> bool {
+        matches!(
+            self,
+            Self::Add
+                | Self::AddAssign { .. }
+                | Self::Mul
+                | Self::MulAssign { .. }
+                | Self::Call { .. }
+                | Self::CallInstance { .. }
+                | Self::CallFn { .. }
+                | Self::IndexSet
+                | Self::Vec { .. }
+                | Self::Tuple { .. }
+                | Self::PushTuple
+                | Self::Object { .. }
+                | Self::TypedObject { .. }
+                | Self::VariantObject { .. }
+                | Self::StringConcat { .. }
+                | Self::StringDebug { .. }
+        )
+    }
+}
+
 impl fmt::Display for Inst {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -962,6 +1141,39 @@ impl fmt::Display for Inst {
             Self::Rem => {
                 write!(fmt, "rem")?;
             }
+            Self::RemAssign { offset } => {
+                write!(fmt, "rem-assign {}", offset)?;
+            }
+            Self::BitAnd => {
+                write!(fmt, "bit-and")?;
+            }
+            Self::BitAndAssign { offset } => {
+                write!(fmt, "bit-and-assign {}", offset)?;
+            }
+            Self::BitXor => {
+                write!(fmt, "bit-xor")?;
+            }
+            Self::BitXorAssign { offset } => {
+                write!(fmt, "bit-xor-assign {}", offset)?;
+            }
+            Self::BitOr => {
+                write!(fmt, "bit-or")?;
+            }
+            Self::BitOrAssign { offset } => {
+                write!(fmt, "bit-or-assign {}", offset)?;
+            }
+            Self::Shl => {
+                write!(fmt, "shl")?;
+            }
+            Self::ShlAssign { offset } => {
+                write!(fmt, "shl-assign {}", offset)?;
+            }
+            Self::Shr => {
+                write!(fmt, "shr")?;
+            }
+            Self::ShrAssign { offset } => {
+                write!(fmt, "shr-assign {}", offset)?;
+            }
             Self::Call { hash, args } => {
                 write!(fmt, "call {}, {}", hash, args)?;
             }
@@ -1085,6 +1297,9 @@ impl fmt::Display for Inst {
             Self::Tuple { count } => {
                 write!(fmt, "tuple {}", count)?;
             }
+            Self::Range { start, end } => {
+                write!(fmt, "range {}, {}", start, end)?;
+            }
             Self::PushTuple => {
                 write!(fmt, "push-tuple")?;
             }
@@ -1110,6 +1325,9 @@ impl fmt::Display for Inst {
             Self::StringConcat { len, size_hint } => {
                 write!(fmt, "string-concat {}, {}", len, size_hint)?;
             }
+            Self::StringDebug { pretty } => {
+                write!(fmt, "string-debug {}", pretty)?;
+            }
             Self::Char { c } => {
                 write!(fmt, "char {:?}", c)?;
             }
@@ -1137,6 +1355,15 @@ impl fmt::Display for Inst {
             Self::Unwrap => {
                 write!(fmt, "unwrap")?;
             }
+            Self::Try => {
+                write!(fmt, "try")?;
+            }
+            Self::PushCatch { offset } => {
+                write!(fmt, "push-catch {}", offset)?;
+            }
+            Self::PopCatch => {
+                write!(fmt, "pop-catch")?;
+            }
             Self::EqByte { byte } => {
                 write!(fmt, "eq-byte {:?}", byte)?;
             }
@@ -1172,6 +1399,9 @@ impl fmt::Display for Inst {
             Self::YieldUnit => {
                 write!(fmt, "yield-unit")?;
             }
+            Self::YieldHost => {
+                write!(fmt, "yield-host")?;
+            }
             Self::Panic { reason } => {
                 write!(fmt, "panic {}", reason.ident())?;
             }
@@ -0,0 +1,760 @@
+use crate::{FromValue, Function, Object, Range, ToValue, Value, VmError};
+use std::fmt;
+use std::iter;
+
+/// An owning iterator over values.
+///
+/// All native iterators are exposed to scripts through this type, which is
+/// also what iterator adapters like `rev` and `peekable` operate over.
+pub struct Iterator {
+    name: &'static str,
+    iter: Box<dyn IteratorTrait>,
+}
+
+impl Iterator {
+    /// Construct a new owning iterator from a Rust iterator.
+    ///
+    /// The name is only used for diagnostics.
+    ///
+    /// This allows native functions to return lazy sequences which scripts
+    /// can loop over. Items are converted with [ToValue] as they are
+    /// produced, and conversion errors are raised by the call to `next`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut module = runestick::Module::new(&["numbers"]);
+    ///
+    /// module.function(&["squares"], |n: i64| {
+    ///     runestick::Iterator::from("numbers::Squares", (0..n).map(|n| n * n))
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from<T>(name: &'static str, iter: T) -> Self
+    where
+        T: 'static + iter::Iterator,
+        T::Item: ToValue,
+    {
+        Self {
+            name,
+            iter: Box::new(IteratorObj { iter }),
+        }
+    }
+
+    /// Construct a new owning iterator from a double-ended Rust iterator.
+    ///
+    /// The name is only used for diagnostics.
+    pub fn from_double_ended<T>(name: &'static str, iter: T) -> Self
+    where
+        T: 'static + iter::DoubleEndedIterator,
+        T::Item: ToValue,
+    {
+        Self {
+            name,
+            iter: Box::new(DoubleEndedIteratorObj { iter }),
+        }
+    }
+
+    /// Convert an iterable value into an iterator.
+    ///
+    /// Iterators are returned as-is, while vectors and objects are iterated
+    /// over by value and ranges over the integers they contain. Errors if the value is not iterable.
+    pub fn from_iterable(value: Value) -> Result<Self, VmError> {
+        match value {
+            Value::Vec(vec) => {
+                let vec = vec.borrow_ref()?.clone();
+                Ok(Self::from_double_ended("std::vec::Iter", vec.into_iter()))
+            }
+            Value::Object(object) => {
+                let entries = object
+                    .borrow_ref()?
+                    .iter()
+                    .map(|(k, v)| (k.clone(), v.clone()))
+                    .collect::<Vec<_>>();
+
+                Ok(Self::from_double_ended(
+                    "std::object::Iter",
+                    entries.into_iter(),
+                ))
+            }
+            Value::Any(any) if any.borrow_ref()?.is::<Self>() => Self::from_value(Value::Any(any)),
+            Value::Any(any) if any.borrow_ref()?.is::<Range>() => {
+                any.downcast_borrow_ref::<Range>()?.iter()
+            }
+            actual => Err(VmError::panic(format!(
+                "`{}` is not iterable",
+                actual.type_info()?
+            ))),
+        }
+    }
+
+    /// Get the name of the iterator.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Get the next value out of the iterator.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Result<Option<Value>, VmError> {
+        self.iter.next()
+    }
+
+    /// Get the next value from the back of the iterator.
+    ///
+    /// Errors if the iterator is not double-ended.
+    pub fn next_back(&mut self) -> Result<Option<Value>, VmError> {
+        if !self.iter.is_double_ended() {
+            return Err(self.not_double_ended());
+        }
+
+        self.iter.next_back()
+    }
+
+    /// Reverse the iterator.
+    ///
+    /// Errors if the iterator is not double-ended.
+    pub fn rev(self) -> Result<Self, VmError> {
+        if !self.iter.is_double_ended() {
+            return Err(self.not_double_ended());
+        }
+
+        Ok(Self {
+            name: self.name,
+            iter: Box::new(Rev { iter: self.iter }),
+        })
+    }
+
+    /// Step over the iterator by the given amount, always starting with the
+    /// first element.
+    ///
+    /// Errors if the step is zero.
+    pub fn step_by(self, step: usize) -> Result<Self, VmError> {
+        if step == 0 {
+            return Err(VmError::panic("step must be non-zero"));
+        }
+
+        Ok(Self {
+            name: self.name,
+            iter: Box::new(StepBy {
+                iter: self.iter,
+                step,
+                first: true,
+            }),
+        })
+    }
+
+    /// Chain this iterator with another, yielding all values of this
+    /// iterator followed by all values of the other.
+    ///
+    /// The chained iterator is double-ended if both iterators are.
+    pub fn chain(self, other: Self) -> Self {
+        Self {
+            name: self.name,
+            iter: Box::new(Chain {
+                a: Some(self.iter),
+                b: Some(other.iter),
+            }),
+        }
+    }
+
+    /// Make the iterator peekable.
+    pub fn peekable(self) -> Peekable {
+        Peekable {
+            iter: self,
+            peeked: None,
+        }
+    }
+
+    /// Pair each value of the iterator with its index, yielding `(index,
+    /// value)` tuples.
+    pub fn enumerate(self) -> Self {
+        Self {
+            name: self.name,
+            iter: Box::new(Enumerate {
+                iter: self.iter,
+                index: 0,
+            }),
+        }
+    }
+
+    /// Map each value of the iterator through the given function.
+    pub fn map(self, f: Function) -> Self {
+        Self {
+            name: self.name,
+            iter: Box::new(Map { iter: self.iter, f }),
+        }
+    }
+
+    /// Map each value of the iterator through the given function, which must
+    /// return an iterable value, and flatten the result.
+    pub fn flat_map(self, f: Function) -> Self {
+        Self {
+            name: self.name,
+            iter: Box::new(FlatMap {
+                iter: self.iter,
+                f,
+                current: None,
+            }),
+        }
+    }
+
+    /// Thread a state through the iterator, starting with `initial`.
+    ///
+    /// The function is called with the current state and the next value,
+    /// and returns either `Some((state, value))` to update the state and
+    /// yield a value, or `None` to end the iteration.
+    pub fn scan(self, initial: Value, f: Function) -> Self {
+        Self {
+            name: self.name,
+            iter: Box::new(Scan {
+                iter: self.iter,
+                f,
+                state: Some(initial),
+            }),
+        }
+    }
+
+    /// Yield values while the predicate returns `true`, stopping at the first
+    /// value for which it returns `false`.
+    pub fn take_while(self, predicate: Function) -> Self {
+        Self {
+            name: self.name,
+            iter: Box::new(TakeWhile {
+                iter: self.iter,
+                predicate,
+                done: false,
+            }),
+        }
+    }
+
+    /// Skip values while the predicate returns `true`, yielding everything
+    /// starting with the first value for which it returns `false`.
+    pub fn skip_while(self, predicate: Function) -> Self {
+        Self {
+            name: self.name,
+            iter: Box::new(SkipWhile {
+                iter: self.iter,
+                predicate,
+                skipping: true,
+            }),
+        }
+    }
+
+    /// Find the first value for which the predicate returns `true`.
+    ///
+    /// Values are consumed up to and including the one which was found.
+    pub fn find(&mut self, predicate: Function) -> Result<Option<Value>, VmError> {
+        while let Some(value) = self.iter.next()? {
+            if predicate.call::<_, bool>((value.clone(),))? {
+                return Ok(Some(value));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Test if the predicate returns `true` for any value, stopping at the
+    /// first one for which it does.
+    pub fn any(&mut self, predicate: Function) -> Result<bool, VmError> {
+        while let Some(value) = self.iter.next()? {
+            if predicate.call::<_, bool>((value,))? {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Test if the predicate returns `true` for all values, stopping at the
+    /// first one for which it doesn't.
+    pub fn all(&mut self, predicate: Function) -> Result<bool, VmError> {
+        while let Some(value) = self.iter.next()? {
+            if !predicate.call::<_, bool>((value,))? {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Consume the iterator and count the number of values it yields.
+    pub fn count(&mut self) -> Result<usize, VmError> {
+        let mut count = 0;
+
+        while self.iter.next()?.is_some() {
+            count += 1;
+        }
+
+        Ok(count)
+    }
+
+    /// Consume the iterator and return its last value.
+    pub fn last(&mut self) -> Result<Option<Value>, VmError> {
+        let mut last = None;
+
+        while let Some(value) = self.iter.next()? {
+            last = Some(value);
+        }
+
+        Ok(last)
+    }
+
+    /// Get the `n`th value of the iterator, consuming all values before it.
+    ///
+    /// Returns `None` if the iterator is exhausted before reaching it.
+    pub fn nth(&mut self, n: usize) -> Result<Option<Value>, VmError> {
+        for _ in 0..n {
+            if self.iter.next()?.is_none() {
+                return Ok(None);
+            }
+        }
+
+        self.iter.next()
+    }
+
+    /// Consume the iterator and collect its `(key, value)` pairs into an
+    /// object.
+    ///
+    /// Panics if any value is not a pair, or if any key is not a string.
+    pub fn collect_object(&mut self) -> Result<Object<Value>, VmError> {
+        let mut object = crate::object_with_capacity(0);
+
+        while let Some(value) = self.iter.next()? {
+            let tuple = match value {
+                Value::Tuple(tuple) => tuple,
+                actual => {
+                    return Err(VmError::panic(format!(
+                        "expected a `(key, value)` pair, but got `{}`",
+                        actual.type_info()?
+                    )));
+                }
+            };
+
+            let tuple = tuple.borrow_ref()?;
+
+            let (key, value) = match &tuple[..] {
+                [key, value] => (key, value),
+                _ => {
+                    return Err(VmError::panic(format!(
+                        "expected a `(key, value)` pair, but got a tuple of length {}",
+                        tuple.len()
+                    )));
+                }
+            };
+
+            let key = match key {
+                Value::String(string) => string.borrow_ref()?.clone(),
+                Value::StaticString(string) => string.as_ref().to_string(),
+                actual => {
+                    return Err(VmError::panic(format!(
+                        "object keys must be strings, but got `{}`",
+                        actual.type_info()?
+                    )));
+                }
+            };
+
+            object.insert(key, value.clone());
+        }
+
+        Ok(object)
+    }
+
+    fn not_double_ended(&self) -> VmError {
+        VmError::panic(format!("`{}` is not a double-ended iterator", self.name))
+    }
+}
+
+impl fmt::Debug for Iterator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Iterator").field(&self.name).finish()
+    }
+}
+
+/// The internal, type-erased iterator trait.
+trait IteratorTrait: 'static {
+    /// Get the next value out of the iterator.
+    fn next(&mut self) -> Result<Option<Value>, VmError>;
+
+    /// Get the next value from the back of the iterator.
+    ///
+    /// Only called if [is_double_ended][Self::is_double_ended] returns `true`.
+    fn next_back(&mut self) -> Result<Option<Value>, VmError>;
+
+    /// Test if the iterator is double-ended.
+    fn is_double_ended(&self) -> bool;
+}
+
+struct IteratorObj<T> {
+    iter: T,
+}
+
+impl<T> IteratorTrait for IteratorObj<T>
+where
+    T: 'static + iter::Iterator,
+    T::Item: ToValue,
+{
+    fn next(&mut self) -> Result<Option<Value>, VmError> {
+        match self.iter.next() {
+            Some(value) => Ok(Some(value.to_value()?)),
+            None => Ok(None),
+        }
+    }
+
+    fn next_back(&mut self) -> Result<Option<Value>, VmError> {
+        Err(VmError::panic("iterator is not double-ended"))
+    }
+
+    fn is_double_ended(&self) -> bool {
+        false
+    }
+}
+
+struct DoubleEndedIteratorObj<T> {
+    iter: T,
+}
+
+impl<T> IteratorTrait for DoubleEndedIteratorObj<T>
+where
+    T: 'static + iter::DoubleEndedIterator,
+    T::Item: ToValue,
+{
+    fn next(&mut self) -> Result<Option<Value>, VmError> {
+        match self.iter.next() {
+            Some(value) => Ok(Some(value.to_value()?)),
+            None => Ok(None),
+        }
+    }
+
+    fn next_back(&mut self) -> Result<Option<Value>, VmError> {
+        match self.iter.next_back() {
+            Some(value) => Ok(Some(value.to_value()?)),
+            None => Ok(None),
+        }
+    }
+
+    fn is_double_ended(&self) -> bool {
+        true
+    }
+}
+
+struct Rev {
+    iter: Box<dyn IteratorTrait>,
+}
+
+impl IteratorTrait for Rev {
+    fn next(&mut self) -> Result<Option<Value>, VmError> {
+        self.iter.next_back()
+    }
+
+    fn next_back(&mut self) -> Result<Option<Value>, VmError> {
+        self.iter.next()
+    }
+
+    fn is_double_ended(&self) -> bool {
+        true
+    }
+}
+
+struct StepBy {
+    iter: Box<dyn IteratorTrait>,
+    step: usize,
+    first: bool,
+}
+
+impl IteratorTrait for StepBy {
+    fn next(&mut self) -> Result<Option<Value>, VmError> {
+        if self.first {
+            self.first = false;
+            return self.iter.next();
+        }
+
+        for _ in 1..self.step {
+            if self.iter.next()?.is_none() {
+                return Ok(None);
+            }
+        }
+
+        self.iter.next()
+    }
+
+    fn next_back(&mut self) -> Result<Option<Value>, VmError> {
+        Err(VmError::panic("iterator is not double-ended"))
+    }
+
+    fn is_double_ended(&self) -> bool {
+        false
+    }
+}
+
+struct Chain {
+    a: Option<Box<dyn IteratorTrait>>,
+    b: Option<Box<dyn IteratorTrait>>,
+}
+
+impl IteratorTrait for Chain {
+    fn next(&mut self) -> Result<Option<Value>, VmError> {
+        if let Some(a) = &mut self.a {
+            if let Some(value) = a.next()? {
+                return Ok(Some(value));
+            }
+
+            self.a = None;
+        }
+
+        match &mut self.b {
+            Some(b) => b.next(),
+            None => Ok(None),
+        }
+    }
+
+    fn next_back(&mut self) -> Result<Option<Value>, VmError> {
+        if let Some(b) = &mut self.b {
+            if let Some(value) = b.next_back()? {
+                return Ok(Some(value));
+            }
+
+            self.b = None;
+        }
+
+        match &mut self.a {
+            Some(a) => a.next_back(),
+            None => Ok(None),
+        }
+    }
+
+    fn is_double_ended(&self) -> bool {
+        let a = self.a.as_ref().map(|a| a.is_double_ended()).unwrap_or(true);
+        let b = self.b.as_ref().map(|b| b.is_double_ended()).unwrap_or(true);
+        a && b
+    }
+}
+
+struct Enumerate {
+    iter: Box<dyn IteratorTrait>,
+    index: usize,
+}
+
+impl IteratorTrait for Enumerate {
+    fn next(&mut self) -> Result<Option<Value>, VmError> {
+        let value = match self.iter.next()? {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+
+        let index = self.index;
+        self.index += 1;
+        Ok(Some((index, value).to_value()?))
+    }
+
+    fn next_back(&mut self) -> Result<Option<Value>, VmError> {
+        Err(VmError::panic("iterator is not double-ended"))
+    }
+
+    fn is_double_ended(&self) -> bool {
+        false
+    }
+}
+
+struct Map {
+    iter: Box<dyn IteratorTrait>,
+    f: Function,
+}
+
+impl IteratorTrait for Map {
+    fn next(&mut self) -> Result<Option<Value>, VmError> {
+        match self.iter.next()? {
+            Some(value) => Ok(Some(self.f.call::<_, Value>((value,))?)),
+            None => Ok(None),
+        }
+    }
+
+    fn next_back(&mut self) -> Result<Option<Value>, VmError> {
+        match self.iter.next_back()? {
+            Some(value) => Ok(Some(self.f.call::<_, Value>((value,))?)),
+            None => Ok(None),
+        }
+    }
+
+    fn is_double_ended(&self) -> bool {
+        self.iter.is_double_ended()
+    }
+}
+
+struct FlatMap {
+    iter: Box<dyn IteratorTrait>,
+    f: Function,
+    current: Option<Box<dyn IteratorTrait>>,
+}
+
+impl IteratorTrait for FlatMap {
+    fn next(&mut self) -> Result<Option<Value>, VmError> {
+        loop {
+            if let Some(current) = &mut self.current {
+                if let Some(value) = current.next()? {
+                    return Ok(Some(value));
+                }
+
+                self.current = None;
+            }
+
+            let value = match self.iter.next()? {
+                Some(value) => value,
+                None => return Ok(None),
+            };
+
+            let value = self.f.call::<_, Value>((value,))?;
+            self.current = Some(Iterator::from_iterable(value)?.iter);
+        }
+    }
+
+    fn next_back(&mut self) -> Result<Option<Value>, VmError> {
+        Err(VmError::panic("iterator is not double-ended"))
+    }
+
+    fn is_double_ended(&self) -> bool {
+        false
+    }
+}
+
+struct Scan {
+    iter: Box<dyn IteratorTrait>,
+    f: Function,
+    /// The current state, or `None` if the iteration has ended.
+    state: Option<Value>,
+}
+
+impl IteratorTrait for Scan {
+    fn next(&mut self) -> Result<Option<Value>, VmError> {
+        let state = match self.state.take() {
+            Some(state) => state,
+            None => return Ok(None),
+        };
+
+        let value = match self.iter.next()? {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+
+        match self.f.call::<_, Option<(Value, Value)>>((state, value))? {
+            Some((state, value)) => {
+                self.state = Some(state);
+                Ok(Some(value))
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_back(&mut self) -> Result<Option<Value>, VmError> {
+        Err(VmError::panic("iterator is not double-ended"))
+    }
+
+    fn is_double_ended(&self) -> bool {
+        false
+    }
+}
+
+struct TakeWhile {
+    iter: Box<dyn IteratorTrait>,
+    predicate: Function,
+    done: bool,
+}
+
+impl IteratorTrait for TakeWhile {
+    fn next(&mut self) -> Result<Option<Value>, VmError> {
+        if self.done {
+            return Ok(None);
+        }
+
+        let value = match self.iter.next()? {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+
+        if self.predicate.call::<_, bool>((value.clone(),))? {
+            return Ok(Some(value));
+        }
+
+        self.done = true;
+        Ok(None)
+    }
+
+    fn next_back(&mut self) -> Result<Option<Value>, VmError> {
+        Err(VmError::panic("iterator is not double-ended"))
+    }
+
+    fn is_double_ended(&self) -> bool {
+        false
+    }
+}
+
+struct SkipWhile {
+    iter: Box<dyn IteratorTrait>,
+    predicate: Function,
+    skipping: bool,
+}
+
+impl IteratorTrait for SkipWhile {
+    fn next(&mut self) -> Result<Option<Value>, VmError> {
+        if !self.skipping {
+            return self.iter.next();
+        }
+
+        while let Some(value) = self.iter.next()? {
+            if !self.predicate.call::<_, bool>((value.clone(),))? {
+                self.skipping = false;
+                return Ok(Some(value));
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn next_back(&mut self) -> Result<Option<Value>, VmError> {
+        Err(VmError::panic("iterator is not double-ended"))
+    }
+
+    fn is_double_ended(&self) -> bool {
+        false
+    }
+}
+
+/// An iterator which can peek at its next value without consuming it.
+///
+/// Constructed through [Iterator::peekable].
+pub struct Peekable {
+    iter: Iterator,
+    peeked: Option<Option<Value>>,
+}
+
+impl Peekable {
+    /// Get the next value out of the iterator.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Result<Option<Value>, VmError> {
+        match self.peeked.take() {
+            Some(peeked) => Ok(peeked),
+            None => self.iter.next(),
+        }
+    }
+
+    /// Peek at the next value without consuming it.
+    pub fn peek(&mut self) -> Result<Option<Value>, VmError> {
+        if let Some(peeked) = &self.peeked {
+            return Ok(peeked.clone());
+        }
+
+        let value = self.iter.next()?;
+        self.peeked = Some(value.clone());
+        Ok(value)
+    }
+}
+
+impl fmt::Debug for Peekable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Peekable").field(&self.iter.name).finish()
+    }
+}
+
+impl_external!(Iterator);
+impl_external!(Peekable);
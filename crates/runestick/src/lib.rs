@@ -38,7 +38,9 @@
 #![deny(missing_docs)]
 
 mod any;
+mod capabilities;
 mod context;
+mod context_config;
 mod value;
 mod vm;
 #[macro_use]
@@ -49,20 +51,26 @@ mod assembly;
 mod awaited;
 mod bytes;
 mod call;
+mod const_value;
 mod debug;
+mod env;
 mod function;
 mod future;
 mod generator;
 mod generator_state;
 mod hash;
+mod input;
 mod inst;
 mod item;
+mod iterator;
 mod meta;
 pub mod module;
 pub mod modules;
 mod names;
+mod output;
 mod panic;
 mod protocol;
+mod range;
 mod reflection;
 mod select;
 mod serde;
@@ -118,27 +126,38 @@ pub use crate::any::Any;
 pub use crate::awaited::Awaited;
 pub use crate::bytes::Bytes;
 pub use crate::call::Call;
+pub use crate::capabilities::{Capabilities, Capability};
+pub use crate::const_value::ConstValue;
 pub use crate::context::{Context, ContextError, IntoInstFnHash};
+pub use crate::context_config::{ContextConfig, DefaultModule};
 pub use crate::debug::{DebugInfo, DebugInst};
 pub use crate::function::Function;
 pub use crate::future::Future;
 pub use crate::hash::{Hash, IntoHash};
+pub use crate::input::Input;
 pub use crate::inst::{Inst, PanicReason, TypeCheck};
 pub use crate::item::{Component, Item};
+pub use crate::iterator::{Iterator, Peekable};
 pub use crate::names::Names;
+pub use crate::output::Output;
 pub use crate::panic::Panic;
 pub use crate::protocol::{
-    Protocol, ADD, ADD_ASSIGN, DIV, DIV_ASSIGN, INDEX_GET, INDEX_SET, INTO_FUTURE, INTO_ITER, MUL,
-    MUL_ASSIGN, NEXT, REM, STRING_DISPLAY, SUB, SUB_ASSIGN,
+    Protocol, ADD, ADD_ASSIGN, BIT_AND, BIT_AND_ASSIGN, BIT_OR, BIT_OR_ASSIGN, BIT_XOR,
+    BIT_XOR_ASSIGN, DIV, DIV_ASSIGN, INDEX_GET, INDEX_SET, INTO_FUTURE, INTO_ITER, MUL, MUL_ASSIGN,
+    NEXT, REM, REM_ASSIGN, SHL, SHL_ASSIGN, SHR, SHR_ASSIGN, STRING_DEBUG, STRING_DISPLAY, SUB,
+    SUB_ASSIGN, TRY,
 };
+pub use crate::range::Range;
 pub use crate::reflection::{FromValue, ToValue, UnsafeFromValue, ValueType};
 pub use crate::shared::{OwnedMut, OwnedRef, RawOwnedMut, RawOwnedRef, Shared};
 pub use crate::stack::{Stack, StackError};
 pub use crate::unit::{
     ImportEntry, ImportKey, LinkerError, LinkerErrors, Unit, UnitError, UnitFnKind,
+    UnitFnSignature, UnitFunction,
 };
 pub use crate::value::{
-    Integer, Object, TypedObject, TypedTuple, Value, VariantObject, VariantTuple,
+    object_with_capacity, set_object_seed, set_recursion_limit, Integer, Object, TypedObject,
+    TypedTuple, Value, VariantObject, VariantTuple, DEFAULT_RECURSION_LIMIT,
 };
 pub use crate::vec_tuple::VecTuple;
 pub use crate::vm::{CallFrame, Vm};
@@ -148,6 +167,7 @@ pub use crate::vm_execution::VmExecution;
 pub use crate::vm_halt::{VmHalt, VmHaltInfo};
 
 mod collections {
+    pub use hashbrown::hash_map::DefaultHashBuilder;
     pub use hashbrown::HashMap;
     pub use hashbrown::HashSet;
 }
@@ -1,9 +1,13 @@
 use crate::collections::HashSet;
-use crate::{Hash, Item, Type};
+use crate::{ConstValue, Hash, Item, Type};
 use std::fmt;
 use std::sync::Arc;
 
-/// Metadata about a closure.
+/// Metadata about a variable captured by a closure.
+///
+/// Captured variables are shared by reference. Both the closure and the scope
+/// which declared the variable hold the same shared cell (a single-element
+/// tuple), so assignments made through either one are visible to the other.
 #[derive(Debug, Clone)]
 pub struct MetaClosureCapture {
     /// Identity of the captured variable.
@@ -58,6 +62,17 @@ pub enum Meta {
         value_type: Type,
         /// The item of the function declaration.
         item: Item,
+        /// The number of arguments the function accepts, if known.
+        ///
+        /// For variadic functions this is the number of fixed arguments.
+        args: Option<usize>,
+        /// The number of trailing arguments which have default values.
+        defaults: usize,
+        /// If the function is variadic.
+        variadic: bool,
+        /// The names of the fixed arguments, if known. Used to resolve calls
+        /// with named arguments.
+        arg_names: Option<Arc<Vec<String>>>,
     },
     /// A closure.
     MetaClosure {
@@ -68,6 +83,13 @@ pub enum Meta {
         /// Sequence of captured variables.
         captures: Arc<Vec<MetaClosureCapture>>,
     },
+    /// A constant.
+    MetaConstant {
+        /// The item of the constant.
+        item: Item,
+        /// The value of the constant.
+        value: ConstValue,
+    },
     /// An async block.
     MetaAsyncBlock {
         /// The value type associated with this meta item.
@@ -91,6 +113,7 @@ impl Meta {
             Meta::MetaFunction { item, .. } => item,
             Meta::MetaClosure { item, .. } => item,
             Meta::MetaAsyncBlock { item, .. } => item,
+            Meta::MetaConstant { item, .. } => item,
         }
     }
 
@@ -105,6 +128,7 @@ impl Meta {
             Self::MetaFunction { value_type, .. } => Some(*value_type),
             Self::MetaClosure { value_type, .. } => Some(*value_type),
             Self::MetaAsyncBlock { value_type, .. } => Some(*value_type),
+            Self::MetaConstant { .. } => None,
         }
     }
 }
@@ -136,6 +160,9 @@ impl fmt::Display for Meta {
             Self::MetaAsyncBlock { item, .. } => {
                 write!(fmt, "async block {}", item)?;
             }
+            Self::MetaConstant { item, .. } => {
+                write!(fmt, "const {}", item)?;
+            }
         }
 
         Ok(())
@@ -5,15 +5,15 @@
 
 use crate::collections::HashMap;
 use crate::{
-    Component, Future, Hash, Stack, ToValue, Type, TypeInfo, UnsafeFromValue, ValueType, VmError,
-    VmErrorKind,
+    Component, ConstValue, Future, Hash, Stack, ToValue, Type, TypeInfo, UnsafeFromValue,
+    ValueType, VmError, VmErrorKind,
 };
 use std::any::type_name;
 use std::future;
 use std::sync::Arc;
 
 use crate::context::{ContextError, Handler, IntoInstFnHash};
-use crate::{GeneratorState, Item, StaticType, TypeCheck, Value};
+use crate::{Capabilities, Capability, GeneratorState, Item, StaticType, TypeCheck, Value};
 
 /// Specialized information on `Option` types.
 pub(crate) struct ModuleUnitType {
@@ -139,6 +139,10 @@ pub struct Module {
     pub(crate) unit_type: Option<ModuleUnitType>,
     /// Registered generator state type.
     pub(crate) internal_enums: Vec<ModuleInternalEnum>,
+    /// Registered constants.
+    pub(crate) constants: HashMap<Item, ConstValue>,
+    /// Capabilities required to install the module.
+    pub(crate) capabilities: Capabilities,
 }
 
 impl Module {
@@ -155,9 +159,35 @@ impl Module {
             types: Default::default(),
             unit_type: None,
             internal_enums: Vec::new(),
+            constants: Default::default(),
+            capabilities: Capabilities::none(),
         }
     }
 
+    /// Declare that the module requires the given capability, which must have
+    /// been granted to the [Context][crate::Context] it's installed into.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::{Capability, Context, Module};
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut module = Module::new(&["fs"]);
+    /// module.require(Capability::Fs);
+    ///
+    /// let mut context = Context::new();
+    /// assert!(context.install(&module).is_err());
+    ///
+    /// context.grant(Capability::Fs);
+    /// context.install(&module)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn require(&mut self, capability: Capability) {
+        self.capabilities = self.capabilities.with(capability);
+    }
+
     /// Register a type. Registering a type is mandatory in order to register
     /// instance functions using that type.
     ///
@@ -343,8 +373,58 @@ impl Module {
         Ok(())
     }
 
+    /// Register a constant value.
+    ///
+    /// Constants are resolved when compiling, so any reference to them in a
+    /// script is replaced with their value. Only primitive values and strings
+    /// can be registered as constants, see [ConstValue].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut module = runestick::Module::new(&["math"]);
+    ///
+    /// module.constant(&["PI"], std::f64::consts::PI)?;
+    /// module.constant(&["VERSION"], "1.0.0")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn constant<N, V>(&mut self, name: N, value: V) -> Result<(), ContextError>
+    where
+        N: IntoIterator,
+        N::Item: Into<Component>,
+        V: ToValue,
+    {
+        let name = Item::of(name);
+
+        if self.constants.contains_key(&name) {
+            return Err(ContextError::ConflictingConstantName { name });
+        }
+
+        let value = match value.to_value() {
+            Ok(value) => value,
+            Err(error) => return Err(ContextError::ConstantValue { name, error }),
+        };
+
+        let value = match ConstValue::from_value(value) {
+            Ok(Ok(value)) => value,
+            Ok(Err(type_info)) => {
+                return Err(ContextError::UnsupportedConstant { name, type_info });
+            }
+            Err(error) => return Err(ContextError::ConstantValue { name, error }),
+        };
+
+        self.constants.insert(name, value);
+        Ok(())
+    }
+
     /// Register a function that cannot error internally.
     ///
+    /// Functions returning [crate::Result] produce a Rune `Result`, where the
+    /// error is a `std::error::Error` which can be caught and inspected by the
+    /// calling script.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -427,7 +507,7 @@ impl Module {
     /// machine.
     pub fn raw_fn<F, N>(&mut self, name: N, f: F) -> Result<(), ContextError>
     where
-        F: 'static + Copy + Fn(&mut Stack, usize) -> Result<(), VmError> + Send + Sync,
+        F: 'static + Fn(&mut Stack, usize) -> Result<(), VmError> + Send + Sync,
         N: IntoIterator,
         N::Item: Into<Component>,
     {
@@ -539,7 +619,13 @@ impl Module {
         Ok(())
     }
 
-    /// Register an instance function.
+    /// Register an async instance function.
+    ///
+    /// The output of the future is converted with [ToValue], the same way as
+    /// for any other native function. So a future producing a `Result<T, E>`
+    /// where `E: ToValue`, like an external error type, results in a Rune
+    /// `Result` which can be handled in the script with `?` or `match`. Only
+    /// errors of type [VmError] abort the execution of the virtual machine.
     ///
     /// # Examples
     ///
@@ -742,15 +828,18 @@ macro_rules! impl_register {
                 // guarantee that it won't be modified.
                 //
                 // The scope is also necessary, since we mutably access `stack`
-                // when we return below.
+                // when we return below. The return value is converted inside
+                // of it, since it might borrow from arguments whose guards are
+                // dropped at the end of the scope.
                 #[allow(unused)]
                 let ret = unsafe {
                     impl_register!{@unsafe-vars $count, $($ty, $var, $num,)*}
 
-                    self($(<$ty>::to_arg($var.0),)*)
+                    let ret = self($(<$ty>::to_arg($var.0),)*);
+                    impl_register!{@to-value ret, Return}
                 };
 
-                impl_register!{@return stack, ret, Return}
+                stack.push(ret);
                 Ok(())
             }
         }
@@ -835,14 +924,17 @@ macro_rules! impl_register {
                 // guarantee that it won't be modified.
                 //
                 // The scope is also necessary, since we mutably access `stack`
-                // when we return below.
+                // when we return below. The return value is converted inside
+                // of it, since it might borrow from arguments whose guards are
+                // dropped at the end of the scope.
                 #[allow(unused)]
                 let ret = unsafe {
                     impl_register!{@unsafe-inst-vars inst, $count, $($ty, $var, $num,)*}
-                    self(Instance::to_arg(inst.0), $(<$ty>::to_arg($var.0),)*)
+                    let ret = self(Instance::to_arg(inst.0), $(<$ty>::to_arg($var.0),)*);
+                    impl_register!{@to-value ret, Return}
                 };
 
-                impl_register!{@return stack, ret, Return}
+                stack.push(ret);
                 Ok(())
             }
         }
@@ -901,15 +993,18 @@ macro_rules! impl_register {
     };
 
     (@return $stack:ident, $ret:ident, $ty:ty) => {
-        let $ret = match $ret.to_value() {
+        let $ret = impl_register!{@to-value $ret, $ty};
+        $stack.push($ret);
+    };
+
+    (@to-value $ret:ident, $ty:ty) => {
+        match $ret.to_value() {
             Ok($ret) => $ret,
             Err(e) => return Err(VmError::from(VmErrorKind::BadReturn {
                 error: e.unpack_critical()?,
                 ret: type_name::<$ty>()
             })),
-        };
-
-        $stack.push($ret);
+        }
     };
 
     // Expand to function variable bindings.
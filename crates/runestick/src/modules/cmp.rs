@@ -0,0 +1,51 @@
+//! The `std::cmp` module.
+
+use crate::{ContextError, Module, Value, VmError};
+use std::cmp::Ordering;
+
+/// Construct the `std::cmp` module.
+pub fn module() -> Result<Module, ContextError> {
+    let mut module = Module::new(&["std", "cmp"]);
+    module.function(&["min"], min)?;
+    module.function(&["max"], max)?;
+    module.function(&["clamp"], clamp)?;
+    Ok(module)
+}
+
+/// Return the smaller of two values, or the first one if they are equal or
+/// unordered.
+fn min(a: Value, b: Value) -> Result<Value, VmError> {
+    match Value::value_ptr_partial_cmp(&b, &a, "min")? {
+        Some(Ordering::Less) => Ok(b),
+        _ => Ok(a),
+    }
+}
+
+/// Return the larger of two values, or the second one if they are equal or
+/// unordered.
+fn max(a: Value, b: Value) -> Result<Value, VmError> {
+    match Value::value_ptr_partial_cmp(&a, &b, "max")? {
+        Some(Ordering::Greater) => Ok(a),
+        _ => Ok(b),
+    }
+}
+
+/// Restrict a value to the interval `[min, max]`.
+///
+/// Errors if `min` is greater than `max`, or if they are unordered.
+fn clamp(value: Value, min: Value, max: Value) -> Result<Value, VmError> {
+    match Value::value_ptr_partial_cmp(&min, &max, "clamp")? {
+        Some(Ordering::Less) | Some(Ordering::Equal) => (),
+        _ => return Err(VmError::panic("clamp requires that `min <= max`")),
+    }
+
+    if let Some(Ordering::Less) = Value::value_ptr_partial_cmp(&value, &min, "clamp")? {
+        return Ok(min);
+    }
+
+    if let Some(Ordering::Greater) = Value::value_ptr_partial_cmp(&value, &max, "clamp")? {
+        return Ok(max);
+    }
+
+    Ok(value)
+}
@@ -0,0 +1,111 @@
+//! The `std::collections` module.
+
+use crate::{ContextError, Iterator, Module, Value, VmError};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fmt::Write as _;
+
+/// Construct the `std::collections` module.
+pub fn module() -> Result<Module, ContextError> {
+    let mut module = Module::new(&["std", "collections"]);
+
+    module.ty(&["SortedMap"]).build::<SortedMap>()?;
+
+    module.function(&["SortedMap", "new"], SortedMap::new)?;
+    module.inst_fn("len", SortedMap::len)?;
+    module.inst_fn("is_empty", SortedMap::is_empty)?;
+    module.inst_fn("insert", SortedMap::insert)?;
+    module.inst_fn("remove", SortedMap::remove)?;
+    module.inst_fn("get", SortedMap::get)?;
+    module.inst_fn("contains_key", SortedMap::contains_key)?;
+    module.inst_fn("clear", SortedMap::clear)?;
+    module.inst_fn("keys", SortedMap::keys)?;
+    module.inst_fn("values", SortedMap::values)?;
+    module.inst_fn("iter", SortedMap::iter)?;
+
+    module.inst_fn(crate::INDEX_GET, SortedMap::index_get)?;
+    module.inst_fn(crate::INDEX_SET, SortedMap::insert_unit)?;
+    module.inst_fn(crate::INTO_ITER, SortedMap::iter)?;
+    module.inst_fn(crate::STRING_DEBUG, SortedMap::string_debug)?;
+    Ok(module)
+}
+
+/// A map with string keys which iterates over its entries in key order.
+#[derive(Debug, Default)]
+struct SortedMap {
+    inner: BTreeMap<String, Value>,
+}
+
+impl SortedMap {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Insert a value, returning the previous value for the key if present.
+    fn insert(&mut self, key: String, value: Value) -> Option<Value> {
+        self.inner.insert(key, value)
+    }
+
+    /// Insert a value as part of an index set operation.
+    fn insert_unit(&mut self, key: String, value: Value) {
+        self.inner.insert(key, value);
+    }
+
+    fn remove(&mut self, key: &str) -> Option<Value> {
+        self.inner.remove(key)
+    }
+
+    fn get(&self, key: &str) -> Option<Value> {
+        self.inner.get(key).cloned()
+    }
+
+    fn contains_key(&self, key: &str) -> bool {
+        self.inner.contains_key(key)
+    }
+
+    fn clear(&mut self) {
+        self.inner.clear();
+    }
+
+    /// Get the keys of the map in sorted order.
+    fn keys(&self) -> Vec<String> {
+        self.inner.keys().cloned().collect()
+    }
+
+    /// Get the values of the map, ordered by their keys.
+    fn values(&self) -> Vec<Value> {
+        self.inner.values().cloned().collect()
+    }
+
+    /// Iterate over `(key, value)` tuples in key order.
+    fn iter(&self) -> Iterator {
+        let entries = self
+            .inner
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect::<Vec<_>>();
+
+        Iterator::from_double_ended("std::collections::sorted_map::Iter", entries.into_iter())
+    }
+
+    fn index_get(&self, key: &str) -> Result<Value, VmError> {
+        match self.inner.get(key) {
+            Some(value) => Ok(value.clone()),
+            None => Err(VmError::panic(format!("missing key `{}`", key))),
+        }
+    }
+
+    fn string_debug(&self, buf: &mut String) -> fmt::Result {
+        write!(buf, "{:?}", self.inner)
+    }
+}
+
+impl_external!(SortedMap);
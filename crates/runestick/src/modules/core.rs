@@ -1,8 +1,10 @@
 //! The core `std` module.
 
-use crate::{ContextError, Module, Panic, Stack, Value, VmError};
-use std::io;
-use std::io::Write as _;
+use crate::env;
+use crate::{
+    Bytes, ContextError, Function, ImportKey, Module, Object, Panic, PanicReason, Shared, Stack,
+    Tuple, Value, VmError, VmErrorKind,
+};
 
 /// Construct the `std` module.
 pub fn module() -> Result<Module, ContextError> {
@@ -12,18 +14,32 @@ pub fn module() -> Result<Module, ContextError> {
     module.ty(&["bool"]).build::<bool>()?;
     module.ty(&["char"]).build::<char>()?;
     module.ty(&["byte"]).build::<u8>()?;
+    module.inst_fn("to_int", byte_to_int)?;
 
-    module.function(&["print"], print_impl)?;
-    module.function(&["println"], println_impl)?;
     module.function(&["panic"], panic_impl)?;
-    module.raw_fn(&["dbg"], dbg_impl)?;
+    module.function(&["todo"], todo_impl)?;
+    module.function(&["unimplemented"], unimplemented_impl)?;
+    module.function(&["try_catch"], try_catch_impl)?;
 
     module.function(&["drop"], drop_impl)?;
+    module.function(&["clone"], clone_impl)?;
     module.function(&["is_readable"], is_readable)?;
     module.function(&["is_writable"], is_writable)?;
+    module.raw_fn(&["hash"], hash_impl)?;
+    module.function(&["type_of"], type_of_impl)?;
+    module.function(&["type_name"], type_name_impl)?;
+
+    module.function(&["mem_swap"], mem_swap_impl)?;
+    module.function(&["mem_replace"], mem_replace_impl)?;
+    module.function(&["mem_take"], mem_take_impl)?;
     Ok(module)
 }
 
+/// Convert a byte to a whole number.
+fn byte_to_int(value: u8) -> i64 {
+    i64::from(value)
+}
+
 fn drop_impl(value: Value) -> Result<(), VmError> {
     match value {
         Value::Any(any) => {
@@ -62,39 +78,73 @@ fn drop_impl(value: Value) -> Result<(), VmError> {
     Ok::<(), VmError>(())
 }
 
-fn dbg_impl(stack: &mut Stack, args: usize) -> Result<(), VmError> {
-    let stdout = io::stdout();
-    let mut stdout = stdout.lock();
+fn clone_impl(value: Value) -> Result<Value, VmError> {
+    value.deep_clone()
+}
 
-    for _ in 0..args {
-        match stack.pop() {
-            Ok(value) => {
-                writeln!(stdout, "{:?}", value).map_err(VmError::panic)?;
-            }
-            Err(e) => {
-                writeln!(stdout, "{}", e).map_err(VmError::panic)?;
+/// Get the type of a value, which compares equal to the type of any other
+/// value of the same type, like `type_of(1) == type_of(2)`.
+fn type_of_impl(value: Value) -> Result<Value, VmError> {
+    Ok(Value::Type(value.value_type()?.as_type_hash()))
+}
+
+/// Get a human readable name of the type of the given value.
+///
+/// Types registered in the context and types declared in the unit are named by
+/// their item, like `widget::Widget`, unless they're available through the
+/// prelude, like `String`. Anything else falls back to its type information.
+fn type_name_impl(value: Value) -> Result<String, VmError> {
+    let (context, unit) = env::current()?;
+    let value_type = value.value_type()?;
+
+    let item = match context.lookup_type(value_type) {
+        Some(info) => &info.name,
+        None => match unit.lookup_type(value_type.as_type_hash()) {
+            Some(info) => &info.item,
+            None => return Ok(value.type_info()?.to_string()),
+        },
+    };
+
+    // NB: prelude imports are the ones without a span, and a type is available
+    // through the prelude if it's imported as its own name.
+    if let Some(name) = item.last() {
+        let entry = unit.lookup_import(&ImportKey::component(name.clone()));
+
+        if let Some(entry) = entry {
+            if entry.span.is_none() && entry.item == *item {
+                return Ok(name.to_string());
             }
         }
     }
 
-    stack.push(Value::Unit);
-    Ok(())
+    Ok(item.to_string())
 }
 
-fn print_impl(m: &str) -> Result<(), Panic> {
-    let stdout = io::stdout();
-    let mut stdout = stdout.lock();
-    write!(stdout, "{}", m).map_err(Panic::custom)
+fn panic_impl(m: &str) -> Result<(), Panic> {
+    Err(Panic::custom(m.to_owned()))
 }
 
-fn println_impl(m: &str) -> Result<(), Panic> {
-    let stdout = io::stdout();
-    let mut stdout = stdout.lock();
-    writeln!(stdout, "{}", m).map_err(Panic::custom)
+fn todo_impl() -> Result<(), Panic> {
+    Err(Panic::from(PanicReason::Todo))
 }
 
-fn panic_impl(m: &str) -> Result<(), Panic> {
-    Err(Panic::custom(m.to_owned()))
+fn unimplemented_impl() -> Result<(), Panic> {
+    Err(Panic::from(PanicReason::NotImplemented))
+}
+
+/// Call the given function, converting any panic it raises into an error
+/// carrying the panic message. Other errors are propagated.
+fn try_catch_impl(function: &Function) -> Result<Result<Value, String>, VmError> {
+    let error = match function.call::<_, Value>(()) {
+        Ok(value) => return Ok(Ok(value)),
+        Err(error) => error,
+    };
+
+    if let (VmErrorKind::Panic { reason }, _) = error.kind().into_unwound_ref() {
+        return Ok(Err(reason.to_string()));
+    }
+
+    Err(error)
 }
 
 fn is_readable(value: Value) -> bool {
@@ -113,6 +163,117 @@ fn is_readable(value: Value) -> bool {
     }
 }
 
+/// Compute a structural hash of the value, which is stable across runs.
+fn hash_impl(stack: &mut Stack, args: usize) -> Result<(), VmError> {
+    if args != 1 {
+        return Err(VmError::from(VmErrorKind::BadArgumentCount {
+            actual: args,
+            expected: 1,
+        }));
+    }
+
+    let value = stack.pop()?;
+    let hash = Value::value_ptr_hash(&value)?;
+    stack.push(Value::Integer(hash as i64));
+    Ok(())
+}
+
+/// Swap the contents of two values of the same kind, like two vectors.
+///
+/// Fails with a panic if either value is currently borrowed, which includes
+/// swapping a value with itself.
+fn mem_swap_impl(a: Value, b: Value) -> Result<(), VmError> {
+    match (&a, &b) {
+        (Value::String(a), Value::String(b)) => swap_shared(a, b),
+        (Value::Bytes(a), Value::Bytes(b)) => swap_shared(a, b),
+        (Value::Vec(a), Value::Vec(b)) => swap_shared(a, b),
+        (Value::Tuple(a), Value::Tuple(b)) => swap_shared(a, b),
+        (Value::Object(a), Value::Object(b)) => swap_shared(a, b),
+        (Value::Option(a), Value::Option(b)) => swap_shared(a, b),
+        (Value::Result(a), Value::Result(b)) => swap_shared(a, b),
+        _ => Err(mem_mismatch("mem_swap", &a, &b)?),
+    }
+}
+
+/// Replace the contents of `cell` with the contents of `new`, returning the
+/// old contents.
+fn mem_replace_impl(cell: Value, new: Value) -> Result<Value, VmError> {
+    Ok(match (&cell, &new) {
+        (Value::String(a), Value::String(b)) => Value::from(replace_shared(a, b)?),
+        (Value::Bytes(a), Value::Bytes(b)) => Value::from(replace_shared(a, b)?),
+        (Value::Vec(a), Value::Vec(b)) => Value::from(replace_shared(a, b)?),
+        (Value::Tuple(a), Value::Tuple(b)) => Value::from(replace_shared(a, b)?),
+        (Value::Object(a), Value::Object(b)) => Value::from(replace_shared(a, b)?),
+        (Value::Option(a), Value::Option(b)) => Value::from(replace_shared(a, b)?),
+        (Value::Result(a), Value::Result(b)) => Value::from(replace_shared(a, b)?),
+        _ => return Err(mem_mismatch("mem_replace", &cell, &new)?),
+    })
+}
+
+/// Take the contents of `cell`, leaving an empty value of the same kind in
+/// its place. `Option`s are left as `None`, while `Result`s can't be taken
+/// since they have no empty value.
+fn mem_take_impl(cell: Value) -> Result<Value, VmError> {
+    Ok(match &cell {
+        Value::String(cell) => Value::from(take_shared(cell, String::new())?),
+        Value::Bytes(cell) => Value::from(take_shared(cell, Bytes::new())?),
+        Value::Vec(cell) => Value::from(take_shared(cell, Vec::new())?),
+        Value::Tuple(cell) => Value::from(take_shared(cell, Tuple::from(Vec::new()))?),
+        Value::Object(cell) => Value::from(take_shared(cell, Object::new())?),
+        Value::Option(cell) => Value::from(take_shared(cell, None)?),
+        actual => {
+            return Err(VmError::from(VmErrorKind::UnsupportedMemOperation {
+                op: "mem_take",
+                actual: actual.type_info()?,
+            }))
+        }
+    })
+}
+
+fn swap_shared<T>(a: &Shared<T>, b: &Shared<T>) -> Result<(), VmError> {
+    let mut a = a.borrow_mut().map_err(VmError::panic)?;
+    let mut b = b.borrow_mut().map_err(VmError::panic)?;
+    std::mem::swap(&mut *a, &mut *b);
+    Ok(())
+}
+
+fn replace_shared<T>(cell: &Shared<T>, new: &Shared<T>) -> Result<Shared<T>, VmError>
+where
+    T: Clone,
+{
+    let new = new.borrow_ref().map_err(VmError::panic)?.clone();
+    take_shared(cell, new)
+}
+
+fn take_shared<T>(cell: &Shared<T>, new: T) -> Result<Shared<T>, VmError> {
+    let mut cell = cell.borrow_mut().map_err(VmError::panic)?;
+    Ok(Shared::new(std::mem::replace(&mut *cell, new)))
+}
+
+/// Construct the error raised when the arguments to a mem operation are of
+/// different kinds, or of a kind which isn't supported.
+fn mem_mismatch(op: &'static str, a: &Value, b: &Value) -> Result<VmError, VmError> {
+    let expected = a.type_info()?;
+
+    Ok(match a {
+        Value::String(..)
+        | Value::Bytes(..)
+        | Value::Vec(..)
+        | Value::Tuple(..)
+        | Value::Object(..)
+        | Value::Option(..)
+        | Value::Result(..) => VmError::from(VmErrorKind::BadArgumentType {
+            arg: 1,
+            expected,
+            actual: b.type_info()?,
+        }),
+        _ => VmError::from(VmErrorKind::UnsupportedMemOperation {
+            op,
+            actual: expected,
+        }),
+    })
+}
+
 fn is_writable(value: Value) -> bool {
     match value {
         Value::Any(any) => any.is_writable(),
@@ -0,0 +1,37 @@
+//! The `std::error` module.
+//!
+//! Native functions returning [crate::Result] produce a Rune `Result`, where
+//! errors are values of the `Error` type registered here.
+
+use crate::{ContextError, Module};
+use std::fmt;
+use std::fmt::Write as _;
+
+/// Construct the `std::error` module.
+pub fn module() -> Result<Module, ContextError> {
+    let mut module = Module::new(&["std", "error"]);
+    module.ty(&["Error"]).build::<anyhow::Error>()?;
+    module.inst_fn(crate::STRING_DISPLAY, format_error)?;
+    module.inst_fn(crate::STRING_DEBUG, format_error_debug)?;
+    module.inst_fn("message", message)?;
+    module.inst_fn("chain", chain)?;
+    Ok(module)
+}
+
+fn format_error(error: &anyhow::Error, buf: &mut String) -> fmt::Result {
+    write!(buf, "{}", error)
+}
+
+fn format_error_debug(error: &anyhow::Error, buf: &mut String) -> fmt::Result {
+    write!(buf, "{:?}", error)
+}
+
+/// The message of the outermost error.
+fn message(error: &anyhow::Error) -> String {
+    error.to_string()
+}
+
+/// The messages of the error and all of its causes, outermost first.
+fn chain(error: &anyhow::Error) -> Vec<String> {
+    error.chain().map(|error| error.to_string()).collect()
+}
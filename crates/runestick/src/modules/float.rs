@@ -13,6 +13,12 @@ fn to_integer(value: f64) -> i64 {
     value as i64
 }
 
+/// Test if two floats are equal by comparing their bits, so that `NaN` is
+/// equal to itself while `0.0` and `-0.0` are not equal.
+fn total_eq(a: f64, b: f64) -> bool {
+    a.to_bits() == b.to_bits()
+}
+
 impl_external!(ParseFloatError);
 
 /// Install the core package into the given functions namespace.
@@ -24,7 +30,18 @@ pub fn module() -> Result<Module, ContextError> {
         .ty(&["float", "ParseFloatError"])
         .build::<ParseFloatError>()?;
     module.function(&["float", "parse"], parse)?;
+    module.function(&["float", "total_eq"], total_eq)?;
     module.inst_fn("to_integer", to_integer)?;
 
+    module.constant(&["float", "NAN"], f64::NAN)?;
+    module.constant(&["float", "INFINITY"], f64::INFINITY)?;
+    module.constant(&["float", "NEG_INFINITY"], f64::NEG_INFINITY)?;
+
+    module.inst_fn("is_nan", f64::is_nan)?;
+    module.inst_fn("is_infinite", f64::is_infinite)?;
+    module.inst_fn("is_finite", f64::is_finite)?;
+    module.inst_fn("min", f64::min)?;
+    module.inst_fn("max", f64::max)?;
+
     Ok(module)
 }
@@ -1,6 +1,8 @@
 //! The `std::int` module.
 
 use crate::{ContextError, Module};
+use std::fmt;
+use std::fmt::Write as _;
 use std::num::ParseIntError;
 
 /// Construct the `std::int` module.
@@ -12,8 +14,11 @@ pub fn module() -> Result<Module, ContextError> {
         .ty(&["int", "ParseIntError"])
         .build::<ParseIntError>()?;
     module.function(&["int", "parse"], parse)?;
+    module.ty(&["int", "RadixError"]).build::<RadixError>()?;
+    module.inst_fn(crate::STRING_DISPLAY, format_radix_error)?;
 
     module.inst_fn("to_float", to_float)?;
+    module.inst_fn("to_byte", to_byte)?;
 
     module.inst_fn("checked_add", i64::checked_add)?;
     module.inst_fn("checked_sub", i64::checked_sub)?;
@@ -34,6 +39,15 @@ pub fn module() -> Result<Module, ContextError> {
     module.inst_fn("saturating_pow", i64::saturating_pow)?;
 
     module.inst_fn("pow", i64::pow)?;
+
+    module.inst_fn("to_hex", to_hex)?;
+    module.inst_fn("to_hex_padded", to_hex_padded)?;
+    module.inst_fn("to_octal", to_octal)?;
+    module.inst_fn("to_octal_padded", to_octal_padded)?;
+    module.inst_fn("to_binary", to_binary)?;
+    module.inst_fn("to_binary_padded", to_binary_padded)?;
+    module.inst_fn("to_radix", to_radix)?;
+    module.inst_fn("to_radix_padded", to_radix_padded)?;
     Ok(module)
 }
 
@@ -47,4 +61,108 @@ fn to_float(value: i64) -> f64 {
     value as f64
 }
 
+/// Convert a whole number to a byte, wrapping it into the range `0` to `255`.
+fn to_byte(value: i64) -> u8 {
+    value as u8
+}
+
+/// Error raised when formatting an integer with a radix outside of the
+/// supported range of `2` to `36`.
+#[derive(Debug, Clone, Copy)]
+pub struct RadixError {
+    radix: i64,
+}
+
+impl fmt::Display for RadixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "radix `{}` is not in the range 2 to 36", self.radix)
+    }
+}
+
+fn format_radix_error(error: &RadixError, buf: &mut String) -> fmt::Result {
+    write!(buf, "{}", error)
+}
+
+/// Format the value in the given radix, zero-padded to the given width.
+///
+/// Negative numbers are formatted with a leading `-`, which counts towards the
+/// width like it does in Rust's `{:0width$}` formatting.
+fn format_radix(value: i64, radix: u32, width: usize) -> String {
+    let mut digits = Vec::new();
+    let mut n = value.unsigned_abs();
+
+    loop {
+        let digit = (n % u64::from(radix)) as u32;
+        digits.push(std::char::from_digit(digit, radix).expect("radix is in range"));
+        n /= u64::from(radix);
+
+        if n == 0 {
+            break;
+        }
+    }
+
+    let mut s = String::new();
+
+    if value < 0 {
+        s.push('-');
+    }
+
+    for _ in (s.len() + digits.len())..width {
+        s.push('0');
+    }
+
+    s.extend(digits.into_iter().rev());
+    s
+}
+
+/// Convert the radix of a script into one supported by [format_radix].
+fn check_radix(radix: i64) -> Result<u32, RadixError> {
+    if (2..=36).contains(&radix) {
+        Ok(radix as u32)
+    } else {
+        Err(RadixError { radix })
+    }
+}
+
+/// Format a number in hexadecimal, like `255.to_hex() == "ff"`.
+fn to_hex(value: i64) -> String {
+    format_radix(value, 16, 0)
+}
+
+/// Format a number in hexadecimal, zero-padded to the given width.
+fn to_hex_padded(value: i64, width: usize) -> String {
+    format_radix(value, 16, width)
+}
+
+/// Format a number in octal.
+fn to_octal(value: i64) -> String {
+    format_radix(value, 8, 0)
+}
+
+/// Format a number in octal, zero-padded to the given width.
+fn to_octal_padded(value: i64, width: usize) -> String {
+    format_radix(value, 8, width)
+}
+
+/// Format a number in binary, like `10.to_binary() == "1010"`.
+fn to_binary(value: i64) -> String {
+    format_radix(value, 2, 0)
+}
+
+/// Format a number in binary, zero-padded to the given width.
+fn to_binary_padded(value: i64, width: usize) -> String {
+    format_radix(value, 2, width)
+}
+
+/// Format a number in the given radix, which must be in the range `2` to `36`.
+fn to_radix(value: i64, radix: i64) -> Result<String, RadixError> {
+    Ok(format_radix(value, check_radix(radix)?, 0))
+}
+
+/// Format a number in the given radix, zero-padded to the given width.
+fn to_radix_padded(value: i64, radix: i64, width: usize) -> Result<String, RadixError> {
+    Ok(format_radix(value, check_radix(radix)?, width))
+}
+
 impl_external!(ParseIntError);
+impl_external!(RadixError);
@@ -1,8 +1,12 @@
 //! The `std::io` module.
 
-use crate::{ContextError, Module};
+use crate::{
+    Capability, ContextError, FromValue as _, Input, Module, Output, Stack, ToValue as _, Value,
+    VmError, VmErrorKind,
+};
 use std::fmt;
 use std::fmt::Write as _;
+use std::io::Write as _;
 
 /// Construct the `std::io` module.
 pub fn module() -> Result<Module, ContextError> {
@@ -12,6 +16,119 @@ pub fn module() -> Result<Module, ContextError> {
     Ok(module)
 }
 
+/// Construct the functions in the `std` module which write to stdout, like
+/// `println` and `dbg`.
+pub fn print_module() -> Result<Module, ContextError> {
+    print_module_with_output(Output::stdout())
+}
+
+/// Construct the functions in the `std` module which write to the given
+/// [Output], like `println` and `dbg`.
+pub fn print_module_with_output(output: Output) -> Result<Module, ContextError> {
+    let mut module = Module::new(&["std"]);
+
+    let o = output.clone();
+    module.raw_fn(&["print"], move |stack, args| {
+        print_impl(&o, stack, args, "")
+    })?;
+    let o = output.clone();
+    module.raw_fn(&["println"], move |stack, args| {
+        print_impl(&o, stack, args, "\n")
+    })?;
+    let o = output.clone();
+    module.raw_fn(&["dbg"], move |stack, args| {
+        dbg_impl(&o, stack, args, |v| format!("{:?}", v))
+    })?;
+    module.raw_fn(&["dbg_pretty"], move |stack, args| {
+        dbg_impl(&output, stack, args, |v| format!("{:#?}", v))
+    })?;
+    Ok(module)
+}
+
+/// Construct the `read_line` function in the `std::io` module, which reads
+/// from the given [Input].
+///
+/// `read_line` returns the next line including its trailing newline, or an
+/// empty string once the end of input has been reached. An empty line is
+/// therefore `"\n"`, which distinguishes it from the end of input. Errors
+/// raised while reading are returned as a `std::io::Error`.
+///
+/// This requires the [Capability::Stdin] capability.
+pub fn input_module_with_input(input: Input) -> Result<Module, ContextError> {
+    let mut module = Module::new(&["std", "io"]);
+    module.require(Capability::Stdin);
+    module.raw_fn(&["read_line"], move |stack, args| {
+        read_line_impl(&input, stack, args)
+    })?;
+    Ok(module)
+}
+
 fn format_io_error(error: &std::io::Error, buf: &mut String) -> fmt::Result {
     write!(buf, "{}", error)
 }
+
+fn dbg_impl(
+    output: &Output,
+    stack: &mut Stack,
+    args: usize,
+    format: fn(&Value) -> String,
+) -> Result<(), VmError> {
+    let mut buf = String::new();
+
+    for _ in 0..args {
+        match stack.pop() {
+            Ok(value) => {
+                buf.push_str(&format(&value));
+            }
+            Err(e) => {
+                write!(buf, "{}", e).map_err(VmError::panic)?;
+            }
+        }
+
+        buf.push('\n');
+    }
+
+    output
+        .clone()
+        .write_all(buf.as_bytes())
+        .map_err(VmError::panic)?;
+    stack.push(Value::Unit);
+    Ok(())
+}
+
+fn print_impl(
+    output: &Output,
+    stack: &mut Stack,
+    args: usize,
+    suffix: &str,
+) -> Result<(), VmError> {
+    if args != 1 {
+        return Err(VmError::from(VmErrorKind::BadArgumentCount {
+            actual: args,
+            expected: 1,
+        }));
+    }
+
+    let mut m = String::from_value(stack.pop()?)?;
+    m.push_str(suffix);
+    output
+        .clone()
+        .write_all(m.as_bytes())
+        .map_err(VmError::panic)?;
+    stack.push(Value::Unit);
+    Ok(())
+}
+
+fn read_line_impl(input: &Input, stack: &mut Stack, args: usize) -> Result<(), VmError> {
+    if args != 0 {
+        return Err(VmError::from(VmErrorKind::BadArgumentCount {
+            actual: args,
+            expected: 0,
+        }));
+    }
+
+    let mut line = String::new();
+    let result = input.read_line(&mut line).map(|_| line);
+    stack.push(result.to_value()?);
+    Ok(())
+}
@@ -1,76 +1,53 @@
 //! The `std::iter` module.
 
-use crate::{ContextError, Module};
+use crate::{ContextError, Iterator, Module, Peekable};
 
 /// Construct the `std::iter` module.
 pub fn module() -> Result<Module, ContextError> {
     let mut module = Module::new(&["std", "iter"]);
-    module.ty(&["Range"]).build::<Range>()?;
-    module.ty(&["Rev"]).build::<Rev>()?;
-    module.function(&["range"], Range::new)?;
-    module.inst_fn(crate::INTO_ITER, Range::into_iter)?;
-    module.inst_fn(crate::NEXT, Range::next)?;
-    module.inst_fn("rev", Range::rev)?;
-    module.inst_fn(crate::INTO_ITER, Rev::into_iter)?;
-    module.inst_fn(crate::NEXT, Rev::next)?;
+    module.ty(&["Iterator"]).build::<Iterator>()?;
+    module.ty(&["Peekable"]).build::<Peekable>()?;
+    module.function(&["range"], range)?;
+    module.inst_fn("next", Iterator::next)?;
+    module.inst_fn("next_back", Iterator::next_back)?;
+    module.inst_fn("rev", Iterator::rev)?;
+    module.inst_fn("step_by", Iterator::step_by)?;
+    module.inst_fn("chain", Iterator::chain)?;
+    module.inst_fn("peekable", Iterator::peekable)?;
+    module.inst_fn("enumerate", Iterator::enumerate)?;
+    module.inst_fn("map", Iterator::map)?;
+    module.inst_fn("flat_map", Iterator::flat_map)?;
+    module.inst_fn("scan", Iterator::scan)?;
+    module.inst_fn("take_while", Iterator::take_while)?;
+    module.inst_fn("skip_while", Iterator::skip_while)?;
+    module.inst_fn("find", Iterator::find)?;
+    module.inst_fn("any", Iterator::any)?;
+    module.inst_fn("all", Iterator::all)?;
+    module.inst_fn("count", Iterator::count)?;
+    module.inst_fn("last", Iterator::last)?;
+    module.inst_fn("nth", Iterator::nth)?;
+    module.inst_fn("collect_object", Iterator::collect_object)?;
+    module.inst_fn(crate::INTO_ITER, into_iter)?;
+    module.inst_fn(crate::NEXT, Iterator::next)?;
+
+    module.inst_fn("next", Peekable::next)?;
+    module.inst_fn("peek", Peekable::peek)?;
+    module.inst_fn(crate::INTO_ITER, peekable_into_iter)?;
+    module.inst_fn(crate::NEXT, Peekable::next)?;
     Ok(module)
 }
 
-#[derive(Debug)]
-struct Rev {
-    current: i64,
-    start: i64,
+/// Construct an iterator over the integers `start..end`.
+fn range(start: i64, end: i64) -> Iterator {
+    Iterator::from_double_ended("std::iter::Range", start..end)
 }
 
-impl Iterator for Rev {
-    type Item = i64;
-
-    fn next(&mut self) -> Option<i64> {
-        if self.current <= self.start {
-            return None;
-        }
-
-        self.current -= 1;
-        Some(self.current)
-    }
-}
-
-#[derive(Debug)]
-struct Range {
-    current: i64,
-    end: i64,
+/// Iterators are their own iterator.
+fn into_iter(iter: Iterator) -> Iterator {
+    iter
 }
 
-impl Range {
-    fn new(start: i64, end: i64) -> Self {
-        Self {
-            current: start,
-            end,
-        }
-    }
-
-    fn rev(self) -> Rev {
-        Rev {
-            current: self.end,
-            start: self.current,
-        }
-    }
+/// Peekable iterators are their own iterator.
+fn peekable_into_iter(iter: Peekable) -> Peekable {
+    iter
 }
-
-impl Iterator for Range {
-    type Item = i64;
-
-    fn next(&mut self) -> Option<i64> {
-        let value = self.current;
-
-        if self.current < self.end {
-            self.current += 1;
-            return Some(value);
-        }
-
-        None
-    }
-}
-
-impl_external!(Range);
-impl_external!(Rev);
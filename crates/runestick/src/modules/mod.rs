@@ -2,7 +2,10 @@
 //! machines.
 
 pub mod bytes;
+pub mod cmp;
+pub mod collections;
 pub mod core;
+pub mod error;
 pub mod float;
 pub mod fmt;
 pub mod future;
@@ -11,6 +14,7 @@ pub mod int;
 pub mod io;
 pub mod iter;
 pub mod object;
+pub mod ops;
 pub mod option;
 pub mod result;
 pub mod stream;
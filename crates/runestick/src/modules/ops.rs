@@ -0,0 +1,27 @@
+//! The `std::ops` module.
+
+use crate::{ContextError, Iterator, Module, Range, Value, VmError};
+
+/// Construct the `std::ops` module.
+pub fn module() -> Result<Module, ContextError> {
+    let mut module = Module::new(&["std", "ops"]);
+    module.ty(&["Range"]).build::<Range>()?;
+    module.getter("start", range_start)?;
+    module.getter("end", range_end)?;
+    module.inst_fn("iter", Range::iter)?;
+    module.inst_fn("enumerate", range_enumerate)?;
+    module.inst_fn(crate::INTO_ITER, Range::iter)?;
+    Ok(module)
+}
+
+fn range_start(range: &Range) -> Option<Value> {
+    range.start.clone()
+}
+
+fn range_end(range: &Range) -> Option<Value> {
+    range.end.clone()
+}
+
+fn range_enumerate(range: &Range) -> Result<Iterator, VmError> {
+    Ok(range.iter()?.enumerate())
+}
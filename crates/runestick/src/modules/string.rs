@@ -1,6 +1,9 @@
 //! The `std::string` module.
 
-use crate::{Bytes, ContextError, Module};
+use crate::{Bytes, ContextError, Iterator, Module, VmError};
+
+/// The largest string, in bytes, that can be constructed through `repeat`.
+const MAX_REPEAT_LEN: usize = 1 << 30;
 
 /// Construct the `std::string` module.
 pub fn module() -> Result<Module, ContextError> {
@@ -20,9 +23,18 @@ pub fn module() -> Result<Module, ContextError> {
     module.inst_fn("reserve", String::reserve)?;
     module.inst_fn("reserve_exact", String::reserve_exact)?;
     module.inst_fn("into_bytes", into_bytes)?;
+    module.inst_fn("bytes", bytes)?;
+    module.inst_fn("chars", chars)?;
+    module.inst_fn("lines", lines)?;
     module.inst_fn("clone", String::clone)?;
     module.inst_fn("shrink_to_fit", String::shrink_to_fit)?;
     module.inst_fn("char_at", char_at)?;
+    module.inst_fn("slice", slice)?;
+    module.inst_fn("find", find)?;
+    module.inst_fn("rfind", rfind)?;
+    module.inst_fn("repeat", repeat)?;
+    module.inst_fn("pad_start", pad_start)?;
+    module.inst_fn("pad_end", pad_end)?;
     module.inst_fn(crate::ADD, add)?;
     module.inst_fn(crate::ADD_ASSIGN, String::push_str)?;
     Ok(module)
@@ -36,6 +48,24 @@ fn into_bytes(s: String) -> Bytes {
     Bytes::from_vec(s.into_bytes())
 }
 
+/// Copy the bytes of a string.
+fn bytes(s: &str) -> Bytes {
+    Bytes::from_vec(s.as_bytes().to_vec())
+}
+
+/// Iterate over the characters of a string.
+fn chars(s: &str) -> Iterator {
+    let chars = s.chars().collect::<Vec<_>>();
+    Iterator::from_double_ended("std::string::Chars", chars.into_iter())
+}
+
+/// Split a string into lines, separated by either `\n` or `\r\n`.
+///
+/// A trailing line ending doesn't produce an empty final line.
+fn lines(s: &str) -> Vec<String> {
+    s.lines().map(String::from).collect()
+}
+
 fn char_at(s: &str, index: usize) -> Result<Option<char>, NotCharBoundary> {
     if !s.is_char_boundary(index) {
         return Err(NotCharBoundary(()));
@@ -44,6 +74,70 @@ fn char_at(s: &str, index: usize) -> Result<Option<char>, NotCharBoundary> {
     Ok(s[index..].chars().next())
 }
 
+/// Copy the part of the string in the byte range `start..end`.
+///
+/// Like `char_at` and `find`, the indexes are byte offsets. Both must fall on
+/// char boundaries, so a slice never splits a multi-byte character.
+fn slice(s: &str, start: usize, end: usize) -> Result<String, VmError> {
+    if start > end || end > s.len() {
+        return Err(VmError::panic(format!(
+            "range `{}..{}` out of bounds for string of length {}",
+            start,
+            end,
+            s.len()
+        )));
+    }
+
+    if !s.is_char_boundary(start) || !s.is_char_boundary(end) {
+        return Err(VmError::panic(format!(
+            "range `{}..{}` is not on a char boundary",
+            start, end
+        )));
+    }
+
+    Ok(String::from(&s[start..end]))
+}
+
+/// Find the byte index of the first occurrence of `needle` in the string.
+fn find(s: &str, needle: &str) -> Option<usize> {
+    s.find(needle)
+}
+
+/// Find the byte index of the last occurrence of `needle` in the string.
+fn rfind(s: &str, needle: &str) -> Option<usize> {
+    s.rfind(needle)
+}
+
+/// Construct a string by repeating `s` the given number of times.
+fn repeat(s: &str, n: usize) -> Result<String, VmError> {
+    match s.len().checked_mul(n) {
+        Some(len) if len <= MAX_REPEAT_LEN => Ok(s.repeat(n)),
+        _ => Err(VmError::panic("repeated string would be too large")),
+    }
+}
+
+/// Pad the start of a string with `ch` until it's at least `width`
+/// characters wide.
+fn pad_start(s: &str, width: usize, ch: char) -> String {
+    let mut string = padding(s, width, ch);
+    string.push_str(s);
+    string
+}
+
+/// Pad the end of a string with `ch` until it's at least `width` characters
+/// wide.
+fn pad_end(s: &str, width: usize, ch: char) -> String {
+    let mut string = String::from(s);
+    string.push_str(&padding(s, width, ch));
+    string
+}
+
+/// Construct the padding needed to make `s` at least `width` characters wide.
+fn padding(s: &str, width: usize, ch: char) -> String {
+    let count = width.saturating_sub(s.chars().count());
+    ch.to_string().repeat(count)
+}
+
 /// The add operation for strings.
 fn add(a: &str, b: &str) -> String {
     let mut string = String::with_capacity(a.len() + b.len());
@@ -1,29 +1,110 @@
 //! The `std::test` module.
 
-use crate::{ContextError, Module, Panic};
+use crate::env;
+use crate::{ContextError, FromValue, Module, Panic, Stack, Value, Vm, VmError, VmErrorKind};
 use std::fmt;
 
 /// Construct the `std::test` module.
 pub fn module() -> Result<Module, ContextError> {
     let mut module = Module::new(&["std", "test"]);
-    module.function(&["assert"], assert)?;
+    module.raw_fn(&["assert"], assert)?;
+    module.raw_fn(&["assert_eq"], assert_eq)?;
     Ok(module)
 }
 
 #[derive(Debug)]
-struct AssertionFailed(String);
+struct AssertionFailed(Option<String>);
 
 impl fmt::Display for AssertionFailed {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(fmt, "assertion failed `{}`", self.0)
+        match &self.0 {
+            Some(message) => write!(fmt, "assertion failed `{}`", message),
+            None => write!(fmt, "assertion failed"),
+        }
     }
 }
 
-/// Assert that a value is true.
-fn assert(value: bool, message: &str) -> Result<(), Panic> {
-    if !value {
-        return Err(Panic::custom(AssertionFailed(message.to_string())));
+#[derive(Debug)]
+struct AssertionEqFailed {
+    left: String,
+    right: String,
+    message: Option<String>,
+}
+
+impl fmt::Display for AssertionEqFailed {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "assertion failed `left == right`")?;
+
+        if let Some(message) = &self.message {
+            write!(fmt, ": {}", message)?;
+        }
+
+        write!(fmt, " (left: {}, right: {})", self.left, self.right)
+    }
+}
+
+/// Pop the arguments of an assertion, which takes `required` arguments
+/// followed by an optional message.
+fn pop_args(
+    stack: &mut Stack,
+    args: usize,
+    required: usize,
+) -> Result<(Vec<Value>, Option<String>), VmError> {
+    if args < required || args > required + 1 {
+        return Err(VmError::from(VmErrorKind::BadArgumentCount {
+            actual: args,
+            expected: required,
+        }));
+    }
+
+    let mut values = stack.pop_sequence(args)?;
+
+    let message = if args > required {
+        values.pop().map(String::from_value).transpose()?
+    } else {
+        None
+    };
+
+    Ok((values, message))
+}
+
+/// Assert that a value is true, with an optional message.
+fn assert(stack: &mut Stack, args: usize) -> Result<(), VmError> {
+    let (values, message) = pop_args(stack, args, 1)?;
+
+    if !bool::from_value(values[0].clone())? {
+        return Err(VmError::from(VmErrorKind::Panic {
+            reason: Panic::custom(AssertionFailed(message)),
+        }));
+    }
+
+    stack.push(Value::Unit);
+    Ok(())
+}
+
+/// Assert that two values are equal, with an optional message.
+///
+/// On failure both values are formatted like `{:?}` would in a template
+/// string, so external types use their `STRING_DEBUG` implementation.
+fn assert_eq(stack: &mut Stack, args: usize) -> Result<(), VmError> {
+    let (values, message) = pop_args(stack, args, 2)?;
+    let (left, right) = (&values[0], &values[1]);
+
+    if !Value::value_ptr_eq(left, right)? {
+        let (context, unit) = env::current()?;
+        let mut vm = Vm::new(context, unit);
+        let left = vm.format_debug(left.clone(), false)?;
+        let right = vm.format_debug(right.clone(), false)?;
+
+        return Err(VmError::from(VmErrorKind::Panic {
+            reason: Panic::custom(AssertionEqFailed {
+                left,
+                right,
+                message,
+            }),
+        }));
     }
 
+    stack.push(Value::Unit);
     Ok(())
 }
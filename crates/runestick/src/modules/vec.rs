@@ -1,60 +1,219 @@
 //! The `std::vec` module.
 
-use crate::{ContextError, Module, Value};
-use std::iter::Rev;
+use crate::{ContextError, Function, Iterator, Module, Range, Value, VmError, VmErrorKind};
+use std::cmp::Ordering;
 
 /// Construct the `std::vec` module.
 pub fn module() -> Result<Module, ContextError> {
     let mut module = Module::new(&["std", "vec"]);
 
     module.ty(&["Vec"]).build::<Vec<Value>>()?;
-    module.ty(&["Iter"]).build::<Iter>()?;
-    module.ty(&["Rev"]).build::<Rev<Iter>>()?;
 
     module.function(&["Vec", "new"], Vec::<Value>::new)?;
     module.inst_fn("iter", vec_iter)?;
+    module.inst_fn("enumerate", vec_enumerate)?;
     module.inst_fn("len", Vec::<Value>::len)?;
     module.inst_fn("push", Vec::<Value>::push)?;
     module.inst_fn("clear", Vec::<Value>::clear)?;
     module.inst_fn("pop", Vec::<Value>::pop)?;
+    module.inst_fn("take", vec_take)?;
+    module.inst_fn("skip", vec_skip)?;
+    module.inst_fn("chunks", vec_chunks)?;
+    module.inst_fn("windows", vec_windows)?;
+    module.inst_fn("flatten", vec_flatten)?;
+    module.inst_fn("flat_map", vec_flat_map)?;
+    module.inst_fn("sum", vec_sum)?;
+    module.inst_fn("product", vec_product)?;
+    module.inst_fn("min", vec_min)?;
+    module.inst_fn("max", vec_max)?;
+    module.inst_fn(crate::INDEX_GET, vec_index_get_range)?;
 
     module.inst_fn(crate::INTO_ITER, vec_iter)?;
-    module.inst_fn("next", Iter::next)?;
-    module.inst_fn(crate::NEXT, Iter::next)?;
-    module.inst_fn(crate::INTO_ITER, Iter::into_iter)?;
-
-    module.inst_fn("rev", Iter::rev)?;
-    module.inst_fn("next", Rev::<Iter>::next)?;
-    module.inst_fn("next_back", Rev::<Iter>::next_back)?;
-    module.inst_fn(crate::NEXT, Rev::<Iter>::next)?;
-    module.inst_fn(crate::INTO_ITER, Rev::<Iter>::into_iter)?;
     Ok(module)
 }
 
-/// An iterator over a vector.
-pub struct Iter {
-    iter: std::vec::IntoIter<Value>,
+fn vec_iter(vec: &[Value]) -> Iterator {
+    let vec = vec.to_vec();
+    Iterator::from_double_ended("std::vec::Iter", vec.into_iter())
 }
 
-impl Iterator for Iter {
-    type Item = Value;
+fn vec_enumerate(vec: &[Value]) -> Iterator {
+    vec_iter(vec).enumerate()
+}
+
+/// Slice a vector using a range.
+fn vec_index_get_range(vec: &[Value], range: &Range) -> Result<Vec<Value>, VmError> {
+    match range.to_slice_bounds(vec.len())? {
+        Some((start, end)) => Ok(vec[start..end].to_vec()),
+        None => Err(VmError::panic("range out of bounds")),
+    }
+}
+
+/// Construct a vector out of the first `n` elements, or all elements if there
+/// are fewer than `n`.
+fn vec_take(vec: &[Value], n: usize) -> Vec<Value> {
+    vec[..usize::min(n, vec.len())].to_vec()
+}
+
+/// Construct a vector out of all but the first `n` elements.
+fn vec_skip(vec: &[Value], n: usize) -> Vec<Value> {
+    vec[usize::min(n, vec.len())..].to_vec()
+}
+
+/// Split a vector into chunks of `n` elements. The last chunk might be
+/// shorter.
+fn vec_chunks(vec: &[Value], n: usize) -> Result<Vec<Value>, VmError> {
+    if n == 0 {
+        return Err(VmError::panic("chunk size must be non-zero"));
+    }
+
+    Ok(vec
+        .chunks(n)
+        .map(|chunk| Value::vec(chunk.to_vec()))
+        .collect())
+}
 
-    fn next(&mut self) -> Option<Value> {
-        self.iter.next()
+/// Construct all overlapping windows of `n` elements in a vector.
+///
+/// If `n` is larger than the vector, it is clamped to its length so that a
+/// non-empty vector always has at least one window.
+fn vec_windows(vec: &[Value], n: usize) -> Result<Vec<Value>, VmError> {
+    if n == 0 {
+        return Err(VmError::panic("window size must be non-zero"));
     }
+
+    if vec.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    Ok(vec
+        .windows(usize::min(n, vec.len()))
+        .map(|window| Value::vec(window.to_vec()))
+        .collect())
 }
 
-impl DoubleEndedIterator for Iter {
-    fn next_back(&mut self) -> Option<Self::Item> {
-        self.iter.next_back()
+/// Concatenate a vector of vectors into a single vector.
+fn vec_flatten(vec: &[Value]) -> Result<Vec<Value>, VmError> {
+    let mut output = Vec::new();
+
+    for value in vec {
+        output.extend(value.clone().into_vec()?.borrow_ref()?.iter().cloned());
     }
+
+    Ok(output)
 }
 
-fn vec_iter(vec: &[Value]) -> Iter {
-    Iter {
-        iter: vec.to_vec().into_iter(),
+/// Map each element of a vector to a vector and concatenate the results.
+fn vec_flat_map(vec: &[Value], f: Function) -> Result<Vec<Value>, VmError> {
+    let mut output = Vec::new();
+
+    for value in vec {
+        output.extend(f.call::<_, Vec<Value>>((value.clone(),))?);
     }
+
+    Ok(output)
+}
+
+/// Sum all numbers in a vector.
+fn vec_sum(vec: &[Value]) -> Result<Value, VmError> {
+    fold_numbers(vec, "+", 0, i64::checked_add, |a, b| a + b)
 }
 
-impl_external!(Iter);
-impl_external!(Rev<Iter>);
+/// Multiply all numbers in a vector.
+fn vec_product(vec: &[Value]) -> Result<Value, VmError> {
+    fold_numbers(vec, "*", 1, i64::checked_mul, |a, b| a * b)
+}
+
+/// Get the smallest value in a vector, or `None` if it's empty.
+fn vec_min(vec: &[Value]) -> Result<Option<Value>, VmError> {
+    select_by(vec, "min", Ordering::Less)
+}
+
+/// Get the largest value in a vector, or `None` if it's empty.
+fn vec_max(vec: &[Value]) -> Result<Option<Value>, VmError> {
+    select_by(vec, "max", Ordering::Greater)
+}
+
+/// Test if the vector contains a float, in which case any integers in it are
+/// promoted to floats before they are aggregated.
+fn has_float(vec: &[Value]) -> bool {
+    vec.iter().any(|value| matches!(value, Value::Float(..)))
+}
+
+/// Fold a vector of numbers.
+///
+/// The fold is performed over integers unless the vector contains a float,
+/// in which case every element is treated as a float regardless of where in
+/// the vector it appears.
+fn fold_numbers(
+    vec: &[Value],
+    op: &'static str,
+    initial: i64,
+    int_op: fn(i64, i64) -> Option<i64>,
+    float_op: fn(f64, f64) -> f64,
+) -> Result<Value, VmError> {
+    if has_float(vec) {
+        let mut acc = initial as f64;
+
+        for value in vec {
+            acc = match value {
+                Value::Integer(n) => float_op(acc, *n as f64),
+                Value::Float(n) => float_op(acc, *n),
+                value => return Err(unsupported(op, Value::Float(acc), value)?),
+            };
+        }
+
+        return Ok(Value::Float(acc));
+    }
+
+    let mut acc = initial;
+
+    for value in vec {
+        acc = match value {
+            Value::Integer(n) => match int_op(acc, *n) {
+                Some(acc) => acc,
+                None => return Err(VmError::from(VmErrorKind::Overflow)),
+            },
+            value => return Err(unsupported(op, Value::Integer(acc), value)?),
+        };
+    }
+
+    Ok(Value::Integer(acc))
+}
+
+/// Select the value in the vector which orders as `ordering` relative to all
+/// the others, preferring the first one found.
+fn select_by(
+    vec: &[Value],
+    op: &'static str,
+    ordering: Ordering,
+) -> Result<Option<Value>, VmError> {
+    let mut it = vec.iter();
+
+    let mut selected = match it.next() {
+        Some(value) => value.clone(),
+        None => return Ok(None),
+    };
+
+    for value in it {
+        if Value::value_ptr_partial_cmp(value, &selected, op)? == Some(ordering) {
+            selected = value.clone();
+        }
+    }
+
+    if let Value::Integer(n) = selected {
+        if has_float(vec) {
+            return Ok(Some(Value::Float(n as f64)));
+        }
+    }
+
+    Ok(Some(selected))
+}
+
+fn unsupported(op: &'static str, lhs: Value, rhs: &Value) -> Result<VmError, VmError> {
+    Ok(VmError::from(VmErrorKind::UnsupportedBinaryOperation {
+        op,
+        lhs: lhs.type_info()?,
+        rhs: rhs.type_info()?,
+    }))
+}
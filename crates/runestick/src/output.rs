@@ -0,0 +1,87 @@
+use std::fmt;
+use std::io;
+use std::sync::{Arc, Mutex};
+
+/// The sink which functions like `print`, `println` and `dbg` write to.
+///
+/// This is a shared handle, so the sink can be replaced with [Output::set]
+/// after the functions writing to it have been installed. By default it writes
+/// to the stdout of the process.
+///
+/// # Examples
+///
+/// ```rust
+/// use runestick::Output;
+/// use std::io::Write as _;
+///
+/// # fn main() -> std::io::Result<()> {
+/// let output = Output::stdout();
+/// output.set(std::io::sink());
+/// writeln!(output.clone(), "this goes nowhere")?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Output {
+    inner: Arc<Mutex<Box<dyn io::Write + Send>>>,
+}
+
+impl Output {
+    /// Construct an output writing to the given writer.
+    pub fn new<W>(writer: W) -> Self
+    where
+        W: 'static + io::Write + Send,
+    {
+        Self {
+            inner: Arc::new(Mutex::new(Box::new(writer))),
+        }
+    }
+
+    /// Construct an output writing to the stdout of the process.
+    pub fn stdout() -> Self {
+        Self::new(io::stdout())
+    }
+
+    /// Replace the writer of this output, affecting all handles to it.
+    pub fn set<W>(&self, writer: W)
+    where
+        W: 'static + io::Write + Send,
+    {
+        *self.lock() = Box::new(writer);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Box<dyn io::Write + Send>> {
+        // NB: a poisoned writer is still usable, since it holds no invariants
+        // of its own.
+        match self.inner.lock() {
+            Ok(guard) => guard,
+            Err(e) => e.into_inner(),
+        }
+    }
+}
+
+impl io::Write for Output {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.lock().write(buf)
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.lock().write_all(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.lock().flush()
+    }
+}
+
+impl Default for Output {
+    fn default() -> Self {
+        Self::stdout()
+    }
+}
+
+impl fmt::Debug for Output {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Output").finish()
+    }
+}
@@ -106,12 +106,85 @@ pub const REM: Protocol = Protocol {
     hash: Hash::new(0x5c6293639c74e671),
 };
 
+/// The function to implement for the remainder assign operation.
+pub const REM_ASSIGN: Protocol = Protocol {
+    name: "rem_assign",
+    hash: Hash::new(0xe0657f9c3775d250),
+};
+
+/// The function to implement for the bitwise and operation.
+pub const BIT_AND: Protocol = Protocol {
+    name: "bit_and",
+    hash: Hash::new(0xb0a3df49c7a2761f),
+};
+
+/// The function to implement for the bitwise and assign operation.
+pub const BIT_AND_ASSIGN: Protocol = Protocol {
+    name: "bit_and_assign",
+    hash: Hash::new(0x3cc4c44d97e2fab5),
+};
+
+/// The function to implement for the bitwise xor operation.
+pub const BIT_XOR: Protocol = Protocol {
+    name: "bit_xor",
+    hash: Hash::new(0x66b19beaca06bb5f),
+};
+
+/// The function to implement for the bitwise xor assign operation.
+pub const BIT_XOR_ASSIGN: Protocol = Protocol {
+    name: "bit_xor_assign",
+    hash: Hash::new(0xda22d46158ee9a60),
+};
+
+/// The function to implement for the bitwise or operation.
+pub const BIT_OR: Protocol = Protocol {
+    name: "bit_or",
+    hash: Hash::new(0xb17522e8b3a27a30),
+};
+
+/// The function to implement for the bitwise or assign operation.
+pub const BIT_OR_ASSIGN: Protocol = Protocol {
+    name: "bit_or_assign",
+    hash: Hash::new(0x51db1cde56514255),
+};
+
+/// The function to implement for the shift left operation.
+pub const SHL: Protocol = Protocol {
+    name: "shl",
+    hash: Hash::new(0xe4d348af7608ca31),
+};
+
+/// The function to implement for the shift left assign operation.
+pub const SHL_ASSIGN: Protocol = Protocol {
+    name: "shl_assign",
+    hash: Hash::new(0x662c1b058bd57bb2),
+};
+
+/// The function to implement for the shift right operation.
+pub const SHR: Protocol = Protocol {
+    name: "shr",
+    hash: Hash::new(0xe11bc7ae8bfd1f7c),
+};
+
+/// The function to implement for the shift right assign operation.
+pub const SHR_ASSIGN: Protocol = Protocol {
+    name: "shr_assign",
+    hash: Hash::new(0xa5f83d067f33d897),
+};
+
 /// Protocol function used by template strings.
 pub const STRING_DISPLAY: Protocol = Protocol {
     name: "string_display",
     hash: Hash::new(0x811b62957ea9d9f9),
 };
 
+/// Function used for a value to format itself for debugging, like with the
+/// `{value:?}` specifier in a template string.
+pub const STRING_DEBUG: Protocol = Protocol {
+    name: "string_debug",
+    hash: Hash::new(0x4064e3867aaa0717),
+};
+
 /// Function used to convert an argument into an iterator.
 pub const INTO_ITER: Protocol = Protocol {
     name: "into_iter",
@@ -129,3 +202,12 @@ pub const INTO_FUTURE: Protocol = Protocol {
     name: "into_future",
     hash: Hash::new(0x596e6428deabfda2),
 };
+
+/// Function used to branch on a value with the try operator `?`.
+///
+/// It must return a `Result`, where `Ok` holds the value to continue with and
+/// `Err` holds the value to return early from the current function.
+pub const TRY: Protocol = Protocol {
+    name: "try",
+    hash: Hash::new(0x5da1a80787003354),
+};
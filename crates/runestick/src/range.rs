@@ -0,0 +1,67 @@
+use crate::{FromValue as _, Iterator, Value, VmError};
+
+/// A range value constructed with the `..` operator.
+///
+/// Both bounds are optional, so this covers the full range `..`, the
+/// partial ranges `a..` and `..b`, and the bounded range `a..b`.
+#[derive(Debug, Clone)]
+pub struct Range {
+    /// The start value of the range.
+    pub start: Option<Value>,
+    /// The end value of the range.
+    pub end: Option<Value>,
+}
+
+impl Range {
+    /// Construct a new range.
+    pub fn new(start: Option<Value>, end: Option<Value>) -> Self {
+        Self { start, end }
+    }
+
+    /// Resolve the range into a pair of slice indexes for a collection with
+    /// the given length.
+    ///
+    /// Returns `None` if the range is out of bounds.
+    pub fn to_slice_bounds(&self, len: usize) -> Result<Option<(usize, usize)>, VmError> {
+        let start = match &self.start {
+            Some(start) => usize::from_value(start.clone())?,
+            None => 0,
+        };
+
+        let end = match &self.end {
+            Some(end) => usize::from_value(end.clone())?,
+            None => len,
+        };
+
+        if start > end || end > len {
+            return Ok(None);
+        }
+
+        Ok(Some((start, end)))
+    }
+
+    /// Iterate over the integers in the range.
+    ///
+    /// Errors if the range doesn't have a start, or if its bounds aren't
+    /// integers.
+    pub fn iter(&self) -> Result<Iterator, VmError> {
+        let start = match &self.start {
+            Some(start) => i64::from_value(start.clone())?,
+            None => {
+                return Err(VmError::panic(
+                    "cannot iterate over a range without a start",
+                ))
+            }
+        };
+
+        Ok(match &self.end {
+            Some(end) => {
+                let end = i64::from_value(end.clone())?;
+                Iterator::from_double_ended("std::ops::Range", start..end)
+            }
+            None => Iterator::from("std::ops::RangeFrom", start..=i64::MAX),
+        })
+    }
+}
+
+impl_external!(Range);
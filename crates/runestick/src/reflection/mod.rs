@@ -65,6 +65,15 @@ pub trait FromValue: 'static + Sized {
 /// registered functions to take references to their inner value.
 ///
 /// This is specifically safe, because a guard is always held to the reference.
+/// Registered functions hold on to the guards of all their arguments for the
+/// duration of the call, including the conversion of the return value, so
+/// functions like `fn(&str) -> usize` or `fn(&[u8]) -> usize` can access the
+/// data of a value without copying it.
+///
+/// The following references are supported out of the box:
+/// * `&str` and `&String` from a string.
+/// * `&[u8]` and `&Bytes` from bytes.
+/// * `&[Value]` and `&Vec<Value>` from a vector.
 pub trait UnsafeFromValue: Sized {
     /// The output type from the unsafe coercion.
     type Output: 'static;
@@ -10,7 +10,7 @@ where
     fn from_value(value: Value) -> Result<Self, VmError> {
         let object = value.into_object()?;
         let object = object.take()?;
-        let mut output = Object::with_capacity(object.len());
+        let mut output = crate::object_with_capacity(object.len());
 
         for (key, value) in object {
             output.insert(key, T::from_value(value)?);
@@ -55,7 +55,7 @@ where
     T: ToValue,
 {
     fn to_value(self) -> Result<Value, VmError> {
-        let mut object = Object::with_capacity(self.len());
+        let mut object = crate::object_with_capacity(self.len());
 
         for (key, value) in self {
             object.insert(key, value.to_value()?);
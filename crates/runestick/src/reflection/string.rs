@@ -15,6 +15,12 @@ impl FromValue for String {
     }
 }
 
+impl ToValue for &str {
+    fn to_value(self) -> Result<Value, VmError> {
+        Ok(Value::from(Shared::new(self.to_owned())))
+    }
+}
+
 impl ToValue for Box<str> {
     fn to_value(self) -> Result<Value, VmError> {
         Ok(Value::from(Shared::new(self.to_string())))
@@ -1,7 +1,6 @@
 use crate::bytes::Bytes;
-use crate::collections::HashMap;
 use crate::shared::Shared;
-use crate::value::Value;
+use crate::value::{visit_shared, Value};
 use serde::{de, ser};
 use std::fmt;
 
@@ -40,7 +39,7 @@ impl ser::Serialize for Value {
                 let bytes = bytes.borrow_ref().map_err(ser::Error::custom)?;
                 serializer.serialize_bytes(&*bytes)
             }
-            Value::Vec(vec) => {
+            Value::Vec(vec) => visit_shared(vec, || {
                 let vec = vec.borrow_ref().map_err(ser::Error::custom)?;
                 let mut serializer = serializer.serialize_seq(Some(vec.len()))?;
 
@@ -49,8 +48,9 @@ impl ser::Serialize for Value {
                 }
 
                 serializer.end()
-            }
-            Value::Tuple(tuple) => {
+            })
+            .unwrap_or_else(|| Err(ser::Error::custom("cannot serialize cyclic values"))),
+            Value::Tuple(tuple) => visit_shared(tuple, || {
                 let tuple = tuple.borrow_ref().map_err(ser::Error::custom)?;
                 let mut serializer = serializer.serialize_seq(Some(tuple.len()))?;
 
@@ -59,8 +59,9 @@ impl ser::Serialize for Value {
                 }
 
                 serializer.end()
-            }
-            Value::Object(object) => {
+            })
+            .unwrap_or_else(|| Err(ser::Error::custom("cannot serialize cyclic values"))),
+            Value::Object(object) => visit_shared(object, || {
                 let object = object.borrow_ref().map_err(ser::Error::custom)?;
                 let mut serializer = serializer.serialize_map(Some(object.len()))?;
 
@@ -69,7 +70,8 @@ impl ser::Serialize for Value {
                 }
 
                 serializer.end()
-            }
+            })
+            .unwrap_or_else(|| Err(ser::Error::custom("cannot serialize cyclic values"))),
             Value::Option(option) => {
                 let option = option.borrow_ref().map_err(ser::Error::custom)?;
                 <Option<Value>>::serialize(&*option, serializer)
@@ -256,7 +258,7 @@ impl<'de> de::Visitor<'de> for VmVisitor {
     where
         V: de::MapAccess<'de>,
     {
-        let mut object = HashMap::<String, Value>::new();
+        let mut object = crate::object_with_capacity(0);
 
         while let Some((key, value)) = visitor.next_entry()? {
             object.insert(key, value);
@@ -512,6 +512,14 @@ impl<T: ?Sized> Clone for Shared<T> {
     }
 }
 
+impl<T: ?Sized> Shared<T> {
+    /// Get a pointer which identifies the shared value, which can be used to
+    /// test if two shared values are the same.
+    pub(crate) fn as_ptr(&self) -> *const () {
+        self.inner.as_ptr() as *const ()
+    }
+}
+
 impl<T: ?Sized> Drop for Shared<T> {
     fn drop(&mut self) {
         unsafe {
@@ -533,7 +541,7 @@ where
             if !inner.access.is_shared() {
                 write!(fmt, "*not accessible*")
             } else {
-                write!(fmt, "{:?}", &&*inner.data.get())
+                fmt::Debug::fmt(&*inner.data.get(), fmt)
             }
         }
     }
@@ -188,6 +188,19 @@ impl Stack {
         Err(StackError(()))
     }
 
+    /// Truncate the stack to the given length and restore its bottom.
+    ///
+    /// This is used internally when unwinding to a catch boundary.
+    pub(crate) fn unwind(&mut self, len: usize, stack_bottom: usize) -> Result<(), StackError> {
+        if len < stack_bottom || len > self.stack.len() {
+            return Err(StackError(()));
+        }
+
+        self.stack.truncate(len);
+        self.stack_bottom = stack_bottom;
+        Ok(())
+    }
+
     /// Pop the current stack top and modify it to a different one.
     ///
     /// This asserts that the size of the current stack frame is exactly zero
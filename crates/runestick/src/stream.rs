@@ -1,22 +1,70 @@
 use crate::{
-    FromValue, GeneratorState, OwnedMut, OwnedRef, RawOwnedMut, RawOwnedRef, Shared,
+    FromValue, GeneratorState, OwnedMut, OwnedRef, RawOwnedMut, RawOwnedRef, Shared, ToValue,
     UnsafeFromValue, Value, Vm, VmError, VmErrorKind, VmExecution,
 };
+use futures::stream::StreamExt as _;
 use std::fmt;
 use std::mem;
+use std::pin::Pin;
 
-/// A stream with a stored virtual machine.
+/// dyn stream alias.
+type DynStream = dyn futures::Stream<Item = Result<Value, VmError>> + 'static;
+
+/// A stream with a stored virtual machine, or a stream produced by native
+/// code.
 pub struct Stream {
-    execution: Option<VmExecution>,
-    first: bool,
+    inner: Inner,
+}
+
+enum Inner {
+    Vm {
+        execution: Option<VmExecution>,
+        first: bool,
+    },
+    Native(Option<Pin<Box<DynStream>>>),
 }
 
 impl Stream {
     /// Construct a stream from a virtual machine.
     pub(crate) fn new(vm: Vm) -> Self {
         Self {
-            execution: Some(VmExecution::of(vm)),
-            first: true,
+            inner: Inner::Vm {
+                execution: Some(VmExecution::of_generator(vm)),
+                first: true,
+            },
+        }
+    }
+
+    /// Construct a stream from a Rust stream.
+    ///
+    /// This allows native functions to return streams which scripts can
+    /// consume with `next().await`. Items are converted with [ToValue] as they
+    /// are produced, so a stream of `Result` items is received by the script
+    /// as Rune results which can be matched on or propagated with `?`.
+    ///
+    /// Values passed to `resume` are ignored by native streams.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut module = runestick::Module::new(&["pages"]);
+    ///
+    /// module.function(&["all"], || {
+    ///     runestick::Stream::from_stream(futures::stream::iter(vec![1, 2, 3]))
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_stream<S>(stream: S) -> Self
+    where
+        S: 'static + futures::Stream,
+        S::Item: ToValue,
+    {
+        let stream = stream.map(ToValue::to_value);
+
+        Self {
+            inner: Inner::Native(Some(Box::pin(stream))),
         }
     }
 
@@ -28,33 +76,66 @@ impl Stream {
         })
     }
 
-    /// Get the next value produced by this stream.
+    /// Resume the stream with the given value, which becomes the result of the
+    /// `yield` expression it's currently suspended on.
+    ///
+    /// For streams defined in a script, the value passed in when it's first
+    /// resumed is discarded, since it is not yet suspended on a `yield` at that
+    /// point.
     pub async fn resume(&mut self, value: Value) -> Result<GeneratorState, VmError> {
-        let execution = match &mut self.execution {
-            Some(execution) => execution,
-            None => {
-                return Err(VmError::from(VmErrorKind::GeneratorComplete));
+        match &mut self.inner {
+            Inner::Vm { execution, first } => {
+                let vm_execution = match execution {
+                    Some(execution) => execution,
+                    None => {
+                        return Err(VmError::from(VmErrorKind::GeneratorComplete));
+                    }
+                };
+
+                if !mem::take(first) {
+                    vm_execution.vm_mut()?.stack_mut().push(value);
+                }
+
+                let state = vm_execution.async_resume().await?;
+
+                if state.is_complete() {
+                    *execution = None;
+                }
+
+                Ok(state)
+            }
+            Inner::Native(stream) => {
+                let native = match stream {
+                    Some(stream) => stream,
+                    None => {
+                        return Err(VmError::from(VmErrorKind::GeneratorComplete));
+                    }
+                };
+
+                match native.next().await {
+                    Some(value) => Ok(GeneratorState::Yielded(value?)),
+                    None => {
+                        *stream = None;
+                        Ok(GeneratorState::Complete(Value::Unit))
+                    }
+                }
             }
-        };
-
-        if !mem::take(&mut self.first) {
-            execution.vm_mut()?.stack_mut().push(value);
         }
+    }
 
-        let state = execution.async_resume().await?;
-
-        if state.is_complete() {
-            self.execution = None;
+    /// Test if the stream is completed.
+    fn is_completed(&self) -> bool {
+        match &self.inner {
+            Inner::Vm { execution, .. } => execution.is_none(),
+            Inner::Native(stream) => stream.is_none(),
         }
-
-        Ok(state)
     }
 }
 
 impl fmt::Debug for Stream {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Stream")
-            .field("completed", &self.execution.is_none())
+            .field("completed", &self.is_completed())
             .finish()
     }
 }
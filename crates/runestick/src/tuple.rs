@@ -18,21 +18,17 @@ impl Tuple {
 
 impl fmt::Debug for Tuple {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "(")?;
-
-        let mut it = self.inner.iter();
-        let last = it.next_back();
-
-        for el in it {
-            write!(f, "{:?}, ", el)?;
+        if self.inner.is_empty() {
+            return write!(f, "()");
         }
 
-        if let Some(last) = last {
-            write!(f, "{:?}", last)?;
+        let mut tuple = f.debug_tuple("");
+
+        for el in self.inner.iter() {
+            tuple.field(el);
         }
 
-        write!(f, ")")?;
-        Ok(())
+        tuple.finish()
     }
 }
 
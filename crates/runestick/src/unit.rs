@@ -155,19 +155,51 @@ pub struct UnitFnInfo {
     pub signature: UnitFnSignature,
 }
 
+/// A function defined in a unit, as listed by [Unit::functions].
+#[derive(Debug, Clone, Copy)]
+pub struct UnitFunction<'a> {
+    /// The hash of the function, which can be used to call it.
+    pub hash: Hash,
+    /// The signature of the function, including its item and arity.
+    pub signature: &'a UnitFnSignature,
+    /// The way the function is called.
+    pub call: Call,
+}
+
+impl UnitFunction<'_> {
+    /// Test if the function is `async`, and must be run with
+    /// [Vm::async_complete][crate::Vm::async_complete].
+    pub fn is_async(&self) -> bool {
+        matches!(self.call, Call::Async)
+    }
+}
+
 /// A description of a function signature.
 #[derive(Debug, Clone)]
 pub struct UnitFnSignature {
     /// The path of the function.
     pub path: Item,
     /// The number of arguments expected in the function.
+    ///
+    /// For variadic functions this is the number of fixed arguments.
     pub args: usize,
+    /// The number of trailing arguments which have default values, and can
+    /// be omitted by the caller.
+    pub defaults: usize,
+    /// If the function is variadic, any arguments in excess of the fixed
+    /// ones are packed into a vector which is passed as the last argument.
+    pub variadic: bool,
 }
 
 impl UnitFnSignature {
     /// Construct a new function signature.
     pub fn new(path: Item, args: usize) -> Self {
-        Self { path, args }
+        Self {
+            path,
+            args,
+            defaults: 0,
+            variadic: false,
+        }
     }
 }
 
@@ -175,15 +207,32 @@ impl fmt::Display for UnitFnSignature {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(fmt, "{}(", self.path)?;
 
+        let required = self.args - self.defaults;
         let mut it = 0..self.args;
         let last = it.next_back();
 
-        for _ in it {
-            write!(fmt, "arg, ")?;
+        for n in it {
+            if n < required {
+                write!(fmt, "arg, ")?;
+            } else {
+                write!(fmt, "arg = .., ")?;
+            }
+        }
+
+        if let Some(n) = last {
+            if n < required {
+                write!(fmt, "arg")?;
+            } else {
+                write!(fmt, "arg = ..")?;
+            }
         }
 
-        if last.is_some() {
-            write!(fmt, "arg")?;
+        if self.variadic {
+            if self.args > 0 {
+                write!(fmt, ", ")?;
+            }
+
+            write!(fmt, "..rest")?;
         }
 
         write!(fmt, ")")?;
@@ -196,6 +245,8 @@ impl fmt::Display for UnitFnSignature {
 pub struct UnitTypeInfo {
     /// A type declared in a unit.
     pub hash: Hash,
+    /// The item of the type.
+    pub item: Item,
     /// value type of the given type.
     pub value_type: Type,
 }
@@ -310,14 +361,26 @@ impl Unit {
     /// Construct a new unit with the default prelude.
     pub fn with_default_prelude() -> Self {
         let mut this = Self::new();
+        this.imports.insert(
+            ImportKey::component("clone"),
+            ImportEntry::of(&["std", "clone"]),
+        );
         this.imports.insert(
             ImportKey::component("dbg"),
             ImportEntry::of(&["std", "dbg"]),
         );
+        this.imports.insert(
+            ImportKey::component("dbg_pretty"),
+            ImportEntry::of(&["std", "dbg_pretty"]),
+        );
         this.imports.insert(
             ImportKey::component("drop"),
             ImportEntry::of(&["std", "drop"]),
         );
+        this.imports.insert(
+            ImportKey::component("hash"),
+            ImportEntry::of(&["std", "hash"]),
+        );
         this.imports.insert(
             ImportKey::component("is_readable"),
             ImportEntry::of(&["std", "is_readable"]),
@@ -326,10 +389,50 @@ impl Unit {
             ImportKey::component("is_writable"),
             ImportEntry::of(&["std", "is_writable"]),
         );
+        this.imports.insert(
+            ImportKey::component("mem_replace"),
+            ImportEntry::of(&["std", "mem_replace"]),
+        );
+        this.imports.insert(
+            ImportKey::component("mem_swap"),
+            ImportEntry::of(&["std", "mem_swap"]),
+        );
+        this.imports.insert(
+            ImportKey::component("mem_take"),
+            ImportEntry::of(&["std", "mem_take"]),
+        );
         this.imports.insert(
             ImportKey::component("panic"),
             ImportEntry::of(&["std", "panic"]),
         );
+        this.imports.insert(
+            ImportKey::component("try_catch"),
+            ImportEntry::of(&["std", "try_catch"]),
+        );
+        this.imports.insert(
+            ImportKey::component("type_of"),
+            ImportEntry::of(&["std", "type_of"]),
+        );
+        this.imports.insert(
+            ImportKey::component("type_name"),
+            ImportEntry::of(&["std", "type_name"]),
+        );
+        this.imports.insert(
+            ImportKey::component("todo"),
+            ImportEntry::of(&["std", "todo"]),
+        );
+        this.imports.insert(
+            ImportKey::component("unimplemented"),
+            ImportEntry::of(&["std", "unimplemented"]),
+        );
+        this.imports.insert(
+            ImportKey::component("assert"),
+            ImportEntry::of(&["std", "test", "assert"]),
+        );
+        this.imports.insert(
+            ImportKey::component("assert_eq"),
+            ImportEntry::of(&["std", "test", "assert_eq"]),
+        );
         this.imports.insert(
             ImportKey::component("print"),
             ImportEntry::of(&["std", "print"]),
@@ -489,6 +592,40 @@ impl Unit {
         })
     }
 
+    /// List all functions defined in the unit, in the order in which they
+    /// were defined.
+    ///
+    /// Unlike [Unit::iter_functions] this doesn't include constructors for
+    /// tuple types and variants.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::Unit;
+    ///
+    /// let unit = Unit::new();
+    /// assert!(unit.functions().is_empty());
+    /// ```
+    pub fn functions(&self) -> Vec<UnitFunction<'_>> {
+        let mut functions = Vec::new();
+
+        for (hash, info) in &self.functions {
+            if let UnitFnKind::Offset { offset, call } = info.kind {
+                functions.push((
+                    offset,
+                    UnitFunction {
+                        hash: *hash,
+                        signature: &info.signature,
+                        call,
+                    },
+                ));
+            }
+        }
+
+        functions.sort_by_key(|(offset, _)| *offset);
+        functions.into_iter().map(|(_, f)| f).collect()
+    }
+
     /// Iterate over known imports.
     pub fn iter_imports<'a>(
         &'a self,
@@ -654,6 +791,8 @@ impl Unit {
                     signature: UnitFnSignature {
                         path: tuple.item.clone(),
                         args: tuple.args,
+                        defaults: 0,
+                        variadic: false,
                     },
                 });
 
@@ -665,6 +804,7 @@ impl Unit {
 
                 let info = UnitTypeInfo {
                     hash: tuple.hash,
+                    item: tuple.item.clone(),
                     value_type: Type::Hash(tuple.hash),
                 };
 
@@ -689,6 +829,8 @@ impl Unit {
                     signature: UnitFnSignature {
                         path: tuple.item.clone(),
                         args: tuple.args,
+                        defaults: 0,
+                        variadic: false,
                     },
                 });
 
@@ -700,6 +842,7 @@ impl Unit {
 
                 let info = UnitTypeInfo {
                     hash: tuple.hash,
+                    item: tuple.item.clone(),
                     value_type: Type::Hash(enum_hash),
                 };
 
@@ -716,6 +859,7 @@ impl Unit {
 
                 let info = UnitTypeInfo {
                     hash,
+                    item: object.item.clone(),
                     value_type: Type::Hash(hash),
                 };
 
@@ -735,6 +879,7 @@ impl Unit {
 
                 let info = UnitTypeInfo {
                     hash,
+                    item: object.item.clone(),
                     value_type: Type::Hash(enum_hash),
                 };
 
@@ -751,6 +896,7 @@ impl Unit {
 
                 let info = UnitTypeInfo {
                     hash,
+                    item: item.clone(),
                     value_type: Type::Hash(hash),
                 };
 
@@ -765,6 +911,7 @@ impl Unit {
             Meta::MetaFunction { item, .. } => item.clone(),
             Meta::MetaClosure { item, .. } => item.clone(),
             Meta::MetaAsyncBlock { item, .. } => item.clone(),
+            Meta::MetaConstant { item, .. } => item.clone(),
         };
 
         self.names.insert(&item);
@@ -788,19 +935,18 @@ impl Unit {
     pub fn new_function(
         &mut self,
         source_id: usize,
-        path: Item,
-        args: usize,
+        signature: UnitFnSignature,
         assembly: Assembly,
         call: Call,
     ) -> Result<(), UnitError> {
         let offset = self.instructions.len();
-        let hash = Hash::type_hash(&path);
+        let hash = Hash::type_hash(&signature.path);
 
         self.functions_rev.insert(offset, hash);
 
         let info = Arc::new(UnitFnInfo {
             kind: UnitFnKind::Offset { offset, call },
-            signature: UnitFnSignature::new(path, args),
+            signature,
         });
 
         if let Some(old) = self.functions.insert(hash, info) {
@@ -817,23 +963,22 @@ impl Unit {
     pub fn new_instance_function(
         &mut self,
         source_id: usize,
-        path: Item,
+        signature: UnitFnSignature,
         value_type: Type,
         name: &str,
-        args: usize,
         assembly: Assembly,
         call: Call,
     ) -> Result<(), UnitError> {
-        log::trace!("instance fn: {}", path);
+        log::trace!("instance fn: {}", signature.path);
 
         let offset = self.instructions.len();
         let instance_fn = Hash::of(name);
         let instance_fn = Hash::instance_function(value_type, instance_fn);
-        let hash = Hash::type_hash(&path);
+        let hash = Hash::type_hash(&signature.path);
 
         let info = Arc::new(UnitFnInfo {
             kind: UnitFnKind::Offset { offset, call },
-            signature: UnitFnSignature::new(path, args),
+            signature,
         });
 
         if let Some(old) = self.functions.insert(instance_fn, info.clone()) {
@@ -857,7 +1002,12 @@ impl Unit {
     fn add_assembly(&mut self, source_id: usize, assembly: Assembly) -> Result<(), UnitError> {
         self.label_count = assembly.label_count;
 
-        self.required_functions.extend(assembly.required_functions);
+        for (hash, spans) in assembly.required_functions {
+            self.required_functions
+                .entry(hash)
+                .or_default()
+                .extend(spans);
+        }
 
         for (pos, (inst, span)) in assembly.instructions.into_iter().enumerate() {
             let mut comment = None;
@@ -896,6 +1046,11 @@ impl Unit {
                     self.instructions
                         .push(Inst::PopAndJumpIfNot { count, offset });
                 }
+                AssemblyInst::PushCatch { label } => {
+                    comment = Some(format!("label:{}", label));
+                    let offset = translate_offset(pos, label, &assembly.labels)?;
+                    self.instructions.push(Inst::PushCatch { offset });
+                }
                 AssemblyInst::Raw { raw } => {
                     self.instructions.push(raw);
                 }
@@ -960,6 +1115,86 @@ impl Unit {
 
         errors.errors.is_empty()
     }
+
+    /// Disassemble the instructions in the unit into a human readable
+    /// listing.
+    ///
+    /// Each instruction is rendered with its instruction pointer and operands,
+    /// under the function it belongs to. If the unit has debug information,
+    /// labels, comments, and the source each instruction was compiled from are
+    /// included as well.
+    pub fn disassemble(&self) -> String {
+        Disassembly { unit: self }.to_string()
+    }
+}
+
+/// Helper to format the disassembly of a unit.
+struct Disassembly<'a> {
+    unit: &'a Unit,
+}
+
+impl Disassembly<'_> {
+    /// The longest source snippet to include for an instruction.
+    const MAX_SNIPPET: usize = 40;
+
+    /// Get a single-line snippet of the source of the given instruction.
+    fn snippet(&self, inst: &DebugInst) -> Option<String> {
+        let source = self.unit.debug_info()?.source_at(inst.source_id)?;
+        let source = source.source(inst.span)?;
+        let source = source.trim();
+        let line = source.lines().next()?.trim_end();
+
+        let mut snippet = line.chars().take(Self::MAX_SNIPPET).collect::<String>();
+
+        if snippet.is_empty() {
+            return None;
+        }
+
+        if snippet.len() < source.len() {
+            snippet.push_str("...");
+        }
+
+        Some(snippet)
+    }
+}
+
+impl fmt::Display for Disassembly<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let debug = self.unit.debug_info();
+        let mut first_function = true;
+
+        for (n, inst) in self.unit.iter_instructions().enumerate() {
+            let debug_inst = debug.and_then(|debug| debug.instruction_at(n));
+
+            if let Some((hash, function)) = self.unit.function_at(n) {
+                if !std::mem::take(&mut first_function) {
+                    writeln!(fmt)?;
+                }
+
+                writeln!(fmt, "fn {} ({}):", function.signature, hash)?;
+            }
+
+            if let Some(label) = debug_inst.and_then(|inst| inst.label) {
+                writeln!(fmt, "{}:", label)?;
+            }
+
+            write!(fmt, "  {:04} = {}", n, inst)?;
+
+            if let Some(debug_inst) = debug_inst {
+                if let Some(comment) = &debug_inst.comment {
+                    write!(fmt, " // {}", comment)?;
+                }
+
+                if let Some(snippet) = self.snippet(debug_inst) {
+                    write!(fmt, " ; `{}`", snippet)?;
+                }
+            }
+
+            writeln!(fmt)?;
+        }
+
+        Ok(())
+    }
 }
 
 /// An error raised during linking.
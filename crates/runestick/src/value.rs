@@ -1,14 +1,81 @@
 use crate::{
     Any, Bytes, Function, Future, Generator, GeneratorState, Hash, OwnedMut, OwnedRef, RawOwnedMut,
-    RawOwnedRef, Shared, StaticString, Stream, Tuple, Type, TypeInfo, VmError,
+    RawOwnedRef, Shared, StaticString, Stream, Tuple, Type, TypeInfo, VmError, VmErrorKind,
 };
 use std::any;
+use std::cell::RefCell;
+use std::cmp::Ordering;
 use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize};
 use std::sync::Arc;
 
 /// The type of an object.
 pub type Object<T> = crate::collections::HashMap<String, T>;
 
+/// If set, the seed used for the hashers of objects constructed by Rune.
+static OBJECT_SEED_ENABLED: AtomicBool = AtomicBool::new(false);
+static OBJECT_SEED: AtomicU64 = AtomicU64::new(0);
+
+/// Seed the hashers of all objects constructed by Rune from now on with a
+/// fixed value, or go back to the default of randomly seeded hashers with
+/// `None`.
+///
+/// With a fixed seed the iteration order of an object only depends on which
+/// keys have been inserted into it and in which order, so it's reproducible
+/// across runs. This is useful for snapshot testing, but it makes objects
+/// susceptible to hash flooding, so it should only be enabled for trusted
+/// scripts.
+///
+/// The setting is global to the process.
+pub fn set_object_seed(seed: Option<u64>) {
+    use std::sync::atomic::Ordering;
+
+    if let Some(seed) = seed {
+        OBJECT_SEED.store(seed, Ordering::SeqCst);
+    }
+
+    OBJECT_SEED_ENABLED.store(seed.is_some(), Ordering::SeqCst);
+}
+
+/// The default limit for how deeply values are traversed when they are
+/// structurally compared.
+pub const DEFAULT_RECURSION_LIMIT: usize = 1 << 16;
+
+static RECURSION_LIMIT: AtomicUsize = AtomicUsize::new(DEFAULT_RECURSION_LIMIT);
+
+/// Set the limit for how deeply nested values can be before structurally
+/// comparing them fails with [VmErrorKind::RecursionLimit].
+///
+/// Comparisons don't use the native stack to traverse values, so the limit
+/// only serves to stop comparisons of self-referential values, which would
+/// otherwise never terminate.
+///
+/// The setting is global to the process.
+pub fn set_recursion_limit(limit: usize) {
+    RECURSION_LIMIT.store(limit, std::sync::atomic::Ordering::SeqCst);
+}
+
+fn recursion_limit() -> usize {
+    RECURSION_LIMIT.load(std::sync::atomic::Ordering::SeqCst)
+}
+
+/// Construct a new, empty object with at least the given capacity.
+///
+/// This uses the seed configured with [set_object_seed] if there is one.
+pub fn object_with_capacity<T>(capacity: usize) -> Object<T> {
+    use crate::collections::DefaultHashBuilder;
+    use std::sync::atomic::Ordering;
+
+    let hasher = if OBJECT_SEED_ENABLED.load(Ordering::SeqCst) {
+        let seed = OBJECT_SEED.load(Ordering::SeqCst);
+        DefaultHashBuilder::with_seeds(seed, seed.rotate_left(32))
+    } else {
+        DefaultHashBuilder::new()
+    };
+
+    Object::with_capacity_and_hasher(capacity, hasher)
+}
+
 /// A tuple with a well-defined type.
 #[derive(Debug)]
 pub struct TypedTuple {
@@ -140,6 +207,32 @@ pub enum Value {
 }
 
 impl Value {
+    /// Test if the value is currently borrowed, either immutably or mutably.
+    ///
+    /// Values which aren't reference counted can't be borrowed.
+    pub(crate) fn is_borrowed(&self) -> bool {
+        match self {
+            Self::String(value) => !value.is_writable(),
+            Self::Bytes(value) => !value.is_writable(),
+            Self::Vec(value) => !value.is_writable(),
+            Self::Tuple(value) => !value.is_writable(),
+            Self::Object(value) => !value.is_writable(),
+            Self::Future(value) => !value.is_writable(),
+            Self::Stream(value) => !value.is_writable(),
+            Self::Generator(value) => !value.is_writable(),
+            Self::GeneratorState(value) => !value.is_writable(),
+            Self::Option(value) => !value.is_writable(),
+            Self::Result(value) => !value.is_writable(),
+            Self::TypedTuple(value) => !value.is_writable(),
+            Self::VariantTuple(value) => !value.is_writable(),
+            Self::TypedObject(value) => !value.is_writable(),
+            Self::VariantObject(value) => !value.is_writable(),
+            Self::Function(value) => !value.is_writable(),
+            Self::Any(value) => !value.is_writable(),
+            _ => false,
+        }
+    }
+
     /// Construct a vector.
     pub fn vec(vec: Vec<Value>) -> Self {
         Self::Vec(Shared::new(vec))
@@ -167,6 +260,73 @@ impl Value {
         }))
     }
 
+    /// Perform a deep clone of the value, where every nested collection is
+    /// copied into a new shared value so that it can be modified without
+    /// affecting the original.
+    ///
+    /// External types, functions, futures, streams and generators can't be
+    /// copied and are shared between the original and the clone.
+    ///
+    /// Errors if the value contains itself.
+    pub fn deep_clone(&self) -> Result<Value, VmError> {
+        Ok(match self {
+            Value::String(string) => Value::from(string.borrow_ref()?.clone()),
+            Value::Bytes(bytes) => Value::from(bytes.borrow_ref()?.clone()),
+            Value::Vec(vec) => {
+                let vec = deep_clone_shared(vec, |vec| deep_clone_all(vec))?;
+                Value::Vec(Shared::new(vec))
+            }
+            Value::Tuple(tuple) => {
+                let tuple = deep_clone_shared(tuple, |tuple| deep_clone_all(tuple))?;
+                Value::tuple(tuple)
+            }
+            Value::Object(object) => {
+                let object = deep_clone_shared(object, deep_clone_object)?;
+                Value::Object(Shared::new(object))
+            }
+            Value::TypedTuple(tuple) => deep_clone_shared(tuple, |tuple| {
+                Ok(Value::typed_tuple(
+                    tuple.hash,
+                    deep_clone_all(&tuple.tuple)?,
+                ))
+            })?,
+            Value::VariantTuple(tuple) => deep_clone_shared(tuple, |tuple| {
+                let values = deep_clone_all(&tuple.tuple)?;
+                Ok(Value::variant_tuple(tuple.enum_hash, tuple.hash, values))
+            })?,
+            Value::TypedObject(object) => deep_clone_shared(object, |object| {
+                Ok(Value::from(TypedObject {
+                    hash: object.hash,
+                    object: deep_clone_object(&object.object)?,
+                }))
+            })?,
+            Value::VariantObject(object) => deep_clone_shared(object, |object| {
+                Ok(Value::from(VariantObject {
+                    enum_hash: object.enum_hash,
+                    hash: object.hash,
+                    object: deep_clone_object(&object.object)?,
+                }))
+            })?,
+            Value::Option(option) => {
+                let option = match &*option.borrow_ref()? {
+                    Some(value) => Some(value.deep_clone()?),
+                    None => None,
+                };
+
+                Value::Option(Shared::new(option))
+            }
+            Value::Result(result) => {
+                let result = match &*result.borrow_ref()? {
+                    Ok(value) => Ok(value.deep_clone()?),
+                    Err(value) => Err(value.deep_clone()?),
+                };
+
+                Value::Result(Shared::new(result))
+            }
+            value => value.clone(),
+        })
+    }
+
     /// Try to coerce value into a unit.
     #[inline]
     pub fn into_unit(self) -> Result<(), VmError> {
@@ -433,7 +593,7 @@ impl Value {
             Self::Byte(..) => TypeInfo::StaticType(crate::BYTE_TYPE),
             Self::Char(..) => TypeInfo::StaticType(crate::CHAR_TYPE),
             Self::Integer(..) => TypeInfo::StaticType(crate::INTEGER_TYPE),
-            Self::Float(..) => TypeInfo::StaticType(crate::STRING_TYPE),
+            Self::Float(..) => TypeInfo::StaticType(crate::FLOAT_TYPE),
             Self::StaticString(..) => TypeInfo::StaticType(crate::STRING_TYPE),
             Self::String(..) => TypeInfo::StaticType(crate::STRING_TYPE),
             Self::Bytes(..) => TypeInfo::StaticType(crate::BYTES_TYPE),
@@ -460,13 +620,50 @@ impl Value {
     /// each other.
     ///
     /// This is the basis for the eq operation (`==`).
+    ///
+    /// Floats are compared according to IEEE 754, so `NaN` is not equal to
+    /// anything including itself. Integers and floats are compared by their
+    /// numerical value, so `1 == 1.0`.
+    ///
+    /// Nested values are traversed without recursion, and comparing values
+    /// nested deeper than the configured [set_recursion_limit] results in an
+    /// error.
     pub(crate) fn value_ptr_eq(a: &Value, b: &Value) -> Result<bool, VmError> {
+        let limit = recursion_limit();
+        let mut queue = vec![(a.clone(), b.clone(), 0)];
+
+        while let Some((a, b, depth)) = queue.pop() {
+            if !Self::value_ptr_eq_shallow(&a, &b, depth, limit, &mut queue)? {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Compare two values without descending into them. Pairs of nested
+    /// values which also need to be equal are added to `queue`.
+    fn value_ptr_eq_shallow(
+        a: &Value,
+        b: &Value,
+        depth: usize,
+        limit: usize,
+        queue: &mut Vec<(Value, Value, usize)>,
+    ) -> Result<bool, VmError> {
         Ok(match (a, b) {
             (Self::Unit, Self::Unit) => true,
             (Self::Char(a), Self::Char(b)) => a == b,
             (Self::Bool(a), Self::Bool(b)) => a == b,
+            (Self::Byte(a), Self::Byte(b)) => a == b,
             (Self::Integer(a), Self::Integer(b)) => a == b,
             (Self::Float(a), Self::Float(b)) => a == b,
+            (Self::Type(a), Self::Type(b)) => a == b,
+            (Self::Integer(a), Self::Float(b)) => {
+                cmp_integer_float(*a, *b) == Some(Ordering::Equal)
+            }
+            (Self::Float(a), Self::Integer(b)) => {
+                cmp_integer_float(*b, *a) == Some(Ordering::Equal)
+            }
             (Self::Vec(a), Self::Vec(b)) => {
                 let a = a.borrow_ref()?;
                 let b = b.borrow_ref()?;
@@ -475,10 +672,12 @@ impl Value {
                     return Ok(false);
                 }
 
-                for (a, b) in a.iter().zip(b.iter()) {
-                    if !Self::value_ptr_eq(a, b)? {
-                        return Ok(false);
-                    }
+                if !a.is_empty() && depth >= limit {
+                    return Err(VmError::from(VmErrorKind::RecursionLimit { limit }));
+                }
+
+                for (a, b) in a.iter().zip(b.iter()).rev() {
+                    queue.push((a.clone(), b.clone(), depth + 1));
                 }
 
                 true
@@ -491,15 +690,17 @@ impl Value {
                     return Ok(false);
                 }
 
+                if !a.is_empty() && depth >= limit {
+                    return Err(VmError::from(VmErrorKind::RecursionLimit { limit }));
+                }
+
                 for (key, a) in a.iter() {
                     let b = match b.get(key) {
                         Some(b) => b,
                         None => return Ok(false),
                     };
 
-                    if !Self::value_ptr_eq(a, b)? {
-                        return Ok(false);
-                    }
+                    queue.push((a.clone(), b.clone(), depth + 1));
                 }
 
                 true
@@ -525,6 +726,270 @@ impl Value {
             _ => false,
         })
     }
+
+    /// Compare two values, which is the basis for the ordering operations
+    /// (`<`, `<=`, `>` and `>=`).
+    ///
+    /// Integers and floats are compared by their numerical value. Vectors and
+    /// tuples are compared lexicographically, element by element, and the
+    /// shorter one is considered smaller if one is a prefix of the other.
+    ///
+    /// Returns `None` if the values are unordered, like when `NaN` is
+    /// involved. Values which can't be compared with each other result in an
+    /// error for the operation `op`.
+    pub(crate) fn value_ptr_partial_cmp(
+        a: &Value,
+        b: &Value,
+        op: &'static str,
+    ) -> Result<Option<Ordering>, VmError> {
+        let limit = recursion_limit();
+        // Sequences currently being compared, and the index of the next pair
+        // of elements to compare in them.
+        let mut stack = Vec::new();
+        let mut next = Self::value_ptr_partial_cmp_shallow(a, b, op)?;
+
+        loop {
+            match next {
+                Compared::Seq(a, b) => {
+                    if stack.len() >= limit {
+                        return Err(VmError::from(VmErrorKind::RecursionLimit { limit }));
+                    }
+
+                    stack.push((a, b, 0));
+                }
+                Compared::Done(Some(Ordering::Equal)) => (),
+                // The first pair of values which isn't equal decides the
+                // ordering of all sequences being compared.
+                Compared::Done(ordering) => return Ok(ordering),
+            }
+
+            let (a, b, index) = match stack.last_mut() {
+                Some(frame) => frame,
+                None => return Ok(Some(Ordering::Equal)),
+            };
+
+            next = match (a.get(*index), b.get(*index)) {
+                (Some(a), Some(b)) => {
+                    *index += 1;
+                    Self::value_ptr_partial_cmp_shallow(a, b, op)?
+                }
+                _ => {
+                    let ordering = a.len().partial_cmp(&b.len());
+                    stack.pop();
+                    Compared::Done(ordering)
+                }
+            };
+        }
+    }
+
+    /// Compare two values without descending into sequences.
+    fn value_ptr_partial_cmp_shallow(
+        a: &Value,
+        b: &Value,
+        op: &'static str,
+    ) -> Result<Compared, VmError> {
+        Ok(Compared::Done(match (a, b) {
+            (Self::Unit, Self::Unit) => Some(Ordering::Equal),
+            (Self::Bool(a), Self::Bool(b)) => a.partial_cmp(b),
+            (Self::Byte(a), Self::Byte(b)) => a.partial_cmp(b),
+            (Self::Char(a), Self::Char(b)) => a.partial_cmp(b),
+            (Self::Integer(a), Self::Integer(b)) => a.partial_cmp(b),
+            (Self::Float(a), Self::Float(b)) => a.partial_cmp(b),
+            (Self::Integer(a), Self::Float(b)) => cmp_integer_float(*a, *b),
+            (Self::Float(a), Self::Integer(b)) => cmp_integer_float(*b, *a).map(Ordering::reverse),
+            (Self::String(a), Self::String(b)) => {
+                let a = a.borrow_ref()?;
+                let b = b.borrow_ref()?;
+                a.partial_cmp(&b)
+            }
+            (Self::StaticString(a), Self::String(b)) => {
+                let b = b.borrow_ref()?;
+                (***a).partial_cmp(&b)
+            }
+            (Self::String(a), Self::StaticString(b)) => {
+                let a = a.borrow_ref()?;
+                (*a).partial_cmp(&***b)
+            }
+            (Self::StaticString(a), Self::StaticString(b)) => (***a).partial_cmp(&***b),
+            (Self::Vec(a), Self::Vec(b)) => {
+                let a = a.borrow_ref()?.clone();
+                let b = b.borrow_ref()?.clone();
+                return Ok(Compared::Seq(a, b));
+            }
+            (Self::Tuple(a), Self::Tuple(b)) => {
+                let a = a.borrow_ref()?.to_vec();
+                let b = b.borrow_ref()?.to_vec();
+                return Ok(Compared::Seq(a, b));
+            }
+            (a, b) => {
+                return Err(VmError::from(VmErrorKind::UnsupportedBinaryOperation {
+                    op,
+                    lhs: a.type_info()?,
+                    rhs: b.type_info()?,
+                }))
+            }
+        }))
+    }
+
+    /// Compute a structural hash of the value, which is the basis for the
+    /// `hash` function.
+    ///
+    /// The hash only depends on the structure of the value, so it's stable
+    /// across runs. Values which are equal hash equally, with the exception
+    /// of floats which can't be hashed at all. Neither can values without a
+    /// meaningful structure, like futures, functions and external types.
+    ///
+    /// Like [value_ptr_eq][Value::value_ptr_eq], nested values are traversed
+    /// without recursion and are subject to [set_recursion_limit].
+    pub(crate) fn value_ptr_hash(value: &Value) -> Result<u64, VmError> {
+        use std::hash::{Hash as _, Hasher as _};
+
+        let limit = recursion_limit();
+        let mut hasher = twox_hash::XxHash64::with_seed(0);
+        let mut queue = vec![(value.clone(), 0)];
+
+        while let Some((value, depth)) = queue.pop() {
+            let mut nested = Vec::new();
+
+            match &value {
+                Self::Unit => hasher.write_u8(0),
+                Self::Bool(b) => {
+                    hasher.write_u8(1);
+                    hasher.write_u8(*b as u8);
+                }
+                Self::Byte(b) => {
+                    hasher.write_u8(2);
+                    hasher.write_u8(*b);
+                }
+                Self::Char(c) => {
+                    hasher.write_u8(3);
+                    hasher.write_u32(*c as u32);
+                }
+                Self::Integer(n) => {
+                    hasher.write_u8(4);
+                    hasher.write_i64(*n);
+                }
+                Self::Type(hash) => {
+                    hasher.write_u8(5);
+                    hash.hash(&mut hasher);
+                }
+                Self::StaticString(string) => hash_bytes(&mut hasher, 6, string.as_bytes()),
+                Self::String(string) => hash_bytes(&mut hasher, 6, string.borrow_ref()?.as_bytes()),
+                Self::Bytes(bytes) => hash_bytes(&mut hasher, 7, &bytes.borrow_ref()?),
+                Self::Vec(vec) => {
+                    let vec = vec.borrow_ref()?;
+                    hasher.write_u8(8);
+                    hasher.write_u64(vec.len() as u64);
+                    nested.extend(vec.iter().cloned());
+                }
+                Self::Tuple(tuple) => {
+                    let tuple = tuple.borrow_ref()?;
+                    hasher.write_u8(9);
+                    hasher.write_u64(tuple.len() as u64);
+                    nested.extend(tuple.iter().cloned());
+                }
+                Self::Object(object) => {
+                    hasher.write_u8(10);
+                    hash_object(&mut hasher, &*object.borrow_ref()?, &mut nested);
+                }
+                Self::Option(option) => match &*option.borrow_ref()? {
+                    Some(value) => {
+                        hasher.write_u8(11);
+                        nested.push(value.clone());
+                    }
+                    None => hasher.write_u8(12),
+                },
+                Self::Result(result) => match &*result.borrow_ref()? {
+                    Ok(value) => {
+                        hasher.write_u8(13);
+                        nested.push(value.clone());
+                    }
+                    Err(value) => {
+                        hasher.write_u8(14);
+                        nested.push(value.clone());
+                    }
+                },
+                Self::TypedTuple(tuple) => {
+                    let tuple = tuple.borrow_ref()?;
+                    hasher.write_u8(15);
+                    tuple.hash.hash(&mut hasher);
+                    hasher.write_u64(tuple.tuple.len() as u64);
+                    nested.extend(tuple.tuple.iter().cloned());
+                }
+                Self::VariantTuple(tuple) => {
+                    let tuple = tuple.borrow_ref()?;
+                    hasher.write_u8(15);
+                    tuple.hash.hash(&mut hasher);
+                    hasher.write_u64(tuple.tuple.len() as u64);
+                    nested.extend(tuple.tuple.iter().cloned());
+                }
+                Self::TypedObject(object) => {
+                    let object = object.borrow_ref()?;
+                    hasher.write_u8(16);
+                    object.hash.hash(&mut hasher);
+                    hash_object(&mut hasher, &object.object, &mut nested);
+                }
+                Self::VariantObject(object) => {
+                    let object = object.borrow_ref()?;
+                    hasher.write_u8(16);
+                    object.hash.hash(&mut hasher);
+                    hash_object(&mut hasher, &object.object, &mut nested);
+                }
+                actual => {
+                    return Err(VmError::from(VmErrorKind::UnsupportedHash {
+                        actual: actual.type_info()?,
+                    }));
+                }
+            }
+
+            if nested.is_empty() {
+                continue;
+            }
+
+            if depth >= limit {
+                return Err(VmError::from(VmErrorKind::RecursionLimit { limit }));
+            }
+
+            for value in nested.into_iter().rev() {
+                queue.push((value, depth + 1));
+            }
+        }
+
+        Ok(hasher.finish())
+    }
+}
+
+/// Hash a string of bytes, distinguished from other kinds of values by `tag`.
+fn hash_bytes(hasher: &mut twox_hash::XxHash64, tag: u8, bytes: &[u8]) {
+    use std::hash::Hasher as _;
+
+    hasher.write_u8(tag);
+    hasher.write_u64(bytes.len() as u64);
+    hasher.write(bytes);
+}
+
+/// Hash the keys of an object in sorted order, since the iteration order of
+/// objects isn't stable, and queue up its values in the same order.
+fn hash_object(hasher: &mut twox_hash::XxHash64, object: &Object<Value>, nested: &mut Vec<Value>) {
+    use std::hash::Hasher as _;
+
+    let mut entries = object.iter().collect::<Vec<_>>();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+    hasher.write_u64(entries.len() as u64);
+
+    for (key, value) in entries {
+        hasher.write_u64(key.len() as u64);
+        hasher.write(key.as_bytes());
+        nested.push(value.clone());
+    }
+}
+
+/// The outcome of comparing two values without descending into them.
+enum Compared {
+    /// The values have been compared.
+    Done(Option<Ordering>),
+    /// The values are sequences which are compared lexicographically.
+    Seq(Vec<Value>, Vec<Value>),
 }
 
 impl fmt::Debug for Value {
@@ -534,76 +999,76 @@ impl fmt::Debug for Value {
                 write!(f, "()")?;
             }
             Value::Bool(value) => {
-                write!(f, "{:?}", value)?;
+                fmt::Debug::fmt(value, f)?;
             }
             Value::Byte(value) => {
-                write!(f, "{:?}", value)?;
+                fmt::Debug::fmt(value, f)?;
             }
             Value::Char(value) => {
-                write!(f, "{:?}", value)?;
+                fmt::Debug::fmt(value, f)?;
             }
             Value::Integer(value) => {
-                write!(f, "{:?}", value)?;
+                fmt::Debug::fmt(value, f)?;
             }
             Value::Float(value) => {
-                write!(f, "{:?}", value)?;
+                fmt::Debug::fmt(value, f)?;
             }
             Value::Type(value) => {
                 write!(f, "Type({})", value)?;
             }
             Value::StaticString(value) => {
-                write!(f, "{:?}", value)?;
+                fmt::Debug::fmt(value, f)?;
             }
             Value::String(value) => {
-                write!(f, "{:?}", value)?;
+                fmt::Debug::fmt(value, f)?;
             }
             Value::Bytes(value) => {
-                write!(f, "{:?}", value)?;
+                fmt::Debug::fmt(value, f)?;
             }
             Value::Vec(value) => {
-                write!(f, "{:?}", value)?;
+                debug_shared(value, f)?;
             }
             Value::Tuple(value) => {
-                write!(f, "{:?}", value)?;
+                debug_shared(value, f)?;
             }
             Value::Object(value) => {
-                write!(f, "{:?}", value)?;
+                debug_shared(value, f)?;
             }
             Value::Future(value) => {
-                write!(f, "{:?}", value)?;
+                fmt::Debug::fmt(value, f)?;
             }
             Value::Stream(value) => {
-                write!(f, "{:?}", value)?;
+                fmt::Debug::fmt(value, f)?;
             }
             Value::Generator(value) => {
-                write!(f, "{:?}", value)?;
+                fmt::Debug::fmt(value, f)?;
             }
             Value::GeneratorState(value) => {
-                write!(f, "{:?}", value)?;
+                fmt::Debug::fmt(value, f)?;
             }
             Value::Option(value) => {
-                write!(f, "{:?}", value)?;
+                fmt::Debug::fmt(value, f)?;
             }
             Value::Result(value) => {
-                write!(f, "{:?}", value)?;
+                fmt::Debug::fmt(value, f)?;
             }
             Value::TypedTuple(value) => {
-                write!(f, "{:?}", value)?;
+                debug_shared(value, f)?;
             }
             Value::VariantTuple(value) => {
-                write!(f, "{:?}", value)?;
+                debug_shared(value, f)?;
             }
             Value::TypedObject(value) => {
-                write!(f, "{:?}", value)?;
+                debug_shared(value, f)?;
             }
             Value::VariantObject(value) => {
-                write!(f, "{:?}", value)?;
+                debug_shared(value, f)?;
             }
             Value::Function(value) => {
-                write!(f, "{:?}", value)?;
+                fmt::Debug::fmt(value, f)?;
             }
             Value::Any(value) => {
-                write!(f, "{:?}", value)?;
+                fmt::Debug::fmt(value, f)?;
             }
         }
 
@@ -611,6 +1076,102 @@ impl fmt::Debug for Value {
     }
 }
 
+/// Compare an integer with a float, without the loss of precision that comes
+/// from converting the integer into a float.
+///
+/// Returns `None` if the float is `NaN`.
+fn cmp_integer_float(integer: i64, float: f64) -> Option<Ordering> {
+    // NB: the bounds are exactly representable as floats, and every float in
+    // them with no fractional part fits in an integer.
+    const MIN: f64 = i64::MIN as f64;
+    const MAX: f64 = -(i64::MIN as f64);
+
+    if float.is_nan() {
+        return None;
+    }
+
+    if float >= MAX {
+        return Some(Ordering::Less);
+    }
+
+    if float < MIN {
+        return Some(Ordering::Greater);
+    }
+
+    let whole = float.trunc();
+
+    match integer.cmp(&(whole as i64)) {
+        Ordering::Equal => 0.0.partial_cmp(&(float - whole)),
+        ordering => Some(ordering),
+    }
+}
+
+thread_local! {
+    /// Shared values which are currently being visited when formatting or
+    /// serializing, used to detect cycles.
+    static VISITED: RefCell<Vec<*const ()>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Call the given closure while visiting the given shared value.
+///
+/// Returns `None` without calling the closure if the value is already being
+/// visited, which means that it contains itself.
+pub(crate) fn visit_shared<T, F, O>(shared: &Shared<T>, f: F) -> Option<O>
+where
+    T: ?Sized,
+    F: FnOnce() -> O,
+{
+    let ptr = shared.as_ptr();
+
+    if VISITED.with(|visited| visited.borrow().contains(&ptr)) {
+        return None;
+    }
+
+    VISITED.with(|visited| visited.borrow_mut().push(ptr));
+    let output = f();
+    VISITED.with(|visited| visited.borrow_mut().pop());
+    Some(output)
+}
+
+/// Deep clone the contents of a shared collection using the given function,
+/// erroring if the collection contains itself.
+fn deep_clone_shared<T, F, O>(shared: &Shared<T>, f: F) -> Result<O, VmError>
+where
+    T: any::Any,
+    F: FnOnce(&T) -> Result<O, VmError>,
+{
+    visit_shared(shared, || f(&*shared.borrow_ref()?))
+        .unwrap_or_else(|| Err(VmError::panic("cannot clone a value which contains itself")))
+}
+
+/// Deep clone a sequence of values.
+fn deep_clone_all(values: &[Value]) -> Result<Vec<Value>, VmError> {
+    values.iter().map(Value::deep_clone).collect()
+}
+
+/// Deep clone the values of an object.
+fn deep_clone_object(object: &Object<Value>) -> Result<Object<Value>, VmError> {
+    let mut output = object_with_capacity(object.len());
+
+    for (key, value) in object {
+        output.insert(key.clone(), value.deep_clone()?);
+    }
+
+    Ok(output)
+}
+
+/// Debug format a shared collection, printing `...` instead of recursing
+/// forever if it contains itself.
+fn debug_shared<T>(shared: &Shared<T>, f: &mut fmt::Formatter<'_>) -> fmt::Result
+where
+    T: any::Any + fmt::Debug,
+{
+    match visit_shared(shared, || fmt::Debug::fmt(shared, f)) {
+        Some(result) => result,
+        None => write!(f, "..."),
+    }
+}
+
 impl From<()> for Value {
     fn from((): ()) -> Self {
         Self::Unit
@@ -1,11 +1,16 @@
+use crate::collections::HashSet;
+use crate::env;
 use crate::future::SelectFuture;
 use crate::unit::UnitFnKind;
 use crate::{
     Args, Awaited, Bytes, Call, Context, FromValue, Function, Future, Generator, Hash, Inst,
-    Integer, IntoHash, Object, Panic, Select, Shared, Stack, Stream, Tuple, TypeCheck, TypedObject,
-    Unit, Value, VariantObject, VmError, VmErrorKind, VmExecution, VmHalt,
+    Integer, IntoHash, Object, Panic, Range, Select, Shared, Stack, Stream, ToValue as _, Tuple,
+    TypeCheck, TypedObject, Unit, Value, VariantObject, VmError, VmErrorKind, VmExecution, VmHalt,
 };
+use std::cmp::Ordering;
+use std::convert::TryFrom as _;
 use std::fmt;
+use std::fmt::Write as _;
 use std::mem;
 use std::sync::Arc;
 
@@ -22,6 +27,11 @@ pub struct Vm {
     stack: Stack,
     /// Frames relative to the stack.
     call_frames: Vec<CallFrame>,
+    /// Catch boundaries which are currently active.
+    catches: Vec<Catch>,
+    /// The approximate number of bytes that collections on the stack are
+    /// permitted to hold.
+    memory_limit: Option<usize>,
 }
 
 impl Vm {
@@ -38,9 +48,25 @@ impl Vm {
             ip: 0,
             stack,
             call_frames: Vec::new(),
+            catches: Vec::new(),
+            memory_limit: None,
         }
     }
 
+    /// Limit the approximate number of bytes that can be held by the stack
+    /// and the collections on it.
+    ///
+    /// The limit is checked after every instruction that might allocate, and
+    /// raises [VmErrorKind::MemoryLimitExceeded] once it's crossed. It's only
+    /// intended as a guardrail: collections are measured by their capacity
+    /// without descending into them, and memory that isn't referenced from
+    /// the stack isn't accounted for. Functions called from native code run
+    /// on a separate virtual machine which doesn't inherit the limit.
+    pub fn with_memory_limit(mut self, limit: usize) -> Self {
+        self.memory_limit = Some(limit);
+        self
+    }
+
     /// Run the given vm to completion.
     ///
     /// If any async instructions are encountered, this will error.
@@ -55,6 +81,55 @@ impl Vm {
         execution.async_complete().await
     }
 
+    /// Call the `main` function of the unit to completion and decode its
+    /// output.
+    ///
+    /// If `main` is declared as `async fn main`, it's driven to completion
+    /// with [async_complete][VmExecution::async_complete] on the provided
+    /// executor. Otherwise it's run synchronously and the executor is not
+    /// used.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use runestick::{Context, Unit, Vm};
+    /// use std::sync::Arc;
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let context = Context::with_default_modules()?;
+    /// let unit = Unit::new();
+    /// // NB: normally the unit would be created by compiling some source.
+    ///
+    /// let vm = Vm::new(Arc::new(context), Arc::new(unit));
+    /// let output = vm.run_main::<i64, _>(futures::executor::block_on)?;
+    /// println!("output: {}", output);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn run_main<T, E>(self, block_on: E) -> Result<T, VmError>
+    where
+        T: FromValue,
+        E: FnOnce(
+            std::pin::Pin<Box<dyn std::future::Future<Output = Result<Value, VmError>>>>,
+        ) -> Result<Value, VmError>,
+    {
+        let hash = Hash::type_hash(["main"]);
+
+        let call = match self.unit.lookup(hash).map(|info| &info.kind) {
+            Some(UnitFnKind::Offset { call, .. }) => *call,
+            _ => return Err(VmError::from(VmErrorKind::MissingFunction { hash })),
+        };
+
+        let mut execution = self.call(hash, ())?;
+
+        let output = match call {
+            Call::Async => block_on(Box::pin(async move { execution.async_complete().await })),
+            _ => execution.complete(),
+        }?;
+
+        T::from_value(output)
+    }
+
     /// Test if the virtual machine is the same context and unit as specified.
     pub fn is_same(&self, context: &Arc<Context>, unit: &Arc<Unit>) -> bool {
         Arc::ptr_eq(&self.context, context) && Arc::ptr_eq(&self.unit, unit)
@@ -95,10 +170,92 @@ impl Vm {
     }
 
     /// Reset this virtual machine, freeing all memory used.
+    ///
+    /// See [Vm::reset] for a variant which checks that nothing is still
+    /// borrowed from the virtual machine in debug builds.
     pub fn clear(&mut self) {
         self.ip = 0;
         self.stack.clear();
         self.call_frames.clear();
+        self.catches.clear();
+    }
+
+    /// Reset this virtual machine so that it can be reused for a new
+    /// top-level call, discarding the stack and all call frames.
+    ///
+    /// This is useful when pooling virtual machines, since the memory
+    /// allocated for the stack is retained. Any execution the virtual machine
+    /// was part of is abandoned.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, this panics if any value on the stack is still
+    /// borrowed, since that means a guard to it is being held onto by
+    /// someone outside of the virtual machine.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::{Context, Unit, Vm};
+    /// use std::sync::Arc;
+    ///
+    /// let context = Arc::new(Context::new());
+    /// let mut vm = Vm::new(context, Arc::new(Unit::new()));
+    /// vm.stack_mut().push(42i64);
+    ///
+    /// vm.reset();
+    /// assert!(vm.stack().is_empty());
+    /// ```
+    pub fn reset(&mut self) {
+        debug_assert!(
+            !self.stack.iter().any(Value::is_borrowed),
+            "virtual machine reset while values on its stack are borrowed"
+        );
+
+        self.clear();
+    }
+
+    /// Replace the unit of this virtual machine with a newly compiled one,
+    /// keeping the context, and return the unit it replaced.
+    ///
+    /// Every function in `entries` must be present in the new unit, or else
+    /// an error is raised and the virtual machine is left unchanged. The
+    /// virtual machine is [reset][Vm::reset] once the unit has been replaced.
+    /// Executions which are already running keep using the unit they were
+    /// started with.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use runestick::{Context, Unit, Vm};
+    /// use std::sync::Arc;
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let context = Arc::new(Context::with_default_modules()?);
+    /// let mut vm = Vm::new(context, Arc::new(Unit::new()));
+    ///
+    /// // NB: normally the new unit would be compiled from updated sources.
+    /// let unit = Arc::new(Unit::new());
+    /// vm.reload(unit, vec![&["main"]])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reload<I>(&mut self, unit: Arc<Unit>, entries: I) -> Result<Arc<Unit>, VmError>
+    where
+        I: IntoIterator,
+        I::Item: IntoHash,
+    {
+        for entry in entries {
+            let hash = entry.into_hash();
+
+            match unit.lookup(hash).map(|info| &info.kind) {
+                Some(UnitFnKind::Offset { .. }) => (),
+                _ => return Err(VmError::from(VmErrorKind::MissingFunction { hash })),
+            }
+        }
+
+        self.reset();
+        Ok(mem::replace(&mut self.unit, unit))
     }
 
     /// Access the current instruction pointer.
@@ -161,12 +318,9 @@ impl Vm {
             .lookup(hash)
             .ok_or_else(|| VmError::from(VmErrorKind::MissingFunction { hash }))?;
 
-        if function.signature.args != A::count() {
-            return Err(VmError::from(VmErrorKind::BadArgumentCount {
-                actual: A::count(),
-                expected: function.signature.args,
-            }));
-        }
+        let expected = function.signature.args;
+        let defaults = function.signature.defaults;
+        let variadic = function.signature.variadic;
 
         let offset = match function.kind {
             // NB: we ignore the calling convention.
@@ -183,6 +337,7 @@ impl Vm {
         // Safety: we bind the lifetime of the arguments to the outgoing task,
         // ensuring that the task won't outlive any references passed in.
         args.into_stack(&mut self.stack)?;
+        Self::prepare_args(&mut self.stack, A::count(), expected, defaults, variadic)?;
         Ok(VmExecution::of(self))
     }
 
@@ -236,20 +391,18 @@ impl Vm {
         let hash = Hash::instance_function(target.value_type()?, hash.into_hash());
 
         if let Some(info) = self.unit.lookup(hash) {
-            if info.signature.args != count {
-                return Err(VmError::from(VmErrorKind::BadArgumentCount {
-                    actual: count,
-                    expected: info.signature.args,
-                }));
-            }
-
             if let UnitFnKind::Offset { offset, call } = &info.kind {
                 let offset = *offset;
                 let call = *call;
+                let expected = info.signature.args;
+                let defaults = info.signature.defaults;
+                let variadic = info.signature.variadic;
 
                 self.stack.push(target.clone());
                 args.into_stack(&mut self.stack)?;
 
+                let count =
+                    Self::prepare_args(&mut self.stack, count, expected, defaults, variadic)?;
                 self.call_offset_fn(offset, call, count)?;
                 return Ok(true);
             }
@@ -355,51 +508,102 @@ impl Vm {
         Ok(())
     }
 
-    fn internal_boolean_ops(
+    /// Compare the two values at the top of the stack, pushing the result of
+    /// testing the ordering between them.
+    fn internal_cmp_op(
         &mut self,
-        int_op: impl FnOnce(i64, i64) -> bool,
-        float_op: impl FnOnce(f64, f64) -> bool,
+        test: impl FnOnce(Ordering) -> bool,
         op: &'static str,
     ) -> Result<(), VmError> {
         let rhs = self.stack.pop()?;
         let lhs = self.stack.pop()?;
-
-        let out = match (lhs, rhs) {
-            (Value::Integer(lhs), Value::Integer(rhs)) => int_op(lhs, rhs),
-            (Value::Float(lhs), Value::Float(rhs)) => float_op(lhs, rhs),
-            (lhs, rhs) => {
-                return Err(VmError::from(VmErrorKind::UnsupportedBinaryOperation {
-                    op,
-                    lhs: lhs.type_info()?,
-                    rhs: rhs.type_info()?,
-                }))
-            }
-        };
-
-        self.stack.push(out);
+        let ordering = Value::value_ptr_partial_cmp(&lhs, &rhs, op)?;
+        self.stack.push(ordering.map(test).unwrap_or_default());
         Ok(())
     }
 
     fn op_gt(&mut self) -> Result<(), VmError> {
-        self.internal_boolean_ops(|a, b| a > b, |a, b| a > b, ">")?;
+        self.internal_cmp_op(|o| o == Ordering::Greater, ">")?;
         Ok(())
     }
 
     fn op_gte(&mut self) -> Result<(), VmError> {
-        self.internal_boolean_ops(|a, b| a >= b, |a, b| a >= b, ">=")?;
+        self.internal_cmp_op(|o| o != Ordering::Less, ">=")?;
         Ok(())
     }
 
     fn op_lt(&mut self) -> Result<(), VmError> {
-        self.internal_boolean_ops(|a, b| a < b, |a, b| a < b, "<")?;
+        self.internal_cmp_op(|o| o == Ordering::Less, "<")?;
         Ok(())
     }
 
     fn op_lte(&mut self) -> Result<(), VmError> {
-        self.internal_boolean_ops(|a, b| a <= b, |a, b| a <= b, "<=")?;
+        self.internal_cmp_op(|o| o != Ordering::Greater, "<=")?;
         Ok(())
     }
 
+    /// Check that the given number of arguments on the top of the stack is
+    /// accepted by a function, and prepare them for the call.
+    ///
+    /// Omitted arguments which have default values are filled in with unit,
+    /// and any variadic arguments are packed into a vector. Functions with
+    /// default arguments are also passed the number of arguments they were
+    /// called with as a trailing argument, so that they can evaluate the
+    /// defaults of the ones which were omitted.
+    ///
+    /// Returns the number of arguments on the stack after preparing them.
+    pub(crate) fn prepare_args(
+        stack: &mut Stack,
+        actual: usize,
+        expected: usize,
+        defaults: usize,
+        variadic: bool,
+    ) -> Result<usize, VmError> {
+        if defaults == 0 && !variadic {
+            if actual != expected {
+                return Err(VmError::from(VmErrorKind::BadArgumentCount {
+                    actual,
+                    expected,
+                }));
+            }
+
+            return Ok(actual);
+        }
+
+        let required = expected - defaults;
+
+        if actual < required || !variadic && actual > expected {
+            return Err(VmError::from(VmErrorKind::BadArgumentCount {
+                actual,
+                expected: if actual < required {
+                    required
+                } else {
+                    expected
+                },
+            }));
+        }
+
+        let mut count = actual;
+
+        while count < expected {
+            stack.push(Value::Unit);
+            count += 1;
+        }
+
+        if variadic {
+            let rest = stack.pop_sequence(count - expected)?;
+            stack.push(Value::vec(rest));
+            count = expected + 1;
+        }
+
+        if defaults > 0 {
+            stack.push(Value::Integer(actual as i64));
+            count += 1;
+        }
+
+        Ok(count)
+    }
+
     /// Push a new call frame.
     ///
     /// This will cause the `args` number of elements on the stack to be
@@ -418,6 +622,16 @@ impl Vm {
 
     /// Pop a call frame and return it.
     fn pop_call_frame(&mut self) -> Result<bool, VmError> {
+        // NB: catch boundaries don't outlive the call frame they were pushed
+        // in.
+        while let Some(catch) = self.catches.last() {
+            if catch.frames < self.call_frames.len() {
+                break;
+            }
+
+            self.catches.pop();
+        }
+
         let frame = match self.call_frames.pop() {
             Some(frame) => frame,
             None => {
@@ -505,6 +719,15 @@ impl Vm {
         Ok(())
     }
 
+    /// Construct a range from the bounds on the stack.
+    #[inline]
+    fn op_range(&mut self, start: bool, end: bool) -> Result<(), VmError> {
+        let end = if end { Some(self.stack.pop()?) } else { None };
+        let start = if start { Some(self.stack.pop()?) } else { None };
+        self.stack.push(Range::new(start, end).to_value()?);
+        Ok(())
+    }
+
     /// Push the tuple that is on top of the stack.
     #[inline]
     fn op_push_tuple(&mut self) -> Result<(), VmError> {
@@ -533,11 +756,12 @@ impl Vm {
     }
 
     /// Internal impl of a numeric operation.
-    fn internal_numeric_op<H, E, I, F>(
+    fn internal_numeric_op<H, E, I, B, F>(
         &mut self,
         hash: H,
         error: E,
         integer_op: I,
+        byte_op: B,
         float_op: F,
         op: &'static str,
     ) -> Result<(), VmError>
@@ -545,6 +769,7 @@ impl Vm {
         H: IntoHash,
         E: Copy + FnOnce() -> VmError,
         I: FnOnce(i64, i64) -> Option<i64>,
+        B: FnOnce(u8, u8) -> Option<u8>,
         F: FnOnce(f64, f64) -> f64,
     {
         let rhs = self.stack.pop()?;
@@ -555,6 +780,10 @@ impl Vm {
                 self.stack.push(integer_op(lhs, rhs).ok_or_else(error)?);
                 return Ok(());
             }
+            (Value::Byte(lhs), Value::Byte(rhs)) => {
+                self.stack.push(byte_op(lhs, rhs).ok_or_else(error)?);
+                return Ok(());
+            }
             (Value::Float(lhs), Value::Float(rhs)) => {
                 self.stack.push(float_op(lhs, rhs));
                 return Ok(());
@@ -579,6 +808,7 @@ impl Vm {
             crate::ADD,
             || VmError::from(VmErrorKind::Overflow),
             i64::checked_add,
+            |a, b| Some(a.wrapping_add(b)),
             std::ops::Add::add,
             "+",
         )?;
@@ -591,6 +821,7 @@ impl Vm {
             crate::SUB,
             || VmError::from(VmErrorKind::Underflow),
             i64::checked_sub,
+            |a, b| Some(a.wrapping_sub(b)),
             std::ops::Sub::sub,
             "-",
         )?;
@@ -603,6 +834,7 @@ impl Vm {
             crate::ADD,
             || VmError::from(VmErrorKind::Overflow),
             i64::checked_mul,
+            |a, b| Some(a.wrapping_mul(b)),
             std::ops::Mul::mul,
             "*",
         )?;
@@ -615,6 +847,7 @@ impl Vm {
             crate::ADD,
             || VmError::from(VmErrorKind::DivideByZero),
             i64::checked_div,
+            u8::checked_div,
             std::ops::Div::div,
             "+",
         )?;
@@ -627,18 +860,20 @@ impl Vm {
             crate::REM,
             || VmError::from(VmErrorKind::DivideByZero),
             i64::checked_rem,
+            u8::checked_rem,
             std::ops::Rem::rem,
             "%",
         )?;
         Ok(())
     }
 
-    fn internal_op_assign<H, E, I, F>(
+    fn internal_op_assign<H, E, I, B, F>(
         &mut self,
         offset: usize,
         hash: H,
         error: E,
         integer_op: I,
+        byte_op: B,
         float_op: F,
         op: &'static str,
     ) -> Result<(), VmError>
@@ -646,6 +881,7 @@ impl Vm {
         H: IntoHash,
         E: Copy + FnOnce() -> VmError,
         I: FnOnce(i64, i64) -> Option<i64>,
+        B: FnOnce(u8, u8) -> Option<u8>,
         F: FnOnce(f64, f64) -> f64,
     {
         let rhs = self.stack.pop()?;
@@ -657,6 +893,11 @@ impl Vm {
                 *lhs = out;
                 return Ok(());
             }
+            (Value::Byte(lhs), Value::Byte(rhs)) => {
+                let out = byte_op(*lhs, rhs).ok_or_else(error)?;
+                *lhs = out;
+                return Ok(());
+            }
             (Value::Float(lhs), Value::Float(rhs)) => {
                 let out = float_op(*lhs, rhs);
                 *lhs = out;
@@ -684,6 +925,7 @@ impl Vm {
             crate::ADD_ASSIGN,
             || VmError::from(VmErrorKind::Overflow),
             i64::checked_add,
+            |a, b| Some(a.wrapping_add(b)),
             std::ops::Add::add,
             "+=",
         )?;
@@ -697,6 +939,7 @@ impl Vm {
             crate::SUB_ASSIGN,
             || VmError::from(VmErrorKind::Underflow),
             i64::checked_sub,
+            |a, b| Some(a.wrapping_sub(b)),
             std::ops::Sub::sub,
             "-=",
         )?;
@@ -710,6 +953,7 @@ impl Vm {
             crate::MUL_ASSIGN,
             || VmError::from(VmErrorKind::Overflow),
             i64::checked_mul,
+            |a, b| Some(a.wrapping_mul(b)),
             std::ops::Mul::mul,
             "*=",
         )?;
@@ -723,12 +967,263 @@ impl Vm {
             crate::DIV_ASSIGN,
             || VmError::from(VmErrorKind::DivideByZero),
             i64::checked_div,
+            u8::checked_div,
             std::ops::Div::div,
             "/=",
         )?;
         Ok(())
     }
 
+    #[inline]
+    fn op_rem_assign(&mut self, offset: usize) -> Result<(), VmError> {
+        self.internal_op_assign(
+            offset,
+            crate::REM_ASSIGN,
+            || VmError::from(VmErrorKind::DivideByZero),
+            i64::checked_rem,
+            u8::checked_rem,
+            std::ops::Rem::rem,
+            "%=",
+        )?;
+        Ok(())
+    }
+
+    /// Internal impl of a bitwise operation.
+    ///
+    /// Booleans are only supported if a `bool_op` is provided.
+    fn internal_bitwise_op<H, E, I, B>(
+        &mut self,
+        hash: H,
+        error: E,
+        integer_op: I,
+        byte_op: B,
+        bool_op: Option<fn(bool, bool) -> bool>,
+        op: &'static str,
+    ) -> Result<(), VmError>
+    where
+        H: IntoHash,
+        E: Copy + FnOnce() -> VmError,
+        I: FnOnce(i64, i64) -> Option<i64>,
+        B: FnOnce(u8, u8) -> Option<u8>,
+    {
+        let rhs = self.stack.pop()?;
+        let lhs = self.stack.pop()?;
+
+        let (lhs, rhs) = match (lhs, rhs, bool_op) {
+            (Value::Integer(lhs), Value::Integer(rhs), _) => {
+                self.stack.push(integer_op(lhs, rhs).ok_or_else(error)?);
+                return Ok(());
+            }
+            (Value::Byte(lhs), Value::Byte(rhs), _) => {
+                self.stack.push(byte_op(lhs, rhs).ok_or_else(error)?);
+                return Ok(());
+            }
+            (Value::Bool(lhs), Value::Bool(rhs), Some(bool_op)) => {
+                self.stack.push(bool_op(lhs, rhs));
+                return Ok(());
+            }
+            (lhs, rhs, _) => (lhs, rhs),
+        };
+
+        if !self.call_instance_fn(&lhs, hash, (&rhs,))? {
+            return Err(VmError::from(VmErrorKind::UnsupportedBinaryOperation {
+                op,
+                lhs: lhs.type_info()?,
+                rhs: rhs.type_info()?,
+            }));
+        }
+
+        Ok(())
+    }
+
+    /// Internal impl of a bitwise assign operation.
+    ///
+    /// Booleans are only supported if a `bool_op` is provided.
+    fn internal_bitwise_op_assign<H, E, I, B>(
+        &mut self,
+        offset: usize,
+        hash: H,
+        error: E,
+        integer_op: I,
+        byte_op: B,
+        bool_op: Option<fn(bool, bool) -> bool>,
+        op: &'static str,
+    ) -> Result<(), VmError>
+    where
+        H: IntoHash,
+        E: Copy + FnOnce() -> VmError,
+        I: FnOnce(i64, i64) -> Option<i64>,
+        B: FnOnce(u8, u8) -> Option<u8>,
+    {
+        let rhs = self.stack.pop()?;
+        let lhs = self.stack.at_offset_mut(offset)?;
+
+        let (lhs, rhs) = match (lhs, rhs, bool_op) {
+            (Value::Integer(lhs), Value::Integer(rhs), _) => {
+                let out = integer_op(*lhs, rhs).ok_or_else(error)?;
+                *lhs = out;
+                return Ok(());
+            }
+            (Value::Byte(lhs), Value::Byte(rhs), _) => {
+                let out = byte_op(*lhs, rhs).ok_or_else(error)?;
+                *lhs = out;
+                return Ok(());
+            }
+            (Value::Bool(lhs), Value::Bool(rhs), Some(bool_op)) => {
+                let out = bool_op(*lhs, rhs);
+                *lhs = out;
+                return Ok(());
+            }
+            (lhs, rhs, _) => (lhs.clone(), rhs),
+        };
+
+        if !self.call_instance_fn(&lhs, hash, (&rhs,))? {
+            return Err(VmError::from(VmErrorKind::UnsupportedBinaryOperation {
+                op,
+                lhs: lhs.type_info()?,
+                rhs: rhs.type_info()?,
+            }));
+        }
+
+        self.stack.pop()?;
+        Ok(())
+    }
+
+    #[inline]
+    fn op_bit_and(&mut self) -> Result<(), VmError> {
+        self.internal_bitwise_op(
+            crate::BIT_AND,
+            || VmError::from(VmErrorKind::Overflow),
+            |a, b| Some(a & b),
+            |a, b| Some(a & b),
+            Some(|a, b| a & b),
+            "&",
+        )?;
+        Ok(())
+    }
+
+    #[inline]
+    fn op_bit_and_assign(&mut self, offset: usize) -> Result<(), VmError> {
+        self.internal_bitwise_op_assign(
+            offset,
+            crate::BIT_AND_ASSIGN,
+            || VmError::from(VmErrorKind::Overflow),
+            |a, b| Some(a & b),
+            |a, b| Some(a & b),
+            Some(|a, b| a & b),
+            "&=",
+        )?;
+        Ok(())
+    }
+
+    #[inline]
+    fn op_bit_xor(&mut self) -> Result<(), VmError> {
+        self.internal_bitwise_op(
+            crate::BIT_XOR,
+            || VmError::from(VmErrorKind::Overflow),
+            |a, b| Some(a ^ b),
+            |a, b| Some(a ^ b),
+            Some(|a, b| a ^ b),
+            "^",
+        )?;
+        Ok(())
+    }
+
+    #[inline]
+    fn op_bit_xor_assign(&mut self, offset: usize) -> Result<(), VmError> {
+        self.internal_bitwise_op_assign(
+            offset,
+            crate::BIT_XOR_ASSIGN,
+            || VmError::from(VmErrorKind::Overflow),
+            |a, b| Some(a ^ b),
+            |a, b| Some(a ^ b),
+            Some(|a, b| a ^ b),
+            "^=",
+        )?;
+        Ok(())
+    }
+
+    #[inline]
+    fn op_bit_or(&mut self) -> Result<(), VmError> {
+        self.internal_bitwise_op(
+            crate::BIT_OR,
+            || VmError::from(VmErrorKind::Overflow),
+            |a, b| Some(a | b),
+            |a, b| Some(a | b),
+            Some(|a, b| a | b),
+            "|",
+        )?;
+        Ok(())
+    }
+
+    #[inline]
+    fn op_bit_or_assign(&mut self, offset: usize) -> Result<(), VmError> {
+        self.internal_bitwise_op_assign(
+            offset,
+            crate::BIT_OR_ASSIGN,
+            || VmError::from(VmErrorKind::Overflow),
+            |a, b| Some(a | b),
+            |a, b| Some(a | b),
+            Some(|a, b| a | b),
+            "|=",
+        )?;
+        Ok(())
+    }
+
+    #[inline]
+    fn op_shl(&mut self) -> Result<(), VmError> {
+        self.internal_bitwise_op(
+            crate::SHL,
+            || VmError::from(VmErrorKind::Overflow),
+            checked_shl,
+            byte_checked_shl,
+            None,
+            "<<",
+        )?;
+        Ok(())
+    }
+
+    #[inline]
+    fn op_shl_assign(&mut self, offset: usize) -> Result<(), VmError> {
+        self.internal_bitwise_op_assign(
+            offset,
+            crate::SHL_ASSIGN,
+            || VmError::from(VmErrorKind::Overflow),
+            checked_shl,
+            byte_checked_shl,
+            None,
+            "<<=",
+        )?;
+        Ok(())
+    }
+
+    #[inline]
+    fn op_shr(&mut self) -> Result<(), VmError> {
+        self.internal_bitwise_op(
+            crate::SHR,
+            || VmError::from(VmErrorKind::Overflow),
+            checked_shr,
+            byte_checked_shr,
+            None,
+            ">>",
+        )?;
+        Ok(())
+    }
+
+    #[inline]
+    fn op_shr_assign(&mut self, offset: usize) -> Result<(), VmError> {
+        self.internal_bitwise_op_assign(
+            offset,
+            crate::SHR_ASSIGN,
+            || VmError::from(VmErrorKind::Overflow),
+            checked_shr,
+            byte_checked_shr,
+            None,
+            ">>=",
+        )?;
+        Ok(())
+    }
+
     /// Perform an index set operation.
     #[inline]
     fn op_index_set(&mut self) -> Result<(), VmError> {
@@ -787,6 +1282,25 @@ impl Vm {
             }
         }
 
+        if let (Value::Vec(..), Value::Integer(n)) | (Value::Tuple(..), Value::Integer(n)) =
+            (&target, &index)
+        {
+            use std::convert::TryFrom as _;
+
+            let n = *n;
+
+            if let Ok(index) = usize::try_from(n) {
+                if Self::try_tuple_like_index_set(&target, index, value)? {
+                    return Ok(());
+                }
+            }
+
+            return Err(VmError::from(VmErrorKind::MissingIndex {
+                target: target.type_info()?,
+                index: Integer::I64(n),
+            }));
+        }
+
         if !self.call_instance_fn(&target, crate::INDEX_SET, (&index, &value))? {
             return Err(VmError::from(VmErrorKind::UnsupportedIndexSet {
                 target: target.type_info()?,
@@ -795,6 +1309,8 @@ impl Vm {
             }));
         }
 
+        // NB: the index set protocol doesn't produce a value.
+        let _ = self.stack.pop()?;
         Ok(())
     }
 
@@ -1004,42 +1520,40 @@ impl Vm {
         let target = self.stack.pop()?;
         let index = self.stack.pop()?;
 
-        // This is a useful pattern.
-        #[allow(clippy::never_loop)]
-        loop {
-            match &index {
-                Value::String(string) => {
-                    let string_ref = string.borrow_ref()?;
-
-                    if self.try_object_like_index_get(&target, string_ref.as_str())? {
-                        return Ok(());
-                    }
-                }
-                Value::StaticString(string) => {
-                    if self.try_object_like_index_get(&target, string.as_ref())? {
-                        return Ok(());
+        let handled = match &index {
+            Value::String(string) => {
+                let string_ref = string.borrow_ref()?;
+                self.try_object_like_index_get(&target, string_ref.as_str())?
+            }
+            Value::StaticString(string) => {
+                self.try_object_like_index_get(&target, string.as_ref())?
+            }
+            Value::Integer(index) => {
+                use std::convert::TryInto as _;
+
+                let index = match (*index).try_into() {
+                    Ok(index) => index,
+                    Err(..) => {
+                        return Err(VmError::from(VmErrorKind::MissingIndex {
+                            target: target.type_info()?,
+                            index: Integer::I64(*index),
+                        }));
                     }
-                }
-                Value::Integer(index) => {
-                    use std::convert::TryInto as _;
-
-                    let index = match (*index).try_into() {
-                        Ok(index) => index,
-                        Err(..) => {
-                            return Err(VmError::from(VmErrorKind::MissingIndex {
-                                target: target.type_info()?,
-                                index: Integer::I64(*index),
-                            }));
-                        }
-                    };
+                };
 
-                    if let Some(value) = Self::try_tuple_like_index_get(&target, index)? {
+                match Self::try_tuple_like_index_get(&target, index)? {
+                    Some(value) => {
                         self.stack.push(value);
-                        return Ok(());
+                        true
                     }
+                    None => false,
                 }
-                _ => break,
-            };
+            }
+            _ => false,
+        };
+
+        if handled {
+            return Ok(());
         }
 
         if !self.call_instance_fn(&target, crate::INDEX_GET, (&index,))? {
@@ -1124,6 +1638,10 @@ impl Vm {
                 match typed_object.object.get(&***index).cloned() {
                     Some(value) => Some(value),
                     None => {
+                        if let Some(bound) = self.try_bind_instance_fn(target, index.hash())? {
+                            return Ok(Some(bound));
+                        }
+
                         return Err(VmError::from(VmErrorKind::ObjectIndexMissing {
                             slot: string_slot,
                         }));
@@ -1136,6 +1654,10 @@ impl Vm {
                 match variant_object.object.get(&***index).cloned() {
                     Some(value) => Some(value),
                     None => {
+                        if let Some(bound) = self.try_bind_instance_fn(target, index.hash())? {
+                            return Ok(Some(bound));
+                        }
+
                         return Err(VmError::from(VmErrorKind::ObjectIndexMissing {
                             slot: string_slot,
                         }));
@@ -1148,7 +1670,7 @@ impl Vm {
                 if self.call_getter(target, hash, ())? {
                     Some(self.stack.pop()?)
                 } else {
-                    None
+                    self.try_bind_instance_fn(target, hash)?
                 }
             }
         })
@@ -1198,7 +1720,7 @@ impl Vm {
             .lookup_object_keys(slot)
             .ok_or_else(|| VmError::from(VmErrorKind::MissingStaticObjectKeys { slot }))?;
 
-        let mut object = Object::with_capacity(keys.len());
+        let mut object = crate::object_with_capacity(keys.len());
         let values = self.stack.drain_stack_top(keys.len())?;
 
         for (key, value) in keys.iter().zip(values) {
@@ -1217,7 +1739,7 @@ impl Vm {
             .lookup_object_keys(slot)
             .ok_or_else(|| VmError::from(VmErrorKind::MissingStaticObjectKeys { slot }))?;
 
-        let mut object = Object::with_capacity(keys.len());
+        let mut object = crate::object_with_capacity(keys.len());
 
         let values = self.stack.drain_stack_top(keys.len())?;
 
@@ -1242,7 +1764,7 @@ impl Vm {
             .lookup_object_keys(slot)
             .ok_or_else(|| VmError::from(VmErrorKind::MissingStaticObjectKeys { slot }))?;
 
-        let mut object = Object::with_capacity(keys.len());
+        let mut object = crate::object_with_capacity(keys.len());
         let values = self.stack.drain_stack_top(keys.len())?;
 
         for (key, value) in keys.iter().zip(values) {
@@ -1272,6 +1794,194 @@ impl Vm {
         Ok(())
     }
 
+    /// Format the value at the top of the stack using its debug
+    /// representation.
+    #[inline]
+    fn op_string_debug(&mut self, pretty: bool) -> Result<(), VmError> {
+        let value = self.stack.pop()?;
+        let buf = self.format_debug(value, pretty)?;
+        self.stack.push(buf);
+        Ok(())
+    }
+
+    /// Format the given value using its debug representation, calling the
+    /// `STRING_DEBUG` protocol where it's implemented.
+    pub(crate) fn format_debug(&mut self, value: Value, pretty: bool) -> Result<String, VmError> {
+        let mut buf = String::new();
+        let mut state = DebugState::new(pretty);
+        self.debug_fmt(value, &mut buf, &mut state)?;
+        Ok(buf)
+    }
+
+    /// Write the debug representation of the given value into the buffer.
+    fn debug_fmt(
+        &mut self,
+        value: Value,
+        buf: &mut String,
+        state: &mut DebugState,
+    ) -> Result<(), VmError> {
+        match value {
+            Value::String(string) => {
+                write!(buf, "{:?}", &*string.borrow_ref()?).map_err(VmError::panic)?;
+            }
+            Value::StaticString(string) => {
+                write!(buf, "{:?}", string.as_ref()).map_err(VmError::panic)?;
+            }
+            Value::Vec(vec) => {
+                if state.enter(vec.as_ptr(), buf) {
+                    let values = vec
+                        .borrow_ref()?
+                        .iter()
+                        .cloned()
+                        .map(|v| (None, v))
+                        .collect();
+                    self.debug_fmt_seq("[", values, "]", buf, state)?;
+                    state.exit();
+                }
+            }
+            Value::Tuple(tuple) => {
+                if state.enter(tuple.as_ptr(), buf) {
+                    let values = tuple
+                        .borrow_ref()?
+                        .iter()
+                        .cloned()
+                        .map(|v| (None, v))
+                        .collect();
+                    self.debug_fmt_seq("(", values, ")", buf, state)?;
+                    state.exit();
+                }
+            }
+            Value::Object(object) => {
+                if state.enter(object.as_ptr(), buf) {
+                    let values = Self::debug_object_entries(&*object.borrow_ref()?);
+                    self.debug_fmt_seq("{", values, "}", buf, state)?;
+                    state.exit();
+                }
+            }
+            Value::TypedObject(object) => {
+                if state.enter(object.as_ptr(), buf) {
+                    let object = object.borrow_ref()?;
+                    write!(buf, "{} ", object.hash).map_err(VmError::panic)?;
+                    let values = Self::debug_object_entries(&object.object);
+                    drop(object);
+                    self.debug_fmt_seq("{", values, "}", buf, state)?;
+                    state.exit();
+                }
+            }
+            Value::VariantObject(object) => {
+                if state.enter(object.as_ptr(), buf) {
+                    let object = object.borrow_ref()?;
+                    write!(buf, "{} ", object.hash).map_err(VmError::panic)?;
+                    let values = Self::debug_object_entries(&object.object);
+                    drop(object);
+                    self.debug_fmt_seq("{", values, "}", buf, state)?;
+                    state.exit();
+                }
+            }
+            Value::Option(option) => match option.borrow_ref()?.clone() {
+                Some(value) => {
+                    buf.push_str("Some(");
+                    self.debug_fmt(value, buf, state)?;
+                    buf.push(')');
+                }
+                None => {
+                    buf.push_str("None");
+                }
+            },
+            Value::Result(result) => {
+                let (prefix, value) = match result.borrow_ref()?.clone() {
+                    Ok(value) => ("Ok(", value),
+                    Err(value) => ("Err(", value),
+                };
+
+                buf.push_str(prefix);
+                self.debug_fmt(value, buf, state)?;
+                buf.push(')');
+            }
+            Value::Any(..) => {
+                let b = Shared::new(std::mem::take(buf));
+
+                if !self.call_instance_fn(
+                    &value,
+                    crate::STRING_DEBUG,
+                    (Value::String(b.clone()),),
+                )? {
+                    *buf = b.take()?;
+                    write!(buf, "{:?}", value).map_err(VmError::panic)?;
+                    return Ok(());
+                }
+
+                let result = fmt::Result::from_value(self.stack.pop()?)?;
+
+                if let Err(fmt::Error) = result {
+                    return Err(VmError::from(VmErrorKind::FormatError));
+                }
+
+                *buf = b.take()?;
+            }
+            value => {
+                write!(buf, "{:?}", value).map_err(VmError::panic)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Collect the entries of an object as keyed values to debug format.
+    fn debug_object_entries(object: &Object<Value>) -> Vec<(Option<String>, Value)> {
+        object
+            .iter()
+            .map(|(key, value)| (Some(format!("{:?}", key)), value.clone()))
+            .collect()
+    }
+
+    /// Write the debug representation of a delimited sequence of values, each
+    /// optionally prefixed with a key.
+    fn debug_fmt_seq(
+        &mut self,
+        open: &str,
+        values: Vec<(Option<String>, Value)>,
+        close: &str,
+        buf: &mut String,
+        state: &mut DebugState,
+    ) -> Result<(), VmError> {
+        buf.push_str(open);
+
+        // NB: a single element tuple needs a trailing comma to be told apart
+        // from a parenthesized value.
+        let trailing = !state.pretty && open == "(" && values.len() == 1;
+        let empty = values.is_empty();
+        state.indent += 1;
+
+        for (n, (key, value)) in values.into_iter().enumerate() {
+            if state.pretty {
+                state.newline(buf);
+            } else if n > 0 {
+                buf.push_str(", ");
+            }
+
+            if let Some(key) = key {
+                buf.push_str(&key);
+                buf.push_str(": ");
+            }
+
+            self.debug_fmt(value, buf, state)?;
+
+            if state.pretty || trailing {
+                buf.push(',');
+            }
+        }
+
+        state.indent -= 1;
+
+        if state.pretty && !empty {
+            state.newline(buf);
+        }
+
+        buf.push_str(close);
+        Ok(())
+    }
+
     /// Optimize operation to perform string concatenation.
     #[inline]
     fn op_string_concat(&mut self, len: usize, size_hint: usize) -> Result<(), VmError> {
@@ -1290,6 +2000,10 @@ impl Vm {
                     let mut buffer = itoa::Buffer::new();
                     buf.push_str(buffer.format(integer));
                 }
+                Value::Byte(byte) => {
+                    let mut buffer = itoa::Buffer::new();
+                    buf.push_str(buffer.format(byte));
+                }
                 Value::Float(float) => {
                     let mut buffer = ryu::Buffer::new();
                     buf.push_str(buffer.format(float));
@@ -1353,6 +2067,62 @@ impl Vm {
         Ok(())
     }
 
+    /// Branch on a result-like value for the try operator.
+    fn op_try(&mut self) -> Result<(), VmError> {
+        let value = self.stack.pop()?;
+
+        let (value, is_value) = match value {
+            Value::Result(result) => match &*result.borrow_ref()? {
+                Ok(value) => (value.clone(), true),
+                Err(..) => (Value::Result(result.clone()), false),
+            },
+            Value::Option(option) => match &*option.borrow_ref()? {
+                Some(value) => (value.clone(), true),
+                None => (Value::Option(option.clone()), false),
+            },
+            actual => {
+                if !self.call_instance_fn(&actual, crate::TRY, ())? {
+                    return Err(VmError::from(VmErrorKind::MissingProtocol {
+                        protocol: crate::TRY,
+                        actual: actual.type_info()?,
+                    }));
+                }
+
+                match Result::<Value, Value>::from_value(self.stack.pop()?)? {
+                    Ok(value) => (value, true),
+                    Err(value) => (value, false),
+                }
+            }
+        };
+
+        self.stack.push(value);
+        self.stack.push(is_value);
+        Ok(())
+    }
+
+    /// Push a catch boundary.
+    #[inline]
+    fn op_push_catch(&mut self, offset: isize) {
+        self.catches.push(Catch {
+            ip: self.ip.wrapping_add(offset as usize).wrapping_add(1),
+            frames: self.call_frames.len(),
+            stack: self.stack.len(),
+            stack_bottom: self.stack.stack_bottom(),
+        });
+    }
+
+    /// Unwind to the given catch boundary, pushing the reason of the caught
+    /// panic as an `Err`.
+    fn unwind(&mut self, catch: Catch, reason: String) -> Result<(), VmError> {
+        self.call_frames.truncate(catch.frames);
+        self.stack.unwind(catch.stack, catch.stack_bottom)?;
+
+        let error = Err(Value::String(Shared::new(reason)));
+        self.stack.push(Value::Result(Shared::new(error)));
+        self.ip = catch.ip;
+        Ok(())
+    }
+
     /// Internal implementation of the instance check.
     fn is_instance(&mut self) -> Result<bool, VmError> {
         let b = self.stack.pop()?;
@@ -1706,6 +2476,16 @@ impl Vm {
     }
 
     fn op_fn(&mut self, hash: Hash) -> Result<(), VmError> {
+        let function = self
+            .lookup_function(hash)
+            .ok_or_else(|| VmError::from(VmErrorKind::MissingFunction { hash }))?;
+
+        self.stack.push(Value::Function(Shared::new(function)));
+        Ok(())
+    }
+
+    /// Look up a function by hash in the unit, falling back to the context.
+    fn lookup_function(&self, hash: Hash) -> Option<Function> {
         let function = match self.unit.lookup(hash) {
             Some(info) => {
                 let args = info.signature.args;
@@ -1716,7 +2496,7 @@ impl Vm {
                         self.unit.clone(),
                         *offset,
                         *call,
-                        args,
+                        &info.signature,
                     ),
                     UnitFnKind::Tuple { hash } => Function::from_tuple(*hash, args),
                     UnitFnKind::TupleVariant { enum_hash, hash } => {
@@ -1724,18 +2504,20 @@ impl Vm {
                     }
                 }
             }
-            None => {
-                let handler = self
-                    .context
-                    .lookup(hash)
-                    .ok_or_else(|| VmError::from(VmErrorKind::MissingFunction { hash }))?;
-
-                Function::from_handler(handler.clone())
-            }
+            None => Function::from_handler(self.context.lookup(hash)?.clone()),
         };
 
-        self.stack.push(Value::Function(Shared::new(function)));
-        Ok(())
+        Some(function)
+    }
+
+    /// Try to bind the instance function with the given name hash to the
+    /// target, producing a bound method value.
+    fn try_bind_instance_fn(&self, target: &Value, hash: Hash) -> Result<Option<Value>, VmError> {
+        let hash = Hash::instance_function(target.value_type()?, hash);
+
+        Ok(self
+            .lookup_function(hash)
+            .map(|function| Value::Function(Shared::new(function.bind(target.clone())))))
     }
 
     /// Construct a closure on the top of the stack.
@@ -1745,8 +2527,6 @@ impl Vm {
             .lookup(hash)
             .ok_or_else(|| VmError::from(VmErrorKind::MissingFunction { hash }))?;
 
-        let args = info.signature.args;
-
         let (offset, call) = match &info.kind {
             UnitFnKind::Offset { offset, call } => (*offset, *call),
             _ => return Err(VmError::from(VmErrorKind::MissingFunction { hash })),
@@ -1761,7 +2541,7 @@ impl Vm {
             environment,
             offset,
             call,
-            args,
+            &info.signature,
         );
         self.stack.push(Value::Function(Shared::new(function)));
         Ok(())
@@ -1771,12 +2551,13 @@ impl Vm {
     fn op_call(&mut self, hash: Hash, args: usize) -> Result<(), VmError> {
         match self.unit.lookup(hash) {
             Some(info) => {
-                if info.signature.args != args {
-                    return Err(VmError::from(VmErrorKind::BadArgumentCount {
-                        actual: args,
-                        expected: info.signature.args,
-                    }));
-                }
+                let args = Self::prepare_args(
+                    &mut self.stack,
+                    args,
+                    info.signature.args,
+                    info.signature.defaults,
+                    info.signature.variadic,
+                )?;
 
                 match info.kind {
                     UnitFnKind::Offset { offset, call } => {
@@ -1820,24 +2601,25 @@ impl Vm {
 
         match self.unit.lookup(hash) {
             Some(info) => {
-                if info.signature.args != args {
-                    return Err(VmError::from(VmErrorKind::BadArgumentCount {
-                        actual: args,
-                        expected: info.signature.args,
-                    }));
-                }
-
-                match info.kind {
-                    UnitFnKind::Offset { offset, call } => {
-                        self.call_offset_fn(offset, call, args)?;
-                    }
+                let (offset, call) = match info.kind {
+                    UnitFnKind::Offset { offset, call } => (offset, call),
                     _ => {
                         return Err(VmError::from(VmErrorKind::MissingInstanceFunction {
                             instance: instance.type_info()?,
                             hash,
                         }));
                     }
-                }
+                };
+
+                let args = Self::prepare_args(
+                    &mut self.stack,
+                    args,
+                    info.signature.args,
+                    info.signature.defaults,
+                    info.signature.variadic,
+                )?;
+
+                self.call_offset_fn(offset, call, args)?;
             }
             None => {
                 let handler = match self.context.lookup(hash) {
@@ -1884,7 +2666,48 @@ impl Vm {
     }
 
     /// Evaluate a single instruction.
+    /// Check that the approximate memory held by the stack is within the
+    /// given limit.
+    fn check_memory_limit(&self, limit: usize) -> Result<(), VmError> {
+        let mut visited = HashSet::new();
+        let mut total = self.stack.len() * mem::size_of::<Value>();
+
+        for value in self.stack.iter() {
+            total += approximate_size(value, &mut visited);
+        }
+
+        if total > limit {
+            return Err(VmError::from(VmErrorKind::MemoryLimitExceeded { limit }));
+        }
+
+        Ok(())
+    }
+
     pub(crate) fn run_for(&mut self, mut limit: Option<usize>) -> Result<VmHalt, VmError> {
+        let _guard = env::Guard::new(&self.context, &self.unit);
+
+        loop {
+            let error = match self.run_instructions(&mut limit) {
+                Ok(halt) => return Ok(halt),
+                Err(error) => error,
+            };
+
+            // NB: only panics are caught, like with `try_catch`. Other errors
+            // are bugs in the program or guardrails set up by the host, like
+            // the memory limit, so they always propagate.
+            let reason = match error.kind().into_unwound_ref().0 {
+                VmErrorKind::Panic { reason } => reason.to_string(),
+                _ => return Err(error),
+            };
+
+            match self.catches.pop() {
+                Some(catch) => self.unwind(catch, reason)?,
+                None => return Err(error),
+            }
+        }
+    }
+
+    fn run_instructions(&mut self, limit: &mut Option<usize>) -> Result<VmHalt, VmError> {
         loop {
             let inst = *self
                 .unit
@@ -1924,6 +2747,39 @@ impl Vm {
                 Inst::Rem => {
                     self.op_rem()?;
                 }
+                Inst::RemAssign { offset } => {
+                    self.op_rem_assign(offset)?;
+                }
+                Inst::BitAnd => {
+                    self.op_bit_and()?;
+                }
+                Inst::BitAndAssign { offset } => {
+                    self.op_bit_and_assign(offset)?;
+                }
+                Inst::BitXor => {
+                    self.op_bit_xor()?;
+                }
+                Inst::BitXorAssign { offset } => {
+                    self.op_bit_xor_assign(offset)?;
+                }
+                Inst::BitOr => {
+                    self.op_bit_or()?;
+                }
+                Inst::BitOrAssign { offset } => {
+                    self.op_bit_or_assign(offset)?;
+                }
+                Inst::Shl => {
+                    self.op_shl()?;
+                }
+                Inst::ShlAssign { offset } => {
+                    self.op_shl_assign(offset)?;
+                }
+                Inst::Shr => {
+                    self.op_shr()?;
+                }
+                Inst::ShrAssign { offset } => {
+                    self.op_shr_assign(offset)?;
+                }
                 Inst::Fn { hash } => {
                     self.op_fn(hash)?;
                 }
@@ -2063,6 +2919,9 @@ impl Vm {
                 Inst::Tuple { count } => {
                     self.op_tuple(count)?;
                 }
+                Inst::Range { start, end } => {
+                    self.op_range(start, end)?;
+                }
                 Inst::PushTuple => {
                     self.op_push_tuple()?;
                 }
@@ -2097,6 +2956,9 @@ impl Vm {
                 Inst::StringConcat { len, size_hint } => {
                     self.op_string_concat(len, size_hint)?;
                 }
+                Inst::StringDebug { pretty } => {
+                    self.op_string_debug(pretty)?;
+                }
                 Inst::Is => {
                     self.op_is()?;
                 }
@@ -2112,6 +2974,15 @@ impl Vm {
                 Inst::Unwrap => {
                     self.op_unwrap()?;
                 }
+                Inst::Try => {
+                    self.op_try()?;
+                }
+                Inst::PushCatch { offset } => {
+                    self.op_push_catch(offset);
+                }
+                Inst::PopCatch => {
+                    self.catches.pop();
+                }
                 Inst::And => {
                     self.op_and()?;
                 }
@@ -2153,6 +3024,10 @@ impl Vm {
                     self.stack.push(Value::Unit);
                     return Ok(VmHalt::Yielded);
                 }
+                Inst::YieldHost => {
+                    self.advance();
+                    return Ok(VmHalt::YieldedHost);
+                }
                 Inst::Panic { reason } => {
                     return Err(VmError::from(VmErrorKind::Panic {
                         reason: Panic::from(reason),
@@ -2160,9 +3035,15 @@ impl Vm {
                 }
             }
 
+            if let Some(memory_limit) = self.memory_limit {
+                if inst.may_allocate() {
+                    self.check_memory_limit(memory_limit)?;
+                }
+            }
+
             self.advance();
 
-            if let Some(limit) = &mut limit {
+            if let Some(limit) = limit {
                 if *limit <= 1 {
                     return Ok(VmHalt::Limited);
                 }
@@ -2173,6 +3054,64 @@ impl Vm {
     }
 }
 
+/// Shift `a` left by `b` bits, failing if `b` is out of range.
+fn checked_shl(a: i64, b: i64) -> Option<i64> {
+    a.checked_shl(u32::try_from(b).ok()?)
+}
+
+/// Shift `a` right by `b` bits, failing if `b` is out of range.
+fn checked_shr(a: i64, b: i64) -> Option<i64> {
+    a.checked_shr(u32::try_from(b).ok()?)
+}
+
+/// Shift the byte `a` left by `b` bits, failing if `b` is out of range.
+fn byte_checked_shl(a: u8, b: u8) -> Option<u8> {
+    a.checked_shl(u32::from(b))
+}
+
+/// Shift the byte `a` right by `b` bits, failing if `b` is out of range.
+fn byte_checked_shr(a: u8, b: u8) -> Option<u8> {
+    a.checked_shr(u32::from(b))
+}
+
+/// Approximate the number of bytes held by the collection `value` refers to,
+/// without descending into it.
+///
+/// Collections which have already been visited, or which are currently
+/// borrowed exclusively, count as empty.
+fn approximate_size(value: &Value, visited: &mut HashSet<*const ()>) -> usize {
+    fn measure<T>(
+        shared: &Shared<T>,
+        visited: &mut HashSet<*const ()>,
+        f: impl FnOnce(&T) -> usize,
+    ) -> usize
+    where
+        T: ?Sized,
+    {
+        if !visited.insert(shared.as_ptr()) {
+            return 0;
+        }
+
+        match shared.borrow_ref() {
+            Ok(value) => f(&*value),
+            Err(..) => 0,
+        }
+    }
+
+    let value_size = mem::size_of::<Value>();
+
+    match value {
+        Value::String(string) => measure(string, visited, String::capacity),
+        Value::Bytes(bytes) => measure(bytes, visited, |bytes| bytes.len()),
+        Value::Vec(vec) => measure(vec, visited, |vec| vec.capacity() * value_size),
+        Value::Tuple(tuple) => measure(tuple, visited, |tuple| tuple.len() * value_size),
+        Value::Object(object) => measure(object, visited, |object| {
+            object.capacity() * (mem::size_of::<String>() + value_size)
+        }),
+        _ => 0,
+    }
+}
+
 /// A call frame.
 ///
 /// This is used to store the return point after an instruction has been run.
@@ -2199,3 +3138,66 @@ impl CallFrame {
         self.stack_bottom
     }
 }
+
+/// A catch boundary pushed by [Inst::PushCatch].
+#[derive(Debug, Clone, Copy)]
+struct Catch {
+    /// The instruction to jump to when an error is caught.
+    ip: usize,
+    /// The number of call frames when the boundary was pushed.
+    frames: usize,
+    /// The length of the stack when the boundary was pushed.
+    stack: usize,
+    /// The bottom of the stack when the boundary was pushed.
+    stack_bottom: usize,
+}
+
+/// State used when formatting the debug representation of values.
+struct DebugState {
+    /// If the output should be pretty printed over multiple lines.
+    pretty: bool,
+    /// The current level of indentation.
+    indent: usize,
+    /// Shared values which are currently being formatted, used to detect
+    /// cycles.
+    visited: Vec<*const ()>,
+}
+
+impl DebugState {
+    /// Construct a new debug formatting state.
+    fn new(pretty: bool) -> Self {
+        Self {
+            pretty,
+            indent: 0,
+            visited: Vec::new(),
+        }
+    }
+
+    /// Enter formatting the shared value with the given pointer.
+    ///
+    /// Returns `false` and writes `...` if the value is already being
+    /// formatted, in which case [exit][Self::exit] must not be called.
+    fn enter(&mut self, ptr: *const (), buf: &mut String) -> bool {
+        if self.visited.contains(&ptr) {
+            buf.push_str("...");
+            return false;
+        }
+
+        self.visited.push(ptr);
+        true
+    }
+
+    /// Exit formatting the last entered shared value.
+    fn exit(&mut self) {
+        self.visited.pop();
+    }
+
+    /// Write a newline followed by the current indentation.
+    fn newline(&self, buf: &mut String) {
+        buf.push('\n');
+
+        for _ in 0..self.indent {
+            buf.push_str("    ");
+        }
+    }
+}
@@ -178,6 +178,18 @@ pub enum VmErrorKind {
         /// The instance type we tried to look up function on.
         instance: TypeInfo,
     },
+    /// Values were nested too deeply to be compared.
+    #[error("recursion limit of {limit} exceeded")]
+    RecursionLimit {
+        /// The configured limit.
+        limit: usize,
+    },
+    /// The memory held by the virtual machine crossed its configured limit.
+    #[error("memory limit of {limit} bytes exceeded")]
+    MemoryLimitExceeded {
+        /// The limit in bytes.
+        limit: usize,
+    },
     /// Instruction pointer went out-of-bounds.
     #[error("instruction pointer is out-of-bounds")]
     IpOutOfBounds,
@@ -320,7 +332,7 @@ pub enum VmErrorKind {
         slot: usize,
     },
     /// Tried to access an index that was missing on a type.
-    #[error("missing index `{}` on `{target}`")]
+    #[error("missing index `{index}` on `{target}`")]
     MissingIndex {
         /// Type where field did not exist.
         target: TypeInfo,
@@ -408,6 +420,25 @@ pub enum VmErrorKind {
     /// Internal error that happens when we run out of items in a list.
     #[error("unexpectedly ran out of items to iterate over")]
     IterationError,
+    /// Error raised when trying to hash a value which can't be hashed.
+    #[error("`{actual}` cannot be hashed")]
+    UnsupportedHash {
+        /// The type of the value.
+        actual: TypeInfo,
+    },
+    /// Error raised when a mem operation like `mem_take` is used on a value
+    /// which doesn't support it.
+    #[error("`{op}` is not supported on `{actual}`")]
+    UnsupportedMemOperation {
+        /// The operation.
+        op: &'static str,
+        /// The type of the value.
+        actual: TypeInfo,
+    },
+    /// Tried to resume an execution with a value while it isn't suspended on
+    /// a `yield` or `yield_host(..)`.
+    #[error("execution is not suspended")]
+    NotSuspended,
 }
 
 impl VmErrorKind {
@@ -418,4 +449,63 @@ impl VmErrorKind {
             kind => (kind, None),
         }
     }
+
+    /// Get the stable code identifying the kind of error, like `R0001`.
+    ///
+    /// Unwound errors use the code of the error they wrap. Codes are never
+    /// reused or changed once assigned, new kinds of errors are given new
+    /// codes.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Unwound { kind, .. } => kind.code(),
+            Self::Panic { .. } => "R0002",
+            Self::NoRunningVm => "R0003",
+            Self::Halted { .. } => "R0004",
+            Self::FormatError => "R0005",
+            Self::StackError { .. } => "R0006",
+            Self::Overflow => "R0007",
+            Self::Underflow => "R0008",
+            Self::DivideByZero => "R0009",
+            Self::MissingFunction { .. } => "R0010",
+            Self::MissingInstanceFunction { .. } => "R0011",
+            Self::RecursionLimit { .. } => "R0012",
+            Self::MemoryLimitExceeded { .. } => "R0013",
+            Self::IpOutOfBounds => "R0014",
+            Self::UnsupportedAwait { .. } => "R0015",
+            Self::UnsupportedBinaryOperation { .. } => "R0016",
+            Self::UnsupportedUnaryOperation { .. } => "R0017",
+            Self::MissingProtocol { .. } => "R0018",
+            Self::MissingStaticString { .. } => "R0019",
+            Self::MissingStaticObjectKeys { .. } => "R0020",
+            Self::BadArgumentCount { .. } => "R0021",
+            Self::BadArgumentType { .. } => "R0022",
+            Self::BadArgument { .. } => "R0023",
+            Self::BadReturn { .. } => "R0024",
+            Self::UnsupportedIndexSet { .. } => "R0025",
+            Self::UnsupportedIndexGet { .. } => "R0026",
+            Self::UnsupportedTupleIndexGet { .. } => "R0027",
+            Self::UnsupportedTupleIndexSet { .. } => "R0028",
+            Self::UnsupportedObjectSlotIndexGet { .. } => "R0029",
+            Self::UnsupportedIs { .. } => "R0030",
+            Self::UnsupportedCallFn { .. } => "R0031",
+            Self::ObjectIndexMissing { .. } => "R0032",
+            Self::MissingIndex { .. } => "R0033",
+            Self::MissingField { .. } => "R0034",
+            Self::UnsupportedUnwrap { .. } => "R0035",
+            Self::UnsupportedUnwrapNone => "R0036",
+            Self::UnsupportedUnwrapErr { .. } => "R0037",
+            Self::UnsupportedIsValueOperand { .. } => "R0038",
+            Self::GeneratorComplete => "R0039",
+            Self::AccessError { .. } => "R0040",
+            Self::Expected { .. } => "R0041",
+            Self::ExpectedAny { .. } => "R0042",
+            Self::ValueToIntegerCoercionError { .. } => "R0043",
+            Self::IntegerToValueCoercionError { .. } => "R0044",
+            Self::ExpectedTupleLength { .. } => "R0045",
+            Self::IterationError => "R0046",
+            Self::UnsupportedHash { .. } => "R0047",
+            Self::UnsupportedMemOperation { .. } => "R0048",
+            Self::NotSuspended => "R0049",
+        }
+    }
 }
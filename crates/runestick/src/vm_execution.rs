@@ -3,12 +3,34 @@ use crate::{GeneratorState, Value, Vm, VmError, VmErrorKind, VmHalt, VmHaltInfo}
 /// The execution environment for a virtual machine.
 pub struct VmExecution {
     vms: Vec<Vm>,
+    /// The virtual machine the execution was started with, once the
+    /// execution has completed.
+    completed: Option<Vm>,
+    /// If the execution drives a generator or a stream, in which case it
+    /// can't yield to the host.
+    generator: bool,
+    /// If the execution is suspended on a `yield` or `yield_host(..)`, and is
+    /// expecting a value to resume with.
+    suspended: bool,
 }
 
 impl VmExecution {
     /// Construct an execution from a virtual machine.
     pub(crate) fn of(vm: Vm) -> Self {
-        Self { vms: vec![vm] }
+        Self {
+            vms: vec![vm],
+            completed: None,
+            generator: false,
+            suspended: false,
+        }
+    }
+
+    /// Construct an execution which drives a generator or a stream.
+    pub(crate) fn of_generator(vm: Vm) -> Self {
+        Self {
+            generator: true,
+            ..Self::of(vm)
+        }
     }
 
     /// Get the current virtual machine.
@@ -31,6 +53,24 @@ impl VmExecution {
         }
     }
 
+    /// Convert the execution back into the virtual machine it was started
+    /// with.
+    ///
+    /// Use [Vm::reset] before reusing it for another call if the execution
+    /// didn't run to completion.
+    pub fn into_vm(mut self) -> Result<Vm, VmError> {
+        if let Some(vm) = self.completed {
+            return Ok(vm);
+        }
+
+        self.vms.truncate(1);
+
+        match self.vms.pop() {
+            Some(vm) => Ok(vm),
+            None => Err(VmError::from(VmErrorKind::NoRunningVm)),
+        }
+    }
+
     /// Run the given task to completion asynchronously.
     pub async fn async_complete(&mut self) -> Result<Value, VmError> {
         match self.async_resume().await? {
@@ -55,6 +95,9 @@ impl VmExecution {
 
     /// Continue executing the current execution.
     pub async fn async_resume(&mut self) -> Result<GeneratorState, VmError> {
+        let generator = self.generator;
+        self.suspended = false;
+
         loop {
             let len = self.vms.len();
             let vm = self.vm_mut()?;
@@ -69,7 +112,16 @@ impl VmExecution {
                     vm_call.into_execution(self)?;
                     continue;
                 }
-                VmHalt::Yielded => return Ok(GeneratorState::Yielded(vm.stack_mut().pop()?)),
+                VmHalt::Yielded => {
+                    let value = vm.stack_mut().pop()?;
+                    self.suspended = true;
+                    return Ok(GeneratorState::Yielded(value));
+                }
+                VmHalt::YieldedHost if !generator => {
+                    let value = vm.stack_mut().pop()?;
+                    self.suspended = true;
+                    return Ok(GeneratorState::Yielded(value));
+                }
                 halt => {
                     return Err(VmError::from(VmErrorKind::Halted {
                         halt: halt.into_info(),
@@ -80,7 +132,7 @@ impl VmExecution {
             if len == 1 {
                 let value = vm.stack_mut().pop()?;
                 debug_assert!(vm.stack().is_empty(), "the final vm should be empty");
-                self.vms.clear();
+                self.completed = self.vms.pop();
                 return Ok(GeneratorState::Complete(value));
             }
 
@@ -90,6 +142,9 @@ impl VmExecution {
 
     /// Continue executing the current execution.
     pub fn resume(&mut self) -> Result<GeneratorState, VmError> {
+        let generator = self.generator;
+        self.suspended = false;
+
         loop {
             let len = self.vms.len();
             let vm = self.vm_mut()?;
@@ -100,7 +155,16 @@ impl VmExecution {
                     vm_call.into_execution(self)?;
                     continue;
                 }
-                VmHalt::Yielded => return Ok(GeneratorState::Yielded(vm.stack_mut().pop()?)),
+                VmHalt::Yielded => {
+                    let value = vm.stack_mut().pop()?;
+                    self.suspended = true;
+                    return Ok(GeneratorState::Yielded(value));
+                }
+                VmHalt::YieldedHost if !generator => {
+                    let value = vm.stack_mut().pop()?;
+                    self.suspended = true;
+                    return Ok(GeneratorState::Yielded(value));
+                }
                 halt => {
                     return Err(VmError::from(VmErrorKind::Halted {
                         halt: halt.into_info(),
@@ -111,7 +175,7 @@ impl VmExecution {
             if len == 1 {
                 let value = vm.stack_mut().pop()?;
                 debug_assert!(vm.stack().is_empty(), "the final vm should be empty");
-                self.vms.clear();
+                self.completed = self.vms.pop();
                 return Ok(GeneratorState::Complete(value));
             }
 
@@ -119,6 +183,26 @@ impl VmExecution {
         }
     }
 
+    /// Resume an execution which yielded with the given value, which becomes
+    /// the result of the `yield` or `yield_host(..)` expression that halted
+    /// it.
+    ///
+    /// Errors with [VmErrorKind::NotSuspended] unless the execution is
+    /// currently suspended after having yielded.
+    pub fn resume_with(&mut self, value: Value) -> Result<GeneratorState, VmError> {
+        self.push_resume_value(value)?;
+        self.resume()
+    }
+
+    /// Resume an execution which yielded with the given value, with support
+    /// for async functions.
+    ///
+    /// See [resume_with][Self::resume_with].
+    pub async fn async_resume_with(&mut self, value: Value) -> Result<GeneratorState, VmError> {
+        self.push_resume_value(value)?;
+        self.async_resume().await
+    }
+
     /// Run the execution for one step.
     pub async fn step(&mut self) -> Result<Option<Value>, VmError> {
         let len = self.vms.len();
@@ -152,6 +236,17 @@ impl VmExecution {
         Ok(None)
     }
 
+    /// Push the value a suspended execution is resumed with.
+    fn push_resume_value(&mut self, value: Value) -> Result<(), VmError> {
+        if !self.suspended {
+            return Err(VmError::from(VmErrorKind::NotSuspended));
+        }
+
+        self.vm_mut()?.stack_mut().push(value);
+        self.suspended = false;
+        Ok(())
+    }
+
     /// Push a virtual machine state onto the execution.
     pub(crate) fn push_vm(&mut self, vm: Vm) {
         self.vms.push(vm);
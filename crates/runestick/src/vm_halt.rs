@@ -10,6 +10,9 @@ pub enum VmHalt {
     Limited,
     /// The virtual machine yielded.
     Yielded,
+    /// The virtual machine yielded to the host, suspending the whole
+    /// execution.
+    YieldedHost,
     /// The virtual machine awaited on the given future.
     Awaited(Awaited),
     /// Call into a new virtual machine.
@@ -23,6 +26,7 @@ impl VmHalt {
             Self::Exited => VmHaltInfo::Exited,
             Self::Limited => VmHaltInfo::Limited,
             Self::Yielded => VmHaltInfo::Yielded,
+            Self::YieldedHost => VmHaltInfo::YieldedHost,
             Self::Awaited(..) => VmHaltInfo::Awaited,
             Self::VmCall(..) => VmHaltInfo::VmCall,
         }
@@ -38,6 +42,8 @@ pub enum VmHaltInfo {
     Limited,
     /// The virtual machine yielded.
     Yielded,
+    /// The virtual machine yielded to the host.
+    YieldedHost,
     /// The virtual machine awaited on the given future.
     Awaited,
     /// Received instruction to push the inner virtual machine.
@@ -50,6 +56,7 @@ impl fmt::Display for VmHaltInfo {
             Self::Exited => write!(f, "exited"),
             Self::Limited => write!(f, "limited"),
             Self::Yielded => write!(f, "yielded"),
+            Self::YieldedHost => write!(f, "yielded to host"),
             Self::Awaited => write!(f, "awaited"),
             Self::VmCall => write!(f, "calling into other vm"),
         }